@@ -1982,6 +1982,10 @@ pub struct ProfileCreate {
 
     /// Indicates if 3ds challenge is forced
     pub force_3ds_challenge: Option<bool>,
+
+    /// Payment method types accepted for the payments of the profile. All payment method types are accepted if not set, an empty list removes the restriction
+    #[schema(value_type = Option<Vec<PaymentMethodType>>, example = json!(["credit", "debit"]))]
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[nutype::nutype(
@@ -2256,6 +2260,10 @@ pub struct ProfileResponse {
 
     /// Indicates if 3ds challenge is forced
     pub force_3ds_challenge: bool,
+
+    /// Payment method types accepted for the payments of the profile. All payment method types are accepted if not set
+    #[schema(value_type = Option<Vec<PaymentMethodType>>, example = json!(["credit", "debit"]))]
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[cfg(feature = "v2")]
@@ -2526,6 +2534,10 @@ pub struct ProfileUpdate {
 
     /// Indicates if 3ds challenge is forced
     pub force_3ds_challenge: Option<bool>,
+
+    /// Payment method types accepted for the payments of the profile. All payment method types are accepted if not set, an empty list removes the restriction
+    #[schema(value_type = Option<Vec<PaymentMethodType>>, example = json!(["credit", "debit"]))]
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[cfg(feature = "v2")]
@@ -0,0 +1,46 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+
+use crate::enums;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ConnectorCircuitBreakerRequest {
+    pub connector: enums::Connector,
+}
+
+/// State of the circuit breaker of a connector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitBreakerState {
+    /// Payments are routed to the connector
+    Closed,
+    /// Payments to the connector are short-circuited until the cooldown elapses
+    Open,
+    /// A single probe payment is let through to check if the connector has recovered
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConnectorCircuitBreakerResponse {
+    pub connector: enums::Connector,
+    pub state: CircuitBreakerState,
+    /// Number of connector calls made in the current window
+    pub total_requests: i64,
+    /// Number of failed connector calls in the current window
+    pub failed_requests: i64,
+    /// Time at which the circuit was opened, if it is currently open
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub opened_at: Option<PrimitiveDateTime>,
+}
+
+impl ApiEventMetric for ConnectorCircuitBreakerRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorCircuitBreakerResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,37 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+
+use crate::enums;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ConnectorLatencyRequest {
+    pub connector: enums::Connector,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConnectorLatencyResponse {
+    pub connector: enums::Connector,
+    /// Weight of the recorded response times, after they have decayed over time
+    pub sample_count: f64,
+    /// Median response time of the connector, in milliseconds
+    pub p50_in_millis: Option<u64>,
+    /// 90th percentile response time of the connector, in milliseconds
+    pub p90_in_millis: Option<u64>,
+    /// 99th percentile response time of the connector, in milliseconds
+    pub p99_in_millis: Option<u64>,
+    /// Time at which a response time of the connector was last recorded
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_recorded_at: Option<PrimitiveDateTime>,
+}
+
+impl ApiEventMetric for ConnectorLatencyRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorLatencyResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -159,6 +159,9 @@ pub struct CustomerResponse {
     /// The identifier for the default payment method.
     #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
     pub default_payment_method_id: Option<String>,
+    /// The identifier of the customer into which this customer has been merged
+    #[schema(value_type = Option<String>, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merged_into_customer_id: Option<id_type::CustomerId>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -372,3 +375,42 @@ pub struct CustomerUpdateRequestInternal {
     pub id: id_type::GlobalCustomerId,
     pub request: CustomerUpdateRequest,
 }
+
+/// The customers to be merged. The payment methods, mandates and payments of the source customer
+/// are moved to the target customer, and the source customer is marked as merged.
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerMergeRequest {
+    /// The identifier of the customer to be merged
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub source_customer_id: id_type::CustomerId,
+    /// The identifier of the customer into which the source customer is merged
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_abcdefgh12345678ijklmnop")]
+    pub target_customer_id: id_type::CustomerId,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerMergeResponse {
+    /// The identifier of the customer which has been merged
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub source_customer_id: id_type::CustomerId,
+    /// The identifier of the customer into which the source customer has been merged
+    #[schema(value_type = String, max_length = 64, example = "cus_abcdefgh12345678ijklmnop")]
+    pub target_customer_id: id_type::CustomerId,
+    /// The default payment method of the target customer after the merge. The default payment
+    /// method of the target customer is retained if set, else the default payment method of the
+    /// source customer is used.
+    #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
+    pub default_payment_method_id: Option<String>,
+    /// The number of payment methods moved to the target customer
+    #[schema(example = 2)]
+    pub payment_methods_merged: usize,
+    /// The number of mandates moved to the target customer
+    #[schema(example = 1)]
+    pub mandates_merged: usize,
+    /// The number of payments moved to the target customer
+    #[schema(example = 5)]
+    pub payments_merged: usize,
+}
@@ -216,6 +216,16 @@ pub struct SubmitEvidenceRequest {
     pub uncategorized_text: Option<String>,
 }
 
+/// The response for submitting a bundle of evidence documents and text fields in a single call
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubmitEvidenceBundleResponse {
+    /// The dispute after the evidence was submitted
+    #[serde(flatten)]
+    pub dispute: DisputeResponse,
+    /// The evidence files attached to the dispute
+    pub evidence: Vec<DisputeEvidenceBlock>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteEvidenceRequest {
     /// Id of the dispute
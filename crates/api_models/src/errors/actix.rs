@@ -18,14 +18,20 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::BadRequest(_) => StatusCode::BAD_REQUEST,
             Self::DomainError(_) => StatusCode::OK,
+            Self::TooManyRequests(_, _) => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response_builder = actix_web::HttpResponseBuilder::new(self.status_code());
+        response_builder.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+
+        if let Self::TooManyRequests(_, Some(retry_after_in_secs)) = self {
+            response_builder.insert_header((header::RETRY_AFTER, retry_after_in_secs.to_string()));
+        }
+
+        response_builder.body(self.to_string())
     }
 }
@@ -97,6 +97,7 @@ pub enum ApiErrorResponse {
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
+    TooManyRequests(ApiError, #[serde(skip_serializing)] Option<u64>),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -126,7 +127,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -144,7 +146,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -159,7 +162,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_, _) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
@@ -66,6 +66,8 @@ impl_api_event_type!(
         PaymentMethodId,
         PaymentMethodCreate,
         PaymentLinkInitiateRequest,
+        SignedPaymentLinkResponse,
+        SignedPaymentLinkQuery,
         RetrievePaymentLinkResponse,
         MandateListConstraints,
         CreateFileResponse,
@@ -3,6 +3,8 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use crate::customers::{
     CustomerDeleteResponse, CustomerRequest, CustomerResponse, CustomerUpdateRequestInternal,
 };
+#[cfg(feature = "v1")]
+use crate::customers::{CustomerMergeRequest, CustomerMergeResponse};
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 impl ApiEventMetric for CustomerDeleteResponse {
@@ -73,3 +75,21 @@ impl ApiEventMetric for CustomerUpdateRequestInternal {
         })
     }
 }
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.source_customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.source_customer_id.clone(),
+        })
+    }
+}
@@ -2,7 +2,7 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
     DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
-    DisputesAggregateResponse, SubmitEvidenceRequest,
+    DisputesAggregateResponse, SubmitEvidenceBundleResponse, SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -26,6 +26,13 @@ impl ApiEventMetric for DisputeResponse {
         })
     }
 }
+impl ApiEventMetric for SubmitEvidenceBundleResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute.dispute_id.clone(),
+        })
+    }
+}
 impl ApiEventMetric for DeleteEvidenceRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Dispute {
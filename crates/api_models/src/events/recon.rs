@@ -2,7 +2,8 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use masking::PeekInterface;
 
 use crate::recon::{
-    ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest, VerifyTokenResponse,
+    ReconReportResponse, ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest,
+    VerifyTokenResponse,
 };
 
 impl ApiEventMetric for ReconUpdateMerchantRequest {
@@ -23,6 +24,12 @@ impl ApiEventMetric for ReconStatusResponse {
     }
 }
 
+impl ApiEventMetric for ReconReportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
 impl ApiEventMetric for VerifyTokenResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::User {
@@ -1,7 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::refunds::{
-    RefundAggregateResponse, RefundListFilters, RefundListMetaData, RefundListRequest,
+    RefundAggregateResponse, RefundBulkRequest, RefundBulkResponse, RefundEligibilityRequest,
+    RefundEligibilityResponse, RefundListFilters, RefundListMetaData, RefundListRequest,
     RefundListResponse, RefundManualUpdateRequest, RefundRequest, RefundResponse,
     RefundUpdateRequest, RefundsRetrieveRequest,
 };
@@ -39,6 +40,44 @@ impl ApiEventMetric for RefundResponse {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundEligibilityRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundEligibilityResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundBulkRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Refund {
+            payment_id: None,
+            refund_id: self.batch_key.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundBulkResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Refund {
+            payment_id: None,
+            refund_id: self.batch_key.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for RefundsRetrieveRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
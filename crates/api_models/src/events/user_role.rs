@@ -2,29 +2,73 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::user_role::{
     role::{
-        CreateRoleRequest, GetRoleRequest, GroupsAndResources, ListRolesAtEntityLevelRequest,
-        ListRolesRequest, RoleInfoResponseNew, RoleInfoWithGroupsResponse, RoleInfoWithParents,
-        UpdateRoleRequest,
+        ApplyRoleTemplateRequest, CloneRoleRequest, CompareRolesRequest, CompareRolesResponse,
+        CreatePermissionGroupRequest, CreateRoleRequest, CreateRoleTemplateRequest,
+        CustomPermissionGroupResponse, EffectivePermissionsResponse, GetRoleRequest,
+        GroupsAndResources, ListRoleAuditRequest, ListRolesAtEntityLevelRequest, ListRolesRequest,
+        PreviewRoleUpdateRequest, PreviewRoleUpdateResponse, RoleInfoResponseNew,
+        RoleInfoWithGroupsResponse, RoleInfoWithParents, RoleTemplateResponse, UpdateRoleRequest,
+        UpdateRoleTemplateRequest,
     },
-    AuthorizationInfoResponse, DeleteUserRoleRequest, ListUsersInEntityRequest,
-    UpdateUserRoleRequest,
+    AcceptInvitationResponse, AuthorizationInfoResponse, CheckRoleIntegrityRequest,
+    CheckRoleIntegrityResponse, CreateRoleAssignmentRuleRequest, DeleteUserRoleRequest,
+    ExportRbacRequest, GetAuthorizationInfoRequest, GetAuthorizationInfoResponse,
+    GetRoleInformationRequest, ImportRbacRequest, ImportRbacResponse, InviteUserByEmailRequest,
+    InviteUserByEmailResponse, ListRolesForUserRequest, ListRolesForUserResponse,
+    ListUsersInEntityPaginatedResponse, ListUsersInEntityRequest, ListUsersInLineageResponse,
+    RbacExport, ResendInvitationRequest, RoleAssignmentRuleResponse, UndoDeleteUserRoleRequest,
+    UpdateUserRoleRequest, WhoAmIResponse,
 };
 
 common_utils::impl_api_event_type!(
     Miscellaneous,
     (
         GetRoleRequest,
+        AcceptInvitationResponse,
         AuthorizationInfoResponse,
+        GetAuthorizationInfoRequest,
+        GetAuthorizationInfoResponse,
+        GetRoleInformationRequest,
         UpdateUserRoleRequest,
         DeleteUserRoleRequest,
+        UndoDeleteUserRoleRequest,
         CreateRoleRequest,
+        CreatePermissionGroupRequest,
+        CustomPermissionGroupResponse,
+        CloneRoleRequest,
+        CompareRolesRequest,
+        CompareRolesResponse,
         UpdateRoleRequest,
+        PreviewRoleUpdateRequest,
+        PreviewRoleUpdateResponse,
         ListRolesAtEntityLevelRequest,
         RoleInfoResponseNew,
         RoleInfoWithGroupsResponse,
         ListUsersInEntityRequest,
+        ListUsersInEntityPaginatedResponse,
+        ListUsersInLineageResponse,
+        ListRolesForUserRequest,
+        ListRolesForUserResponse,
+        CheckRoleIntegrityRequest,
+        CheckRoleIntegrityResponse,
+        ExportRbacRequest,
+        RbacExport,
+        ImportRbacRequest,
+        ImportRbacResponse,
+        ResendInvitationRequest,
+        InviteUserByEmailRequest,
+        InviteUserByEmailResponse,
         ListRolesRequest,
         GroupsAndResources,
+        EffectivePermissionsResponse,
+        WhoAmIResponse,
+        ListRoleAuditRequest,
+        CreateRoleTemplateRequest,
+        UpdateRoleTemplateRequest,
+        RoleTemplateResponse,
+        ApplyRoleTemplateRequest,
+        CreateRoleAssignmentRuleRequest,
+        RoleAssignmentRuleResponse,
         RoleInfoWithParents
     )
 );
@@ -44,12 +44,30 @@ pub struct SupportedPaymentMethod {
     pub supported_currencies: Option<HashSet<Currency>>,
 }
 
+/// Features supported by the connector for at least one of its payment method types
+#[derive(Debug, Clone, ToSchema, Serialize)]
+pub struct ConnectorCapabilities {
+    /// Indicates whether three_ds card payments are supported
+    pub three_ds: FeatureStatus,
+    /// Indicates whether mandates can be set up and used for recurring payments
+    pub mandates: FeatureStatus,
+    /// Indicates whether payments can be refunded
+    pub refunds: FeatureStatus,
+    /// Indicates whether payments can be authorized and captured later
+    pub manual_capture: FeatureStatus,
+    /// Indicates whether an authorized payment can be captured partially in multiple captures
+    pub partial_capture: FeatureStatus,
+    /// Indicates whether the authorized amount of a payment can be incremented
+    pub incremental_authorization: FeatureStatus,
+}
+
 #[derive(Debug, ToSchema, Serialize)]
 pub struct ConnectorFeatureMatrixResponse {
     pub name: String,
     pub display_name: Option<String>,
     pub description: Option<String>,
     pub category: Option<PaymentConnectorCategory>,
+    pub capabilities: ConnectorCapabilities,
     pub supported_payment_methods: Vec<SupportedPaymentMethod>,
     pub supported_webhook_flows: Option<Vec<EventClass>>,
 }
@@ -64,3 +82,4 @@ pub struct FeatureMatrixListResponse {
 
 impl common_utils::events::ApiEventMetric for FeatureMatrixListResponse {}
 impl common_utils::events::ApiEventMetric for FeatureMatrixRequest {}
+impl common_utils::events::ApiEventMetric for ConnectorFeatureMatrixResponse {}
@@ -4,8 +4,10 @@ pub mod api_keys;
 pub mod apple_pay_certificates_migration;
 pub mod blocklist;
 pub mod cards_info;
+pub mod circuit_breaker;
 pub mod conditional_configs;
 pub mod connector_enums;
+pub mod connector_latency;
 pub mod connector_onboarding;
 pub mod consts;
 pub mod currency;
@@ -102,6 +102,12 @@ pub struct PaymentMethodCreate {
     /// this is added only for cards migration api and is skipped during deserialization of the
     /// payment method create request as it should not be passed in the request
     pub network_transaction_id: Option<String>,
+
+    /// By default, if the same card is already saved for the customer, the existing payment method is returned.
+    /// Set this to true to save the payment method as a new entry instead.
+    #[serde(default)]
+    #[schema(default = false, example = false)]
+    pub force_new_entry: bool,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -143,6 +149,12 @@ pub struct PaymentMethodCreate {
     /// The network tokenization configuration if applicable
     #[schema(value_type = Option<NetworkTokenization>)]
     pub network_tokenization: Option<common_types::payment_methods::NetworkTokenization>,
+
+    /// By default, if the same payment method is already saved for the customer, the existing payment method is returned.
+    /// Set this to true to save the payment method as a new entry instead.
+    #[serde(default)]
+    #[schema(default = false, example = false)]
+    pub force_new_entry: bool,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -435,6 +447,7 @@ impl PaymentMethodCreate {
             #[cfg(feature = "payouts")]
             wallet: payment_method_migrate.wallet.clone(),
             network_transaction_id: payment_method_migrate.network_transaction_id.clone(),
+            force_new_entry: false,
         }
     }
 }
@@ -1907,6 +1920,43 @@ pub struct PaymentMethodDeleteResponse {
     pub id: id_type::GlobalPaymentMethodId,
 }
 
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct PaymentMethodContextsResponse {
+    /// The unique identifier of the Payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIg")]
+    pub payment_method_id: String,
+
+    /// The merchant connector accounts and currencies with which the payment method can be reused
+    pub contexts: Vec<PaymentMethodContext>,
+}
+
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct PaymentMethodContext {
+    /// The merchant connector account with which the payment method was saved
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The connector of the merchant connector account
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector: api_enums::Connector,
+
+    /// The currency in which the payment method was saved with the connector
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// Whether the connector only allows the payment method to be reused in the currency it was saved in
+    #[schema(example = false)]
+    pub is_currency_bound: bool,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Debug, serde::Serialize, ToSchema)]
 pub struct CustomerDefaultPaymentMethodResponse {
@@ -2511,6 +2561,7 @@ impl
             wallet: None,
             payment_method_data: None,
             network_transaction_id: record.original_transaction_id,
+            force_new_entry: false,
         })
     }
 }
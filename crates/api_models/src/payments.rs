@@ -878,6 +878,12 @@ pub struct PaymentsRequest {
     #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest, PaymentsConfirmRequest)]
     pub capture_on: Option<PrimitiveDateTime>,
 
+    /// Number of hours after creation at which the payment is captured automatically, if it has not been captured manually by then.
+    /// Can be used only when `capture_method` is `manual`
+    #[schema(example = 24)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub capture_delay_in_hours: Option<u16>,
+
     /// Whether to confirm the payment (if applicable). It can be used to completely process a payment by attaching a payment method, setting `confirm=true` and `capture_method = automatic` in the *Payments/Create API* request itself.
     #[schema(default = false, example = true)]
     pub confirm: Option<bool>,
@@ -939,6 +945,10 @@ pub struct PaymentsRequest {
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
 
+    /// Use the default payment method of the customer for the payment, instead of providing the `payment_token` or the `payment_method_data`
+    #[schema(example = true)]
+    pub use_default_payment_method: Option<bool>,
+
     /// This is used along with the payment_token field while collecting during saved card payments. This field will be deprecated soon, use the payment_method_data.card_token object instead
     #[schema(value_type = Option<String>, deprecated)]
     #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest, PaymentsConfirmRequest)]
@@ -947,11 +957,11 @@ pub struct PaymentsRequest {
     /// The shipping address for the payment
     pub shipping: Option<Address>,
 
-    /// For non-card charges, you can use this value as the complete description that appears on your customers’ statements. Must contain at least one letter, maximum 22 characters.
+    /// For non-card charges, you can use this value as the complete description that appears on your customers’ statements. Must contain at least one letter, and only latin characters excluding `<`, `>`, `\`, `'`, `"` and `*`. The maximum length is enforced by the connector the payment is routed to, it is 22 characters for Stripe, Adyen and Trustpay. The statement descriptor is ignored by connectors that do not support it.
     #[schema(max_length = 255, example = "Hyperswitch Router")]
     pub statement_descriptor_name: Option<String>,
 
-    /// Provides information about a card payment that customers see on their statements. Concatenated with the prefix (shortened descriptor) or statement descriptor that’s set on the account to form the complete statement descriptor. Maximum 22 characters for the concatenated descriptor.
+    /// Provides information about a card payment that customers see on their statements. Concatenated with the prefix (shortened descriptor) or statement descriptor that’s set on the account to form the complete statement descriptor. Must contain only latin characters excluding `<`, `>`, `\`, `'`, `"` and `*`. The maximum length is enforced by the connector the payment is routed to, it is 22 characters for Stripe, Adyen and Trustpay. The suffix is ignored by connectors that do not support statement descriptors.
     #[schema(max_length = 255, example = "Payment for shoes purchase")]
     pub statement_descriptor_suffix: Option<String>,
 
@@ -5002,6 +5012,9 @@ pub struct PaymentsResponse {
     /// Method through which card was discovered
     #[schema(value_type = Option<CardDiscovery>, example = "manual")]
     pub card_discovery: Option<enums::CardDiscovery>,
+
+    /// Details of the conversion of the payment amount to the settlement currency of the merchant
+    pub fx_details: Option<PaymentFxDetailsResponse>,
 }
 
 #[cfg(feature = "v2")]
@@ -5818,6 +5831,34 @@ pub struct PaymentListResponse {
     /// The list of payments response objects
     pub data: Vec<PaymentsListResponseItem>,
 }
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentFxDetailsResponse {
+    /// Amount of the payment in the currency of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: enums::Currency,
+    /// Amount of the payment in the settlement currency of the merchant
+    #[schema(value_type = i64, example = 7085)]
+    pub settlement_amount: MinorUnit,
+    /// The settlement currency of the merchant
+    #[schema(value_type = Currency, example = "USD")]
+    pub settlement_currency: enums::Currency,
+    /// Exchange rate from the currency of the payment to the settlement currency, as a decimal
+    /// string
+    #[schema(example = "1.0834")]
+    pub exchange_rate: String,
+    /// Provider from which the exchange rate was fetched
+    #[schema(example = "forex_api")]
+    pub provider: String,
+    /// Time at which the exchange rate was fetched
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub rate_fetched_at: PrimitiveDateTime,
+}
+
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
 pub struct IncrementalAuthorizationResponse {
     /// The unique identifier of authorization
@@ -5847,6 +5888,26 @@ pub struct PaymentListResponseV2 {
     pub data: Vec<PaymentsResponse>,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMetadataSearchRequest {
+    /// The metadata key by which payments are searched, it has to be one of the indexed metadata keys
+    #[schema(example = "udf1")]
+    pub key: String,
+    /// The value of the metadata key, it is coerced to the `value_type` before searching
+    #[schema(value_type = Object)]
+    pub value: serde_json::Value,
+    /// The type of the value stored against the metadata key
+    #[schema(value_type = MetadataValueType)]
+    pub value_type: common_utils::metadata::MetadataValueType,
+    /// The limit on the number of objects. The default limit is 10 and max limit is 100
+    #[serde(default = "default_payments_list_limit")]
+    pub limit: u32,
+    /// The starting point within a list of objects
+    pub offset: Option<u32>,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct PaymentListFilterConstraints {
@@ -6708,6 +6769,13 @@ pub struct PaymentProcessingDetails {
     pub payment_processing_certificate: Secret<String>,
     #[schema(value_type = String)]
     pub payment_processing_certificate_key: Secret<String>,
+    /// Payment processing certificate being rotated out, tokens encrypted with it can still be
+    /// decrypted until it is removed
+    #[schema(value_type = Option<String>)]
+    pub previous_payment_processing_certificate: Option<Secret<String>>,
+    /// Private key of the payment processing certificate being rotated out
+    #[schema(value_type = Option<String>)]
+    pub previous_payment_processing_certificate_key: Option<Secret<String>>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -7235,6 +7303,10 @@ pub struct PaymentsCancelRequest {
     pub payment_id: id_type::PaymentId,
     /// The reason for the payment cancel
     pub cancellation_reason: Option<String>,
+    /// The amount of the authorization to be released, for connectors which support a partial
+    /// void. The entire authorization is voided if the amount is not provided.
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub amount_to_void: Option<MinorUnit>,
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>, deprecated)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
@@ -7312,6 +7384,195 @@ pub struct PaymentsManualUpdateResponse {
     pub connector_transaction_id: Option<String>,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
+pub struct PaymentsRawConnectorPayloadsRequest {
+    /// The identifier for the payment
+    pub payment_id: id_type::PaymentId,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone)]
+pub struct PaymentsRawConnectorPayloadsResponse {
+    /// The identifier for the payment
+    pub payment_id: id_type::PaymentId,
+    /// The masked requests sent to and responses received from the connectors for the payment, oldest first
+    pub payloads: Vec<serde_json::Value>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
+pub struct PaymentsTimelineRequest {
+    /// The identifier for the payment
+    pub payment_id: id_type::PaymentId,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsTimelineResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The events of the payment, oldest first
+    pub events: Vec<PaymentTimelineEvent>,
+}
+
+/// An event in the timeline of a payment. Request and response bodies and payment method details are
+/// never part of the event.
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentTimelineEvent {
+    /// The type of the event
+    pub event_type: PaymentTimelineEventType,
+    /// The time at which the event occurred
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The attempt to which the event belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt_id: Option<String>,
+    /// The connector involved in the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector: Option<String>,
+    /// The status of the payment or the attempt after the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// The error code of the attempt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// The error message of the attempt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    /// The flow of the connector call, like Authorize or PSync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow: Option<String>,
+    /// The HTTP status code returned by the connector
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_status_code: Option<u16>,
+    /// The time taken by the connector call in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_in_millis: Option<u64>,
+    /// The identifier of the outgoing webhook event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_event_id: Option<String>,
+    /// The type of the outgoing webhook event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_event_type: Option<api_enums::EventType>,
+    /// Whether the outgoing webhook was delivered to the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_webhook_delivered: Option<bool>,
+}
+
+impl PaymentTimelineEvent {
+    pub fn new(event_type: PaymentTimelineEventType, created_at: PrimitiveDateTime) -> Self {
+        Self {
+            event_type,
+            created_at,
+            attempt_id: None,
+            connector: None,
+            status: None,
+            error_code: None,
+            error_message: None,
+            flow: None,
+            http_status_code: None,
+            latency_in_millis: None,
+            webhook_event_id: None,
+            webhook_event_type: None,
+            is_webhook_delivered: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentTimelineEventType {
+    /// The payment was created
+    PaymentCreated,
+    /// An attempt was made to process the payment
+    AttemptCreated,
+    /// The status of an attempt was last updated
+    AttemptStatusUpdated,
+    /// A request was sent to the connector, only recorded for merchants who store raw connector payloads
+    ConnectorCall,
+    /// A webhook was received from the connector, only recorded when API events are stored in analytics
+    IncomingWebhook,
+    /// An outgoing webhook was sent to the merchant
+    OutgoingWebhook,
+    /// The status of the payment after an API call on it, only recorded when API events are stored in analytics
+    PaymentStatusUpdated,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsVerifyRequest {
+    /// The identifier for the customer to whom the card is saved once it is verified
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The currency in which the card is verified
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The connector with which the card is verified
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector: api_enums::Connector,
+
+    /// The card to be verified
+    pub card: Card,
+
+    /// The billing details of the card holder
+    pub billing: Option<Address>,
+
+    /// The customer's acceptance for the card to be saved and used for future payments
+    #[schema(value_type = CustomerAcceptance)]
+    pub customer_acceptance: CustomerAcceptance,
+
+    /// The business profile with which the card is verified
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsVerifyResponse {
+    /// The identifier for the payment made to verify the card
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+
+    /// The status of the payment made to verify the card
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+
+    /// Whether the card was verified by the connector
+    pub is_verified: bool,
+
+    /// The method with which the card was verified
+    pub verification_method: CardVerificationMethod,
+
+    /// The connector with which the card was verified
+    pub connector: Option<String>,
+
+    /// The identifier for the saved card, which is marked as verified
+    pub payment_method_id: Option<String>,
+
+    /// The error code returned by the connector if the card could not be verified
+    pub error_code: Option<String>,
+
+    /// The error message returned by the connector if the card could not be verified
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardVerificationMethod {
+    /// A zero amount authorization is made with the connector
+    ZeroAmountAuthorization,
+    /// A small amount is authorized with the connector and then voided, for connectors which do not
+    /// support zero amount authorizations
+    AuthorizationAndVoid,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub enum ThreeDsCompletionIndicator {
     /// 3DS method successfully completed
@@ -7843,6 +8104,27 @@ pub struct PaymentLinkInitiateRequest {
     pub payment_id: id_type::PaymentId,
 }
 
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SignedPaymentLinkResponse {
+    /// Identifier of the payment the link is tied to
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// Link to the hosted payment page, which can be opened only once. The link does not contain any detail of the payment
+    pub link: String,
+    /// Date and time after which the link can no longer be opened
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SignedPaymentLinkQuery {
+    /// Unix timestamp after which the link can no longer be opened
+    pub expires_at: i64,
+    /// Signature of the link token and the expiry of the link
+    pub signature: String,
+}
+
 #[derive(Debug, serde::Serialize)]
 #[serde(untagged)]
 pub enum PaymentLinkData {
@@ -1,4 +1,4 @@
-use common_utils::{id_type, pii};
+use common_utils::{id_type, pii, types::MinorUnit};
 use masking::Secret;
 
 use crate::enums;
@@ -26,3 +26,92 @@ pub struct VerifyTokenResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acl: Option<String>,
 }
+
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconReportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconTransactionType {
+    Payment,
+    Refund,
+}
+
+/// Outcome of matching a transaction against the settlement records of the connector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconRecordStatus {
+    /// The settlement record matches the transaction
+    Matched,
+    /// The settled amount differs from the amount of the transaction
+    AmountMismatch,
+    /// The settled currency differs from the currency of the transaction
+    CurrencyMismatch,
+    /// The transaction was settled by the connector but has not succeeded
+    StatusMismatch,
+    /// The transaction succeeded but is missing in the settlement records of the connector
+    MissingInConnectorSettlement,
+    /// The settlement record does not match any transaction of the merchant in the date range
+    MissingInHyperswitch,
+    /// The transaction appears more than once in the settlement records of the connector
+    DuplicateSettlementRecord,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ReconReportQuery {
+    #[serde(flatten)]
+    pub time_range: common_utils::types::TimeRange,
+    #[serde(default)]
+    pub format: ReconReportFormat,
+}
+
+/// A row of the settlement file of a connector
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorSettlementRecord {
+    pub connector: enums::Connector,
+    pub transaction_type: ReconTransactionType,
+    pub connector_transaction_id: String,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReconReportRecord {
+    pub status: ReconRecordStatus,
+    pub transaction_type: ReconTransactionType,
+    pub connector: Option<String>,
+    pub connector_transaction_id: Option<String>,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub refund_id: Option<String>,
+    pub transaction_status: Option<String>,
+    pub amount: Option<MinorUnit>,
+    pub currency: Option<enums::Currency>,
+    pub settled_amount: Option<MinorUnit>,
+    pub settled_currency: Option<enums::Currency>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ReconReportSummary {
+    pub total_count: usize,
+    pub matched: usize,
+    pub amount_mismatch: usize,
+    pub currency_mismatch: usize,
+    pub status_mismatch: usize,
+    pub missing_in_connector_settlement: usize,
+    pub missing_in_hyperswitch: usize,
+    pub duplicate_settlement_record: usize,
+}
+
+/// Reconciliation report in the JSON format, which is streamed with the summary following the
+/// records
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReconReportResponse {
+    pub time_range: common_utils::types::TimeRange,
+    pub records: Vec<ReconReportRecord>,
+    pub summary: ReconReportSummary,
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::payments::AmountFilter;
+use super::payments::{AmountFilter, Order};
 use crate::{
     admin::{self, MerchantConnectorInfo},
     enums,
@@ -105,6 +105,152 @@ pub struct RefundsRetrieveBody {
     pub force_sync: Option<bool>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RefundEligibilityRequest {
+    /// The identifier for the payment
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct RefundEligibilityResponse {
+    /// The identifier for the payment
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "pay_mbabizu24mvu3mela5njyhpit4",
+        value_type = String,
+    )]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// Whether a refund can be initiated against the payment
+    pub is_refundable: bool,
+    /// The reason why a refund can not be initiated against the payment
+    pub ineligibility_reason: Option<String>,
+    /// The amount that can still be refunded, after accounting for the previous refunds
+    #[schema(value_type = i64, minimum = 0, example = 6540)]
+    pub refundable_amount: MinorUnit,
+    /// The amount of the previous refunds which have either succeeded or are still being processed
+    #[schema(value_type = i64, minimum = 0, example = 0)]
+    pub refunded_amount: MinorUnit,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency)]
+    pub currency: enums::Currency,
+    /// The connector through which the payment was processed
+    pub connector: String,
+    /// The constraints that apply to refunds against the payment
+    pub constraints: RefundEligibilityConstraints,
+}
+
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct RefundEligibilityConstraints {
+    /// Whether the connector supports refunds for the payment method used. This is not populated if the connector does not publish its supported features
+    pub connector_supports_refunds: Option<bool>,
+    /// The number of refunds that can still be initiated against the payment
+    pub remaining_refund_count: usize,
+    /// The time until which refunds can be initiated against the payment
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub refundable_until: PrimitiveDateTime,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBulkRequest {
+    /// Unique identifier for the batch. Retrying a batch with the same key does not refund the same payment again, as long as the refund idempotency window has not elapsed
+    #[schema(max_length = 64, example = "recall_2024_09_10")]
+    pub batch_key: String,
+
+    /// The payments to be refunded
+    pub items: Vec<RefundBulkItem>,
+
+    /// Reason for the refunds, applied to every refund in the batch
+    #[schema(max_length = 255, example = "Product recall")]
+    pub reason: Option<String>,
+
+    /// To indicate whether the refunds need to be instant or scheduled. Default value is instant
+    #[schema(default = "Instant", example = "Instant")]
+    pub refund_type: Option<RefundType>,
+
+    /// Metadata attached to every refund in the batch
+    #[schema(value_type  = Option<Object>, example = r#"{ "recall_id": "RC-1024" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Only validate whether the payments are eligible for a refund, without creating any refunds
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBulkItem {
+    /// The payment id against which refund is to be initiated
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "pay_mbabizu24mvu3mela5njyhpit4",
+        value_type = String,
+    )]
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// Amount to be refunded for the payment. If not provided, this will default to the full payment amount
+    #[schema(value_type = Option<i64> , minimum = 100, example = 6540)]
+    pub amount: Option<MinorUnit>,
+}
+
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct RefundBulkResponse {
+    /// Unique identifier for the batch
+    pub batch_key: String,
+    /// Whether the batch was only validated, without creating any refunds
+    pub dry_run: bool,
+    /// The number of payments in the batch
+    pub total_count: usize,
+    /// The number of payments which were refunded, or which are eligible for a refund in case of a dry run
+    pub success_count: usize,
+    /// The number of payments which could not be refunded, or which are not eligible for a refund in case of a dry run
+    pub failure_count: usize,
+    /// The result of each payment in the batch, in the order of the request
+    pub results: Vec<RefundBulkItemResponse>,
+}
+
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct RefundBulkItemResponse {
+    /// The payment id against which refund was to be initiated
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The outcome of the item
+    pub status: RefundBulkItemStatus,
+    /// The refund created against the payment
+    pub refund: Option<RefundResponse>,
+    /// The amount that would be refunded against the payment, in case of a dry run
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub refundable_amount: Option<MinorUnit>,
+    /// The code for the error, if the item failed
+    pub error_code: Option<String>,
+    /// The error message, if the item failed
+    pub error_message: Option<String>,
+}
+
+/// The outcome of a payment in a bulk refund
+#[derive(Debug, Clone, Copy, ToSchema, Serialize, Eq, PartialEq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundBulkItemStatus {
+    /// The refund was initiated against the payment
+    Processed,
+    /// The refund could not be initiated against the payment
+    Failed,
+    /// The payment is eligible for the refund, in case of a dry run
+    Eligible,
+    /// The payment is not eligible for the refund, in case of a dry run
+    Ineligible,
+}
+
+impl RefundBulkItemStatus {
+    pub fn is_success(self) -> bool {
+        matches!(self, Self::Processed | Self::Eligible)
+    }
+}
+
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 pub struct RefundsRetrieveRequest {
     /// Unique Identifier for the Refund. This is to ensure idempotency for multiple partial refund initiated against the same payment. If the identifiers is not defined by the merchant, this filed shall be auto generated and provide in the API response. It is recommended to generate uuid(v4) as the refund_id.
@@ -314,6 +460,8 @@ pub struct RefundListRequest {
     /// The list of refund statuses to filter refunds list
     #[schema(value_type = Option<Vec<RefundStatus>>)]
     pub refund_status: Option<Vec<enums::RefundStatus>>,
+    /// The order in which refunds list should be sorted, refunds with the same sort value are ordered by their refund id. Refunds are ordered by the time they were last modified, latest first, if not provided
+    pub order: Option<Order>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
@@ -278,6 +278,7 @@ pub enum RoutingAlgorithmKind {
     VolumeSplit,
     Advanced,
     Dynamic,
+    LowestLatency,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -300,6 +301,8 @@ pub enum RoutingAlgorithm {
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     #[schema(value_type=ProgramConnectorSelection)]
     Advanced(ast::Program<ConnectorSelection>),
+    /// Connectors ordered by their recent response times, fastest first
+    LowestLatency(Vec<RoutableConnectorChoice>),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -309,6 +312,7 @@ pub enum RoutingAlgorithmSerde {
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(ast::Program<ConnectorSelection>),
+    LowestLatency(Vec<RoutableConnectorChoice>),
 }
 
 impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
@@ -326,6 +330,11 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            RoutingAlgorithmSerde::LowestLatency(i) if i.is_empty() => {
+                Err(ParsingError::StructParseFailure(
+                    "Connectors list can't be empty for Lowest latency Algorithm",
+                ))?
+            }
             _ => {}
         };
         Ok(match value {
@@ -333,6 +342,7 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
             RoutingAlgorithmSerde::Priority(i) => Self::Priority(i),
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
+            RoutingAlgorithmSerde::LowestLatency(i) => Self::LowestLatency(i),
         })
     }
 }
@@ -435,6 +445,7 @@ impl RoutingAlgorithm {
             Self::Priority(_) => RoutingAlgorithmKind::Priority,
             Self::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
             Self::Advanced(_) => RoutingAlgorithmKind::Advanced,
+            Self::LowestLatency(_) => RoutingAlgorithmKind::LowestLatency,
         }
     }
 }
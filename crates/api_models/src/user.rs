@@ -203,6 +203,12 @@ pub struct TokenResponse {
     pub token_type: TokenPurpose,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ImpersonateUserRequest {
+    pub email: pii::Email,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct TwoFactorAuthStatusResponse {
     pub totp: bool,
@@ -1,17 +1,57 @@
+use std::collections::HashMap;
+
 use common_enums::{ParentGroup, PermissionGroup};
-use common_utils::pii;
+use common_utils::{id_type, pii};
 use masking::Secret;
+use time::PrimitiveDateTime;
 
 pub mod role;
 
 #[derive(Debug, serde::Serialize)]
 pub struct AuthorizationInfoResponse(pub Vec<AuthorizationInfo>);
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct GetAuthorizationInfoRequest {
+    #[serde(default)]
+    pub include_feature_flags: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum GetAuthorizationInfoResponse {
+    AuthorizationInfo(AuthorizationInfoResponse),
+    AuthorizationInfoWithFeatureFlags {
+        authorization_info: Vec<AuthorizationInfo>,
+        feature_flags: HashMap<RoleFeatureFlag, bool>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, strum::EnumIter)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleFeatureFlag {
+    CanViewOperations,
+    CanManageOperations,
+    CanViewConnectors,
+    CanManageConnectors,
+    CanViewWorkflows,
+    CanManageWorkflows,
+    CanViewAnalytics,
+    CanViewUsers,
+    CanManageUsers,
+    CanViewAccount,
+    CanManageAccount,
+    CanViewReconReports,
+    CanManageReconReports,
+    CanViewReconOps,
+    CanManageReconOps,
+}
+
 #[derive(Debug, serde::Serialize)]
 #[serde(untagged)]
 pub enum AuthorizationInfo {
     Group(GroupInfo),
     GroupWithTag(ParentInfo),
+    CustomGroup(role::CustomPermissionGroupResponse),
 }
 
 // TODO: To be deprecated
@@ -26,15 +66,46 @@ pub struct ParentInfo {
     pub name: ParentGroup,
     pub description: &'static str,
     pub groups: Vec<PermissionGroup>,
+    pub group_descriptions: Vec<GroupDescription>,
+}
+
+#[derive(Debug, serde::Serialize, Clone)]
+pub struct GroupDescription {
+    pub group: PermissionGroup,
+    pub description: &'static str,
+    pub category: &'static str,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct GetRoleInformationRequest {
+    pub lang: Option<String>,
+    /// Comma separated parent group tags to filter the groups by, unknown tags are ignored
+    pub tags: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct UpdateUserRoleRequest {
     pub email: pii::Email,
     pub role_id: String,
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    /// Assigns the role only within this profile of the merchant, instead of the whole merchant
+    pub profile_id: Option<id_type::ProfileId>,
 }
 
+pub type BulkUpdateUserRolesRequest = Vec<UpdateUserRoleRequest>;
+
 #[derive(Debug, serde::Serialize)]
+pub struct BulkUpdateUserRoleResponse {
+    pub email: pii::Email,
+    pub is_updated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub enum UserStatus {
     Active,
     InvitationSent,
@@ -43,12 +114,245 @@ pub enum UserStatus {
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct DeleteUserRoleRequest {
     pub email: pii::Email,
+    /// User to whom the organization admin role is transferred, required when the user being
+    /// deleted is the last organization admin
+    pub transfer_to: Option<String>,
+    /// Reason for revoking the user's access, recorded in the role audit trail
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UndoDeleteUserRoleRequest {
+    pub email: pii::Email,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ResendInvitationRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct InviteUserByEmailRequest {
+    pub email: pii::Email,
+    pub name: Secret<String>,
+    pub role_id: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct InviteUserByEmailResponse {
+    pub email: pii::Email,
+    pub is_email_sent: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum ListUsersInLineageResponse {
+    Flat(ListUsersInEntityPaginatedResponse),
+    Tree(ListUsersInLineageTreeResponse),
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ListUsersInEntityPaginatedResponse {
+    pub total_count: usize,
+    pub users: Vec<ListUsersInEntityResponse>,
+}
+
+/// Users of the page grouped under the entity nodes they hold roles at. A user holding roles at
+/// multiple entities appears under each of those nodes, with only the roles held at that node.
+#[derive(Debug, serde::Serialize)]
+pub struct ListUsersInLineageTreeResponse {
+    pub total_count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tenant_users: Vec<ListUsersInEntityResponse>,
+    pub organization: OrganizationLineageNode,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct OrganizationLineageNode {
+    pub org_id: id_type::OrganizationId,
+    pub users: Vec<ListUsersInEntityResponse>,
+    pub merchants: Vec<MerchantLineageNode>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct MerchantLineageNode {
+    pub merchant_id: id_type::MerchantId,
+    pub users: Vec<ListUsersInEntityResponse>,
+    pub profiles: Vec<ProfileLineageNode>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ProfileLineageNode {
+    pub profile_id: id_type::ProfileId,
+    pub users: Vec<ListUsersInEntityResponse>,
 }
 
 #[derive(Debug, serde::Serialize)]
 pub struct ListUsersInEntityResponse {
     pub email: pii::Email,
-    pub roles: Vec<role::MinimalRoleInfo>,
+    pub roles: Vec<UserRoleAssignmentInfo>,
+}
+
+#[derive(Debug, serde::Serialize, Clone)]
+pub struct UserRoleAssignmentInfo {
+    #[serde(flatten)]
+    pub role: role::MinimalRoleInfo,
+    pub invitation_status: InvitationStatus,
+    #[serde(
+        with = "common_utils::custom_serde::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub invited_at: Option<PrimitiveDateTime>,
+    #[serde(
+        with = "common_utils::custom_serde::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationStatus {
+    Active,
+    Pending,
+    Expired,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+pub struct UserRoleWebhookContent {
+    pub user_id: String,
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    pub old_role_id: String,
+    pub new_role_id: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ListRolesForUserRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ListRolesForUserResponse {
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub role_id: String,
+    pub role_name: String,
+    pub role_scope: common_enums::RoleScope,
+    pub entity_type: common_enums::EntityType,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CheckRoleIntegrityRequest {
+    pub start_after_id: Option<i32>,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct OrphanedUserRole {
+    pub user_role_id: i32,
+    pub user_id: String,
+    pub role_id: String,
+    pub merchant_id: Option<id_type::MerchantId>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct CheckRoleIntegrityResponse {
+    pub scanned_count: usize,
+    pub orphaned_role_count: usize,
+    pub orphaned_merchant_count: usize,
+    pub orphaned_role_samples: Vec<OrphanedUserRole>,
+    pub orphaned_merchant_samples: Vec<OrphanedUserRole>,
+    /// Present when the scan stopped at the limit, pass it as `start_after_id` to continue
+    pub next_start_after_id: Option<i32>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ExportRbacRequest {
+    pub start_after_id: Option<i32>,
+    pub limit: Option<u32>,
+}
+
+/// Versioned document of the custom roles of a merchant and the user role assignments in it
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RbacExport {
+    pub version: u32,
+    pub merchant_id: id_type::MerchantId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub exported_at: PrimitiveDateTime,
+    /// Only present in the first page of the export
+    #[serde(default)]
+    pub roles: Vec<RbacExportRole>,
+    #[serde(default)]
+    pub assignments: Vec<RbacExportAssignment>,
+    /// Present when more assignments are left, pass it as `start_after_id` to fetch the next page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_start_after_id: Option<i32>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RbacExportRole {
+    pub role_id: String,
+    pub role_name: String,
+    pub groups: Vec<PermissionGroup>,
+    #[serde(default)]
+    pub custom_permissions: Vec<String>,
+    pub entity_type: common_enums::EntityType,
+    pub scope: common_enums::RoleScope,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RbacExportAssignment {
+    pub email: pii::Email,
+    pub role_id: String,
+    pub entity_type: common_enums::EntityType,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub status: UserStatus,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RbacImportConflictStrategy {
+    /// Roles whose name is already taken on the merchant are not imported
+    #[default]
+    Skip,
+    /// Roles whose name is already taken on the merchant are imported under a suffixed name
+    Rename,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ImportRbacRequest {
+    #[serde(default)]
+    pub on_conflict: RbacImportConflictStrategy,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct ImportRbacResponse {
+    pub created_roles: Vec<ImportedRbacRole>,
+    pub skipped_roles: Vec<SkippedRbacRole>,
+    pub created_assignments_count: usize,
+    pub skipped_assignments: Vec<SkippedRbacAssignment>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ImportedRbacRole {
+    /// Role id in the export document
+    pub source_role_id: String,
+    pub role_id: String,
+    pub role_name: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SkippedRbacRole {
+    pub role_id: String,
+    pub role_name: String,
+    pub reason: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SkippedRbacAssignment {
+    pub email: pii::Email,
+    pub role_id: String,
+    pub reason: String,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -59,6 +363,30 @@ pub struct ListInvitationForUserResponse {
     pub role_id: String,
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct SwitchableMerchantResponse {
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_name: Option<Secret<String>>,
+    pub role_id: String,
+    pub role_name: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AcceptInvitationResponse {
+    pub entity_id: String,
+    pub entity_type: common_enums::EntityType,
+    pub inviter_name: Secret<String>,
+    pub inviter_email: Option<pii::Email>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AcceptAllInvitationsResponse {
+    pub entity_id: String,
+    pub entity_type: common_enums::EntityType,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub is_accepted: bool,
+}
+
 pub type AcceptInvitationsV2Request = Vec<Entity>;
 pub type AcceptInvitationsPreAuthRequest = Vec<Entity>;
 
@@ -66,9 +394,59 @@ pub type AcceptInvitationsPreAuthRequest = Vec<Entity>;
 pub struct Entity {
     pub entity_id: String,
     pub entity_type: common_enums::EntityType,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct ListUsersInEntityRequest {
     pub entity_type: Option<common_enums::EntityType>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Groups the users under their organization, merchant and profile nodes
+    #[serde(default)]
+    pub as_tree: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WhoAmIResponse {
+    /// Bumped whenever fields are removed or change meaning
+    pub schema_version: u32,
+    // This field is added for audit/debug reasons
+    #[serde(skip_serializing)]
+    pub user_id: String,
+    pub email: pii::Email,
+    pub name: Secret<String>,
+    pub tenant_id: id_type::TenantId,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub role_id: String,
+    pub role_name: String,
+    pub entity_type: common_enums::EntityType,
+    pub role_scope: common_enums::RoleScope,
+    pub groups: Vec<PermissionGroup>,
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreateRoleAssignmentRuleRequest {
+    /// Position of the rule in evaluation order, rules with lower values are evaluated first.
+    /// Defaults to after the last rule of the merchant.
+    pub priority: Option<i32>,
+    pub attribute: common_enums::RoleAssignmentAttribute,
+    pub value: String,
+    pub role_id: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RoleAssignmentRuleResponse {
+    pub rule_id: i32,
+    pub priority: i32,
+    pub attribute: common_enums::RoleAssignmentAttribute,
+    pub value: String,
+    pub role_id: String,
+    pub created_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
 }
@@ -1,28 +1,63 @@
 use common_enums::{
-    EntityType, ParentGroup, PermissionGroup, PermissionScope, Resource, RoleScope,
+    EntityType, ParentGroup, PermissionGroup, PermissionScope, Resource, RoleAuditAction, RoleScope,
 };
+use common_utils::{id_type, pii};
+use time::PrimitiveDateTime;
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct CreateRoleRequest {
     pub role_name: String,
     pub groups: Vec<PermissionGroup>,
+    /// Names of custom permission groups of the merchant, whose permissions are granted to the
+    /// role directly
+    #[serde(default)]
+    pub custom_groups: Vec<String>,
     pub role_scope: RoleScope,
     pub entity_type: Option<EntityType>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CloneRoleRequest {
+    pub role_name: String,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct UpdateRoleRequest {
     pub groups: Option<Vec<PermissionGroup>>,
     pub role_name: Option<String>,
+    /// Version of the role the update is based on, as returned by get role
+    pub version: i32,
 }
 
 #[derive(Debug, serde::Serialize)]
 pub struct RoleInfoWithGroupsResponse {
     pub role_id: String,
+    /// Effective groups, including the groups listed in `inherited_groups`
     pub groups: Vec<PermissionGroup>,
+    /// Groups inherited from roles the user holds at higher entities in the lineage. Groups not
+    /// listed here are directly granted by the role.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub inherited_groups: Vec<InheritedPermissionGroup>,
     pub role_name: String,
     pub role_scope: RoleScope,
     pub entity_type: EntityType,
+    /// Number of users in the organization actively holding the role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_user_count: Option<i64>,
+    /// Current version of the role, present only for custom roles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+    /// Last time a request was authenticated under the role, updated at most once an hour
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct InheritedPermissionGroup {
+    pub group: PermissionGroup,
+    /// Role held at the higher entity from which the group is inherited
+    pub role_id: String,
+    pub entity_type: EntityType,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -43,6 +78,19 @@ pub struct ParentGroupInfo {
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct ListRolesRequest {
     pub entity_type: Option<EntityType>,
+    pub name_search: Option<String>,
+    #[serde(default)]
+    pub fields: ListRolesFields,
+}
+
+/// Details of the roles to be returned while listing roles
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListRolesFields {
+    /// Only the identifying details of the roles, without their permission groups
+    Summary,
+    #[default]
+    Full,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -50,8 +98,12 @@ pub struct RoleInfoResponseNew {
     pub role_id: String,
     pub role_name: String,
     pub entity_type: EntityType,
-    pub groups: Vec<PermissionGroup>,
+    /// Not returned when roles are listed with summary fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<PermissionGroup>>,
     pub scope: RoleScope,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -59,6 +111,51 @@ pub struct GetRoleRequest {
     pub role_id: String,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CompareRolesRequest {
+    pub base: String,
+    pub target: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CompareRolesResponse {
+    pub base_role_id: String,
+    pub target_role_id: String,
+    pub only_in_base: Vec<super::GroupInfo>,
+    pub only_in_target: Vec<super::GroupInfo>,
+    pub in_both: Vec<super::GroupInfo>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PreviewRoleUpdateRequest {
+    pub groups: Vec<PermissionGroup>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PreviewRoleUpdateResponse {
+    pub role_id: String,
+    pub groups_added: Vec<PermissionGroup>,
+    pub groups_removed: Vec<PermissionGroup>,
+    /// Number of users in the organization actively holding the role
+    pub total_affected_users: i64,
+    /// Set when `affected_users` holds fewer entries than `total_affected_users`
+    pub is_truncated: bool,
+    pub affected_users: Vec<AffectedUserInfo>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AffectedUserInfo {
+    pub user_id: String,
+    pub email: pii::Email,
+    pub permission_delta: PermissionDelta,
+}
+
+#[derive(Debug, serde::Serialize, Clone)]
+pub struct PermissionDelta {
+    pub permissions_gained: Vec<String>,
+    pub permissions_lost: Vec<String>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct ListRolesAtEntityLevelRequest {
     pub entity_type: EntityType,
@@ -76,8 +173,107 @@ pub struct MinimalRoleInfo {
     pub role_name: String,
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct EffectivePermissionsResponse {
+    pub groups: Vec<PermissionGroup>,
+    pub permissions: Vec<String>,
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct GroupsAndResources {
     pub groups: Vec<PermissionGroup>,
     pub resources: Vec<Resource>,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ListRoleAuditRequest {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RoleAuditResponse {
+    pub action: RoleAuditAction,
+    pub actor_user_id: String,
+    pub target_id: String,
+    pub groups_before: Vec<PermissionGroup>,
+    pub groups_after: Vec<PermissionGroup>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Reason given for the change, if any
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreatePermissionGroupRequest {
+    pub group_name: String,
+    pub description: Option<String>,
+    /// Leaf permissions bundled in the group, as returned by the permissions endpoint
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize, Clone)]
+pub struct CustomPermissionGroupResponse {
+    pub group_name: String,
+    pub description: Option<String>,
+    pub permissions: Vec<String>,
+    pub created_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreateRoleTemplateRequest {
+    /// Name of the merchant level roles created from the template
+    pub role_name: String,
+    pub groups: Vec<PermissionGroup>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdateRoleTemplateRequest {
+    pub groups: Option<Vec<PermissionGroup>>,
+    pub role_name: Option<String>,
+    /// Propagates the updated groups to the roles already created from the template. Names of
+    /// the derived roles are left untouched.
+    #[serde(default)]
+    pub sync_derived_roles: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RoleTemplateResponse {
+    pub template_id: String,
+    pub role_name: String,
+    pub groups: Vec<PermissionGroup>,
+    pub created_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+    /// Results of propagating the update to derived roles, present only when a sync was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synced_roles: Option<Vec<DerivedRoleSyncResponse>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DerivedRoleSyncResponse {
+    pub role_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub is_synced: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ApplyRoleTemplateRequest {
+    pub merchant_ids: Vec<id_type::MerchantId>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ApplyRoleTemplateResponse {
+    pub merchant_id: id_type::MerchantId,
+    pub is_applied: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
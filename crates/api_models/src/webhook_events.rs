@@ -187,3 +187,143 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The request body for replaying webhooks delivered within a time range.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookReplayRequest {
+    /// Replay events created after the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Replay events created before the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+
+    /// Replay only the events associated with the specified business profile ID.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Replay events even if a delivery attempt for them has already been acknowledged by the
+    /// merchant endpoint.
+    #[serde(default)]
+    #[schema(default = false, example = false)]
+    pub force: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookReplayRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: WebhookReplayRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body for replaying webhooks delivered within a time range. The events are
+/// replayed in batches in the background, once the replay is scheduled.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookReplayResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The business profile ID whose events are replayed, if any.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Events created after this time are replayed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Events created before this time are replayed. This is limited to the time at which the
+    /// replay was scheduled.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+
+    /// Indicates whether events already acknowledged by the merchant endpoint are replayed as well.
+    pub force: bool,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The outgoing webhook event types a merchant is subscribed to.
+///
+/// An empty list means that the merchant is subscribed to all event types. This is also the case
+/// for merchants who have never updated their subscription, so that existing merchants continue
+/// to receive every event.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct WebhookEventSubscription {
+    /// The event types for which outgoing webhooks are delivered. Leave empty to subscribe to all
+    /// event types.
+    #[serde(default)]
+    #[schema(example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub event_types: Vec<EventType>,
+}
+
+impl WebhookEventSubscription {
+    /// Checks whether outgoing webhooks should be delivered for the specified event type.
+    pub fn is_subscribed_to(&self, event_type: EventType) -> bool {
+        self.event_types.is_empty() || self.event_types.contains(&event_type)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEventSubscriptionRetrieveRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventSubscriptionRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEventSubscriptionUpdateRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub subscription: WebhookEventSubscription,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventSubscriptionUpdateRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body for retrieving or updating the webhook event subscription of a merchant.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookEventSubscriptionResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    #[serde(flatten)]
+    pub subscription: WebhookEventSubscription,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventSubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
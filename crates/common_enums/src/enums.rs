@@ -1196,6 +1196,7 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    UserRoles,
 }
 
 #[derive(
@@ -1222,6 +1223,8 @@ pub enum EventType {
     PaymentCancelled,
     PaymentAuthorized,
     PaymentCaptured,
+    /// The payment was not confirmed within the expiry configured by the merchant
+    PaymentExpired,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -1241,6 +1244,8 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    UserRoleUpdated,
+    UserRoleDeleted,
 }
 
 #[derive(
@@ -1333,13 +1338,20 @@ pub enum IntentStatus {
     PartiallyCaptured,
     /// The payment has been captured partially and the remaining amount is capturable
     PartiallyCapturedAndCapturable,
+    /// The payment was not confirmed within the expiry configured by the merchant and can no
+    /// longer be confirmed.
+    Expired,
 }
 
 impl IntentStatus {
     /// Indicates whether the payment intent is in terminal state or not
     pub fn is_in_terminal_state(self) -> bool {
         match self {
-            Self::Succeeded | Self::Failed | Self::Cancelled | Self::PartiallyCaptured => true,
+            Self::Succeeded
+            | Self::Failed
+            | Self::Cancelled
+            | Self::PartiallyCaptured
+            | Self::Expired => true,
             Self::Processing
             | Self::RequiresCustomerAction
             | Self::RequiresMerchantAction
@@ -1361,7 +1373,8 @@ impl IntentStatus {
             | Self::Failed
             | Self::Cancelled
             |  Self::PartiallyCaptured
-            |  Self::RequiresCapture => false,
+            |  Self::RequiresCapture
+            |  Self::Expired => false,
             Self::Processing
             | Self::RequiresCustomerAction
             | Self::RequiresMerchantAction
@@ -1749,6 +1762,7 @@ pub enum ScaExemptionType {
     #[default]
     LowValue,
     TransactionRiskAnalysis,
+    Recurring,
 }
 
 #[derive(
@@ -6761,7 +6775,10 @@ pub enum PermissionGroup {
     ReconOpsManage,
 }
 
-#[derive(Clone, Debug, serde::Serialize, PartialEq, Eq, Hash, strum::EnumIter)]
+#[derive(
+    Clone, Debug, serde::Serialize, PartialEq, Eq, Hash, strum::EnumIter, strum::EnumString,
+)]
+#[strum(ascii_case_insensitive)]
 pub enum ParentGroup {
     Operations,
     Connectors,
@@ -6783,6 +6800,7 @@ pub enum Resource {
     Connector,
     Routing,
     Dispute,
+    DisputeEvidence,
     Mandate,
     Customer,
     Analytics,
@@ -6799,6 +6817,7 @@ pub enum Resource {
     ReconReports,
     RunRecon,
     ReconConfig,
+    SupportAdmin,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, Hash)]
@@ -7036,7 +7055,9 @@ pub enum GenericLinkType {
     PayoutLink,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, strum::Display, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, strum::Display, serde::Deserialize, serde::Serialize,
+)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum TokenPurpose {
@@ -7053,6 +7074,7 @@ pub enum TokenPurpose {
     ResetPassword,
     AcceptInvite,
     UserInfo,
+    Impersonation,
 }
 
 #[derive(
@@ -7143,6 +7165,54 @@ pub enum EntityType {
     Profile = 0,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RoleAuditAction {
+    CreateRole,
+    UpdateRole,
+    DeleteRole,
+    RestoreRole,
+    UpdateUserRole,
+    DeleteUserRole,
+    RestoreUserRole,
+    ImpersonateUser,
+    CreateRoleTemplate,
+    UpdateRoleTemplate,
+}
+
+/// User attribute a role assignment rule is evaluated against
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RoleAssignmentAttribute {
+    EmailDomain,
+}
+
 #[derive(Clone, Debug, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PayoutRetryType {
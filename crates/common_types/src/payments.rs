@@ -12,7 +12,7 @@ use euclid::frontend::{
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domain::{AdyenSplitData, XenditSplitSubMerchantData};
+use crate::domain::{AdyenSplitData, AdyenSplitItem, XenditSplitSubMerchantData};
 
 #[derive(
     Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
@@ -28,9 +28,82 @@ pub enum SplitPaymentsRequest {
     AdyenSplitPayment(AdyenSplitData),
     /// XenditSplitPayment
     XenditSplitPayment(XenditSplitRequest),
+    /// GenericSplitPayment
+    GenericSplitPayment(GenericSplitPaymentRequest),
 }
 impl_to_sql_from_sql_json!(SplitPaymentsRequest);
 
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Split instructions to distribute a payment across multiple recipients. These are sent to the connectors which support splitting a payment across multiple accounts (Adyen and Xendit), and are recorded on the payment for reconciliation otherwise
+pub struct GenericSplitPaymentRequest {
+    /// Recipients of the payment along with their share, the split amounts should sum up to the amount of the payment
+    pub split_items: Vec<GenericSplitItem>,
+}
+impl_to_sql_from_sql_json!(GenericSplitPaymentRequest);
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Share of a recipient in a split payment
+pub struct GenericSplitItem {
+    /// Identifier of the account of the recipient at the connector
+    pub recipient_id: String,
+    /// The amount allocated to the recipient
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// Unique identifier for the split item
+    pub reference: String,
+    /// Description for the part of the payment that will be allocated to the recipient
+    pub description: Option<String>,
+}
+impl_to_sql_from_sql_json!(GenericSplitItem);
+
+impl From<GenericSplitPaymentRequest> for AdyenSplitData {
+    fn from(split_payment: GenericSplitPaymentRequest) -> Self {
+        Self {
+            store: None,
+            split_items: split_payment
+                .split_items
+                .into_iter()
+                .map(|split_item| AdyenSplitItem {
+                    amount: Some(split_item.amount),
+                    split_type: enums::AdyenSplitType::BalanceAccount,
+                    account: Some(split_item.recipient_id),
+                    reference: split_item.reference,
+                    description: split_item.description,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<(GenericSplitPaymentRequest, enums::Currency)> for XenditMultipleSplitRequest {
+    fn from((split_payment, currency): (GenericSplitPaymentRequest, enums::Currency)) -> Self {
+        Self {
+            name: "generic_split_payment".to_string(),
+            description: "Split of the payment across multiple recipients".to_string(),
+            for_user_id: None,
+            routes: split_payment
+                .split_items
+                .into_iter()
+                .map(|split_item| XenditSplitRoute {
+                    flat_amount: Some(split_item.amount),
+                    percent_amount: None,
+                    currency,
+                    destination_account_id: split_item.recipient_id,
+                    reference_id: split_item.reference,
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(
     Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
 )]
@@ -84,7 +157,21 @@ impl AuthenticationConnectorAccountMap {
 pub struct ConditionalConfigs {
     /// Override 3DS
     pub override_3ds: Option<common_enums::AuthenticationType>,
+    /// Override the 3DS flow to either a challenge or a frictionless flow
+    pub override_3ds_challenge_flow: Option<common_enums::DecoupledAuthenticationType>,
+    /// PSD2 SCA exemption to be requested from the issuer during authorization
+    pub sca_exemption: Option<common_enums::ScaExemptionType>,
+}
+
+impl ConditionalConfigs {
+    /// Get the authentication type to be used for the payment, a 3DS flow override implies 3DS
+    pub fn get_authentication_type(&self) -> Option<common_enums::AuthenticationType> {
+        self.override_3ds.or(self
+            .override_3ds_challenge_flow
+            .map(|_| common_enums::AuthenticationType::ThreeDs))
+    }
 }
+
 impl EuclidDirFilter for ConditionalConfigs {
     const ALLOWED: &'static [DirKeyKind] = &[
         DirKeyKind::PaymentMethod,
@@ -93,6 +180,7 @@ impl EuclidDirFilter for ConditionalConfigs {
         DirKeyKind::MetaData,
         DirKeyKind::PaymentAmount,
         DirKeyKind::PaymentCurrency,
+        DirKeyKind::CardBin,
         DirKeyKind::CaptureMethod,
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
@@ -160,6 +248,8 @@ pub enum ConnectorChargeResponseData {
     AdyenSplitPayment(AdyenSplitData),
     /// XenditChargeResponseData
     XenditSplitPayment(XenditChargeResponseData),
+    /// Split instructions recorded for connectors without support for split payments
+    GenericSplitPayment(GenericSplitPaymentRequest),
 }
 
 impl_to_sql_from_sql_json!(ConnectorChargeResponseData);
@@ -107,6 +107,11 @@ impl MerchantId {
         format!("max_auto_retries_enabled_{}", self.get_string_repr())
     }
 
+    /// get_auto_retry_failover_chain_key
+    pub fn get_auto_retry_failover_chain_key(&self) -> String {
+        format!("auto_retry_failover_chain_{}", self.get_string_repr())
+    }
+
     /// get_requires_cvv_key
     pub fn get_requires_cvv_key(&self) -> String {
         format!("{}_requires_cvv", self.get_string_repr())
@@ -181,6 +186,17 @@ impl MerchantId {
         )
     }
 
+    /// Get the key for the outgoing webhook event types the merchant is subscribed to
+    pub fn get_webhook_event_subscription_key(&self) -> String {
+        format!("whconf_subscribed_events_{}", self.get_string_repr())
+    }
+
+    /// Get the key for the time in seconds after which the unconfirmed payments of the merchant
+    /// expire
+    pub fn get_payment_intent_expiry_key(&self) -> String {
+        format!("payment_intent_expiry_{}", self.get_string_repr())
+    }
+
     /// get_should_call_gsm_payout_key
     pub fn get_should_call_gsm_payout_key(
         &self,
@@ -203,6 +219,37 @@ impl MerchantId {
         format!("should_call_gsm_{}", self.get_string_repr())
     }
 
+    /// Get the redis key which maps the idempotency key of a refund create request to the refund
+    pub fn get_refund_idempotency_key(&self, idempotency_key: &str) -> String {
+        format!(
+            "refund_idempotency_{}_{idempotency_key}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key for the currency in which the payments of the merchant are settled
+    pub fn get_settlement_currency_key(&self) -> String {
+        format!("settlement_currency_{}", self.get_string_repr())
+    }
+
+    /// Get the key for the validity in seconds of the signed payment links of the merchant
+    pub fn get_signed_payment_link_ttl_key(&self) -> String {
+        format!("signed_payment_link_ttl_{}", self.get_string_repr())
+    }
+
+    /// Get the key for checking whether the raw connector payloads should be stored
+    pub fn get_store_raw_connector_payloads_key(&self) -> String {
+        format!("store_raw_connector_payloads_{}", self.get_string_repr())
+    }
+
+    /// Get the redis key under which the raw connector payloads of a payment are stored
+    pub fn get_raw_connector_payloads_key(&self, payment_id: &str) -> String {
+        format!(
+            "raw_connector_payloads_{}_{payment_id}",
+            self.get_string_repr()
+        )
+    }
+
     /// get_max_auto_single_connector_payout_retries_enabled_
     pub fn get_max_auto_single_connector_payout_retries_enabled(
         &self,
@@ -19,6 +19,7 @@ pub mod id_type;
 pub mod keymanager;
 pub mod link_utils;
 pub mod macros;
+pub mod metadata;
 pub mod new_type;
 pub mod payout_method_utils;
 pub mod pii;
@@ -0,0 +1,190 @@
+//! Utilities for reading and writing typed values in merchant defined metadata
+
+use serde_json::{Map, Value};
+
+/// Type to which the value of a metadata key is coerced
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MetadataValueType {
+    /// A string, numbers and booleans are converted to their string representation
+    String,
+    /// A 64 bit signed integer, strings holding an integer are converted
+    Integer,
+    /// A number, strings holding a number are converted
+    Number,
+    /// A boolean, the strings `true` and `false` are converted
+    Boolean,
+}
+
+impl MetadataValueType {
+    /// Coerces the value to this type, returns None if the value cannot be represented as this type
+    pub fn coerce(self, value: &Value) -> Option<Value> {
+        match (self, value) {
+            (Self::String, Value::String(_))
+            | (Self::Number, Value::Number(_))
+            | (Self::Boolean, Value::Bool(_)) => Some(value.clone()),
+            (Self::String, Value::Number(number)) => Some(Value::String(number.to_string())),
+            (Self::String, Value::Bool(boolean)) => Some(Value::String(boolean.to_string())),
+            (Self::Integer, Value::Number(number)) => number.as_i64().map(Value::from),
+            (Self::Integer, Value::String(string)) => {
+                string.trim().parse::<i64>().ok().map(Value::from)
+            }
+            // Integers are kept as such, so that their text representation has no fraction
+            (Self::Number, Value::String(string)) => string
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .map(Value::from)
+                .or_else(|| {
+                    string
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(serde_json::Number::from_f64)
+                        .map(Value::Number)
+                }),
+            (Self::Boolean, Value::String(string)) => {
+                string.trim().parse::<bool>().ok().map(Value::Bool)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Errors that could occur while reading or writing a typed metadata value
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MetadataError {
+    /// The metadata is not a JSON object
+    #[error("Metadata is not a JSON object")]
+    NotAnObject,
+    /// The metadata does not contain the key
+    #[error("Metadata does not contain the key `{0}`")]
+    KeyNotFound(String),
+    /// The value of the key cannot be coerced to the requested type
+    #[error("Value of the metadata key `{key}` cannot be coerced to {value_type}")]
+    InvalidValue {
+        /// The metadata key
+        key: String,
+        /// The requested type of the value
+        value_type: MetadataValueType,
+    },
+}
+
+/// Returns the text representation of a metadata value, which is the value of the key as
+/// extracted by the `->>` operator of Postgres. Values are matched on their text representation,
+/// so that a value matches irrespective of whether it is stored as a string or as a number.
+pub fn get_text_representation(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        _ => value.to_string(),
+    }
+}
+
+/// Sets the key in the metadata to the value coerced to the requested type, the metadata is
+/// created if it does not exist
+pub fn set_metadata_value(
+    metadata: &mut Option<Value>,
+    key: &str,
+    value: &Value,
+    value_type: MetadataValueType,
+) -> Result<(), MetadataError> {
+    let value = value_type
+        .coerce(value)
+        .ok_or_else(|| MetadataError::InvalidValue {
+            key: key.to_owned(),
+            value_type,
+        })?;
+
+    metadata
+        .get_or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or(MetadataError::NotAnObject)?
+        .insert(key.to_owned(), value);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_values_are_coerced_to_the_requested_type() {
+        assert_eq!(
+            MetadataValueType::Integer.coerce(&json!("42")),
+            Some(json!(42))
+        );
+        assert_eq!(
+            MetadataValueType::Number.coerce(&json!("4.5")),
+            Some(json!(4.5))
+        );
+        assert_eq!(
+            MetadataValueType::Number.coerce(&json!("42")),
+            Some(json!(42))
+        );
+        assert_eq!(
+            MetadataValueType::Boolean.coerce(&json!("true")),
+            Some(json!(true))
+        );
+        assert_eq!(
+            MetadataValueType::String.coerce(&json!(1001)),
+            Some(json!("1001"))
+        );
+    }
+
+    #[test]
+    fn test_values_which_cannot_be_coerced_are_rejected() {
+        assert_eq!(MetadataValueType::Integer.coerce(&json!(4.5)), None);
+        assert_eq!(MetadataValueType::Boolean.coerce(&json!("yes")), None);
+        assert_eq!(MetadataValueType::String.coerce(&json!(["gift"])), None);
+    }
+
+    #[test]
+    fn test_text_representation_is_independent_of_the_stored_type() {
+        assert_eq!(get_text_representation(&json!("42")), "42");
+        assert_eq!(get_text_representation(&json!(42)), "42");
+        assert_eq!(
+            get_text_representation(&MetadataValueType::Number.coerce(&json!("42")).unwrap()),
+            "42"
+        );
+        assert_eq!(get_text_representation(&json!(true)), "true");
+        assert_eq!(get_text_representation(&json!("true")), "true");
+    }
+
+    #[test]
+    fn test_set_metadata_value_creates_the_metadata() {
+        let mut metadata = None;
+
+        set_metadata_value(
+            &mut metadata,
+            "order_count",
+            &json!("7"),
+            MetadataValueType::Integer,
+        )
+        .unwrap();
+
+        assert_eq!(metadata, Some(json!({ "order_count": 7 })));
+        assert!(set_metadata_value(
+            &mut metadata,
+            "order_count",
+            &json!("seven"),
+            MetadataValueType::Integer,
+        )
+        .is_err());
+    }
+}
@@ -65,6 +65,7 @@ pub struct Profile {
     pub card_testing_secret_key: Option<Encryption>,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: Option<bool>,
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[cfg(feature = "v1")]
@@ -115,6 +116,7 @@ pub struct ProfileNew {
     pub card_testing_secret_key: Option<Encryption>,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: Option<bool>,
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[cfg(feature = "v1")]
@@ -163,6 +165,7 @@ pub struct ProfileUpdateInternal {
     pub card_testing_secret_key: Option<Encryption>,
     pub is_clear_pan_retries_enabled: Option<bool>,
     pub force_3ds_challenge: Option<bool>,
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[cfg(feature = "v1")]
@@ -209,6 +212,7 @@ impl ProfileUpdateInternal {
             card_testing_secret_key,
             is_clear_pan_retries_enabled,
             force_3ds_challenge,
+            allowed_payment_method_types,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -280,6 +284,8 @@ impl ProfileUpdateInternal {
             is_clear_pan_retries_enabled: is_clear_pan_retries_enabled
                 .unwrap_or(source.is_clear_pan_retries_enabled),
             force_3ds_challenge,
+            allowed_payment_method_types: allowed_payment_method_types
+                .or(source.allowed_payment_method_types),
         }
     }
 }
@@ -334,6 +340,7 @@ pub struct Profile {
     pub card_testing_secret_key: Option<Encryption>,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: Option<bool>,
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -581,6 +588,7 @@ impl ProfileUpdateInternal {
             is_clear_pan_retries_enabled: is_clear_pan_retries_enabled
                 .unwrap_or(source.is_clear_pan_retries_enabled),
             force_3ds_challenge: None,
+            allowed_payment_method_types: None,
         }
     }
 }
@@ -0,0 +1,33 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::custom_permission_groups;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = custom_permission_groups, check_for_backend(diesel::pg::Pg))]
+pub struct CustomPermissionGroup {
+    pub id: i32,
+    pub group_name: String,
+    pub description: Option<String>,
+    #[diesel(deserialize_as = super::DieselArray<String>)]
+    pub permissions: Vec<String>,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub tenant_id: id_type::TenantId,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = custom_permission_groups)]
+pub struct CustomPermissionGroupNew {
+    pub group_name: String,
+    pub description: Option<String>,
+    pub permissions: Vec<String>,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub tenant_id: id_type::TenantId,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+}
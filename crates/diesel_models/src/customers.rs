@@ -58,6 +58,7 @@ impl From<CustomerNew> for Customer {
             default_payment_method_id: None,
             updated_by: customer_new.updated_by,
             version: customer_new.version,
+            merged_into_customer_id: None,
         }
     }
 }
@@ -142,6 +143,7 @@ pub struct Customer {
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: ApiVersion,
+    pub merged_into_customer_id: Option<common_utils::id_type::CustomerId>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -187,6 +189,7 @@ pub struct CustomerUpdateInternal {
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<Option<String>>,
     pub updated_by: Option<String>,
+    pub merged_into_customer_id: Option<common_utils::id_type::CustomerId>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -202,6 +205,7 @@ impl CustomerUpdateInternal {
             connector_customer,
             address_id,
             default_payment_method_id,
+            merged_into_customer_id,
             ..
         } = self;
 
@@ -218,6 +222,8 @@ impl CustomerUpdateInternal {
             default_payment_method_id: default_payment_method_id
                 .flatten()
                 .map_or(source.default_payment_method_id, Some),
+            merged_into_customer_id: merged_into_customer_id
+                .map_or(source.merged_into_customer_id, Some),
             ..source
         }
     }
@@ -0,0 +1,111 @@
+use common_enums::EntityType;
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums,
+    schema::deleted_user_roles,
+    user_role::{UserRole, UserRoleNew},
+};
+
+/// User role which has been deleted but can still be restored until it is purged
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = deleted_user_roles, check_for_backend(diesel::pg::Pg))]
+pub struct DeletedUserRole {
+    pub id: i32,
+    pub user_id: String,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub role_id: String,
+    pub org_id: Option<id_type::OrganizationId>,
+    pub status: enums::UserStatus,
+    pub created_by: String,
+    pub last_modified_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified: PrimitiveDateTime,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entity_id: Option<String>,
+    pub entity_type: Option<EntityType>,
+    pub version: enums::UserRoleVersion,
+    pub tenant_id: id_type::TenantId,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub deleted_by: String,
+    pub deleted_at: PrimitiveDateTime,
+}
+
+impl DeletedUserRole {
+    pub fn into_user_role_new(self, modified_by: String) -> UserRoleNew {
+        UserRoleNew {
+            user_id: self.user_id,
+            merchant_id: self.merchant_id,
+            role_id: self.role_id,
+            org_id: self.org_id,
+            status: self.status,
+            created_by: self.created_by,
+            last_modified_by: modified_by,
+            created_at: self.created_at,
+            last_modified: common_utils::date_time::now(),
+            profile_id: self.profile_id,
+            entity_id: self.entity_id,
+            entity_type: self.entity_type,
+            version: self.version,
+            tenant_id: self.tenant_id,
+            expires_at: self.expires_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = deleted_user_roles)]
+pub struct DeletedUserRoleNew {
+    pub user_id: String,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub role_id: String,
+    pub org_id: Option<id_type::OrganizationId>,
+    pub status: enums::UserStatus,
+    pub created_by: String,
+    pub last_modified_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified: PrimitiveDateTime,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entity_id: Option<String>,
+    pub entity_type: Option<EntityType>,
+    pub version: enums::UserRoleVersion,
+    pub tenant_id: id_type::TenantId,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub deleted_by: String,
+    pub deleted_at: PrimitiveDateTime,
+}
+
+impl DeletedUserRoleNew {
+    pub fn new(user_role: UserRole, deleted_by: String) -> Self {
+        Self {
+            user_id: user_role.user_id,
+            merchant_id: user_role.merchant_id,
+            role_id: user_role.role_id,
+            org_id: user_role.org_id,
+            status: user_role.status,
+            created_by: user_role.created_by,
+            last_modified_by: user_role.last_modified_by,
+            created_at: user_role.created_at,
+            last_modified: user_role.last_modified,
+            profile_id: user_role.profile_id,
+            entity_id: user_role.entity_id,
+            entity_type: user_role.entity_type,
+            version: user_role.version,
+            tenant_id: user_role.tenant_id,
+            expires_at: user_role.expires_at,
+            deleted_by,
+            deleted_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DeletedUserRolePurgeTrackingData {
+    pub user_id: String,
+    pub tenant_id: id_type::TenantId,
+    pub deleted_user_role_ids: Vec<i32>,
+}
@@ -54,6 +54,7 @@ pub enum RoutingAlgorithmKind {
     VolumeSplit,
     Advanced,
     Dynamic,
+    LowestLatency,
 }
 
 #[derive(
@@ -76,6 +77,7 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    UserRoleDetails,
 }
 
 #[derive(
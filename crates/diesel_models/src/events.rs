@@ -81,6 +81,13 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    UserRole {
+        user_id: String,
+        /// The content of the webhook, which cannot be fetched again as user role changes are
+        /// point in time events
+        #[serde(default)]
+        content: serde_json::Value,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
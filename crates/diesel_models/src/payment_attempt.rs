@@ -556,6 +556,7 @@ pub enum PaymentAttemptUpdate {
     AmountToCaptureUpdate {
         status: storage_enums::AttemptStatus,
         amount_capturable: MinorUnit,
+        amount_to_capture: Option<MinorUnit>,
         updated_by: String,
     },
     PreprocessingUpdate {
@@ -3106,6 +3107,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
                 amount_capturable,
+                amount_to_capture,
                 updated_by,
             } => Self {
                 status: Some(status),
@@ -3116,7 +3118,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 net_amount: None,
                 currency: None,
                 connector_transaction_id: None,
-                amount_to_capture: None,
+                amount_to_capture,
                 connector: None,
                 authentication_type: None,
                 payment_method: None,
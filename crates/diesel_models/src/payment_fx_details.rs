@@ -0,0 +1,48 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_fx_details};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_fx_details)]
+pub struct PaymentFxDetailsNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub provider: String,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub settlement_amount: common_utils::types::MinorUnit,
+    pub settlement_currency: storage_enums::Currency,
+    pub exchange_rate: String,
+    pub rate_fetched_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Identifiable,
+    Queryable,
+    Selectable,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[diesel(table_name = payment_fx_details, primary_key(merchant_id, attempt_id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentFxDetails {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub provider: String,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub settlement_amount: common_utils::types::MinorUnit,
+    pub settlement_currency: storage_enums::Currency,
+    /// The exchange rate from `currency` to `settlement_currency`, stored as a decimal string so
+    /// that the rate is retained without any loss of precision
+    pub exchange_rate: String,
+    pub rate_fetched_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,62 @@
+use common_utils::{id_type, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::pending_invitations};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = pending_invitations, check_for_backend(diesel::pg::Pg))]
+pub struct PendingInvitation {
+    pub id: i32,
+    pub email: pii::Email,
+    pub tenant_id: id_type::TenantId,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entity_id: String,
+    pub entity_type: enums::EntityType,
+    pub role_id: String,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = pending_invitations)]
+pub struct PendingInvitationNew {
+    pub email: pii::Email,
+    pub tenant_id: id_type::TenantId,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entity_id: String,
+    pub entity_type: enums::EntityType,
+    pub role_id: String,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = pending_invitations)]
+pub struct PendingInvitationUpdate {
+    pub profile_id: Option<Option<id_type::ProfileId>>,
+    pub entity_id: String,
+    pub entity_type: enums::EntityType,
+    pub role_id: String,
+    pub created_by: String,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PendingInvitationNew> for PendingInvitationUpdate {
+    fn from(value: PendingInvitationNew) -> Self {
+        Self {
+            profile_id: Some(value.profile_id),
+            entity_id: value.entity_id,
+            entity_type: value.entity_type,
+            role_id: value.role_id,
+            created_by: value.created_by,
+            last_modified_at: value.last_modified_at,
+        }
+    }
+}
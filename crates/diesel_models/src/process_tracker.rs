@@ -216,6 +216,13 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    DeletedUserRolePurgeWorkflow,
+    ScheduledCaptureWorkflow,
+    PayoutsSyncWorkflow,
+    PaymentIntentExpiryWorkflow,
+    StalePaymentSyncWorkflow,
+    WebhookReplayWorkflow,
+    CardVerificationVoidWorkflow,
 }
 
 #[cfg(test)]
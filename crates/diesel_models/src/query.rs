@@ -11,8 +11,10 @@ pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod callback_mapper;
+pub mod custom_permission_group;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod deleted_user_role;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod events;
@@ -28,16 +30,21 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
+pub mod payment_fx_details;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
+pub mod pending_invitation;
 pub mod process_tracker;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
+pub mod role_assignment_rule;
+pub mod role_audit;
+pub mod role_template;
 pub mod routing_algorithm;
 pub mod unified_translations;
 pub mod user;
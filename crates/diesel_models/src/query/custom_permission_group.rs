@@ -0,0 +1,36 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    custom_permission_group::{CustomPermissionGroup, CustomPermissionGroupNew},
+    schema::custom_permission_groups::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomPermissionGroupNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomPermissionGroup> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomPermissionGroup {
+    pub async fn list_by_tenant_id_org_id_merchant_id(
+        conn: &PgPooledConn,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::tenant_id
+                .eq(tenant_id.to_owned())
+                .and(dsl::org_id.eq(org_id.to_owned()))
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+            None,
+            None,
+            Some(dsl::group_name.asc()),
+        )
+        .await
+    }
+}
@@ -1,9 +1,15 @@
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::id_type;
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use diesel::BoolExpressionMethods;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use diesel::BoolExpressionMethods;
 use diesel::{associations::HasTable, ExpressionMethods};
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use diesel::{debug_query, pg::Pg, QueryDsl};
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use error_stack::ResultExt;
 
 use super::generics;
 // #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -132,6 +138,36 @@ impl Customer {
         }
     }
 
+    /// Locks the customers which have not been merged into another customer until the end of the
+    /// transaction. The customers are locked in the order of their ids, so that transactions
+    /// locking the same customers wait on each other instead of deadlocking.
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    pub async fn lock_unmerged_by_customer_ids_merchant_id(
+        conn: &PgPooledConn,
+        customer_ids: Vec<id_type::CustomerId>,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        let query = <Self as HasTable>::table()
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::customer_id.eq_any(customer_ids))
+                    .and(dsl::merged_into_customer_id.is_null()),
+            )
+            .order(dsl::customer_id.asc())
+            .for_update();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to lock the customers")
+    }
+
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
     pub async fn delete_by_customer_id_merchant_id(
         conn: &PgPooledConn,
@@ -0,0 +1,82 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    deleted_user_role::{DeletedUserRole, DeletedUserRoleNew},
+    schema::deleted_user_roles::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl DeletedUserRoleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DeletedUserRole> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DeletedUserRole {
+    /// Lists the deleted user roles of the user which lie in the given lineage, that is at the
+    /// tenant, org, merchant or profile level of it
+    pub async fn list_by_user_id_tenant_id_org_id_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        user_id: String,
+        tenant_id: id_type::TenantId,
+        org_id: id_type::OrganizationId,
+        merchant_id: id_type::MerchantId,
+        profile_id: id_type::ProfileId,
+    ) -> StorageResult<Vec<Self>> {
+        let check_lineage = dsl::org_id
+            .is_null()
+            .and(dsl::merchant_id.is_null())
+            .and(dsl::profile_id.is_null())
+            .or(dsl::org_id
+                .eq(org_id.clone())
+                .and(dsl::merchant_id.is_null())
+                .and(dsl::profile_id.is_null()))
+            .or(dsl::org_id
+                .eq(org_id.clone())
+                .and(dsl::merchant_id.eq(merchant_id.clone()))
+                .and(dsl::profile_id.is_null()))
+            .or(dsl::org_id
+                .eq(org_id)
+                .and(dsl::merchant_id.eq(merchant_id))
+                .and(dsl::profile_id.eq(profile_id)));
+
+        let predicate = dsl::user_id
+            .eq(user_id)
+            .and(dsl::tenant_id.eq(tenant_id))
+            .and(check_lineage);
+
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            predicate,
+            None,
+            None,
+            Some(dsl::deleted_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_user_id_tenant_id(
+        conn: &PgPooledConn,
+        user_id: String,
+        tenant_id: id_type::TenantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::user_id.eq(user_id).and(dsl::tenant_id.eq(tenant_id)),
+            None,
+            None,
+            Some(dsl::deleted_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_id(conn: &PgPooledConn, id: i32) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq(id),
+        )
+        .await
+    }
+}
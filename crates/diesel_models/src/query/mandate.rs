@@ -61,6 +61,22 @@ impl Mandate {
         .await
     }
 
+    pub async fn update_customer_id_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            dsl::customer_id.eq(new_customer_id.to_owned()),
+        )
+        .await
+    }
+
     //Fix this function once V2 mandate is schema is being built
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     pub async fn find_by_global_customer_id(
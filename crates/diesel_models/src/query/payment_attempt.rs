@@ -313,6 +313,30 @@ impl PaymentAttempt {
         .await
     }
 
+    /// Finds the attempts of all merchants which are in any of the statuses and were last modified
+    /// within the time range, least recently modified first
+    #[cfg(feature = "v1")]
+    pub async fn find_by_statuses_modified_between(
+        conn: &PgPooledConn,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        excluded_connectors: Vec<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::status
+                .eq_any(statuses)
+                .and(dsl::modified_at.between(modified_after, modified_before))
+                .and(dsl::connector.ne_all(excluded_connectors)),
+            Some(limit),
+            None,
+            Some(dsl::modified_at.asc()),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn get_filters_for_payments(
         conn: &PgPooledConn,
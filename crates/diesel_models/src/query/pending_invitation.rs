@@ -0,0 +1,78 @@
+use common_utils::{id_type, pii};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    pending_invitation::{PendingInvitation, PendingInvitationNew, PendingInvitationUpdate},
+    schema::pending_invitations::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PendingInvitationNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PendingInvitation> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PendingInvitation {
+    pub async fn find_by_email_tenant_id_merchant_id(
+        conn: &PgPooledConn,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::email
+                .eq(email.to_owned())
+                .and(dsl::tenant_id.eq(tenant_id.to_owned()))
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_id(
+        conn: &PgPooledConn,
+        id: i32,
+        pending_invitation_update: PendingInvitationUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(conn, dsl::id.eq(id), pending_invitation_update)
+        .await
+    }
+
+    pub async fn list_by_email_tenant_id(
+        conn: &PgPooledConn,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::email
+                .eq(email.to_owned())
+                .and(dsl::tenant_id.eq(tenant_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_email_tenant_id(
+        conn: &PgPooledConn,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::email
+                .eq(email.to_owned())
+                .and(dsl::tenant_id.eq(tenant_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -7,6 +7,7 @@ use diesel::{
 };
 use error_stack::{report, ResultExt};
 use strum::IntoEnumIterator;
+use time::PrimitiveDateTime;
 
 use crate::{
     enums::RoleScope, errors, query::generics, role::*, schema::roles::dsl, PgPooledConn,
@@ -98,7 +99,68 @@ impl Role {
         >(
             conn,
             dsl::role_id.eq(role_id.to_owned()),
-            RoleUpdateInternal::from(role_update),
+            (
+                RoleUpdateInternal::from(role_update),
+                dsl::version.eq(dsl::version + 1),
+            ),
+        )
+        .await
+    }
+
+    /// Stamps the usage of the role without touching its modification metadata or version
+    pub async fn update_last_used_at_by_role_id(
+        conn: &PgPooledConn,
+        role_id: &str,
+        last_used_at: PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::role_id.eq(role_id.to_owned()),
+            dsl::last_used_at.eq(Some(last_used_at)),
+        )
+        .await
+    }
+
+    pub async fn update_by_role_id_and_version(
+        conn: &PgPooledConn,
+        role_id: &str,
+        version: i32,
+        role_update: RoleUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::role_id
+                .eq(role_id.to_owned())
+                .and(dsl::version.eq(version)),
+            (
+                RoleUpdateInternal::from(role_update),
+                dsl::version.eq(dsl::version + 1),
+            ),
+        )
+        .await
+    }
+
+    pub async fn list_by_template_id(
+        conn: &PgPooledConn,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::template_id
+                .eq(template_id.to_owned())
+                .and(dsl::org_id.eq(org_id.to_owned()))
+                .and(dsl::tenant_id.eq(tenant_id.to_owned()))
+                .and(dsl::deleted_at.is_null()),
+            None,
+            None,
+            Some(dsl::merchant_id.asc()),
         )
         .await
     }
@@ -218,4 +280,30 @@ impl Role {
             Err(err) => Err(report!(err)).change_context(errors::DatabaseError::Others),
         }
     }
+
+    pub async fn get_count_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        tenant_id: &id_type::TenantId,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table()
+            .count()
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::tenant_id.eq(tenant_id.to_owned()))
+                    .and(dsl::deleted_at.is_null()),
+            )
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of roles")
+    }
 }
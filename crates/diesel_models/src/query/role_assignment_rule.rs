@@ -0,0 +1,58 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums,
+    role_assignment_rule::{RoleAssignmentRule, RoleAssignmentRuleNew},
+    schema::role_assignment_rules::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoleAssignmentRuleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoleAssignmentRule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoleAssignmentRule {
+    /// Lists the rules of the merchant in evaluation order
+    pub async fn list_by_tenant_id_org_id_merchant_id(
+        conn: &PgPooledConn,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::tenant_id
+                .eq(tenant_id.to_owned())
+                .and(dsl::org_id.eq(org_id.to_owned()))
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+            None,
+            None,
+            Some(dsl::priority.asc()),
+        )
+        .await
+    }
+
+    /// Lists the rules of the tenant evaluated against the attribute, which match the value
+    pub async fn list_by_tenant_id_attribute_value(
+        conn: &PgPooledConn,
+        tenant_id: &id_type::TenantId,
+        attribute: enums::RoleAssignmentAttribute,
+        value: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::tenant_id
+                .eq(tenant_id.to_owned())
+                .and(dsl::attribute.eq(attribute))
+                .and(dsl::value.eq(value.to_owned())),
+            None,
+            None,
+            Some(dsl::priority.asc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,38 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    role_audit::{RoleAudit, RoleAuditNew},
+    schema::role_audit::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoleAuditNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoleAudit> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoleAudit {
+    pub async fn list_by_tenant_id_org_id_merchant_id(
+        conn: &PgPooledConn,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::tenant_id
+                .eq(tenant_id.to_owned())
+                .and(dsl::org_id.eq(org_id.to_owned()))
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,53 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    role_template::{
+        RoleTemplate, RoleTemplateNew, RoleTemplateUpdate, RoleTemplateUpdateInternal,
+    },
+    schema::role_templates::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoleTemplateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoleTemplate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoleTemplate {
+    pub async fn find_by_template_id_org_id_tenant_id(
+        conn: &PgPooledConn,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::template_id
+                .eq(template_id.to_owned())
+                .and(dsl::org_id.eq(org_id.to_owned()))
+                .and(dsl::tenant_id.eq(tenant_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_template_id(
+        conn: &PgPooledConn,
+        template_id: &str,
+        template_update: RoleTemplateUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::template_id.eq(template_id.to_owned()),
+            RoleTemplateUpdateInternal::from(template_update),
+        )
+        .await
+    }
+}
@@ -1,11 +1,13 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::pii;
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, debug_query, pg::Pg, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
 
 pub mod sample_data;
 pub mod theme;
 
 use crate::{
-    query::generics, schema::users::dsl as users_dsl, user::*, PgPooledConn, StorageResult,
+    errors, query::generics, schema::users::dsl as users_dsl, user::*, PgPooledConn, StorageResult,
 };
 
 impl UserNew {
@@ -90,4 +92,40 @@ impl User {
         >(conn, users_dsl::user_id.eq_any(user_ids), None, None, None)
         .await
     }
+
+    pub async fn find_users_by_user_ids_paginated(
+        conn: &PgPooledConn,
+        user_ids: Vec<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            users_dsl::user_id.eq_any(user_ids),
+            limit,
+            offset,
+            Some((users_dsl::created_at.asc(), users_dsl::user_id.asc())),
+        )
+        .await
+    }
+
+    pub async fn get_count_by_user_ids(
+        conn: &PgPooledConn,
+        user_ids: Vec<String>,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table()
+            .count()
+            .filter(users_dsl::user_id.eq_any(user_ids))
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of users")
+    }
 }
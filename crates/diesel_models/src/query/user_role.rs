@@ -218,6 +218,15 @@ impl UserRole {
 
         if let Some(status) = status {
             query = query.filter(dsl::status.eq(status));
+
+            // Expired assignments are not honoured as active roles
+            if status == UserStatus::Active {
+                query = query.filter(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                );
+            }
         }
 
         if let Some(limit) = limit {
@@ -324,4 +333,140 @@ impl UserRole {
             },
         }
     }
+
+    pub async fn list_user_roles_by_tenant_id_after_id(
+        conn: &PgPooledConn,
+        tenant_id: id_type::TenantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::tenant_id.eq(tenant_id))
+            .into_boxed();
+
+        if let Some(start_after_id) = start_after_id {
+            query = query.filter(dsl::id.gt(start_after_id));
+        }
+
+        query = query.order(dsl::id.asc()).limit(limit.into());
+
+        router_env::logger::debug!(query = %debug_query::<Pg,_>(&query).to_string());
+
+        match generics::db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        {
+            Ok(value) => Ok(value),
+            Err(err) => Err(report!(err)).change_context(errors::DatabaseError::Others),
+        }
+    }
+
+    pub async fn list_user_roles_by_merchant_id_after_id(
+        conn: &PgPooledConn,
+        tenant_id: id_type::TenantId,
+        org_id: id_type::OrganizationId,
+        merchant_id: id_type::MerchantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(
+                dsl::tenant_id
+                    .eq(tenant_id)
+                    .and(dsl::org_id.eq(org_id))
+                    .and(dsl::merchant_id.eq(merchant_id)),
+            )
+            .into_boxed();
+
+        if let Some(start_after_id) = start_after_id {
+            query = query.filter(dsl::id.gt(start_after_id));
+        }
+
+        query = query.order(dsl::id.asc()).limit(limit.into());
+
+        router_env::logger::debug!(query = %debug_query::<Pg,_>(&query).to_string());
+
+        match generics::db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        {
+            Ok(value) => Ok(value),
+            Err(err) => Err(report!(err)).change_context(errors::DatabaseError::Others),
+        }
+    }
+
+    pub async fn get_active_users_count_for_role(
+        conn: &PgPooledConn,
+        tenant_id: id_type::TenantId,
+        org_id: id_type::OrganizationId,
+        role_id: String,
+    ) -> StorageResult<i64> {
+        // A user can hold the same role through both V1 and V2 rows, so users are counted once
+        let query = <Self as HasTable>::table()
+            .select(diesel::dsl::count_distinct(dsl::user_id))
+            .filter(
+                dsl::tenant_id
+                    .eq(tenant_id)
+                    .and(dsl::org_id.eq(org_id))
+                    .and(dsl::role_id.eq(role_id))
+                    .and(dsl::status.eq(UserStatus::Active))
+                    .and(
+                        dsl::expires_at
+                            .is_null()
+                            .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                    ),
+            )
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of users for the role")
+    }
+
+    pub async fn list_active_user_ids_for_role(
+        conn: &PgPooledConn,
+        tenant_id: id_type::TenantId,
+        org_id: id_type::OrganizationId,
+        role_id: String,
+        limit: u32,
+    ) -> StorageResult<Vec<String>> {
+        let query = <Self as HasTable>::table()
+            .select(dsl::user_id)
+            .distinct()
+            .filter(
+                dsl::tenant_id
+                    .eq(tenant_id)
+                    .and(dsl::org_id.eq(org_id))
+                    .and(dsl::role_id.eq(role_id))
+                    .and(dsl::status.eq(UserStatus::Active))
+                    .and(
+                        dsl::expires_at
+                            .is_null()
+                            .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                    ),
+            )
+            .order(dsl::user_id.asc())
+            .limit(limit.into())
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<String>(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to list users for the role")
+    }
 }
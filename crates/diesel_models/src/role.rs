@@ -21,6 +21,12 @@ pub struct Role {
     pub entity_type: enums::EntityType,
     pub profile_id: Option<id_type::ProfileId>,
     pub tenant_id: id_type::TenantId,
+    pub deleted_at: Option<PrimitiveDateTime>,
+    pub version: i32,
+    #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
+    pub custom_permissions: Option<Vec<String>>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+    pub template_id: Option<String>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -40,6 +46,8 @@ pub struct RoleNew {
     pub entity_type: enums::EntityType,
     pub profile_id: Option<id_type::ProfileId>,
     pub tenant_id: id_type::TenantId,
+    pub custom_permissions: Option<Vec<String>>,
+    pub template_id: Option<String>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -49,6 +57,7 @@ pub struct RoleUpdateInternal {
     role_name: Option<String>,
     last_modified_by: String,
     last_modified_at: PrimitiveDateTime,
+    deleted_at: Option<Option<PrimitiveDateTime>>,
 }
 
 pub enum RoleUpdate {
@@ -58,6 +67,14 @@ pub enum RoleUpdate {
         last_modified_at: PrimitiveDateTime,
         last_modified_by: String,
     },
+    SoftDelete {
+        last_modified_at: PrimitiveDateTime,
+        last_modified_by: String,
+    },
+    Restore {
+        last_modified_at: PrimitiveDateTime,
+        last_modified_by: String,
+    },
 }
 
 impl From<RoleUpdate> for RoleUpdateInternal {
@@ -73,6 +90,27 @@ impl From<RoleUpdate> for RoleUpdateInternal {
                 role_name,
                 last_modified_at,
                 last_modified_by,
+                deleted_at: None,
+            },
+            RoleUpdate::SoftDelete {
+                last_modified_at,
+                last_modified_by,
+            } => Self {
+                groups: None,
+                role_name: None,
+                last_modified_at,
+                last_modified_by,
+                deleted_at: Some(Some(last_modified_at)),
+            },
+            RoleUpdate::Restore {
+                last_modified_at,
+                last_modified_by,
+            } => Self {
+                groups: None,
+                role_name: None,
+                last_modified_at,
+                last_modified_by,
+                deleted_at: Some(None),
             },
         }
     }
@@ -0,0 +1,34 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::role_assignment_rules};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = role_assignment_rules, check_for_backend(diesel::pg::Pg))]
+pub struct RoleAssignmentRule {
+    pub id: i32,
+    pub priority: i32,
+    pub attribute: enums::RoleAssignmentAttribute,
+    pub value: String,
+    pub role_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub tenant_id: id_type::TenantId,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = role_assignment_rules)]
+pub struct RoleAssignmentRuleNew {
+    pub priority: i32,
+    pub attribute: enums::RoleAssignmentAttribute,
+    pub value: String,
+    pub role_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub tenant_id: id_type::TenantId,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+}
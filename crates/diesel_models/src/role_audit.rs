@@ -0,0 +1,38 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::role_audit};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = role_audit, check_for_backend(diesel::pg::Pg))]
+pub struct RoleAudit {
+    pub id: i32,
+    pub action: enums::RoleAuditAction,
+    pub actor_user_id: String,
+    pub target_id: String,
+    #[diesel(deserialize_as = super::DieselArray<enums::PermissionGroup>)]
+    pub groups_before: Vec<enums::PermissionGroup>,
+    #[diesel(deserialize_as = super::DieselArray<enums::PermissionGroup>)]
+    pub groups_after: Vec<enums::PermissionGroup>,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub tenant_id: id_type::TenantId,
+    pub created_at: PrimitiveDateTime,
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = role_audit)]
+pub struct RoleAuditNew {
+    pub action: enums::RoleAuditAction,
+    pub actor_user_id: String,
+    pub target_id: String,
+    pub groups_before: Vec<enums::PermissionGroup>,
+    pub groups_after: Vec<enums::PermissionGroup>,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub tenant_id: id_type::TenantId,
+    pub created_at: PrimitiveDateTime,
+    pub reason: Option<String>,
+}
@@ -0,0 +1,70 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::role_templates};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = role_templates, primary_key(template_id), check_for_backend(diesel::pg::Pg))]
+pub struct RoleTemplate {
+    pub template_id: String,
+    pub role_name: String,
+    #[diesel(deserialize_as = super::DieselArray<enums::PermissionGroup>)]
+    pub groups: Vec<enums::PermissionGroup>,
+    pub org_id: id_type::OrganizationId,
+    pub tenant_id: id_type::TenantId,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_by: String,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = role_templates)]
+pub struct RoleTemplateNew {
+    pub template_id: String,
+    pub role_name: String,
+    pub groups: Vec<enums::PermissionGroup>,
+    pub org_id: id_type::OrganizationId,
+    pub tenant_id: id_type::TenantId,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_by: String,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = role_templates)]
+pub struct RoleTemplateUpdateInternal {
+    groups: Option<Vec<enums::PermissionGroup>>,
+    role_name: Option<String>,
+    last_modified_by: String,
+    last_modified_at: PrimitiveDateTime,
+}
+
+pub enum RoleTemplateUpdate {
+    UpdateDetails {
+        groups: Option<Vec<enums::PermissionGroup>>,
+        role_name: Option<String>,
+        last_modified_at: PrimitiveDateTime,
+        last_modified_by: String,
+    },
+}
+
+impl From<RoleTemplateUpdate> for RoleTemplateUpdateInternal {
+    fn from(value: RoleTemplateUpdate) -> Self {
+        match value {
+            RoleTemplateUpdate::UpdateDetails {
+                groups,
+                role_name,
+                last_modified_at,
+                last_modified_by,
+            } => Self {
+                groups,
+                role_name,
+                last_modified_by,
+                last_modified_at,
+            },
+        }
+    }
+}
@@ -221,6 +221,7 @@ diesel::table! {
         card_testing_secret_key -> Nullable<Bytea>,
         is_clear_pan_retries_enabled -> Bool,
         force_3ds_challenge -> Nullable<Bool>,
+        allowed_payment_method_types -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -313,6 +314,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    custom_permission_groups (id) {
+        id -> Int4,
+        #[max_length = 64]
+        group_name -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        permissions -> Array<Nullable<Text>>,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        org_id -> Varchar,
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -340,6 +364,8 @@ diesel::table! {
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
         version -> ApiVersion,
+        #[max_length = 64]
+        merged_into_customer_id -> Nullable<Varchar>,
     }
 }
 
@@ -366,6 +392,43 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    deleted_user_roles (id) {
+        id -> Int4,
+        #[max_length = 64]
+        user_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        role_id -> Varchar,
+        #[max_length = 64]
+        org_id -> Nullable<Varchar>,
+        status -> UserStatus,
+        #[max_length = 64]
+        created_by -> Varchar,
+        #[max_length = 64]
+        last_modified_by -> Varchar,
+        created_at -> Timestamp,
+        last_modified -> Timestamp,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        entity_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        entity_type -> Nullable<Varchar>,
+        version -> UserRoleVersion,
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        expires_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        deleted_by -> Varchar,
+        deleted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -925,6 +988,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_fx_details (merchant_id, attempt_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        provider -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        settlement_amount -> Int8,
+        settlement_currency -> Currency,
+        #[max_length = 64]
+        exchange_rate -> Varchar,
+        rate_fetched_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1098,6 +1185,7 @@ diesel::table! {
         #[max_length = 64]
         network_token_locker_id -> Nullable<Varchar>,
         network_token_payment_method_data -> Nullable<Bytea>,
+        verified_at -> Nullable<Timestamp>,
     }
 }
 
@@ -1189,6 +1277,35 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    pending_invitations (id) {
+        id -> Int4,
+        #[max_length = 255]
+        email -> Varchar,
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        #[max_length = 64]
+        org_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        entity_id -> Varchar,
+        #[max_length = 64]
+        entity_type -> Varchar,
+        #[max_length = 64]
+        role_id -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1326,6 +1443,80 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    role_assignment_rules (id) {
+        id -> Int4,
+        priority -> Int4,
+        #[max_length = 64]
+        attribute -> Varchar,
+        #[max_length = 255]
+        value -> Varchar,
+        #[max_length = 64]
+        role_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        org_id -> Varchar,
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    role_audit (id) {
+        id -> Int4,
+        #[max_length = 64]
+        action -> Varchar,
+        #[max_length = 64]
+        actor_user_id -> Varchar,
+        #[max_length = 64]
+        target_id -> Varchar,
+        groups_before -> Array<Nullable<Text>>,
+        groups_after -> Array<Nullable<Text>>,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        org_id -> Varchar,
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        created_at -> Timestamp,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    role_templates (template_id) {
+        #[max_length = 64]
+        template_id -> Varchar,
+        #[max_length = 64]
+        role_name -> Varchar,
+        groups -> Array<Nullable<Text>>,
+        #[max_length = 64]
+        org_id -> Varchar,
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+        #[max_length = 64]
+        last_modified_by -> Varchar,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1353,6 +1544,12 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 64]
         tenant_id -> Varchar,
+        deleted_at -> Nullable<Timestamp>,
+        version -> Int4,
+        custom_permissions -> Nullable<Array<Nullable<Text>>>,
+        last_used_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        template_id -> Nullable<Varchar>,
     }
 }
 
@@ -1497,6 +1694,7 @@ diesel::table! {
         version -> UserRoleVersion,
         #[max_length = 64]
         tenant_id -> Varchar,
+        expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -1535,8 +1733,10 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    custom_permission_groups,
     customers,
     dashboard_metadata,
+    deleted_user_roles,
     dispute,
     dynamic_routing_stats,
     events,
@@ -1552,15 +1752,20 @@ diesel::allow_tables_to_appear_in_same_query!(
     merchant_key_store,
     organization,
     payment_attempt,
+    payment_fx_details,
     payment_intent,
     payment_link,
     payment_methods,
     payout_attempt,
     payouts,
+    pending_invitations,
     process_tracker,
     refund,
     relay,
     reverse_lookup,
+    role_assignment_rules,
+    role_audit,
+    role_templates,
     roles,
     routing_algorithm,
     themes,
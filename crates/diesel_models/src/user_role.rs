@@ -25,6 +25,7 @@ pub struct UserRole {
     pub entity_type: Option<EntityType>,
     pub version: enums::UserRoleVersion,
     pub tenant_id: id_type::TenantId,
+    pub expires_at: Option<PrimitiveDateTime>,
 }
 
 impl UserRole {
@@ -44,6 +45,11 @@ impl UserRole {
             (enums::UserRoleVersion::V2, _, _) => self.entity_id.clone().zip(self.entity_type),
         }
     }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= common_utils::date_time::now())
+    }
 }
 
 impl Hash for UserRole {
@@ -92,6 +98,7 @@ pub struct UserRoleNew {
     pub entity_type: Option<EntityType>,
     pub version: enums::UserRoleVersion,
     pub tenant_id: id_type::TenantId,
+    pub expires_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -101,6 +108,7 @@ pub struct UserRoleUpdateInternal {
     status: Option<enums::UserStatus>,
     last_modified_by: Option<String>,
     last_modified: PrimitiveDateTime,
+    expires_at: Option<Option<PrimitiveDateTime>>,
 }
 
 #[derive(Clone)]
@@ -108,10 +116,13 @@ pub enum UserRoleUpdate {
     UpdateStatus {
         status: enums::UserStatus,
         modified_by: String,
+        expires_at: Option<PrimitiveDateTime>,
     },
     UpdateRole {
         role_id: String,
         modified_by: String,
+        /// The expiry of the assignment is left unchanged if not provided
+        expires_at: Option<PrimitiveDateTime>,
     },
 }
 
@@ -122,20 +133,24 @@ impl From<UserRoleUpdate> for UserRoleUpdateInternal {
             UserRoleUpdate::UpdateRole {
                 role_id,
                 modified_by,
+                expires_at,
             } => Self {
                 role_id: Some(role_id),
                 last_modified_by: Some(modified_by),
                 status: None,
                 last_modified,
+                expires_at: expires_at.map(Some),
             },
             UserRoleUpdate::UpdateStatus {
                 status,
                 modified_by,
+                expires_at,
             } => Self {
                 status: Some(status),
                 last_modified,
                 last_modified_by: Some(modified_by),
                 role_id: None,
+                expires_at: Some(expires_at),
             },
         }
     }
@@ -13,6 +13,7 @@ use hyperswitch_domain_models::{
     types::{self, RefundsRouterData},
 };
 use hyperswitch_interfaces::{
+    api,
     consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE},
     errors,
 };
@@ -189,6 +190,10 @@ pub struct BraintreeMeta {
     merchant_config_currency: enums::Currency,
 }
 
+impl api::ConnectorMetadataSchema for BraintreeMeta {
+    const FIELDS: &'static [&'static str] = &["merchant_account_id", "merchant_config_currency"];
+}
+
 impl TryFrom<&Option<pii::SecretSerdeValue>> for BraintreeMeta {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
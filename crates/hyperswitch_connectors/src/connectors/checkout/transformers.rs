@@ -31,7 +31,7 @@ use crate::{
     },
     unimplemented_payment_method,
     utils::{
-        self, ApplePayDecrypt, PaymentsCaptureRequestData, RouterData as OtherRouterData,
+        self, PaymentsCaptureRequestData, RouterData as OtherRouterData,
         WalletData as OtherWalletData,
     },
 };
@@ -221,6 +221,35 @@ pub struct ApplePayPredecrypt {
     cryptogram: Secret<String>,
 }
 
+/// Network token source of a wallet token decrypted by the router, built from the card details
+/// which are normalized across the wallets
+fn get_decrypted_wallet_source(
+    payment_method_token: &PaymentMethodToken,
+    token_type: &str,
+) -> Result<PaymentSource, error_stack::Report<errors::ConnectorError>> {
+    let decrypted_card_data = payment_method_token
+        .get_decrypted_wallet_card_data()
+        .ok_or(errors::ConnectorError::InvalidWalletToken {
+            wallet_name: token_type.to_string(),
+        })?;
+
+    Ok(PaymentSource::ApplePayPredecrypt(Box::new(
+        ApplePayPredecrypt {
+            token: decrypted_card_data.card_number,
+            decrypt_type: "network_token".to_string(),
+            token_type: token_type.to_string(),
+            expiry_month: decrypted_card_data.card_exp_month,
+            expiry_year: decrypted_card_data.card_exp_year,
+            eci: decrypted_card_data.eci_indicator,
+            cryptogram: decrypted_card_data.cryptogram.ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "cryptogram",
+                },
+            )?,
+        },
+    )))
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckoutSourceTypes {
@@ -312,21 +341,27 @@ impl TryFrom<&CheckoutRouterData<&PaymentsAuthorizeRouterData>> for PaymentsRequ
                 Ok(a)
             }
             PaymentMethodData::Wallet(wallet_data) => match wallet_data {
-                WalletData::GooglePay(_) => Ok(PaymentSource::Wallets(WalletSource {
-                    source_type: CheckoutSourceTypes::Token,
-                    token: match item.router_data.get_payment_method_token()? {
-                        PaymentMethodToken::Token(token) => token,
+                WalletData::GooglePay(_) => {
+                    let payment_method_token = item.router_data.get_payment_method_token()?;
+                    match payment_method_token {
+                        PaymentMethodToken::Token(token) => {
+                            Ok(PaymentSource::Wallets(WalletSource {
+                                source_type: CheckoutSourceTypes::Token,
+                                token,
+                            }))
+                        }
+                        PaymentMethodToken::GooglePayDecrypt(_) => Ok(get_decrypted_wallet_source(
+                            &payment_method_token,
+                            "googlepay",
+                        )?),
                         PaymentMethodToken::ApplePayDecrypt(_) => Err(
                             unimplemented_payment_method!("Apple Pay", "Simplified", "Checkout"),
                         )?,
                         PaymentMethodToken::PazeDecrypt(_) => {
                             Err(unimplemented_payment_method!("Paze", "Checkout"))?
                         }
-                        PaymentMethodToken::GooglePayDecrypt(_) => {
-                            Err(unimplemented_payment_method!("Google Pay", "Checkout"))?
-                        }
-                    },
-                })),
+                    }
+                }
                 WalletData::ApplePay(_) => {
                     let payment_method_token = item.router_data.get_payment_method_token()?;
                     match payment_method_token {
@@ -336,21 +371,10 @@ impl TryFrom<&CheckoutRouterData<&PaymentsAuthorizeRouterData>> for PaymentsRequ
                                 token: apple_pay_payment_token,
                             }))
                         }
-                        PaymentMethodToken::ApplePayDecrypt(decrypt_data) => {
-                            let exp_month = decrypt_data.get_expiry_month()?;
-                            let expiry_year_4_digit = decrypt_data.get_four_digit_expiry_year()?;
-                            Ok(PaymentSource::ApplePayPredecrypt(Box::new(
-                                ApplePayPredecrypt {
-                                    token: decrypt_data.application_primary_account_number,
-                                    decrypt_type: "network_token".to_string(),
-                                    token_type: "applepay".to_string(),
-                                    expiry_month: exp_month,
-                                    expiry_year: expiry_year_4_digit,
-                                    eci: decrypt_data.payment_data.eci_indicator,
-                                    cryptogram: decrypt_data.payment_data.online_payment_cryptogram,
-                                },
-                            )))
-                        }
+                        PaymentMethodToken::ApplePayDecrypt(_) => Ok(get_decrypted_wallet_source(
+                            &payment_method_token,
+                            "applepay",
+                        )?),
                         PaymentMethodToken::PazeDecrypt(_) => {
                             Err(unimplemented_payment_method!("Paze", "Checkout"))?
                         }
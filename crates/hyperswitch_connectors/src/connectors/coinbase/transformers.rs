@@ -10,7 +10,7 @@ use hyperswitch_domain_models::{
     router_response_types::{PaymentsResponseData, RedirectForm},
     types,
 };
-use hyperswitch_interfaces::errors;
+use hyperswitch_interfaces::{api, errors};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 
@@ -254,6 +254,10 @@ pub struct CoinbaseConnectorMeta {
     pub pricing_type: String,
 }
 
+impl api::ConnectorMetadataSchema for CoinbaseConnectorMeta {
+    const FIELDS: &'static [&'static str] = &["pricing_type"];
+}
+
 impl TryFrom<&Option<pii::SecretSerdeValue>> for CoinbaseConnectorMeta {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
@@ -1328,12 +1328,14 @@ impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Cy
         req: &PaymentsCancelRouterData,
         _connectors: &Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
-        let minor_amount =
-            req.request
-                .minor_amount
-                .ok_or(errors::ConnectorError::MissingRequiredField {
-                    field_name: "Amount",
-                })?;
+        // A partial reversal releases only the amount to be voided from the authorization
+        let minor_amount = req
+            .request
+            .minor_amount_to_void
+            .or(req.request.minor_amount)
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "Amount",
+            })?;
         let currency =
             req.request
                 .currency
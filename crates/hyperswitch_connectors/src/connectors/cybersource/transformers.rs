@@ -105,6 +105,10 @@ pub struct CybersourceConnectorMetadataObject {
     pub disable_cvn: Option<bool>,
 }
 
+impl api::ConnectorMetadataSchema for CybersourceConnectorMetadataObject {
+    const FIELDS: &'static [&'static str] = &["disable_avs", "disable_cvn"];
+}
+
 impl TryFrom<&Option<pii::SecretSerdeValue>> for CybersourceConnectorMetadataObject {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
@@ -9,7 +9,7 @@ use hyperswitch_domain_models::{
     router_response_types::{PaymentsResponseData, RefundsResponseData},
     types,
 };
-use hyperswitch_interfaces::errors;
+use hyperswitch_interfaces::{api, errors};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 
@@ -446,6 +446,10 @@ pub struct FiservSessionObject {
     pub terminal_id: Secret<String>,
 }
 
+impl api::ConnectorMetadataSchema for FiservSessionObject {
+    const FIELDS: &'static [&'static str] = &["terminal_id"];
+}
+
 impl TryFrom<&Option<pii::SecretSerdeValue>> for FiservSessionObject {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
@@ -13,7 +13,7 @@ use hyperswitch_domain_models::{
     router_response_types::{PaymentsResponseData, RedirectForm, RefundsResponseData},
     types,
 };
-use hyperswitch_interfaces::errors;
+use hyperswitch_interfaces::{api, errors};
 use masking::{ExposeInterface, Secret};
 use serde::{Deserialize, Serialize};
 
@@ -45,6 +45,10 @@ pub struct KlarnaConnectorMetadataObject {
     pub klarna_region: Option<KlarnaEndpoint>,
 }
 
+impl api::ConnectorMetadataSchema for KlarnaConnectorMetadataObject {
+    const FIELDS: &'static [&'static str] = &["klarna_region"];
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum KlarnaEndpoint {
     Europe,
@@ -11,7 +11,7 @@ use hyperswitch_domain_models::{
     router_response_types::{PaymentsResponseData, RedirectForm},
     types,
 };
-use hyperswitch_interfaces::errors;
+use hyperswitch_interfaces::{api, errors};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::Date;
@@ -44,6 +44,10 @@ pub struct MifinityConnectorMetadataObject {
     pub destination_account_number: Secret<String>,
 }
 
+impl api::ConnectorMetadataSchema for MifinityConnectorMetadataObject {
+    const FIELDS: &'static [&'static str] = &["brand_id", "destination_account_number"];
+}
+
 impl TryFrom<&Option<pii::SecretSerdeValue>> for MifinityConnectorMetadataObject {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
@@ -1045,4 +1045,10 @@ impl utils::ConnectorErrorTypeMapping for Trustpay {
     }
 }
 
-impl ConnectorSpecifications for Trustpay {}
+impl ConnectorSpecifications for Trustpay {
+    fn get_statement_descriptor_max_length(&self) -> Option<usize> {
+        Some(usize::from(
+            common_utils::consts::MAX_STATEMENT_DESCRIPTOR_LENGTH,
+        ))
+    }
+}
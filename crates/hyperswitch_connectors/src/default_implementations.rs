@@ -572,7 +572,11 @@ macro_rules! default_imp_for_incremental_authorization {
             PaymentsIncrementalAuthorizationData,
             PaymentsResponseData,
         > for $path::$connector
-        {}
+        {
+            fn is_flow_implemented(&self) -> bool {
+                false
+            }
+        }
     )*
     };
 }
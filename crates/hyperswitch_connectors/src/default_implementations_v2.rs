@@ -140,7 +140,11 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 PaymentsIncrementalAuthorizationData,
                 PaymentsResponseData,
             >
-            for $path::$connector{}
+            for $path::$connector{
+                fn is_flow_implemented(&self) -> bool {
+                    false
+                }
+            }
             impl
             ConnectorIntegrationV2<
             CompleteAuthorize,
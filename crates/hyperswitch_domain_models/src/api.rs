@@ -16,6 +16,7 @@ pub enum ApplicationResponse<R> {
     Form(Box<RedirectionFormData>),
     PaymentLinkForm(Box<PaymentLinkAction>),
     FileData((Vec<u8>, mime::Mime)),
+    FileStream(FileStream),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
 }
@@ -33,6 +34,7 @@ impl<R> ApplicationResponse<R> {
             | Self::Form(_)
             | Self::PaymentLinkForm(_)
             | Self::FileData(_)
+            | Self::FileStream(_)
             | Self::GenericLinkForm(_)
             | Self::StatusOk => Err(common_utils::errors::ValidationError::InvalidValue {
                 message: "expected either Json or JsonWithHeaders Response".to_string(),
@@ -54,6 +56,29 @@ impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
 
 impl_api_event_type!(Miscellaneous, (PaymentLinkFormData, GenericLinkFormData));
 
+/// File which is sent to the client chunk by chunk, as the chunks are generated
+pub struct FileStream {
+    pub stream: futures::stream::BoxStream<'static, Result<Vec<u8>, std::io::Error>>,
+    pub content_type: mime::Mime,
+}
+
+impl std::fmt::Debug for FileStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStream")
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
+}
+
+// The chunks of a stream cannot be compared, so a file stream is only equal to itself
+impl PartialEq for FileStream {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for FileStream {}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct RedirectionFormData {
     pub redirect_form: crate::router_response_types::RedirectForm,
@@ -66,6 +66,7 @@ pub struct Profile {
     pub card_testing_secret_key: OptionalEncryptableName,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: bool,
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[cfg(feature = "v1")]
@@ -114,6 +115,7 @@ pub struct ProfileSetter {
     pub card_testing_secret_key: OptionalEncryptableName,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: bool,
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[cfg(feature = "v1")]
@@ -168,6 +170,7 @@ impl From<ProfileSetter> for Profile {
             card_testing_secret_key: value.card_testing_secret_key,
             is_clear_pan_retries_enabled: value.is_clear_pan_retries_enabled,
             force_3ds_challenge: value.force_3ds_challenge,
+            allowed_payment_method_types: value.allowed_payment_method_types,
         }
     }
 }
@@ -182,6 +185,20 @@ impl Profile {
     pub fn get_id(&self) -> &common_utils::id_type::ProfileId {
         &self.id
     }
+
+    /// Checks whether the payment method type is accepted for the payments of the profile
+    #[cfg(feature = "v1")]
+    pub fn is_payment_method_type_allowed(
+        &self,
+        payment_method_type: common_enums::PaymentMethodType,
+    ) -> bool {
+        self.allowed_payment_method_types
+            .as_ref()
+            .map_or(true, |allowed_payment_method_types| {
+                allowed_payment_method_types.is_empty()
+                    || allowed_payment_method_types.contains(&payment_method_type)
+            })
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -224,6 +241,7 @@ pub struct ProfileGeneralUpdate {
     pub card_testing_secret_key: OptionalEncryptableName,
     pub is_clear_pan_retries_enabled: Option<bool>,
     pub force_3ds_challenge: Option<bool>,
+    pub allowed_payment_method_types: Option<Vec<common_enums::PaymentMethodType>>,
 }
 
 #[cfg(feature = "v1")]
@@ -295,6 +313,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     card_testing_secret_key,
                     is_clear_pan_retries_enabled,
                     force_3ds_challenge,
+                    allowed_payment_method_types,
                 } = *update;
 
                 Self {
@@ -339,6 +358,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     card_testing_secret_key: card_testing_secret_key.map(Encryption::from),
                     is_clear_pan_retries_enabled,
                     force_3ds_challenge,
+                    allowed_payment_method_types,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -385,6 +405,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                allowed_payment_method_types: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -429,6 +450,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                allowed_payment_method_types: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -473,6 +495,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                allowed_payment_method_types: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -517,6 +540,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                allowed_payment_method_types: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -561,6 +585,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                allowed_payment_method_types: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -605,6 +630,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: card_testing_secret_key.map(Encryption::from),
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                allowed_payment_method_types: None,
             },
         }
     }
@@ -668,6 +694,7 @@ impl super::behaviour::Conversion for Profile {
             card_testing_secret_key: self.card_testing_secret_key.map(|name| name.into()),
             is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled,
             force_3ds_challenge: Some(self.force_3ds_challenge),
+            allowed_payment_method_types: self.allowed_payment_method_types,
         })
     }
 
@@ -756,6 +783,7 @@ impl super::behaviour::Conversion for Profile {
                     .await?,
                 is_clear_pan_retries_enabled: item.is_clear_pan_retries_enabled,
                 force_3ds_challenge: item.force_3ds_challenge.unwrap_or_default(),
+                allowed_payment_method_types: item.allowed_payment_method_types,
             })
         }
         .await
@@ -814,6 +842,7 @@ impl super::behaviour::Conversion for Profile {
             card_testing_secret_key: self.card_testing_secret_key.map(Encryption::from),
             is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled,
             force_3ds_challenge: Some(self.force_3ds_challenge),
+            allowed_payment_method_types: self.allowed_payment_method_types,
         })
     }
 }
@@ -1563,6 +1592,7 @@ impl super::behaviour::Conversion for Profile {
             card_testing_secret_key: self.card_testing_secret_key.map(|name| name.into()),
             is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled,
             force_3ds_challenge: None,
+            allowed_payment_method_types: None,
         })
     }
 
@@ -40,6 +40,8 @@ pub struct Customer {
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: common_enums::ApiVersion,
+    /// The customer into which this customer has been merged
+    pub merged_into_customer_id: Option<id_type::CustomerId>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -129,6 +131,7 @@ impl super::behaviour::Conversion for Customer {
             default_payment_method_id: self.default_payment_method_id,
             updated_by: self.updated_by,
             version: self.version,
+            merged_into_customer_id: self.merged_into_customer_id,
         })
     }
 
@@ -187,6 +190,7 @@ impl super::behaviour::Conversion for Customer {
             default_payment_method_id: item.default_payment_method_id,
             updated_by: item.updated_by,
             version: item.version,
+            merged_into_customer_id: item.merged_into_customer_id,
         })
     }
 
@@ -442,6 +446,9 @@ pub enum CustomerUpdate {
     UpdateDefaultPaymentMethod {
         default_payment_method_id: Option<Option<String>>,
     },
+    Merge {
+        merged_into_customer_id: id_type::CustomerId,
+    },
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -469,6 +476,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 address_id,
                 default_payment_method_id: None,
                 updated_by: None,
+                merged_into_customer_id: None,
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
@@ -482,6 +490,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 default_payment_method_id: None,
                 updated_by: None,
                 address_id: None,
+                merged_into_customer_id: None,
             },
             CustomerUpdate::UpdateDefaultPaymentMethod {
                 default_payment_method_id,
@@ -497,6 +506,25 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 connector_customer: None,
                 updated_by: None,
                 address_id: None,
+                merged_into_customer_id: None,
+            },
+            // The payment methods of a merged customer now belong to the customer it has been
+            // merged into, so its default payment method is cleared
+            CustomerUpdate::Merge {
+                merged_into_customer_id,
+            } => Self {
+                merged_into_customer_id: Some(merged_into_customer_id),
+                default_payment_method_id: Some(None),
+                modified_at: date_time::now(),
+                name: None,
+                email: None,
+                phone: None,
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+                updated_by: None,
+                address_id: None,
             },
         }
     }
@@ -50,6 +50,8 @@ pub enum ApiErrorResponse {
     VerificationFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Dispute operation failed while processing with connector. Retry operation")]
     DisputeFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ConnectorError, code = "CE_09", message = "{connector} is temporarily unavailable. Retry after some time")]
+    ConnectorUnavailable { connector: String },
 
     #[error(error_type = ErrorType::LockTimeout, code = "HE_00", message = "Resource is busy. Please try again later.")]
     ResourceBusy,
@@ -363,6 +365,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::DisputeFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 8, "Dispute operation failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()})))
             }
+            Self::ConnectorUnavailable { connector } => {
+                AER::ConnectorError(ApiError::new("CE", 9, format!("{connector} is temporarily unavailable. Retry after some time"), Some(Extra {connector: Some(connector.clone()), ..Default::default()})), StatusCode::SERVICE_UNAVAILABLE)
+            }
 
             Self::ResourceBusy => {
                 AER::Unprocessable(ApiError::new("HE", 0, "There was an issue processing the webhook body", None))
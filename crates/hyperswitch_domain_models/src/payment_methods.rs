@@ -82,6 +82,8 @@ pub struct PaymentMethod {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: OptionalEncryptableValue,
+    /// Time at which the card was verified with the connector without charging the customer
+    pub verified_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -283,6 +285,7 @@ impl super::behaviour::Conversion for PaymentMethod {
             network_token_payment_method_data: self
                 .network_token_payment_method_data
                 .map(|val| val.into()),
+            verified_at: self.verified_at,
         })
     }
 
@@ -370,6 +373,7 @@ impl super::behaviour::Conversion for PaymentMethod {
                         .and_then(|val| val.try_into_optionaloperation())
                     })
                     .await?,
+                verified_at: item.verified_at,
             })
         }
         .await
@@ -942,6 +946,7 @@ mod tests {
             network_token_requestor_reference_id: None,
             network_token_locker_id: None,
             network_token_payment_method_data: None,
+            verified_at: None,
         };
         payment_method.clone()
     }
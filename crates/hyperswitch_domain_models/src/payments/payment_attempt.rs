@@ -183,6 +183,15 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_by_statuses_modified_within(
+        &self,
+        statuses: Vec<storage_enums::AttemptStatus>,
+        time_range: &common_utils::types::TimeRange,
+        excluded_connectors: Vec<String>,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filters_for_payments(
         &self,
@@ -1248,6 +1257,7 @@ pub enum PaymentAttemptUpdate {
     AmountToCaptureUpdate {
         status: storage_enums::AttemptStatus,
         amount_capturable: MinorUnit,
+        amount_to_capture: Option<MinorUnit>,
         updated_by: String,
     },
     PreprocessingUpdate {
@@ -1596,10 +1606,12 @@ impl PaymentAttemptUpdate {
             Self::AmountToCaptureUpdate {
                 status,
                 amount_capturable,
+                amount_to_capture,
                 updated_by,
             } => DieselPaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
                 amount_capturable,
+                amount_to_capture,
                 updated_by,
             },
             Self::ConnectorResponse {
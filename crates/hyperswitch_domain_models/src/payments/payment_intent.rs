@@ -1211,6 +1211,12 @@ pub struct PaymentIntentListParams {
     pub card_network: Option<Vec<common_enums::CardNetwork>>,
     pub card_discovery: Option<Vec<common_enums::CardDiscovery>>,
     pub merchant_order_reference_id: Option<String>,
+    /// Metadata key and the text representation of the value which has to be stored against the
+    /// key in the metadata of the payment
+    pub metadata: Option<(String, String)>,
+    /// Creation time and id of the last payment of the previous page, the payments after which
+    /// are listed when the payments are ordered by the creation time
+    pub page_after: Option<(PrimitiveDateTime, id_type::PaymentId)>,
 }
 
 #[cfg(feature = "v2")]
@@ -1271,6 +1277,8 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             card_network: None,
             card_discovery: None,
             merchant_order_reference_id: None,
+            metadata: None,
+            page_after: None,
         }))
     }
 }
@@ -1365,6 +1373,8 @@ impl From<common_utils::types::TimeRange> for PaymentIntentFetchConstraints {
             card_network: None,
             card_discovery: None,
             merchant_order_reference_id: None,
+            metadata: None,
+            page_after: None,
         }))
     }
 }
@@ -1416,11 +1426,50 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
                 card_network,
                 card_discovery,
                 merchant_order_reference_id,
+                metadata: None,
+                page_after: None,
             }))
         }
     }
 }
 
+#[cfg(feature = "v1")]
+impl From<api_models::payments::PaymentMetadataSearchRequest> for PaymentIntentFetchConstraints {
+    fn from(value: api_models::payments::PaymentMetadataSearchRequest) -> Self {
+        let api_models::payments::PaymentMetadataSearchRequest {
+            key,
+            value,
+            value_type: _,
+            limit,
+            offset,
+        } = value;
+        Self::List(Box::new(PaymentIntentListParams {
+            offset: offset.unwrap_or_default(),
+            starting_at: None,
+            ending_at: None,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: None,
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(std::cmp::min(limit, PAYMENTS_LIST_MAX_LIMIT_V1)),
+            order: Default::default(),
+            card_network: None,
+            card_discovery: None,
+            merchant_order_reference_id: None,
+            metadata: Some((key, common_utils::metadata::get_text_representation(&value))),
+            page_after: None,
+        }))
+    }
+}
+
 #[cfg(feature = "v1")]
 impl<T> TryFrom<(T, Option<Vec<id_type::ProfileId>>)> for PaymentIntentFetchConstraints
 where
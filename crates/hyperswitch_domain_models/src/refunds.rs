@@ -12,6 +12,10 @@ pub struct RefundListConstraints {
     pub merchant_connector_id: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub refund_status: Option<Vec<common_enums::RefundStatus>>,
+    pub order: Option<api_models::payments::Order>,
+    /// Creation time and id of the last refund of the previous page, the refunds after which are
+    /// listed when the refunds are ordered by the creation time
+    pub page_after: Option<(time::PrimitiveDateTime, String)>,
 }
 
 impl
@@ -40,6 +44,7 @@ impl
             time_range,
             amount_filter,
             merchant_connector_id,
+            order,
         } = value;
         let profile_id_from_request_body = profile_id;
         let profile_id_list = match (profile_id_from_request_body, auth_profile_id_list) {
@@ -77,6 +82,8 @@ impl
             merchant_connector_id,
             currency,
             refund_status,
+            order,
+            page_after: None,
         })
     }
 }
@@ -241,6 +241,67 @@ pub enum PaymentMethodToken {
     PazeDecrypt(Box<PazeDecryptedData>),
 }
 
+impl PaymentMethodToken {
+    /// Card details of a wallet token decrypted by the router, in a form which any connector can
+    /// consume irrespective of the wallet
+    pub fn get_decrypted_wallet_card_data(&self) -> Option<DecryptedWalletCardData> {
+        match self {
+            Self::Token(_) => None,
+            Self::ApplePayDecrypt(apple_pay_data) => {
+                // The expiration date is in the YYMMDD format
+                let expiration_date = &apple_pay_data.application_expiration_date;
+                Some(DecryptedWalletCardData {
+                    card_number: apple_pay_data.application_primary_account_number.clone(),
+                    card_exp_month: Secret::new(expiration_date.get(2..4)?.to_owned()),
+                    card_exp_year: Secret::new(format!("20{}", expiration_date.get(0..2)?)),
+                    cryptogram: Some(
+                        apple_pay_data
+                            .payment_data
+                            .online_payment_cryptogram
+                            .clone(),
+                    ),
+                    eci_indicator: apple_pay_data.payment_data.eci_indicator.clone(),
+                })
+            }
+            Self::GooglePayDecrypt(google_pay_data) => {
+                let details = &google_pay_data.payment_method_details;
+                Some(DecryptedWalletCardData {
+                    card_number: Secret::new(details.pan.get_card_no()),
+                    card_exp_month: Secret::new(details.expiration_month.two_digits()),
+                    card_exp_year: Secret::new(details.expiration_year.four_digits()),
+                    cryptogram: details.cryptogram.clone(),
+                    eci_indicator: details.eci_indicator.clone(),
+                })
+            }
+            Self::PazeDecrypt(paze_data) => Some(DecryptedWalletCardData {
+                card_number: Secret::new(paze_data.token.payment_token.get_card_no()),
+                card_exp_month: paze_data.token.token_expiration_month.clone(),
+                card_exp_year: paze_data.token.token_expiration_year.clone(),
+                cryptogram: paze_data
+                    .dynamic_data
+                    .iter()
+                    .find_map(|dynamic_data| dynamic_data.dynamic_data_value.clone()),
+                eci_indicator: paze_data.eci.clone(),
+            }),
+        }
+    }
+}
+
+/// Card details decrypted from a wallet token
+#[derive(Debug, Clone)]
+pub struct DecryptedWalletCardData {
+    /// The card number, which is a device specific network token for tokenized wallets
+    pub card_number: Secret<String>,
+    /// Two digit expiry month of the card
+    pub card_exp_month: Secret<String>,
+    /// Four digit expiry year of the card
+    pub card_exp_year: Secret<String>,
+    /// Cryptogram generated for the transaction
+    pub cryptogram: Option<Secret<String>>,
+    /// Electronic commerce indicator of the transaction
+    pub eci_indicator: Option<String>,
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplePayPredecryptData {
@@ -646,7 +707,8 @@ impl
             }
             // Invalid statues for this flow, after doing authorization this state is invalid
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 
@@ -678,7 +740,8 @@ impl
             common_enums::IntentStatus::RequiresCapture => Some(MinorUnit::zero()),
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 }
@@ -851,7 +914,8 @@ impl
             }
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 
@@ -888,7 +952,8 @@ impl
             | common_enums::IntentStatus::Processing => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
-            | common_enums::IntentStatus::RequiresConfirmation => None,
+            | common_enums::IntentStatus::RequiresConfirmation
+            | common_enums::IntentStatus::Expired => None,
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::PartiallyCapturedAndCapturable => {
@@ -1073,7 +1138,8 @@ impl
             }
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 
@@ -1112,7 +1178,8 @@ impl
             }
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 }
@@ -1300,7 +1367,8 @@ impl
             }
             // Invalid statues for this flow, after doing authorization this state is invalid
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 
@@ -1332,7 +1400,8 @@ impl
             common_enums::IntentStatus::RequiresCapture => Some(MinorUnit::zero()),
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 }
@@ -74,6 +74,8 @@ pub struct PaymentsAuthorizeData {
     pub additional_payment_method_data: Option<AdditionalPaymentData>,
     pub merchant_account_id: Option<Secret<String>>,
     pub merchant_config_currency: Option<storage_enums::Currency>,
+    /// 3DS challenge flow to be requested from the issuer, the connector default applies if not set
+    pub three_ds_challenge_flow: Option<storage_enums::DecoupledAuthenticationType>,
 }
 #[derive(Debug, Clone)]
 pub struct PaymentsPostSessionTokensData {
@@ -467,6 +469,9 @@ pub struct PaymentsCancelData {
 
     // minor amount data for amount framework
     pub minor_amount: Option<MinorUnit>,
+    /// Amount of the authorization to be released in a partial void, the entire authorization
+    /// is voided if it is not set
+    pub minor_amount_to_void: Option<MinorUnit>,
 }
 
 #[derive(Debug, Default, Clone)]
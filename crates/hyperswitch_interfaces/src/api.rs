@@ -100,6 +100,12 @@ pub trait ConnectorIntegration<T, Req, Resp>:
         mime::APPLICATION_JSON.essence_str()
     }
 
+    /// Whether the connector implements this flow, the default implementations of the flows
+    /// that are not supported by the connector return false
+    fn is_flow_implemented(&self) -> bool {
+        true
+    }
+
     /// primarily used when creating signature based on request method of payment flow
     fn get_http_method(&self) -> Method {
         Method::Post
@@ -306,6 +312,19 @@ pub trait ConnectorSpecifications {
     fn get_connector_about(&self) -> Option<&'static ConnectorInfo> {
         None
     }
+
+    /// Maximum length of the statement descriptor accepted by the connector, None if the connector
+    /// does not support setting the statement descriptor of a payment
+    fn get_statement_descriptor_max_length(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Schema of the metadata of the merchant connector account read by the connector, against which
+/// the metadata is validated when the merchant connector account is created or updated
+pub trait ConnectorMetadataSchema: serde::de::DeserializeOwned {
+    /// Top level fields of the metadata known to the connector
+    const FIELDS: &'static [&'static str];
 }
 
 /// Extended trait for connector common to allow functions with generic type
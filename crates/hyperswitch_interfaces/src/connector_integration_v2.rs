@@ -54,6 +54,12 @@ pub trait ConnectorIntegrationV2<Flow, ResourceCommonData, Req, Resp>:
         mime::APPLICATION_JSON.essence_str()
     }
 
+    /// Whether the connector implements this flow, the default implementations of the flows
+    /// that are not supported by the connector return false
+    fn is_flow_implemented(&self) -> bool {
+        true
+    }
+
     /// primarily used when creating signature based on request method of payment flow
     fn get_http_method(&self) -> Method {
         Method::Post
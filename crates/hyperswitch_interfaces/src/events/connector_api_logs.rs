@@ -26,6 +26,7 @@ pub struct ConnectorEvent {
     refund_id: Option<String>,
     dispute_id: Option<String>,
     status_code: u16,
+    retry_count: u8,
 }
 
 impl ConnectorEvent {
@@ -69,6 +70,7 @@ impl ConnectorEvent {
             refund_id,
             dispute_id,
             status_code,
+            retry_count: 0,
         }
     }
 
@@ -96,4 +98,9 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// fn set_retry_count
+    pub fn set_retry_count(&mut self, retry_count: u8) {
+        self.retry_count = retry_count;
+    }
 }
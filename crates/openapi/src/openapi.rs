@@ -78,8 +78,12 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_connector_session,
         routes::payments::payments_cancel,
         routes::payments::payments_list,
+        routes::payments::payments_search_by_metadata,
+        routes::payments::payments_timeline_retrieve,
+        routes::payments::payments_verify_card,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::create_signed_payment_link,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
@@ -91,6 +95,8 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for refunds
         routes::refunds::refunds_create,
         routes::refunds::refunds_retrieve,
+        routes::refunds::refunds_eligibility,
+        routes::refunds::refunds_bulk_create,
         routes::refunds::refunds_update,
         routes::refunds::refunds_list,
 
@@ -130,6 +136,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_list,
         routes::customers::customers_update,
         routes::customers::customers_delete,
+        routes::customers::customers_merge,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -204,6 +211,7 @@ Never share your secret api keys. Keep them guarded and secure.
     components(schemas(
         common_utils::types::MinorUnit,
         common_utils::types::TimeRange,
+        common_utils::metadata::MetadataValueType,
         common_utils::link_utils::GenericLinkUiConfig,
         common_utils::link_utils::EnabledPaymentMethod,
         common_utils::payout_method_utils::AdditionalPayoutMethodData,
@@ -225,6 +233,8 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::XenditChargeResponseData,
         common_types::payments::XenditMultipleSplitResponse,
         common_types::payments::XenditMultipleSplitRequest,
+        common_types::payments::GenericSplitPaymentRequest,
+        common_types::payments::GenericSplitItem,
         common_types::domain::XenditSplitSubMerchantData,
         common_utils::types::ChargeRefunds,
         common_types::refunds::SplitRefund,
@@ -234,6 +244,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundRequest,
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
+        api_models::refunds::RefundEligibilityResponse,
+        api_models::refunds::RefundEligibilityConstraints,
+        api_models::refunds::RefundBulkRequest,
+        api_models::refunds::RefundBulkItem,
+        api_models::refunds::RefundBulkResponse,
+        api_models::refunds::RefundBulkItemResponse,
+        api_models::refunds::RefundBulkItemStatus,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
         api_models::organization::OrganizationCreateRequest,
@@ -255,6 +272,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerUpdateRequest,
         api_models::customers::CustomerDeleteResponse,
+        api_models::customers::CustomerMergeRequest,
+        api_models::customers::CustomerMergeResponse,
         api_models::payment_methods::PaymentMethodCreate,
         api_models::payment_methods::PaymentMethodResponse,
         api_models::payment_methods::CustomerPaymentMethod,
@@ -481,6 +500,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsCancelRequest,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
+        api_models::payments::PaymentMetadataSearchRequest,
+        api_models::payments::PaymentsTimelineResponse,
+        api_models::payments::PaymentTimelineEvent,
+        api_models::payments::PaymentTimelineEventType,
+        api_models::payments::PaymentsVerifyRequest,
+        api_models::payments::PaymentsVerifyResponse,
+        api_models::payments::CardVerificationMethod,
         api_models::payments::CashappQr,
         api_models::payments::BankTransferData,
         api_models::payments::BankTransferNextStepsData,
@@ -518,6 +544,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::IncrementalAuthorizationResponse,
+        api_models::payments::PaymentFxDetailsResponse,
         api_models::payments::PaymentsCompleteAuthorizeRequest,
         api_models::payments::PaymentsExternalAuthenticationRequest,
         api_models::payments::PaymentsExternalAuthenticationResponse,
@@ -557,6 +584,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::RelayStatus,
         api_models::relay::RelayError,
         api_models::payments::AmountFilter,
+        api_models::payments::Order,
+        api_models::payments::SortOn,
+        api_models::payments::SortBy,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
@@ -605,6 +635,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
+        api_models::user_role::UserRoleWebhookContent,
         api_models::enums::EventClass,
         api_models::enums::EventType,
         api_models::enums::DecoupledAuthenticationType,
@@ -625,6 +656,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
         api_models::payments::PaymentLinkInitiateRequest,
+        api_models::payments::SignedPaymentLinkResponse,
         api_models::payouts::PayoutLinkInitiateRequest,
         api_models::payments::ExtendedCardInfoResponse,
         api_models::payments::GooglePayAssuranceDetails,
@@ -723,6 +755,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::feature_matrix::FeatureMatrixListResponse,
         api_models::feature_matrix::FeatureMatrixRequest,
         api_models::feature_matrix::ConnectorFeatureMatrixResponse,
+        api_models::feature_matrix::ConnectorCapabilities,
         api_models::feature_matrix::PaymentMethodSpecificFeatures,
         api_models::feature_matrix::CardSpecificFeatures,
         api_models::feature_matrix::SupportedPaymentMethod,
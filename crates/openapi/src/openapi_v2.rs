@@ -172,6 +172,8 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::XenditChargeResponseData,
         common_types::payments::XenditMultipleSplitResponse,
         common_types::payments::XenditMultipleSplitRequest,
+        common_types::payments::GenericSplitPaymentRequest,
+        common_types::payments::GenericSplitItem,
         common_types::domain::XenditSplitSubMerchantData,
         common_types::domain::AdyenSplitItem,
         common_types::refunds::StripeSplitRefundRequest,
@@ -689,6 +691,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::feature_matrix::FeatureMatrixListResponse,
         api_models::feature_matrix::FeatureMatrixRequest,
         api_models::feature_matrix::ConnectorFeatureMatrixResponse,
+        api_models::feature_matrix::ConnectorCapabilities,
         api_models::feature_matrix::PaymentMethodSpecificFeatures,
         api_models::feature_matrix::CardSpecificFeatures,
         api_models::feature_matrix::SupportedPaymentMethod,
@@ -106,6 +106,25 @@ pub async fn customers_delete() {}
 #[cfg(feature = "v1")]
 pub async fn customers_list() {}
 
+/// Customers - Merge
+///
+/// Merges a customer into another customer. The payment methods, mandates and payments of the source customer are moved to the target customer, and the source customer is marked as merged. The target customer retains its default payment method if set, else the default payment method of the source customer is used.
+#[utoipa::path(
+    post,
+    path = "/customers/merge",
+    request_body = CustomerMergeRequest,
+    responses(
+        (status = 200, description = "Customers merged", body = CustomerMergeResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Merge Customers",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customers_merge() {}
+
 /// Customers - Create
 ///
 /// Creates a customer object and stores the customer details to be reused for future payments.
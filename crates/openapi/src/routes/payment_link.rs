@@ -17,3 +17,22 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Payment Link - Create Signed
+///
+/// To create a signed link to the hosted payment page of a payment, which can be opened only once before it expires. The validity of the link is configurable per merchant, and the link is signed using the payment response hash key of the profile
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/signed_payment_link",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Signed payment link created", body = SignedPaymentLinkResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Create a Signed Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn create_signed_payment_link() {}
@@ -482,6 +482,63 @@ pub fn payments_cancel() {}
 )]
 pub fn payments_list() {}
 
+/// Payments - Search by Metadata
+///
+/// To list the payments whose metadata contains the given value of an indexed metadata key. The value is coerced to the requested type before searching.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/payments/metadata/search",
+    request_body = PaymentMetadataSearchRequest,
+    responses(
+        (status = 200, description = "Successfully retrieved a payment list", body = PaymentListResponse),
+        (status = 400, description = "The metadata key is not indexed or the value cannot be coerced to the requested type"),
+        (status = 404, description = "No payments found")
+    ),
+    tag = "Payments",
+    operation_id = "Search Payments by Metadata",
+    security(("api_key" = []))
+)]
+pub fn payments_search_by_metadata() {}
+
+/// Payments - Timeline
+///
+/// Retrieves the chronological timeline of events of a payment, like its creation, attempts, connector calls, outgoing webhooks and status updates. Request and response bodies are not part of the timeline.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/timeline",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Gets the timeline of the payment", body = PaymentsTimelineResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Timeline of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_timeline_retrieve() {}
+
+/// Payments - Verify Card
+///
+/// Verifies a card with the connector without charging the customer, and saves it for the customer. Connectors which support it are sent a zero amount authorization, the other connectors are sent a small authorization which is voided right after, if it is enabled. The saved card is marked as verified.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/payments/verify",
+    request_body = PaymentsVerifyRequest,
+    responses(
+        (status = 200, description = "Card verification attempted", body = PaymentsVerifyResponse),
+        (status = 400, description = "Card verification is not supported by the connector")
+    ),
+    tag = "Payments",
+    operation_id = "Verify a Card",
+    security(("api_key" = []))
+)]
+pub fn payments_verify_card() {}
+
 /// Profile level Payments - List
 ///
 /// To list the payments
@@ -4,6 +4,12 @@
 #[utoipa::path(
     post,
     path = "/refunds",
+    params(
+        (
+            "Idempotency-Key" = Option<String>, Header,
+            description = "Unique key to safely retry the request. A request replayed with the same key returns the refund created by the original request instead of creating a new one"
+        ),
+    ),
     request_body(
         content = RefundRequest,
         examples(
@@ -66,6 +72,44 @@ pub async fn refunds_create() {}
 )]
 pub async fn refunds_retrieve() {}
 
+/// Refunds - Eligibility
+///
+/// To check whether a refund can be initiated against a payment, and for how much, without creating a refund
+#[utoipa::path(
+    get,
+    path = "/refunds/eligibility/{payment_id}",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Refund eligibility retrieved", body = RefundEligibilityResponse),
+        (status = 404, description = "Payment does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve Refund Eligibility",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_eligibility() {}
+
+/// Refunds - Bulk Create
+///
+/// To create refunds against multiple payments at once, or to validate whether they can be refunded
+#[utoipa::path(
+    post,
+    path = "/refunds/bulk",
+    request_body=RefundBulkRequest,
+    responses(
+        (status = 200, description = "Bulk refund processed", body = RefundBulkResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Refunds",
+    operation_id = "Create Refunds in Bulk",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_bulk_create() {}
+
 /// Refunds - Retrieve (POST)
 ///
 /// To retrieve the properties of a Refund. This may be used to get the status of a previously initiated payment or next action for an ongoing payment
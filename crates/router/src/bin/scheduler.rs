@@ -92,6 +92,13 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 
     logger::debug!(startup_config=?state.conf);
 
+    #[cfg(feature = "v1")]
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Producer)
+        && state.conf.stale_payment_sync.enabled
+    {
+        add_stale_payment_sync_tasks(&state).await;
+    }
+
     start_scheduler(&state, scheduler_flow, (tx, rx)).await?;
 
     logger::error!("Scheduler shut down");
@@ -324,6 +331,37 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => Ok(Box::new(
                     workflows::passive_churn_recovery_workflow::ExecutePcrWorkflow,
                 )),
+                storage::ProcessTrackerRunner::DeletedUserRolePurgeWorkflow => Ok(Box::new(
+                    workflows::deleted_user_role_purge::DeletedUserRolePurgeWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_intent_expiry::PaymentIntentExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::StalePaymentSyncWorkflow => Ok(Box::new(
+                    workflows::stale_payment_sync::StalePaymentSyncWorkflow,
+                )),
+                storage::ProcessTrackerRunner::WebhookReplayWorkflow => {
+                    Ok(Box::new(workflows::webhook_replay::WebhookReplayWorkflow))
+                }
+                storage::ProcessTrackerRunner::CardVerificationVoidWorkflow => Ok(Box::new(
+                    workflows::card_verification_void::CardVerificationVoidWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PayoutsSyncWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_sync::PayoutsSyncWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payouts sync workflow when payouts feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
@@ -362,6 +400,31 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
     }
 }
 
+/// Adds the recurring task which syncs the status of stale payments for every tenant
+#[cfg(feature = "v1")]
+async fn add_stale_payment_sync_tasks(state: &routes::AppState) {
+    let app_state = Arc::new(state.clone());
+    for tenant in state.stores.keys() {
+        let session_state = match app_state.clone().get_session_state(tenant, None, || {
+            error_stack::report!(ProcessTrackerError::TenantNotFound)
+        }) {
+            Ok(session_state) => session_state,
+            Err(error) => {
+                logger::error!(?error, "Failed to get the session state of the tenant");
+                continue;
+            }
+        };
+
+        router::core::payments::add_stale_payment_sync_task(
+            &*session_state.store,
+            common_utils::date_time::now(),
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to add the stale payment sync task"))
+        .ok();
+    }
+}
+
 async fn start_scheduler(
     state: &routes::AppState,
     scheduler_flow: scheduler::SchedulerFlow,
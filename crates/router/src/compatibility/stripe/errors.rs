@@ -500,6 +500,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::PaymentIntentPaymentAttemptFailed { data }
             }
             errors::ApiErrorResponse::DisputeFailed { data } => Self::DisputeFailed { data },
+            errors::ApiErrorResponse::ConnectorUnavailable { .. } => Self::InternalServerError,
             errors::ApiErrorResponse::InvalidCardData { data: _ } => Self::InvalidCardType, // Maybe it is better to de generalize this router error
             errors::ApiErrorResponse::CardExpired { data: _ } => Self::ExpiredCard,
             errors::ApiErrorResponse::RefundNotPossible { connector: _ } => Self::RefundFailed,
@@ -843,6 +844,9 @@ impl ErrorSwitch<StripeErrorCode> for CustomersErrorResponse {
             Self::MandateActive => SC::MandateActive,
             Self::CustomerNotFound => SC::CustomerNotFound,
             Self::CustomerAlreadyExists => SC::DuplicateCustomer,
+            Self::InvalidRequestData { message } => SC::InvalidRequestData {
+                message: message.clone(),
+            },
         }
     }
 }
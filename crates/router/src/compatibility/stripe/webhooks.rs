@@ -87,6 +87,7 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    UserRole(Box<api_models::user_role::UserRoleWebhookContent>),
 }
 
 #[derive(Serialize, Debug)]
@@ -268,6 +269,8 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
         api_models::enums::EventType::PaymentProcessing => "payment_intent.processing",
         api_models::enums::EventType::PaymentCancelled => "payment_intent.canceled",
+        // stripe cancels payment intents which are not confirmed in time
+        api_models::enums::EventType::PaymentExpired => "payment_intent.canceled",
 
         // the below are not really stripe compatible because stripe doesn't provide this
         api_models::enums::EventType::ActionRequired => "action.required",
@@ -296,6 +299,8 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::UserRoleUpdated => "user_role.updated",
+        api_models::enums::EventType::UserRoleDeleted => "user_role.deleted",
     }
 }
 
@@ -338,6 +343,7 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::UserRoleDetails(user_role) => Self::UserRole(user_role),
         }
     }
 }
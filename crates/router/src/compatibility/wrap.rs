@@ -113,6 +113,9 @@ where
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
             api::http_response_file_data(file_data, content_type)
         }
+        Ok(api::ApplicationResponse::FileStream(file_stream)) => {
+            api::http_response_file_stream(file_stream)
+        }
         Ok(api::ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => api::http_redirect_response(res, response),
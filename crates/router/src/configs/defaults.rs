@@ -36,6 +36,92 @@ impl Default for super::settings::CorsSettings {
         }
     }
 }
+impl Default for super::settings::ConnectorRetryPolicy {
+    fn default() -> Self {
+        // Connector calls are only retried if enabled in the configuration
+        Self {
+            max_attempts: 1,
+            backoff_in_ms: 100,
+        }
+    }
+}
+
+impl Default for super::settings::RawConnectorPayloadsConfig {
+    fn default() -> Self {
+        Self {
+            // 1 day
+            ttl_in_seconds: 86400,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            failure_rate_threshold: 50,
+            minimum_request_count: 20,
+            window_in_seconds: 60,
+            cooldown_in_seconds: 30,
+        }
+    }
+}
+
+impl Default for super::settings::FraudCheckHookConfig {
+    fn default() -> Self {
+        Self {
+            hook: super::settings::FraudCheckHookType::default(),
+            timeout_in_millis: 2000,
+        }
+    }
+}
+
+impl Default for super::settings::DelayedCaptureConfig {
+    fn default() -> Self {
+        Self {
+            // 7 days
+            authorization_validity_in_hours: 168,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookReplayConfig {
+    fn default() -> Self {
+        Self {
+            events_per_batch: 50,
+            batch_interval_in_seconds: 10,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorLatencyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            half_life_in_seconds: 300,
+            minimum_sample_count: 10,
+            routing_percentile: 90,
+        }
+    }
+}
+
+impl Default for super::settings::StalePaymentSyncConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_in_seconds: 300,
+            stale_after_in_seconds: 900,
+            // 7 days
+            lookback_in_seconds: 604_800,
+            batch_size: 100,
+            connector_failure_threshold: 3,
+            backoff_in_seconds: 600,
+            // 6 hours
+            max_backoff_in_seconds: 21_600,
+        }
+    }
+}
+
 impl Default for super::settings::Database {
     fn default() -> Self {
         Self {
@@ -106,6 +192,17 @@ impl Default for super::settings::Refund {
         Self {
             max_attempts: 10,
             max_age: 365,
+            // 1 day
+            idempotency_key_ttl: 86400,
+        }
+    }
+}
+
+impl Default for super::settings::RoleWriteRateLimit {
+    fn default() -> Self {
+        Self {
+            max_requests: 20,
+            window_in_secs: 60,
         }
     }
 }
@@ -169,11 +169,23 @@ impl SecretsHandler for settings::ApplePayDecryptConfig {
                 .get_secret(applepay_decrypt_keys.apple_pay_merchant_cert_key.clone()),
         )?;
 
+        let apple_pay_previous_ppc = match applepay_decrypt_keys.apple_pay_previous_ppc.clone() {
+            Some(ppc) => Some(secret_management_client.get_secret(ppc).await?),
+            None => None,
+        };
+        let apple_pay_previous_ppc_key =
+            match applepay_decrypt_keys.apple_pay_previous_ppc_key.clone() {
+                Some(ppc_key) => Some(secret_management_client.get_secret(ppc_key).await?),
+                None => None,
+            };
+
         Ok(value.transition_state(|_| Self {
             apple_pay_ppc,
             apple_pay_ppc_key,
             apple_pay_merchant_cert,
             apple_pay_merchant_cert_key,
+            apple_pay_previous_ppc,
+            apple_pay_previous_ppc_key,
         }))
     }
 }
@@ -503,6 +515,23 @@ pub(crate) async fn fetch_raw_secrets(
         mandates: conf.mandates,
         network_transaction_id_supported_connectors: conf
             .network_transaction_id_supported_connectors,
+        incremental_authorization: conf.incremental_authorization,
+        partial_void: conf.partial_void,
+        cross_currency_mandate_supported_connectors: conf
+            .cross_currency_mandate_supported_connectors,
+        mandate_revoke_supported_connectors: conf.mandate_revoke_supported_connectors,
+        connector_retry_policy: conf.connector_retry_policy,
+        raw_connector_payloads: conf.raw_connector_payloads,
+        connector_circuit_breaker: conf.connector_circuit_breaker,
+        connector_latency: conf.connector_latency,
+        delayed_capture: conf.delayed_capture,
+        webhook_replay: conf.webhook_replay,
+        fx_rate_provider: conf.fx_rate_provider,
+        fraud_check_hook: conf.fraud_check_hook,
+        connector_metadata_validation: conf.connector_metadata_validation,
+        payment_metadata_search: conf.payment_metadata_search,
+        card_verification: conf.card_verification,
+        stale_payment_sync: conf.stale_payment_sync,
         required_fields: conf.required_fields,
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
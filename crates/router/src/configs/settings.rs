@@ -7,7 +7,11 @@ use std::{
 #[cfg(feature = "olap")]
 use analytics::{opensearch::OpenSearchConfig, ReportConfig};
 use api_models::{enums, payment_methods::RequiredFieldInfo};
-use common_utils::{ext_traits::ConfigExt, id_type, types::theme::EmailThemeConfig};
+use common_utils::{
+    ext_traits::ConfigExt,
+    id_type,
+    types::{theme::EmailThemeConfig, MinorUnit},
+};
 use config::{Environment, File};
 use error_stack::ResultExt;
 #[cfg(feature = "email")]
@@ -93,6 +97,22 @@ pub struct Settings<S: SecretState> {
     pub cors: CorsSettings,
     pub mandates: Mandates,
     pub network_transaction_id_supported_connectors: NetworkTransactionIdSupportedConnectors,
+    pub incremental_authorization: IncrementalAuthorizationConfig,
+    pub partial_void: PartialVoidConfig,
+    pub cross_currency_mandate_supported_connectors: CrossCurrencyMandateSupportedConnectors,
+    pub mandate_revoke_supported_connectors: MandateRevokeSupportedConnectors,
+    pub connector_retry_policy: ConnectorRetryPolicyConfig,
+    pub raw_connector_payloads: RawConnectorPayloadsConfig,
+    pub connector_circuit_breaker: ConnectorCircuitBreakerConfig,
+    pub connector_latency: ConnectorLatencyConfig,
+    pub delayed_capture: DelayedCaptureConfig,
+    pub webhook_replay: WebhookReplayConfig,
+    pub fx_rate_provider: FxRateProviderConfig,
+    pub fraud_check_hook: FraudCheckHookConfig,
+    pub connector_metadata_validation: ConnectorMetadataValidationConfig,
+    pub payment_metadata_search: PaymentMetadataSearchConfig,
+    pub card_verification: CardVerificationConfig,
+    pub stale_payment_sync: StalePaymentSyncConfig,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
@@ -521,6 +541,213 @@ pub struct NetworkTransactionIdSupportedConnectors {
     pub connector_list: HashSet<enums::Connector>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct CrossCurrencyMandateSupportedConnectors {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct MandateRevokeSupportedConnectors {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct IncrementalAuthorizationConfig {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+    /// Maximum total amount that can be authorized with a connector, as a percentage of the original authorized amount
+    #[serde(default)]
+    pub max_authorized_amount_percentage: HashMap<enums::Connector, u16>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PartialVoidConfig {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorRetryPolicyConfig {
+    /// Retry policy applied to connectors which do not have a policy of their own
+    pub default: ConnectorRetryPolicy,
+    pub connectors: HashMap<String, ConnectorRetryPolicy>,
+}
+
+impl ConnectorRetryPolicyConfig {
+    pub fn get_retry_policy(&self, connector: &str) -> ConnectorRetryPolicy {
+        self.connectors
+            .get(connector)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct ConnectorRetryPolicy {
+    /// Maximum number of calls made to the connector, including the first one
+    pub max_attempts: u8,
+    /// Delay before the first retry, doubled for every subsequent retry
+    pub backoff_in_ms: u64,
+}
+
+impl ConnectorRetryPolicy {
+    pub fn get_backoff_duration(&self, attempt: u8) -> std::time::Duration {
+        let multiplier = 1_u64
+            .checked_shl(u32::from(attempt.saturating_sub(1)))
+            .unwrap_or(u64::MAX);
+        std::time::Duration::from_millis(self.backoff_in_ms.saturating_mul(multiplier))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RawConnectorPayloadsConfig {
+    /// Time for which the raw connector payloads of a payment are retained
+    pub ttl_in_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorCircuitBreakerConfig {
+    pub enabled: bool,
+    /// Percentage of failed connector calls in a window, beyond which the circuit is opened
+    pub failure_rate_threshold: u8,
+    /// Minimum number of connector calls in a window before the failure rate is evaluated
+    pub minimum_request_count: i64,
+    /// Duration of the window over which connector calls are counted
+    pub window_in_seconds: i64,
+    /// Time for which the circuit stays open before a probe call is let through
+    pub cooldown_in_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorLatencyConfig {
+    pub enabled: bool,
+    /// Time after which a recorded response time carries half its original weight
+    pub half_life_in_seconds: i64,
+    /// Minimum weight of recorded response times before the latency of a connector is used for
+    /// routing
+    pub minimum_sample_count: u32,
+    /// Percentile of the response times by which connectors are ranked in lowest latency routing
+    pub routing_percentile: u8,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DelayedCaptureConfig {
+    /// Time for which an authorization is considered valid, beyond which it can no longer be captured
+    pub authorization_validity_in_hours: u16,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct WebhookReplayConfig {
+    /// Number of events replayed in a single run of the replay task
+    pub events_per_batch: i64,
+    /// Time to wait between consecutive batches, so that the merchant endpoint is not overwhelmed
+    pub batch_interval_in_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct FxRateProviderConfig {
+    /// The provider of the exchange rates used for recording the settlement amount of payments.
+    /// The settlement amount is not recorded if no provider is configured
+    pub provider: Option<FxRateProviderType>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FxRateProviderType {
+    /// Exchange rates fetched through the forex API
+    ForexApi,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FraudCheckHookConfig {
+    /// The hook consulted before a payment is authorized
+    pub hook: FraudCheckHookType,
+    /// Time within which the hook has to respond, the payment is accepted if it does not
+    pub timeout_in_millis: u64,
+}
+
+#[derive(Debug, Default, Deserialize, Clone, Copy, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckHookType {
+    /// Accepts every payment
+    #[default]
+    Noop,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorMetadataValidationConfig {
+    /// How fields of the merchant connector account metadata not known to the connector are handled
+    pub unknown_fields: UnknownMetadataFieldsHandling,
+}
+
+#[derive(Debug, Default, Deserialize, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum UnknownMetadataFieldsHandling {
+    /// The unknown fields are logged and the metadata is accepted
+    #[default]
+    Warn,
+    /// The metadata is rejected
+    Reject,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PaymentMetadataSearchConfig {
+    /// Metadata keys on which payments can be searched, searching on other keys is rejected as it
+    /// would not be served by an index. Every key has to have an expression index on
+    /// `(merchant_id, (metadata ->> '<key>'))` of the payment intent table, which is created by
+    /// the migrations for the default keys `udf1`, `udf2` and `udf3`.
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub indexed_keys: HashSet<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CardVerificationConfig {
+    /// Connectors with which a card is verified by a zero amount authorization
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub zero_auth_supported_connectors: HashSet<enums::Connector>,
+    /// Amount authorized and voided to verify a card with the other connectors, the verification
+    /// is rejected for those connectors if it is not set
+    pub auth_and_void_amount: Option<MinorUnit>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct StalePaymentSyncConfig {
+    pub enabled: bool,
+    /// Time between consecutive runs of the job which syncs the status of stale payments
+    pub interval_in_seconds: i64,
+    /// Time for which a payment is pending before its status is synced with the connector
+    pub stale_after_in_seconds: i64,
+    /// Time beyond which a pending payment is no longer synced with the connector
+    pub lookback_in_seconds: i64,
+    /// Maximum number of payments synced in a single run
+    pub batch_size: i64,
+    /// Number of consecutive failed syncs after which a connector is considered to be down
+    pub connector_failure_threshold: u32,
+    /// Time for which a connector which is down is not synced with, doubled for every consecutive
+    /// run in which the connector is down
+    pub backoff_in_seconds: i64,
+    /// Upper limit of the time for which a connector which is down is not synced with
+    pub max_backoff_in_seconds: i64,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct NetworkTokenizationSupportedCardNetworks {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -685,6 +912,50 @@ pub struct UserSettings {
     pub base_url: String,
     pub force_two_factor_auth: bool,
     pub force_cookies: bool,
+    pub invitation_expiry_in_secs: i64,
+    #[serde(default)]
+    pub single_purpose_token_expiry_in_secs: HashMap<common_enums::TokenPurpose, u64>,
+    #[serde(default)]
+    pub role_write_rate_limit: RoleWriteRateLimit,
+    /// Purpose of the single purpose token required to accept invitations before signing in
+    pub accept_invitation_token_purpose: Option<common_enums::TokenPurpose>,
+    /// Number of seconds for which a deleted user role can be restored, deleted user roles are
+    /// purged immediately if not configured
+    #[serde(default)]
+    pub user_role_deletion_grace_period_in_secs: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoleWriteRateLimit {
+    pub max_requests: usize,
+    pub window_in_secs: i64,
+}
+
+impl UserSettings {
+    pub fn get_single_purpose_token_expiry_in_secs(
+        &self,
+        purpose: &common_enums::TokenPurpose,
+    ) -> u64 {
+        let expiry = self
+            .single_purpose_token_expiry_in_secs
+            .get(purpose)
+            .copied()
+            .unwrap_or(crate::consts::SINGLE_PURPOSE_TOKEN_TIME_IN_SECS);
+
+        match purpose {
+            // Impersonation tokens must stay short-lived irrespective of the configuration
+            common_enums::TokenPurpose::Impersonation => {
+                expiry.min(crate::consts::IMPERSONATION_TOKEN_TIME_IN_SECS)
+            }
+            _ => expiry,
+        }
+    }
+
+    pub fn get_accept_invitation_token_purpose(&self) -> common_enums::TokenPurpose {
+        self.accept_invitation_token_purpose
+            .clone()
+            .unwrap_or(common_enums::TokenPurpose::AcceptInvite)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -714,6 +985,8 @@ pub enum DecryptionScheme {
 pub struct Refund {
     pub max_attempts: usize,
     pub max_age: i64,
+    /// Time in seconds for which a refund can be replayed using the same idempotency key
+    pub idempotency_key_ttl: i64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -860,6 +1133,10 @@ pub struct ApplePayDecryptConfig {
     pub apple_pay_ppc_key: Secret<String>,
     pub apple_pay_merchant_cert: Secret<String>,
     pub apple_pay_merchant_cert_key: Secret<String>,
+    /// Payment processing certificate being rotated out, tokens encrypted with it can still be
+    /// decrypted until it is removed
+    pub apple_pay_previous_ppc: Option<Secret<String>>,
+    pub apple_pay_previous_ppc_key: Option<Secret<String>>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -1032,6 +1309,7 @@ impl Settings<SecuredSecret> {
             .transpose()?;
 
         self.key_manager.get_inner().validate()?;
+        self.user.validate()?;
         #[cfg(feature = "email")]
         self.email
             .validate()
@@ -293,3 +293,25 @@ impl super::settings::KeyManagerConfig {
         })
     }
 }
+
+impl super::settings::UserSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        // Tokens of other purposes are issued for other flows, and must not be usable to accept
+        // invitations
+        when(
+            !matches!(
+                self.get_accept_invitation_token_purpose(),
+                common_enums::TokenPurpose::AcceptInvite
+                    | common_enums::TokenPurpose::AcceptInvitationFromEmail
+            ),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "accept_invitation_token_purpose must be an invitation acceptance purpose"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
@@ -2293,4 +2293,10 @@ impl api::FileUpload for Adyen {
     }
 }
 
-impl ConnectorSpecifications for Adyen {}
+impl ConnectorSpecifications for Adyen {
+    fn get_statement_descriptor_max_length(&self) -> Option<usize> {
+        Some(usize::from(
+            common_utils::consts::MAX_STATEMENT_DESCRIPTOR_LENGTH,
+        ))
+    }
+}
@@ -55,6 +55,10 @@ pub struct AdyenConnectorMetadataObject {
     pub endpoint_prefix: Option<String>,
 }
 
+impl types::api::ConnectorMetadataSchema for AdyenConnectorMetadataObject {
+    const FIELDS: &'static [&'static str] = &["endpoint_prefix"];
+}
+
 impl TryFrom<&Option<pii::SecretSerdeValue>> for AdyenConnectorMetadataObject {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
@@ -84,6 +88,7 @@ pub enum AdyenShopperInteraction {
 pub enum AdyenRecurringModel {
     UnscheduledCardOnFile,
     CardOnFile,
+    Subscription,
 }
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
@@ -108,6 +113,16 @@ pub struct AdditionalData {
     #[cfg(feature = "payouts")]
     payout_eligible: Option<PayoutEligibility>,
     funds_availability: Option<String>,
+    sca_exemption: Option<AdyenScaExemption>,
+    sca_exemption_requested: Option<String>,
+    three_d_authenticated: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdyenScaExemption {
+    LowValue,
+    TransactionRiskAnalysis,
 }
 
 #[serde_with::skip_serializing_none]
@@ -150,6 +165,8 @@ pub struct AdyenPaymentRequest<'a> {
     merchant_account: Secret<String>,
     payment_method: PaymentMethod<'a>,
     mpi_data: Option<AdyenMpiData>,
+    #[serde(rename = "threeDS2RequestData")]
+    three_ds2_request_data: Option<AdyenThreeDS2RequestData>,
     reference: String,
     return_url: String,
     browser_info: Option<AdyenBrowserInfo>,
@@ -188,6 +205,28 @@ struct AdyenSplitData {
     description: Option<String>,
 }
 
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct AdyenThreeDS2RequestData {
+    device_channel: AdyenDeviceChannel,
+    #[serde(rename = "threeDSRequestorChallengeInd")]
+    three_ds_requestor_challenge_ind: AdyenChallengeIndicator,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum AdyenDeviceChannel {
+    Browser,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+enum AdyenChallengeIndicator {
+    #[serde(rename = "02")]
+    NoChallengeRequested,
+    #[serde(rename = "03")]
+    ChallengeRequested,
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -1679,7 +1718,9 @@ fn get_recurring_processing_model(
             ))
         }
         (_, Some(true)) => Ok((
-            Some(AdyenRecurringModel::UnscheduledCardOnFile),
+            Some(get_off_session_recurring_model(
+                item.psd2_sca_exemption_type,
+            )),
             None,
             Some(format!(
                 "{}_{}",
@@ -1691,6 +1732,17 @@ fn get_recurring_processing_model(
     }
 }
 
+/// Merchant initiated payments requesting the recurring SCA exemption are part of a series of
+/// payments on a fixed schedule, which are out of the scope of SCA
+fn get_off_session_recurring_model(
+    psd2_sca_exemption_type: Option<enums::ScaExemptionType>,
+) -> AdyenRecurringModel {
+    match psd2_sca_exemption_type {
+        Some(enums::ScaExemptionType::Recurring) => AdyenRecurringModel::Subscription,
+        _ => AdyenRecurringModel::UnscheduledCardOnFile,
+    }
+}
+
 fn get_browser_info(
     item: &types::PaymentsAuthorizeRouterData,
 ) -> Result<Option<AdyenBrowserInfo>, Error> {
@@ -1729,8 +1781,13 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
     } else {
         None
     };
-    if authorisation_type.is_none() && manual_capture.is_none() && execute_three_d.is_none() {
-        //without this if-condition when the above 3 values are None, additionalData will be serialized to JSON like this -> additionalData: {}
+    let sca_exemption = get_sca_exemption(item.psd2_sca_exemption_type);
+    if authorisation_type.is_none()
+        && manual_capture.is_none()
+        && execute_three_d.is_none()
+        && sca_exemption.is_none()
+    {
+        //without this if-condition when the above 4 values are None, additionalData will be serialized to JSON like this -> additionalData: {}
         //returning None, ensures that additionalData key will not be present in the serialized JSON
         None
     } else {
@@ -1742,11 +1799,74 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
             recurring_detail_reference: None,
             recurring_shopper_reference: None,
             recurring_processing_model: None,
+            sca_exemption,
             ..AdditionalData::default()
         })
     }
 }
 
+fn get_sca_exemption(
+    psd2_sca_exemption_type: Option<enums::ScaExemptionType>,
+) -> Option<AdyenScaExemption> {
+    match psd2_sca_exemption_type? {
+        enums::ScaExemptionType::LowValue => Some(AdyenScaExemption::LowValue),
+        enums::ScaExemptionType::TransactionRiskAnalysis => {
+            Some(AdyenScaExemption::TransactionRiskAnalysis)
+        }
+        // Adyen flags recurring payments through the recurring processing model instead of an
+        // exemption, see `get_off_session_recurring_model`
+        enums::ScaExemptionType::Recurring => None,
+    }
+}
+
+/// Requests a challenge or a frictionless flow from the issuer when the decision manager rules of
+/// the merchant have decided on one, the issuer decides otherwise
+fn get_three_ds2_request_data(
+    auth_type: enums::AuthenticationType,
+    three_ds_challenge_flow: Option<enums::DecoupledAuthenticationType>,
+) -> Option<AdyenThreeDS2RequestData> {
+    if auth_type != enums::AuthenticationType::ThreeDs {
+        return None;
+    }
+    let three_ds_requestor_challenge_ind = match three_ds_challenge_flow? {
+        enums::DecoupledAuthenticationType::Challenge => {
+            AdyenChallengeIndicator::ChallengeRequested
+        }
+        enums::DecoupledAuthenticationType::Frictionless => {
+            AdyenChallengeIndicator::NoChallengeRequested
+        }
+    };
+    Some(AdyenThreeDS2RequestData {
+        device_channel: AdyenDeviceChannel::Browser,
+        three_ds_requestor_challenge_ind,
+    })
+}
+
+/// Records the SCA exemption requested from the issuer and whether it was honored, i.e. the
+/// payment was authorised without authenticating the customer
+fn get_sca_exemption_connector_response(
+    response: &Response,
+) -> Option<types::ConnectorResponseData> {
+    let additional_data = response.additional_data.as_ref()?;
+    let sca_exemption_requested = additional_data.sca_exemption_requested.as_ref()?;
+    let sca_exemption_honored = matches!(response.result_code, AdyenStatus::Authorised)
+        && additional_data.three_d_authenticated.as_deref() != Some("true");
+
+    let authentication_data = Some(serde_json::json!({
+        "sca_exemption_requested": sca_exemption_requested,
+        "sca_exemption_honored": sca_exemption_honored,
+    }));
+
+    Some(
+        types::ConnectorResponseData::with_additional_payment_method_data(
+            types::AdditionalPaymentMethodConnectorResponse::Card {
+                authentication_data,
+                payment_checks: None,
+            },
+        ),
+    )
+}
+
 fn get_channel_type(pm_type: Option<storage_enums::PaymentMethodType>) -> Option<Channel> {
     pm_type.as_ref().and_then(|pmt| match pmt {
         storage_enums::PaymentMethodType::GoPay | storage_enums::PaymentMethodType::Vipps => {
@@ -2663,6 +2783,7 @@ impl
             browser_info,
             additional_data,
             mpi_data: None,
+            three_ds2_request_data: None,
             telephone_number: None,
             shopper_name: None,
             shopper_email: None,
@@ -2712,6 +2833,10 @@ impl
             get_address_info(item.router_data.get_optional_billing()).and_then(Result::ok);
         let country_code = get_country_code(item.router_data.get_optional_billing());
         let additional_data = get_additional_data(item.router_data);
+        let three_ds2_request_data = get_three_ds2_request_data(
+            item.router_data.auth_type,
+            item.router_data.request.three_ds_challenge_flow,
+        );
         let return_url = item.router_data.request.get_router_return_url()?;
         let card_holder_name = item.router_data.get_optional_billing_full_name();
         let payment_method = PaymentMethod::AdyenPaymentMethod(Box::new(
@@ -2738,6 +2863,7 @@ impl
             browser_info,
             additional_data,
             mpi_data: None,
+            three_ds2_request_data,
             telephone_number: None,
             shopper_name,
             shopper_email,
@@ -2805,6 +2931,7 @@ impl
             recurring_processing_model,
             additional_data,
             mpi_data: None,
+            three_ds2_request_data: None,
             shopper_name: None,
             shopper_locale: None,
             shopper_email: item.router_data.get_optional_billing_email(),
@@ -2879,6 +3006,7 @@ impl
             shopper_email: item.router_data.get_optional_billing_email(),
             social_security_number,
             mpi_data: None,
+            three_ds2_request_data: None,
             telephone_number: None,
             billing_address,
             delivery_address: None,
@@ -2938,6 +3066,7 @@ impl
             recurring_processing_model: None,
             additional_data: None,
             mpi_data: None,
+            three_ds2_request_data: None,
             shopper_name: None,
             shopper_locale: None,
             shopper_email: item.router_data.get_optional_billing_email(),
@@ -3001,6 +3130,7 @@ impl
             recurring_processing_model: None,
             additional_data: None,
             mpi_data: None,
+            three_ds2_request_data: None,
             shopper_name: None,
             shopper_locale: None,
             shopper_email: item.router_data.get_optional_billing_email(),
@@ -3071,6 +3201,7 @@ impl
             browser_info,
             additional_data,
             mpi_data: None,
+            three_ds2_request_data: None,
             telephone_number: None,
             shopper_name: None,
             shopper_email: item.router_data.get_optional_billing_email(),
@@ -3191,6 +3322,7 @@ impl
             browser_info,
             additional_data,
             mpi_data,
+            three_ds2_request_data: None,
             telephone_number: None,
             shopper_name: None,
             shopper_email,
@@ -3280,6 +3412,7 @@ impl
             shopper_name,
             shopper_email,
             mpi_data: None,
+            three_ds2_request_data: None,
             shopper_locale: None,
             social_security_number: None,
             billing_address,
@@ -3349,6 +3482,7 @@ impl
             browser_info: None,
             additional_data: None,
             mpi_data: None,
+            three_ds2_request_data: None,
             telephone_number,
             shopper_name,
             shopper_email,
@@ -4123,6 +4257,12 @@ impl<F, Req>
         ),
     ) -> Result<Self, Self::Error> {
         let is_manual_capture = utils::is_manual_capture(capture_method);
+        let connector_response = match &item.response {
+            AdyenPaymentResponse::Response(response) => {
+                get_sca_exemption_connector_response(response)
+            }
+            _ => None,
+        };
         let (status, error, payment_response_data) = match item.response {
             AdyenPaymentResponse::Response(response) => {
                 get_adyen_response(*response, is_manual_capture, item.http_code, pmt)?
@@ -4150,6 +4290,7 @@ impl<F, Req>
         Ok(Self {
             status,
             response: error.map_or_else(|| Ok(payment_response_data), Err),
+            connector_response: connector_response.or(item.data.connector_response),
             ..item.data
         })
     }
@@ -5564,8 +5705,88 @@ impl
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
             mpi_data: Some(mpi_data),
+            three_ds2_request_data: None,
             store,
             splits,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_three_ds2_request_data_follows_the_challenge_flow() {
+        assert_eq!(
+            get_three_ds2_request_data(
+                enums::AuthenticationType::ThreeDs,
+                Some(enums::DecoupledAuthenticationType::Challenge)
+            ),
+            Some(AdyenThreeDS2RequestData {
+                device_channel: AdyenDeviceChannel::Browser,
+                three_ds_requestor_challenge_ind: AdyenChallengeIndicator::ChallengeRequested,
+            })
+        );
+        assert_eq!(
+            get_three_ds2_request_data(
+                enums::AuthenticationType::ThreeDs,
+                Some(enums::DecoupledAuthenticationType::Frictionless)
+            ),
+            Some(AdyenThreeDS2RequestData {
+                device_channel: AdyenDeviceChannel::Browser,
+                three_ds_requestor_challenge_ind: AdyenChallengeIndicator::NoChallengeRequested,
+            })
+        );
+    }
+
+    #[test]
+    fn test_three_ds2_request_data_falls_back_to_the_connector_default() {
+        assert_eq!(
+            get_three_ds2_request_data(enums::AuthenticationType::ThreeDs, None),
+            None
+        );
+        assert_eq!(
+            get_three_ds2_request_data(
+                enums::AuthenticationType::NoThreeDs,
+                Some(enums::DecoupledAuthenticationType::Challenge)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_three_ds2_request_data_serialization() {
+        let three_ds2_request_data = get_three_ds2_request_data(
+            enums::AuthenticationType::ThreeDs,
+            Some(enums::DecoupledAuthenticationType::Challenge),
+        );
+
+        assert_eq!(
+            serde_json::to_value(three_ds2_request_data).ok(),
+            Some(serde_json::json!({
+                "deviceChannel": "browser",
+                "threeDSRequestorChallengeInd": "03"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_recurring_sca_exemption_is_requested_as_a_subscription() {
+        assert_eq!(
+            serde_json::to_value(get_off_session_recurring_model(Some(
+                enums::ScaExemptionType::Recurring
+            )))
+            .ok(),
+            Some(serde_json::json!("Subscription"))
+        );
+        assert_eq!(
+            serde_json::to_value(get_off_session_recurring_model(Some(
+                enums::ScaExemptionType::LowValue
+            )))
+            .ok(),
+            Some(serde_json::json!("UnscheduledCardOnFile"))
+        );
+        assert!(get_sca_exemption(Some(enums::ScaExemptionType::Recurring)).is_none());
+    }
+}
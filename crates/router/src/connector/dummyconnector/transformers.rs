@@ -65,6 +65,7 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    capture_method: Option<enums::CaptureMethod>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -175,6 +176,7 @@ impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
             payment_method_data: payment_method_data?,
             return_url: item.request.router_return_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            capture_method: item.request.capture_method,
         })
     }
 }
@@ -201,17 +203,21 @@ impl TryFrom<&types::ConnectorAuthType> for DummyConnectorAuthType {
 #[serde(rename_all = "lowercase")]
 pub enum DummyConnectorPaymentStatus {
     Succeeded,
+    Authorized,
     Failed,
     #[default]
     Processing,
+    Cancelled,
 }
 
 impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
     fn from(item: DummyConnectorPaymentStatus) -> Self {
         match item {
             DummyConnectorPaymentStatus::Succeeded => Self::Charged,
+            DummyConnectorPaymentStatus::Authorized => Self::Authorized,
             DummyConnectorPaymentStatus::Failed => Self::Failure,
             DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Cancelled => Self::Voided,
         }
     }
 }
@@ -280,6 +286,22 @@ impl DummyConnectorNextAction {
     }
 }
 
+// CAPTURE :
+// Type definition for CaptureRequest
+#[derive(Default, Debug, Serialize)]
+pub struct DummyConnectorCaptureRequest {
+    pub amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
 // REFUND :
 // Type definition for RefundRequest
 #[derive(Default, Debug, Serialize)]
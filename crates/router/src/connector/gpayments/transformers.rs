@@ -81,6 +81,10 @@ pub struct GpaymentsMetaData {
     pub merchant_id: common_utils::id_type::MerchantId,
 }
 
+impl api::ConnectorMetadataSchema for GpaymentsMetaData {
+    const FIELDS: &'static [&'static str] = &["endpoint_prefix", "merchant_id"];
+}
+
 impl TryFrom<&Option<common_utils::pii::SecretSerdeValue>> for GpaymentsMetaData {
     type Error = error_stack::Report<errors::ConnectorError>;
 
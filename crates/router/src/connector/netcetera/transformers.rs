@@ -262,6 +262,18 @@ pub struct NetceteraMetaData {
     pub merchant_configuration_id: Option<String>,
 }
 
+impl api::ConnectorMetadataSchema for NetceteraMetaData {
+    const FIELDS: &'static [&'static str] = &[
+        "mcc",
+        "merchant_country_code",
+        "merchant_name",
+        "endpoint_prefix",
+        "three_ds_requestor_name",
+        "three_ds_requestor_id",
+        "merchant_configuration_id",
+    ];
+}
+
 impl TryFrom<&Option<common_utils::pii::SecretSerdeValue>> for NetceteraMetaData {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
@@ -2920,4 +2920,10 @@ impl
     }
 }
 
-impl ConnectorSpecifications for Stripe {}
+impl ConnectorSpecifications for Stripe {
+    fn get_statement_descriptor_max_length(&self) -> Option<usize> {
+        Some(usize::from(
+            common_utils::consts::MAX_STATEMENT_DESCRIPTOR_LENGTH,
+        ))
+    }
+}
@@ -1978,6 +1978,7 @@ impl TryFrom<(&types::PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntent
             }
             Some(common_types::payments::SplitPaymentsRequest::AdyenSplitPayment(_))
             | Some(common_types::payments::SplitPaymentsRequest::XenditSplitPayment(_))
+            | Some(common_types::payments::SplitPaymentsRequest::GenericSplitPayment(_))
             | None => (None, item.connector_customer.to_owned().map(Secret::new)),
         };
 
@@ -70,6 +70,8 @@ pub const JWT_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24 * 2; // 2 days
 // TODO: This should be fixed in future.
 pub const SINGLE_PURPOSE_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24 * 2; // 2 days
 
+pub const IMPERSONATION_TOKEN_TIME_IN_SECS: u64 = 60 * 15; // 15 minutes
+
 pub const JWT_TOKEN_COOKIE_NAME: &str = "login_token";
 
 pub const USER_BLACKLIST_PREFIX: &str = "BU_";
@@ -109,6 +111,12 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 /// Min payment session expiry
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
+/// Default validity of a signed payment link in seconds
+pub const DEFAULT_SIGNED_PAYMENT_LINK_TTL: i64 = 15 * 60;
+
+/// Characters that are not allowed in the statement descriptors of a payment
+pub const STATEMENT_DESCRIPTOR_DISALLOWED_CHARACTERS: [char; 6] = ['<', '>', '\\', '\'', '"', '*'];
+
 /// Max payment intent fulfillment expiry
 pub const MAX_INTENT_FULFILLMENT_EXPIRY: u32 = 1800;
 
@@ -232,3 +240,12 @@ pub(crate) const PROTOCOL: &str = "ECv2";
 
 /// Sender ID for Google Pay Decryption
 pub(crate) const SENDER_ID: &[u8] = b"Google";
+
+/// Maximum number of payments that can be refunded in a single bulk refund request
+pub const MAX_BULK_REFUND_COUNT: usize = 100;
+
+/// Number of transactions fetched at a time while generating a reconciliation report
+pub const RECON_REPORT_BATCH_SIZE: u32 = 1000;
+
+/// Maximum number of days a reconciliation report can span
+pub const MAX_RECON_REPORT_DAYS: i64 = 31;
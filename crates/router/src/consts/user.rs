@@ -38,6 +38,17 @@ pub const REDIS_RECOVERY_CODE_ATTEMPTS_TTL_IN_SECS: i64 = 10 * 60; // 10 mins
 pub const REDIS_SSO_PREFIX: &str = "SSO_";
 pub const REDIS_SSO_TTL: i64 = 5 * 60; // 5 minutes
 
+pub const DEFAULT_LIST_USERS_IN_LINEAGE_LIMIT: u32 = 50;
+pub const DEFAULT_LIST_ROLE_AUDIT_LIMIT: u32 = 100;
+
+pub const REDIS_RESEND_INVITATION_PREFIX: &str = "RESEND_INVITE_";
+pub const REDIS_RESEND_INVITATION_TTL_IN_SECS: i64 = 60; // 1 minute
+pub const REDIS_ROLE_WRITE_RATE_LIMIT_PREFIX: &str = "ROLE_WRITE_RATE_LIMIT_";
+pub const REDIS_ROLE_USAGE_THROTTLE_PREFIX: &str = "ROLE_USAGE_THROTTLE_";
+pub const REDIS_ROLE_USAGE_THROTTLE_TTL_IN_SECS: i64 = 60 * 60; // 1 hour
+pub const REDIS_PREDEFINED_ROLE_USAGE_PREFIX: &str = "PREDEFINED_ROLE_USAGE_";
+pub const REDIS_PREDEFINED_ROLE_USAGE_TTL_IN_SECS: i64 = 90 * 24 * 60 * 60; // 90 days
+
 /// Email subject
 pub const EMAIL_SUBJECT_SIGNUP: &str = "Welcome to the Hyperswitch community!";
 pub const EMAIL_SUBJECT_INVITATION: &str = "You have been invited to join Hyperswitch Community!";
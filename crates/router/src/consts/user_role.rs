@@ -5,6 +5,7 @@ pub const ROLE_ID_MERCHANT_IAM_ADMIN: &str = "merchant_iam_admin";
 pub const ROLE_ID_MERCHANT_DEVELOPER: &str = "merchant_developer";
 pub const ROLE_ID_MERCHANT_OPERATOR: &str = "merchant_operator";
 pub const ROLE_ID_MERCHANT_CUSTOMER_SUPPORT: &str = "merchant_customer_support";
+pub const ROLE_ID_MERCHANT_AUDITOR: &str = "merchant_auditor";
 
 pub const ROLE_ID_PROFILE_ADMIN: &str = "profile_admin";
 pub const ROLE_ID_PROFILE_VIEW_ONLY: &str = "profile_view_only";
@@ -15,3 +16,30 @@ pub const ROLE_ID_PROFILE_CUSTOMER_SUPPORT: &str = "profile_customer_support";
 
 pub const INTERNAL_USER_MERCHANT_ID: &str = "juspay000";
 pub const MAX_ROLE_NAME_LENGTH: usize = 64;
+pub const UNKNOWN_INVITER_NAME: &str = "Unknown";
+pub const DEFAULT_MAX_CUSTOM_ROLES_PER_MERCHANT: i64 = 100;
+pub const CUSTOM_ROLES_LIMIT_CONFIG_KEY_PREFIX: &str = "custom_roles_limit";
+pub const MAX_ROLE_AUDIT_REASON_LENGTH: usize = 255;
+pub const MAX_ROLE_UPDATE_PREVIEW_USERS: u32 = 100;
+pub const MAX_PERMISSION_GROUP_NAME_LENGTH: usize = 64;
+pub const MAX_ROLE_TEMPLATE_APPLY_MERCHANTS: usize = 100;
+pub const MAX_ROLE_ASSIGNMENT_RULES_PER_MERCHANT: usize = 50;
+pub const MAX_ROLE_ASSIGNMENT_RULE_VALUE_LENGTH: usize = 255;
+
+// Role integrity check
+pub const ROLE_INTEGRITY_CHECK_BATCH_SIZE: u32 = 1000;
+pub const ROLE_INTEGRITY_CHECK_DEFAULT_LIMIT: u32 = 10000;
+pub const ROLE_INTEGRITY_CHECK_MAX_LIMIT: u32 = 100000;
+pub const ROLE_INTEGRITY_CHECK_MAX_SAMPLES: usize = 10;
+
+// RBAC export
+pub const RBAC_EXPORT_VERSION: u32 = 1;
+pub const RBAC_EXPORT_DEFAULT_LIMIT: u32 = 100;
+pub const RBAC_EXPORT_MAX_LIMIT: u32 = 1000;
+
+// Whoami
+pub const WHOAMI_SCHEMA_VERSION: u32 = 1;
+
+// Deleted user role purge
+pub const DELETED_USER_ROLE_PURGE_TASK: &str = "DELETED_USER_ROLE_PURGE";
+pub const DELETED_USER_ROLE_PURGE_TAG: &str = "USER_ROLE";
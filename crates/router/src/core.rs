@@ -9,8 +9,10 @@ pub mod blocklist;
 pub mod cache;
 pub mod card_testing_guard;
 pub mod cards_info;
+pub mod circuit_breaker;
 pub mod conditional_config;
 pub mod configs;
+pub mod connector_latency;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -26,6 +26,7 @@ use uuid::Uuid;
 #[cfg(any(feature = "v1", feature = "v2"))]
 use crate::types::transformers::ForeignFrom;
 use crate::{
+    configs::settings::UnknownMetadataFieldsHandling,
     consts,
     core::{
         encryption::transfer_encryption_key,
@@ -58,6 +59,16 @@ use crate::{
 const IBAN_MAX_LENGTH: usize = 34;
 const BACS_SORT_CODE_LENGTH: usize = 6;
 const BACS_MAX_ACCOUNT_NUMBER_LENGTH: usize = 8;
+/// Fields of the merchant connector account metadata holding the wallet configuration, which is
+/// read by the core irrespective of the connector
+const WALLET_METADATA_FIELDS: &[&str] = &[
+    "apple_pay",
+    "apple_pay_combined",
+    "google_pay",
+    "paypal_sdk",
+    "paze",
+    "samsung_pay",
+];
 
 #[inline]
 pub fn create_merchant_publishable_key() -> String {
@@ -1202,6 +1213,7 @@ struct ConnectorAuthTypeAndMetadataValidation<'a> {
     connector_name: &'a api_models::enums::Connector,
     auth_type: &'a types::ConnectorAuthType,
     connector_meta_data: &'a Option<pii::SecretSerdeValue>,
+    unknown_metadata_fields: UnknownMetadataFieldsHandling,
 }
 
 impl ConnectorAuthTypeAndMetadataValidation<'_> {
@@ -1212,6 +1224,7 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
             auth_type: self.auth_type,
         };
         connector_auth_type_validation.validate_connector_auth_type()?;
+        self.validate_metadata_schema()?;
         self.validate_auth_and_metadata_type_with_connector()
             .map_err(|err| match *err.current_context() {
                 errors::ConnectorError::InvalidConnectorName => {
@@ -1234,6 +1247,106 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
             })
     }
 
+    fn validate_metadata_schema(&self) -> RouterResult<()> {
+        use crate::connector::{
+            adyen::transformers::AdyenConnectorMetadataObject,
+            braintree::transformers::BraintreeMeta, coinbase::transformers::CoinbaseConnectorMeta,
+            cybersource::transformers::CybersourceConnectorMetadataObject,
+            fiserv::transformers::FiservSessionObject, gpayments::transformers::GpaymentsMetaData,
+            klarna::transformers::KlarnaConnectorMetadataObject,
+            mifinity::transformers::MifinityConnectorMetadataObject,
+            netcetera::transformers::NetceteraMetaData,
+        };
+
+        match self.connector_name {
+            api_enums::Connector::Adyen => {
+                self.validate_metadata_against_schema::<AdyenConnectorMetadataObject>()
+            }
+            api_enums::Connector::Braintree => {
+                self.validate_metadata_against_schema::<BraintreeMeta>()
+            }
+            api_enums::Connector::Coinbase => {
+                self.validate_metadata_against_schema::<CoinbaseConnectorMeta>()
+            }
+            api_enums::Connector::Cybersource => {
+                self.validate_metadata_against_schema::<CybersourceConnectorMetadataObject>()
+            }
+            api_enums::Connector::Fiserv => {
+                self.validate_metadata_against_schema::<FiservSessionObject>()
+            }
+            api_enums::Connector::Gpayments => {
+                self.validate_metadata_against_schema::<GpaymentsMetaData>()
+            }
+            api_enums::Connector::Klarna => {
+                self.validate_metadata_against_schema::<KlarnaConnectorMetadataObject>()
+            }
+            api_enums::Connector::Mifinity => {
+                self.validate_metadata_against_schema::<MifinityConnectorMetadataObject>()
+            }
+            api_enums::Connector::Netcetera => {
+                self.validate_metadata_against_schema::<NetceteraMetaData>()
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates the metadata against the schema of the connector, so that malformed metadata is
+    /// rejected with the path of the offending field instead of failing the payments later on
+    fn validate_metadata_against_schema<T: api::ConnectorMetadataSchema>(
+        &self,
+    ) -> RouterResult<()> {
+        // Metadata required by the connector is checked when it is parsed by the connector
+        let Some(metadata) = self.connector_meta_data else {
+            return Ok(());
+        };
+        let metadata = metadata.peek();
+
+        serde_path_to_error::deserialize::<_, T>(metadata).map_err(|error| {
+            let path = error.path().to_string();
+            let message = if path == "." {
+                format!("The metadata is invalid: {}", error.inner())
+            } else {
+                format!("The metadata field `{path}` is invalid: {}", error.inner())
+            };
+            report!(errors::ApiErrorResponse::InvalidRequestData { message })
+        })?;
+
+        let unknown_fields = metadata
+            .as_object()
+            .map(|fields| {
+                fields
+                    .keys()
+                    .filter(|field| {
+                        !T::FIELDS.contains(&field.as_str())
+                            && !WALLET_METADATA_FIELDS.contains(&field.as_str())
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        if unknown_fields.is_empty() {
+            return Ok(());
+        }
+
+        let message = format!(
+            "The metadata fields {} are not supported by {}",
+            unknown_fields.join(", "),
+            self.connector_name
+        );
+        match self.unknown_metadata_fields {
+            UnknownMetadataFieldsHandling::Warn => {
+                crate::logger::warn!("{message}");
+                Ok(())
+            }
+            UnknownMetadataFieldsHandling::Reject => {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message
+                }))
+            }
+        }
+    }
+
     fn validate_auth_and_metadata_type_with_connector(
         &self,
     ) -> Result<(), error_stack::Report<errors::ConnectorError>> {
@@ -2149,6 +2262,7 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             connector_name: &mca.connector_name,
             auth_type: &auth,
             connector_meta_data: &metadata,
+            unknown_metadata_fields: state.conf.connector_metadata_validation.unknown_fields,
         };
 
         connector_auth_type_and_metadata_validation.validate_auth_and_metadata_type()?;
@@ -2328,6 +2442,7 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             connector_name: &connector_enum,
             auth_type: &auth,
             connector_meta_data: &metadata,
+            unknown_metadata_fields: state.conf.connector_metadata_validation.unknown_fields,
         };
         connector_auth_type_and_metadata_validation.validate_auth_and_metadata_type()?;
         let connector_status_and_disabled_validation = ConnectorStatusAndDisabledValidation {
@@ -2484,6 +2599,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             connector_name: &self.connector_name,
             auth_type: &auth,
             connector_meta_data: &self.metadata,
+            unknown_metadata_fields: state.conf.connector_metadata_validation.unknown_fields,
         };
         connector_auth_type_and_metadata_validation.validate_auth_and_metadata_type()?;
         let connector_status_and_disabled_validation = ConnectorStatusAndDisabledValidation {
@@ -2691,6 +2807,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             connector_name: &self.connector_name,
             auth_type: &auth,
             connector_meta_data: &self.metadata,
+            unknown_metadata_fields: state.conf.connector_metadata_validation.unknown_fields,
         };
         connector_auth_type_and_metadata_validation.validate_auth_and_metadata_type()?;
         let connector_status_and_disabled_validation = ConnectorStatusAndDisabledValidation {
@@ -3793,6 +3910,7 @@ impl ProfileCreateBridge for api::ProfileCreate {
                 .attach_printable("error while generating card testing secret key")?,
             is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled.unwrap_or_default(),
             force_3ds_challenge: self.force_3ds_challenge.unwrap_or_default(),
+            allowed_payment_method_types: self.allowed_payment_method_types,
         }))
     }
 
@@ -4235,6 +4353,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 card_testing_secret_key,
                 is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled,
                 force_3ds_challenge: self.force_3ds_challenge,
+                allowed_payment_method_types: self.allowed_payment_method_types,
             },
         )))
     }
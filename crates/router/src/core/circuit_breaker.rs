@@ -0,0 +1,327 @@
+use api_models::{
+    circuit_breaker::{
+        CircuitBreakerState, ConnectorCircuitBreakerRequest, ConnectorCircuitBreakerResponse,
+    },
+    enums as api_enums,
+};
+use common_utils::date_time;
+use error_stack::ResultExt;
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResponse, RouterResult};
+use crate::{routes::SessionState, services, types::api};
+
+const TOTAL_REQUESTS_FIELD: &str = "total_requests";
+const FAILED_REQUESTS_FIELD: &str = "failed_requests";
+
+fn get_window_key(connector: api_enums::Connector) -> String {
+    format!("circuit_breaker_window_{connector}")
+}
+
+fn get_open_key(connector: api_enums::Connector) -> String {
+    format!("circuit_breaker_open_{connector}")
+}
+
+fn get_half_open_key(connector: api_enums::Connector) -> String {
+    format!("circuit_breaker_half_open_{connector}")
+}
+
+fn get_probe_key(connector: api_enums::Connector) -> String {
+    format!("circuit_breaker_probe_{connector}")
+}
+
+/// Returns the time at which the circuit was opened, if the circuit of the connector is open
+async fn get_opened_at(
+    redis_conn: &redis::RedisConnectionPool,
+    connector: api_enums::Connector,
+) -> errors::CustomResult<Option<i64>, redis::errors::RedisError> {
+    redis_conn
+        .get_key::<Option<String>>(&get_open_key(connector).as_str().into())
+        .await
+        .map(|opened_at| opened_at.and_then(|opened_at| opened_at.parse::<i64>().ok()))
+}
+
+async fn get_circuit_breaker_state(
+    redis_conn: &redis::RedisConnectionPool,
+    connector: api_enums::Connector,
+) -> errors::CustomResult<CircuitBreakerState, redis::errors::RedisError> {
+    if get_opened_at(redis_conn, connector).await?.is_some() {
+        return Ok(CircuitBreakerState::Open);
+    }
+
+    let is_half_open = redis_conn
+        .exists::<()>(&get_half_open_key(connector).as_str().into())
+        .await?;
+
+    Ok(if is_half_open {
+        CircuitBreakerState::HalfOpen
+    } else {
+        CircuitBreakerState::Closed
+    })
+}
+
+async fn open_circuit(
+    state: &SessionState,
+    redis_conn: &redis::RedisConnectionPool,
+    connector: api_enums::Connector,
+) -> errors::CustomResult<(), redis::errors::RedisError> {
+    let config = &state.conf.connector_circuit_breaker;
+
+    logger::warn!("Opening circuit breaker for connector: {connector}");
+
+    redis_conn
+        .set_key_with_expiry(
+            &get_open_key(connector).as_str().into(),
+            date_time::now_unix_timestamp().to_string(),
+            config.cooldown_in_seconds,
+        )
+        .await?;
+
+    // The half open marker outlives the open key, so that the first call after the cooldown is
+    // treated as a probe
+    redis_conn
+        .set_key_with_expiry(
+            &get_half_open_key(connector).as_str().into(),
+            true.to_string(),
+            config.cooldown_in_seconds + config.window_in_seconds,
+        )
+        .await?;
+
+    redis_conn
+        .delete_key(&get_window_key(connector).as_str().into())
+        .await?;
+    redis_conn
+        .delete_key(&get_probe_key(connector).as_str().into())
+        .await?;
+
+    Ok(())
+}
+
+async fn close_circuit(
+    redis_conn: &redis::RedisConnectionPool,
+    connector: api_enums::Connector,
+) -> errors::CustomResult<(), redis::errors::RedisError> {
+    logger::info!("Closing circuit breaker for connector: {connector}");
+
+    for key in [
+        get_half_open_key(connector),
+        get_probe_key(connector),
+        get_window_key(connector),
+    ] {
+        redis_conn.delete_key(&key.as_str().into()).await?;
+    }
+
+    Ok(())
+}
+
+/// Removes the connectors whose circuit is open from the routed connectors, so that the payment
+/// falls back to the next eligible connector. The list is returned as is if the circuit of every
+/// connector is open, in which case the payment is short-circuited while calling the connector.
+#[instrument(skip_all)]
+pub async fn filter_connectors_with_open_circuit(
+    state: &SessionState,
+    connectors: Vec<api::ConnectorData>,
+) -> Vec<api::ConnectorData> {
+    if !state.conf.connector_circuit_breaker.enabled {
+        return connectors;
+    }
+
+    let Ok(redis_conn) = state.store.get_redis_conn() else {
+        return connectors;
+    };
+
+    let mut available_connectors = Vec::with_capacity(connectors.len());
+    for connector in connectors.iter() {
+        match get_opened_at(&redis_conn, connector.connector_name).await {
+            Ok(Some(_)) => {
+                logger::info!(
+                    "Skipping connector: {} as its circuit is open",
+                    connector.connector_name
+                );
+            }
+            Ok(None) => available_connectors.push(connector.clone()),
+            Err(error) => {
+                logger::error!(circuit_breaker_error=?error);
+                available_connectors.push(connector.clone());
+            }
+        }
+    }
+
+    if available_connectors.is_empty() {
+        connectors
+    } else {
+        available_connectors
+    }
+}
+
+/// Fails fast if the circuit of the connector is open. When the circuit is half open, only a
+/// single probe call is let through until its outcome is recorded.
+#[instrument(skip_all)]
+pub async fn validate_connector_circuit(
+    state: &SessionState,
+    connector: api_enums::Connector,
+) -> RouterResult<()> {
+    let config = &state.conf.connector_circuit_breaker;
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(circuit_breaker_error=?error);
+            return Ok(());
+        }
+    };
+
+    let is_call_allowed = match get_circuit_breaker_state(&redis_conn, connector).await {
+        Ok(CircuitBreakerState::Closed) => true,
+        Ok(CircuitBreakerState::Open) => false,
+        Ok(CircuitBreakerState::HalfOpen) => redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &get_probe_key(connector).as_str().into(),
+                date_time::now_unix_timestamp().to_string(),
+                Some(config.window_in_seconds),
+            )
+            .await
+            .map(|reply| matches!(reply, redis::SetnxReply::KeySet))
+            .unwrap_or_else(|error| {
+                logger::error!(circuit_breaker_error=?error);
+                true
+            }),
+        Err(error) => {
+            logger::error!(circuit_breaker_error=?error);
+            true
+        }
+    };
+
+    if is_call_allowed {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::ConnectorUnavailable {
+            connector: connector.to_string(),
+        })
+        .attach_printable("Circuit breaker is open for the connector")
+    }
+}
+
+async fn update_circuit_breaker(
+    state: &SessionState,
+    connector: api_enums::Connector,
+    is_failure: bool,
+) -> errors::CustomResult<(), redis::errors::RedisError> {
+    let config = &state.conf.connector_circuit_breaker;
+    let redis_conn = state.store.get_redis_conn()?;
+
+    match get_circuit_breaker_state(&redis_conn, connector).await? {
+        // Outcomes of calls which were in flight when the circuit was opened are ignored
+        CircuitBreakerState::Open => Ok(()),
+        CircuitBreakerState::HalfOpen => {
+            if is_failure {
+                open_circuit(state, &redis_conn, connector).await
+            } else {
+                close_circuit(&redis_conn, connector).await
+            }
+        }
+        CircuitBreakerState::Closed => {
+            let window_key = get_window_key(connector);
+            let counts = redis_conn
+                .increment_fields_in_hash(
+                    &window_key.as_str().into(),
+                    &[
+                        (TOTAL_REQUESTS_FIELD, 1),
+                        (FAILED_REQUESTS_FIELD, i64::from(is_failure)),
+                    ],
+                )
+                .await?;
+
+            let total_requests = counts.first().copied().unwrap_or_default();
+            let failed_requests = counts.get(1).copied().unwrap_or_default();
+
+            if total_requests == 1 {
+                redis_conn
+                    .set_expiry(&window_key.as_str().into(), config.window_in_seconds)
+                    .await?;
+            }
+
+            let is_threshold_breached = i64::try_from(total_requests).unwrap_or(i64::MAX)
+                >= config.minimum_request_count
+                && failed_requests * 100
+                    >= total_requests * usize::from(config.failure_rate_threshold);
+
+            if is_threshold_breached {
+                open_circuit(state, &redis_conn, connector).await
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Records the outcome of a connector call, opening the circuit when the failure rate of the
+/// window breaches the configured threshold, and resolving the probe when the circuit is half open.
+#[instrument(skip_all)]
+pub async fn record_connector_call_outcome(
+    state: &SessionState,
+    connector: api_enums::Connector,
+    is_failure: bool,
+) {
+    if !state.conf.connector_circuit_breaker.enabled {
+        return;
+    }
+
+    update_circuit_breaker(state, connector, is_failure)
+        .await
+        .map_err(|error| logger::error!(circuit_breaker_error=?error))
+        .ok();
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_circuit_breaker(
+    state: SessionState,
+    req: ConnectorCircuitBreakerRequest,
+) -> RouterResponse<ConnectorCircuitBreakerResponse> {
+    let connector = req.connector;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let circuit_breaker_state = get_circuit_breaker_state(&redis_conn, connector)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch circuit breaker state")?;
+
+    let opened_at = get_opened_at(&redis_conn, connector)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch circuit breaker open time")?
+        .and_then(|opened_at| time::OffsetDateTime::from_unix_timestamp(opened_at).ok())
+        .map(|opened_at| time::PrimitiveDateTime::new(opened_at.date(), opened_at.time()));
+
+    let counts = redis_conn
+        .get_hash_fields::<std::collections::HashMap<String, i64>>(
+            &get_window_key(connector).as_str().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch circuit breaker window")?;
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorCircuitBreakerResponse {
+            connector,
+            state: circuit_breaker_state,
+            total_requests: counts
+                .get(TOTAL_REQUESTS_FIELD)
+                .copied()
+                .unwrap_or_default(),
+            failed_requests: counts
+                .get(FAILED_REQUESTS_FIELD)
+                .copied()
+                .unwrap_or_default(),
+            opened_at,
+        },
+    ))
+}
@@ -0,0 +1,348 @@
+use std::{collections::HashMap, time::Duration};
+
+use api_models::{
+    connector_latency::{ConnectorLatencyRequest, ConnectorLatencyResponse},
+    enums as api_enums,
+};
+use common_utils::date_time;
+use error_stack::ResultExt;
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResponse};
+use crate::{consts, routes::SessionState, services, types::api::routing as routing_types};
+
+/// Upper bounds of the buckets in which response times are counted, response times beyond the
+/// last bound are counted in the last bucket
+const LATENCY_BUCKET_BOUNDS_IN_MILLIS: [u64; 12] = [
+    50, 100, 200, 300, 500, 750, 1_000, 1_500, 2_000, 3_000, 5_000, 10_000,
+];
+const UPDATED_AT_FIELD: &str = "updated_at";
+/// Response times are dropped after decaying for these many half lives, by which point they carry
+/// less than 0.1% of their original weight
+const RETENTION_IN_HALF_LIVES: i64 = 10;
+
+fn get_latency_key(connector: api_enums::Connector) -> String {
+    format!("connector_latency_{connector}")
+}
+
+fn get_bucket_field(bucket: usize) -> String {
+    format!("bucket_{bucket}")
+}
+
+/// Histogram of the response times of a connector, in which the weight of every response time
+/// decays exponentially with its age
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LatencyHistogram {
+    weights: [f64; LATENCY_BUCKET_BOUNDS_IN_MILLIS.len()],
+    /// Time up to which the weights have been decayed
+    updated_at: Option<i64>,
+}
+
+impl LatencyHistogram {
+    fn from_hash_fields(fields: &HashMap<String, String>) -> Self {
+        let mut histogram = Self {
+            updated_at: fields
+                .get(UPDATED_AT_FIELD)
+                .and_then(|updated_at| updated_at.parse().ok()),
+            ..Default::default()
+        };
+        for (bucket, weight) in histogram.weights.iter_mut().enumerate() {
+            *weight = fields
+                .get(&get_bucket_field(bucket))
+                .and_then(|weight| weight.parse().ok())
+                .unwrap_or_default();
+        }
+        histogram
+    }
+
+    fn to_hash_fields(&self) -> Vec<(String, String)> {
+        self.weights
+            .iter()
+            .enumerate()
+            .map(|(bucket, weight)| (get_bucket_field(bucket), weight.to_string()))
+            .chain(
+                self.updated_at
+                    .map(|updated_at| (UPDATED_AT_FIELD.to_string(), updated_at.to_string())),
+            )
+            .collect()
+    }
+
+    /// Decays the weights by the time elapsed since they were last updated
+    fn decay(&mut self, now: i64, half_life_in_seconds: i64) {
+        let Some(updated_at) = self.updated_at else {
+            return;
+        };
+        let elapsed = u32::try_from(now.saturating_sub(updated_at).max(0)).unwrap_or(u32::MAX);
+        let half_life = u32::try_from(half_life_in_seconds.max(1)).unwrap_or(u32::MAX);
+        let decay_factor = 0.5_f64.powf(f64::from(elapsed) / f64::from(half_life));
+        for weight in self.weights.iter_mut() {
+            *weight *= decay_factor;
+        }
+        self.updated_at = Some(now);
+    }
+
+    fn record(&mut self, latency_in_millis: u64, now: i64, half_life_in_seconds: i64) {
+        self.decay(now, half_life_in_seconds);
+        let bucket = LATENCY_BUCKET_BOUNDS_IN_MILLIS
+            .iter()
+            .position(|bound| latency_in_millis <= *bound)
+            .unwrap_or(LATENCY_BUCKET_BOUNDS_IN_MILLIS.len() - 1);
+        if let Some(weight) = self.weights.get_mut(bucket) {
+            *weight += 1.0;
+        }
+        self.updated_at = Some(now);
+    }
+
+    fn get_sample_count(&self) -> f64 {
+        self.weights.iter().sum()
+    }
+
+    /// Returns the upper bound of the bucket in which the percentile falls
+    fn get_percentile(&self, percentile: u8) -> Option<u64> {
+        let sample_count = self.get_sample_count();
+        if sample_count <= 0.0 {
+            return None;
+        }
+
+        let target = sample_count * f64::from(percentile.min(100)) / 100.0;
+        let mut cumulative_weight = 0.0;
+        for (weight, bound) in self.weights.iter().zip(LATENCY_BUCKET_BOUNDS_IN_MILLIS) {
+            cumulative_weight += weight;
+            if cumulative_weight >= target {
+                return Some(bound);
+            }
+        }
+        LATENCY_BUCKET_BOUNDS_IN_MILLIS.last().copied()
+    }
+}
+
+async fn get_latency_histogram(
+    redis_conn: &redis::RedisConnectionPool,
+    connector: api_enums::Connector,
+) -> errors::CustomResult<LatencyHistogram, redis::errors::RedisError> {
+    redis_conn
+        .get_hash_fields::<HashMap<String, String>>(&get_latency_key(connector).as_str().into())
+        .await
+        .map(|fields| LatencyHistogram::from_hash_fields(&fields))
+}
+
+async fn update_latency_histogram(
+    state: &SessionState,
+    connector: api_enums::Connector,
+    latency: Duration,
+) -> errors::CustomResult<(), redis::errors::RedisError> {
+    let config = &state.conf.connector_latency;
+    let redis_conn = state.store.get_redis_conn()?;
+
+    // Concurrent updates may overwrite each other, which only drops a few response times from
+    // the histogram
+    let mut histogram = get_latency_histogram(&redis_conn, connector).await?;
+    histogram.record(
+        u64::try_from(latency.as_millis()).unwrap_or(u64::MAX),
+        date_time::now_unix_timestamp(),
+        config.half_life_in_seconds,
+    );
+
+    redis_conn
+        .set_hash_fields(
+            &get_latency_key(connector).as_str().into(),
+            histogram.to_hash_fields(),
+            Some(config.half_life_in_seconds * RETENTION_IN_HALF_LIVES),
+        )
+        .await
+}
+
+/// Returns the response time which is recorded for a connector call. A call which timed out is
+/// recorded at the timeout, so that a connector which hangs ranks behind the responsive ones.
+pub fn get_recorded_latency(elapsed: Duration, is_timeout: bool) -> Duration {
+    if is_timeout {
+        elapsed.max(Duration::from_secs(consts::REQUEST_TIME_OUT))
+    } else {
+        elapsed
+    }
+}
+
+/// Records the response time of a connector call
+#[instrument(skip_all)]
+pub async fn record_connector_latency(
+    state: &SessionState,
+    connector: api_enums::Connector,
+    latency: Duration,
+) {
+    if !state.conf.connector_latency.enabled {
+        return;
+    }
+
+    update_latency_histogram(state, connector, latency)
+        .await
+        .map_err(|error| logger::error!(connector_latency_error=?error))
+        .ok();
+}
+
+/// Orders the connectors by their response time at the configured percentile, fastest first.
+/// Connectors without enough recent response times are placed behind the others in their
+/// configured order, so that a connector whose slow responses have decayed does not jump ahead of
+/// the connectors which are known to be fast. Connectors whose circuit is open are removed later,
+/// when the connector is decided.
+#[instrument(skip_all)]
+pub async fn order_connectors_by_latency(
+    state: &SessionState,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let config = &state.conf.connector_latency;
+    if !config.enabled {
+        return connectors;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(connector_latency_error=?error);
+            return connectors;
+        }
+    };
+
+    let now = date_time::now_unix_timestamp();
+    let mut ranked_connectors = Vec::with_capacity(connectors.len());
+    for choice in connectors {
+        let connector = api_enums::Connector::from(choice.connector);
+        let latency = match get_latency_histogram(&redis_conn, connector).await {
+            Ok(mut histogram) => {
+                histogram.decay(now, config.half_life_in_seconds);
+                (histogram.get_sample_count() >= f64::from(config.minimum_sample_count))
+                    .then(|| histogram.get_percentile(config.routing_percentile))
+                    .flatten()
+            }
+            Err(error) => {
+                logger::error!(connector_latency_error=?error);
+                None
+            }
+        };
+        ranked_connectors.push((choice, latency));
+    }
+
+    logger::debug!(?ranked_connectors, "Response times of the connectors");
+    rank_by_latency(ranked_connectors)
+}
+
+/// Sorts the items by their latency, fastest first. Items without a latency are placed last, and
+/// the sort is stable so that items with equal latencies keep their order.
+fn rank_by_latency<T>(mut items: Vec<(T, Option<u64>)>) -> Vec<T> {
+    items.sort_by_key(|(_, latency)| (latency.is_none(), *latency));
+    items.into_iter().map(|(item, _)| item).collect()
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_latency(
+    state: SessionState,
+    req: ConnectorLatencyRequest,
+) -> RouterResponse<ConnectorLatencyResponse> {
+    let connector = req.connector;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut histogram = get_latency_histogram(&redis_conn, connector)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector latency")?;
+
+    // The stored histogram is only updated when a response time is recorded
+    let last_recorded_at = histogram
+        .updated_at
+        .and_then(|last_recorded_at| {
+            time::OffsetDateTime::from_unix_timestamp(last_recorded_at).ok()
+        })
+        .map(|last_recorded_at| {
+            time::PrimitiveDateTime::new(last_recorded_at.date(), last_recorded_at.time())
+        });
+    histogram.decay(
+        date_time::now_unix_timestamp(),
+        state.conf.connector_latency.half_life_in_seconds,
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorLatencyResponse {
+            connector,
+            sample_count: histogram.get_sample_count(),
+            p50_in_millis: histogram.get_percentile(50),
+            p90_in_millis: histogram.get_percentile(90),
+            p99_in_millis: histogram.get_percentile(99),
+            last_recorded_at,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_percentiles_of_recorded_latencies() {
+        let mut histogram = LatencyHistogram::default();
+        for latency in [40, 80, 90, 150, 180, 250, 400, 450, 900, 4_000] {
+            histogram.record(latency, 0, 60);
+        }
+
+        assert_eq!(histogram.get_sample_count(), 10.0);
+        assert_eq!(histogram.get_percentile(50), Some(200));
+        assert_eq!(histogram.get_percentile(90), Some(1_000));
+        assert_eq!(histogram.get_percentile(99), Some(5_000));
+    }
+
+    #[test]
+    fn test_old_latencies_decay() {
+        let mut histogram = LatencyHistogram::default();
+        for _ in 0..8 {
+            histogram.record(4_000, 0, 60);
+        }
+        histogram.decay(120, 60);
+        assert_eq!(histogram.get_sample_count(), 2.0);
+
+        for _ in 0..8 {
+            histogram.record(80, 120, 60);
+        }
+        assert_eq!(histogram.get_percentile(50), Some(100));
+        assert_eq!(
+            LatencyHistogram::from_hash_fields(&histogram.to_hash_fields().into_iter().collect()),
+            histogram
+        );
+    }
+
+    #[test]
+    fn test_connectors_without_latency_are_ranked_last() {
+        assert_eq!(
+            rank_by_latency(vec![
+                ("adyen", None),
+                ("stripe", Some(500)),
+                ("checkout", Some(100)),
+                ("cybersource", None),
+                ("paypal", Some(500)),
+            ]),
+            ["checkout", "stripe", "paypal", "adyen", "cybersource"]
+        );
+    }
+
+    #[test]
+    fn test_timed_out_calls_are_recorded_at_the_timeout() {
+        let timeout = Duration::from_secs(consts::REQUEST_TIME_OUT);
+
+        assert_eq!(
+            get_recorded_latency(Duration::from_millis(200), false),
+            Duration::from_millis(200)
+        );
+        assert_eq!(
+            get_recorded_latency(Duration::from_millis(200), true),
+            timeout
+        );
+        assert_eq!(
+            get_recorded_latency(timeout + Duration::from_secs(1), true),
+            timeout + Duration::from_secs(1)
+        );
+    }
+}
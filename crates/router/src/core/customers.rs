@@ -16,6 +16,11 @@ use router_env::{instrument, tracing};
 use crate::core::payment_methods::cards::create_encrypted_data;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use crate::utils::CustomerAddress;
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+use crate::{
+    core::{api_locking, payment_methods::transformers as payment_methods},
+    routes::lock_utils,
+};
 use crate::{
     core::{
         errors::{self, StorageErrorExt},
@@ -187,6 +192,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             created_at: common_utils::date_time::now(),
             modified_at: common_utils::date_time::now(),
             default_payment_method_id: None,
+            merged_into_customer_id: None,
             updated_by: None,
             version: hyperswitch_domain_models::consts::API_VERSION,
         })
@@ -1400,3 +1406,382 @@ pub async fn migrate_customers(
     }
     Ok(services::ApplicationResponse::Json(()))
 }
+
+/// Moves the payment methods, mandates and payments of the source customer to the target customer
+/// and marks the source customer as merged into the target customer. Merging a customer which has
+/// already been merged into the same target customer is a no-op.
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+#[instrument(skip(state))]
+pub async fn merge_customers(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerMergeRequest,
+) -> errors::CustomerResponse<customers::CustomerMergeResponse> {
+    if req.source_customer_id == req.target_customer_id {
+        return Err(report!(
+            errors::CustomersErrorResponse::InvalidRequestData {
+                message: "`source_customer_id` and `target_customer_id` must be different"
+                    .to_string(),
+            }
+        ));
+    }
+
+    // The records of the customers are moved directly in the database
+    if merchant_account.storage_scheme == common_enums::MerchantStorageScheme::RedisKv {
+        return Err(report!(
+            errors::CustomersErrorResponse::InvalidRequestData {
+                message:
+                    "Merging customers is not supported for the storage scheme of the merchant"
+                        .to_string(),
+            }
+        ));
+    }
+
+    let lock_actions =
+        get_customer_merge_lock_actions(&req.source_customer_id, &req.target_customer_id);
+    let mut held_lock_actions = Vec::new();
+    let mut locking_result = Ok(());
+    for lock_action in lock_actions {
+        locking_result = lock_action
+            .clone()
+            .perform_locking_action(&state, merchant_account.get_id().clone())
+            .await;
+        if locking_result.is_err() {
+            break;
+        }
+        held_lock_actions.push(lock_action);
+    }
+
+    let merge_result = match locking_result {
+        Ok(()) => {
+            merge_source_customer_into_target(&state, &merchant_account, &key_store, &req).await
+        }
+        Err(error) => Err(error).switch(),
+    };
+
+    for lock_action in held_lock_actions.into_iter().rev() {
+        lock_action
+            .free_lock_action(&state, merchant_account.get_id().clone())
+            .await
+            .switch()?;
+    }
+
+    merge_result.map(services::ApplicationResponse::Json)
+}
+
+/// Locks of both the customers being merged, ordered by the customer ids, so that merges of the
+/// same customers in opposite directions wait on each other instead of forming a cycle
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+fn get_customer_merge_lock_actions(
+    source_customer_id: &id_type::CustomerId,
+    target_customer_id: &id_type::CustomerId,
+) -> Vec<api_locking::LockAction> {
+    let mut customer_ids = [
+        source_customer_id.get_string_repr(),
+        target_customer_id.get_string_repr(),
+    ];
+    customer_ids.sort_unstable();
+
+    customer_ids
+        .into_iter()
+        .map(|customer_id| api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: customer_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::Customers,
+                override_lock_retries: None,
+            },
+        })
+        .collect()
+}
+
+/// Checks whether the customers can be merged, returning `true` when the source customer has
+/// already been merged into the target customer by an earlier request
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+fn is_customer_merge_completed(
+    target_customer_id: &id_type::CustomerId,
+    source_merged_into_customer_id: Option<&id_type::CustomerId>,
+    target_merged_into_customer_id: Option<&id_type::CustomerId>,
+) -> errors::CustomResult<bool, errors::CustomersErrorResponse> {
+    match source_merged_into_customer_id {
+        Some(merged_into_customer_id) if merged_into_customer_id == target_customer_id => {
+            return Ok(true);
+        }
+        Some(_) => {
+            return Err(report!(
+                errors::CustomersErrorResponse::InvalidRequestData {
+                    message: "The source customer has already been merged into another customer"
+                        .to_string(),
+                }
+            ));
+        }
+        None => (),
+    }
+
+    if target_merged_into_customer_id.is_some() {
+        return Err(report!(
+            errors::CustomersErrorResponse::InvalidRequestData {
+                message: "The target customer has been merged into another customer".to_string(),
+            }
+        ));
+    }
+
+    Ok(false)
+}
+
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+async fn merge_source_customer_into_target(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &customers::CustomerMergeRequest,
+) -> errors::CustomResult<customers::CustomerMergeResponse, errors::CustomersErrorResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+    let source_customer_id = &req.source_customer_id;
+    let target_customer_id = &req.target_customer_id;
+
+    let source_customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            source_customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .switch()?;
+
+    let target_customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            target_customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .switch()?;
+
+    // A retried merge request, the records have already been moved
+    if is_customer_merge_completed(
+        target_customer_id,
+        source_customer.merged_into_customer_id.as_ref(),
+        target_customer.merged_into_customer_id.as_ref(),
+    )? {
+        return Ok(customers::CustomerMergeResponse {
+            source_customer_id: source_customer_id.clone(),
+            target_customer_id: target_customer_id.clone(),
+            default_payment_method_id: target_customer.default_payment_method_id,
+            payment_methods_merged: 0,
+            mandates_merged: 0,
+            payments_merged: 0,
+        });
+    }
+
+    let source_payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            key_store,
+            source_customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("failed find_payment_method_by_customer_id_merchant_id_list")?,
+    };
+
+    let mut locker_references = Vec::new();
+    for payment_method in source_payment_methods {
+        if payment_method.get_payment_method_type() == Some(enums::PaymentMethod::Card) {
+            locker_references.extend(payment_method.locker_id);
+            locker_references.extend(payment_method.network_token_locker_id);
+        } else if payment_method.locker_id.is_some() {
+            return Err(report!(errors::CustomersErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Payment method `{}` of the source customer cannot be moved to another customer",
+                    payment_method.payment_method_id
+                ),
+            }));
+        }
+    }
+
+    // Cards are stored in the locker against the customer, they are stored against the target
+    // customer with the same card reference so that the payment methods can be moved as is
+    for locker_reference in &locker_references {
+        copy_card_in_locker(
+            state,
+            merchant_id,
+            source_customer_id,
+            target_customer_id,
+            locker_reference,
+        )
+        .await
+        .switch()?;
+    }
+
+    // The target customer retains its default payment method, if any
+    let target_customer_update = match (
+        &target_customer.default_payment_method_id,
+        source_customer.default_payment_method_id,
+    ) {
+        (None, Some(default_payment_method_id)) => {
+            Some(storage::CustomerUpdate::UpdateDefaultPaymentMethod {
+                default_payment_method_id: Some(Some(default_payment_method_id)),
+            })
+        }
+        _ => None,
+    };
+
+    let merge_result = db
+        .merge_customers(
+            key_manager_state,
+            merchant_id,
+            source_customer_id,
+            target_customer_id,
+            target_customer_update,
+            key_store,
+        )
+        .await
+        .switch()?
+        .ok_or(errors::CustomersErrorResponse::InvalidRequestData {
+            message: "One of the customers has been merged into another customer".to_string(),
+        })?;
+
+    for locker_reference in &locker_references {
+        if let Err(error) =
+            cards::delete_card_from_locker(state, source_customer_id, merchant_id, locker_reference)
+                .await
+        {
+            router_env::logger::error!(
+                ?error,
+                "Failed to delete the card of the merged customer from locker"
+            );
+        }
+    }
+
+    Ok(customers::CustomerMergeResponse {
+        source_customer_id: source_customer_id.clone(),
+        target_customer_id: target_customer_id.clone(),
+        default_payment_method_id: merge_result.target_customer.default_payment_method_id,
+        payment_methods_merged: merge_result.payment_methods_merged,
+        mandates_merged: merge_result.mandates_merged,
+        payments_merged: merge_result.payments_merged,
+    })
+}
+
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+async fn copy_card_in_locker(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    source_customer_id: &id_type::CustomerId,
+    target_customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> errors::RouterResult<()> {
+    let card =
+        cards::get_card_from_locker(state, source_customer_id, merchant_id, card_reference).await?;
+
+    let payload = payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+        merchant_id: merchant_id.to_owned(),
+        merchant_customer_id: target_customer_id.to_owned(),
+        requestor_card_reference: Some(card_reference.to_owned()),
+        card,
+        ttl: state.conf.locker.ttl_for_storage_in_secs,
+    });
+
+    let stored_card = cards::add_card_to_hs_locker(
+        state,
+        &payload,
+        target_customer_id,
+        api_models::enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the card against the target customer in locker")?;
+
+    if stored_card.card_reference != card_reference {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Locker returned a different card reference for the copied card");
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "v1", not(feature = "payment_methods_v2")))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn get_customer_id(customer_id: &'static str) -> id_type::CustomerId {
+        id_type::CustomerId::try_from(Cow::from(customer_id)).unwrap()
+    }
+
+    fn get_locking_keys(lock_actions: Vec<api_locking::LockAction>) -> Vec<String> {
+        lock_actions
+            .into_iter()
+            .filter_map(|lock_action| match lock_action {
+                api_locking::LockAction::Hold { input } => Some(input.unique_locking_key),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_customer_merge_locks_both_customers_in_the_same_order() {
+        let customer_a = get_customer_id("cus_a");
+        let customer_b = get_customer_id("cus_b");
+
+        let expected_locking_keys = vec!["cus_a".to_string(), "cus_b".to_string()];
+        assert_eq!(
+            get_locking_keys(get_customer_merge_lock_actions(&customer_a, &customer_b)),
+            expected_locking_keys
+        );
+        assert_eq!(
+            get_locking_keys(get_customer_merge_lock_actions(&customer_b, &customer_a)),
+            expected_locking_keys
+        );
+    }
+
+    #[test]
+    fn test_customer_merge_of_unmerged_customers() {
+        let target_customer_id = get_customer_id("cus_b");
+
+        assert!(!is_customer_merge_completed(&target_customer_id, None, None).unwrap());
+    }
+
+    #[test]
+    fn test_customer_merge_retried_after_completion() {
+        let target_customer_id = get_customer_id("cus_b");
+
+        assert!(
+            is_customer_merge_completed(&target_customer_id, Some(&target_customer_id), None)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_customer_merge_of_merged_customers_is_rejected() {
+        let target_customer_id = get_customer_id("cus_b");
+        let other_customer_id = get_customer_id("cus_c");
+
+        assert!(
+            is_customer_merge_completed(&target_customer_id, Some(&other_customer_id), None)
+                .is_err()
+        );
+        // The target customer was merged into the source customer by a concurrent merge
+        assert!(is_customer_merge_completed(
+            &target_customer_id,
+            None,
+            Some(&get_customer_id("cus_a"))
+        )
+        .is_err());
+    }
+}
@@ -5,7 +5,7 @@ use api_models::{
 };
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use strum::IntoEnumIterator;
 pub mod transformers;
 
@@ -419,6 +419,212 @@ pub async fn submit_evidence(
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
+#[cfg(feature = "v2")]
+#[instrument(skip_all)]
+pub async fn submit_evidence_bundle(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::SubmitEvidenceBundleRequest,
+) -> RouterResponse<dispute_models::SubmitEvidenceBundleResponse> {
+    todo!()
+}
+
+/// Uploads the documents of an evidence bundle and submits them along with the text fields of the
+/// evidence to the connector. The uploaded documents are deleted if any of the uploads or the
+/// submission fails, so that incomplete evidence is never submitted.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn submit_evidence_bundle(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::SubmitEvidenceBundleRequest,
+) -> RouterResponse<dispute_models::SubmitEvidenceBundleResponse> {
+    let db = &state.store;
+    let dispute_id = req.submit_evidence_request.dispute_id.clone();
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.clone(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id.clone(), &dispute)?;
+    common_utils::fp_utils::when(
+        !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
+        || {
+            metrics::EVIDENCE_SUBMISSION_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC.add(1, &[]);
+            Err(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                reason: format!(
+                "Evidence cannot be submitted because the dispute is in {} stage and has {} status",
+                dispute.dispute_stage, dispute.dispute_status
+            ),
+            })
+        },
+    )?;
+
+    // Validate all the documents before any of them are uploaded to the connector
+    for (index, evidence_file) in req.evidence_files.iter().enumerate() {
+        common_utils::fp_utils::when(
+            req.evidence_files[..index]
+                .iter()
+                .any(|other| other.evidence_type == evidence_file.evidence_type),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Multiple documents provided for evidence type `{}`",
+                        evidence_file.evidence_type
+                    ),
+                })
+            },
+        )?;
+        files::helpers::validate_file_upload(
+            &state,
+            merchant_account.clone(),
+            evidence_file.create_file_request.clone(),
+        )
+        .await?;
+    }
+
+    let mut uploaded_files = Vec::with_capacity(req.evidence_files.len());
+    let mut bundle_evidence = api::DisputeEvidence::default();
+    for evidence_file in req.evidence_files {
+        let upload_result = Box::pin(files::files_create_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            evidence_file.create_file_request,
+        ))
+        .await
+        .and_then(|create_file_response| match create_file_response {
+            services::ApplicationResponse::Json(res) => Ok(res.file_id),
+            _ => Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unexpected response received from files create core"),
+        });
+
+        match upload_result {
+            Ok(file_id) => {
+                bundle_evidence = transformers::update_dispute_evidence(
+                    bundle_evidence,
+                    evidence_file.evidence_type.clone(),
+                    file_id.clone(),
+                );
+                uploaded_files.push((evidence_file.evidence_type, file_id));
+            }
+            Err(error) => {
+                delete_uploaded_evidence_files(&state, &merchant_account, &uploaded_files).await;
+                return Err(error.attach_printable("Failed to upload evidence bundle document"));
+            }
+        }
+    }
+
+    let submit_evidence_request =
+        transformers::update_submit_evidence_request(req.submit_evidence_request, bundle_evidence);
+    let dispute_response = match Box::pin(submit_evidence(
+        state.clone(),
+        merchant_account.clone(),
+        profile_id,
+        key_store,
+        submit_evidence_request,
+    ))
+    .await
+    {
+        Ok(services::ApplicationResponse::Json(dispute_response)) => dispute_response,
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response received from submit evidence core")?,
+        Err(error) => {
+            delete_uploaded_evidence_files(&state, &merchant_account, &uploaded_files).await;
+            return Err(error);
+        }
+    };
+
+    // Record the uploaded documents against the dispute, as is done when attaching evidence
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.clone(),
+        })?;
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let updated_dispute_evidence = uploaded_files.into_iter().fold(
+        dispute_evidence,
+        |dispute_evidence, (evidence_type, file_id)| {
+            transformers::update_dispute_evidence(dispute_evidence, evidence_type, file_id)
+        },
+    );
+    let update_dispute = diesel_models::dispute::DisputeUpdate::EvidenceUpdate {
+        evidence: updated_dispute_evidence
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error while encoding dispute evidence")?
+            .into(),
+    };
+    let updated_dispute = db
+        .update_dispute(dispute, update_dispute)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.to_owned(),
+        })
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+    let dispute_evidence: api::DisputeEvidence = updated_dispute
+        .evidence
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let evidence =
+        transformers::get_dispute_evidence_vec(&state, merchant_account, dispute_evidence).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::SubmitEvidenceBundleResponse {
+            dispute: dispute_response,
+            evidence,
+        },
+    ))
+}
+
+/// Deletes the documents uploaded as part of an evidence bundle which could not be submitted.
+/// Documents uploaded to connectors which store files themselves cannot be deleted, and only their
+/// metadata is removed.
+#[cfg(feature = "v1")]
+async fn delete_uploaded_evidence_files(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    uploaded_files: &[(disputes::EvidenceType, String)],
+) {
+    for (_, file_id) in uploaded_files {
+        files::helpers::delete_file_using_file_id(state, file_id.clone(), merchant_account)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to delete evidence bundle document {file_id}"
+                )
+            })
+            .ok();
+        state
+            .store
+            .delete_file_metadata_by_merchant_id_file_id(merchant_account.get_id(), file_id)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to delete metadata of evidence bundle document {file_id}"
+                )
+            })
+            .ok();
+    }
+}
+
 pub async fn attach_evidence(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
@@ -208,6 +208,45 @@ pub fn update_dispute_evidence(
     }
 }
 
+/// Attaches the files of an evidence bundle to the submit evidence request, overriding any file
+/// IDs specified for the same evidence type
+pub fn update_submit_evidence_request(
+    submit_evidence_request: api_models::disputes::SubmitEvidenceRequest,
+    bundle_evidence: DisputeEvidence,
+) -> api_models::disputes::SubmitEvidenceRequest {
+    api_models::disputes::SubmitEvidenceRequest {
+        cancellation_policy: bundle_evidence
+            .cancellation_policy
+            .or(submit_evidence_request.cancellation_policy),
+        customer_communication: bundle_evidence
+            .customer_communication
+            .or(submit_evidence_request.customer_communication),
+        customer_signature: bundle_evidence
+            .customer_signature
+            .or(submit_evidence_request.customer_signature),
+        receipt: bundle_evidence.receipt.or(submit_evidence_request.receipt),
+        refund_policy: bundle_evidence
+            .refund_policy
+            .or(submit_evidence_request.refund_policy),
+        service_documentation: bundle_evidence
+            .service_documentation
+            .or(submit_evidence_request.service_documentation),
+        shipping_documentation: bundle_evidence
+            .shipping_documentation
+            .or(submit_evidence_request.shipping_documentation),
+        invoice_showing_distinct_transactions: bundle_evidence
+            .invoice_showing_distinct_transactions
+            .or(submit_evidence_request.invoice_showing_distinct_transactions),
+        recurring_transaction_agreement: bundle_evidence
+            .recurring_transaction_agreement
+            .or(submit_evidence_request.recurring_transaction_agreement),
+        uncategorized_file: bundle_evidence
+            .uncategorized_file
+            .or(submit_evidence_request.uncategorized_file),
+        ..submit_evidence_request
+    }
+}
+
 pub async fn get_dispute_evidence_block(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
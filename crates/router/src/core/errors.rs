@@ -218,6 +218,16 @@ impl WebhooksFlowError {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum OutgoingWebhookSignatureError {
+    #[error("Webhook signature header not found")]
+    MissingSignature,
+    #[error("Webhook signature header is malformed")]
+    MalformedSignature,
+    #[error("Webhook signature does not match the payload")]
+    SignatureMismatch,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ApplePayDecryptionError {
     #[error("Failed to base64 decode input data")]
@@ -232,6 +242,10 @@ pub enum ApplePayDecryptionError {
     KeyDeserializationFailed,
     #[error("Failed to Derive a shared secret key")]
     DerivingSharedSecretKeyFailed,
+    #[error("The payment processing certificate has expired")]
+    CertificateExpired,
+    #[error("No payment processing certificate matches the public key hash of the token")]
+    CertificateNotFound,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -1,7 +1,9 @@
 use common_utils::errors::ErrorSwitch;
 use hyperswitch_domain_models::errors::api_error_response::ApiErrorResponse;
 
-use super::{CustomersErrorResponse, StorageError};
+use super::{
+    ApplePayDecryptionError, CustomersErrorResponse, GooglePayDecryptionError, StorageError,
+};
 
 impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for CustomersErrorResponse {
     fn switch(&self) -> api_models::errors::types::ApiErrorResponse {
@@ -34,6 +36,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for CustomersError
                 "Customer with the given `customer_id` already exists",
                 None,
             )),
+            Self::InvalidRequestData { message } => {
+                AER::BadRequest(ApiError::new("IR", 6, message.to_string(), None))
+            }
         }
     }
 }
@@ -62,7 +67,65 @@ impl ErrorSwitch<CustomersErrorResponse> for ApiErrorResponse {
             Self::InternalServerError => CER::InternalServerError,
             Self::MandateActive => CER::MandateActive,
             Self::CustomerNotFound => CER::CustomerNotFound,
+            Self::InvalidRequestData { message } => CER::InvalidRequestData {
+                message: message.clone(),
+            },
             _ => CER::InternalServerError,
         }
     }
 }
+
+impl ErrorSwitch<ApiErrorResponse> for ApplePayDecryptionError {
+    fn switch(&self) -> ApiErrorResponse {
+        match self {
+            Self::CertificateExpired => ApiErrorResponse::PreconditionFailed {
+                message: "The Apple Pay payment processing certificate has expired".to_string(),
+            },
+            Self::CertificateNotFound => ApiErrorResponse::PreconditionFailed {
+                message: "The Apple Pay token was encrypted with a payment processing certificate which is not configured".to_string(),
+            },
+            Self::Base64DecodingFailed | Self::DecryptionFailed => {
+                ApiErrorResponse::InvalidWalletToken {
+                    wallet_name: "Apple Pay".to_string(),
+                }
+            }
+            Self::CertificateParsingFailed
+            | Self::MissingMerchantId
+            | Self::KeyDeserializationFailed
+            | Self::DerivingSharedSecretKeyFailed => ApiErrorResponse::InternalServerError,
+        }
+    }
+}
+
+impl ErrorSwitch<ApiErrorResponse> for GooglePayDecryptionError {
+    fn switch(&self) -> ApiErrorResponse {
+        match self {
+            Self::DecryptionFailed
+            | Self::DeserializationFailed
+            | Self::ParsingTagError
+            | Self::HmacVerificationFailed
+            | Self::InvalidSignature
+            | Self::SignatureVerificationFailed
+            | Self::SignedKeyParsingFailure
+            | Self::SignedKeyExpired
+            | Self::InvalidIntermediateSignature
+            | Self::InvalidProtocolVersion
+            | Self::DecryptedTokenExpired
+            | Self::ParsingFailed => ApiErrorResponse::InvalidWalletToken {
+                wallet_name: "Google Pay".to_string(),
+            },
+            Self::InvalidExpirationTime
+            | Self::Base64DecodingFailed
+            | Self::CertificateParsingFailed
+            | Self::KeyDeserializationFailed
+            | Self::DerivingSharedEphemeralKeyFailed
+            | Self::DerivingSharedSecretKeyFailed
+            | Self::DerivingEcKeyFailed
+            | Self::DerivingPublicKeyFailed
+            | Self::DerivingEcGroupFailed
+            | Self::BigNumAllocationFailed
+            | Self::EcdsaSignatureFailed
+            | Self::EcdsaSignatureParsingFailed => ApiErrorResponse::InternalServerError,
+        }
+    }
+}
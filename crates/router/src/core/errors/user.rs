@@ -4,9 +4,10 @@ use crate::services::ApplicationResponse;
 
 pub type UserResult<T> = CustomResult<T, UserErrors>;
 pub type UserResponse<T> = CustomResult<ApplicationResponse<T>, UserErrors>;
+pub mod role;
 pub mod sample_data;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum UserErrors {
     #[error("User InternalServerError")]
     InternalServerError,
@@ -110,6 +111,18 @@ pub enum UserErrors {
     MissingEmailConfig,
     #[error("Invalid Auth Method Operation: {0}")]
     InvalidAuthMethodOperationWithMessage(String),
+    #[error("Invitation resent too frequently")]
+    InvitationResendTooFrequent,
+    #[error("User is not part of the requestor's lineage")]
+    UserNotInLineage,
+    #[error("User role has expired")]
+    UserRoleExpired,
+    #[error("Maximum number of custom roles reached")]
+    MaxCustomRolesReached,
+    #[error("Too many role write requests")]
+    RoleWriteRateLimitExceeded { retry_after_in_secs: u64 },
+    #[error("Token does not grant access to the requested entity")]
+    EntityNotAllowedForToken,
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -285,6 +298,33 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::InvalidAuthMethodOperationWithMessage(_) => {
                 AER::BadRequest(ApiError::new(sub_code, 57, self.get_error_message(), None))
             }
+            Self::InvitationResendTooFrequent => {
+                AER::BadRequest(ApiError::new(sub_code, 58, self.get_error_message(), None))
+            }
+            Self::UserNotInLineage => AER::ForbiddenCommonResource(ApiError::new(
+                sub_code,
+                60,
+                self.get_error_message(),
+                None,
+            )),
+            Self::UserRoleExpired => {
+                AER::Unauthorized(ApiError::new(sub_code, 61, self.get_error_message(), None))
+            }
+            Self::MaxCustomRolesReached => {
+                AER::BadRequest(ApiError::new(sub_code, 62, self.get_error_message(), None))
+            }
+            Self::RoleWriteRateLimitExceeded {
+                retry_after_in_secs,
+            } => AER::TooManyRequests(
+                ApiError::new(sub_code, 63, self.get_error_message(), None),
+                Some(*retry_after_in_secs),
+            ),
+            Self::EntityNotAllowedForToken => AER::ForbiddenCommonResource(ApiError::new(
+                sub_code,
+                64,
+                self.get_error_message(),
+                None,
+            )),
         }
     }
 }
@@ -355,6 +395,23 @@ impl UserErrors {
             Self::InvalidAuthMethodOperationWithMessage(operation) => {
                 format!("Invalid Auth Method Operation: {}", operation)
             }
+            Self::InvitationResendTooFrequent => {
+                "Invitation was resent recently, please try again after a minute".to_string()
+            }
+            Self::UserNotInLineage => "User is not part of the requestor's lineage".to_string(),
+            Self::UserRoleExpired => "User role has expired".to_string(),
+            Self::MaxCustomRolesReached => {
+                "Maximum number of custom roles for the merchant reached".to_string()
+            }
+            Self::RoleWriteRateLimitExceeded {
+                retry_after_in_secs,
+            } => format!(
+                "Too many role create or update requests, please try again after {} seconds",
+                retry_after_in_secs
+            ),
+            Self::EntityNotAllowedForToken => {
+                "Token does not grant access to the requested entity".to_string()
+            }
         }
     }
 }
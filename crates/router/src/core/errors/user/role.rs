@@ -0,0 +1,134 @@
+use api_models::errors::types::{ApiError, ApiErrorResponse};
+use common_utils::errors::{CustomResult, ErrorSwitch, ErrorSwitchFrom};
+
+use super::UserErrors;
+use crate::services::ApplicationResponse;
+
+pub type RoleResult<T> = CustomResult<T, RoleError>;
+pub type RoleResponse<T> = CustomResult<ApplicationResponse<T>, RoleError>;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RoleError {
+    #[error("Role InternalServerError")]
+    InternalServerError,
+    #[error("Role not found")]
+    RoleNotFound,
+    #[error("Role name already exists")]
+    DuplicateRoleName,
+    #[error("Predefined roles cannot be modified")]
+    PredefinedRoleImmutable,
+    #[error("Permission escalation: {0}")]
+    PermissionEscalation(String),
+    #[error("Invalid role operation: {0}")]
+    InvalidRoleOperation(String),
+    #[error("Role version mismatch")]
+    RoleVersionMismatch,
+    #[error("Permission group name already exists")]
+    DuplicatePermissionGroupName,
+    #[error("Role template not found")]
+    RoleTemplateNotFound,
+    #[error("Role template name already exists")]
+    DuplicateRoleTemplateName,
+    #[error(transparent)]
+    User(UserErrors),
+}
+
+impl ErrorSwitch<ApiErrorResponse> for RoleError {
+    fn switch(&self) -> ApiErrorResponse {
+        let sub_code = "RL";
+        match self {
+            Self::InternalServerError => ApiErrorResponse::InternalServerError(ApiError::new(
+                "HE",
+                0,
+                self.get_error_message(),
+                None,
+            )),
+            Self::RoleNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                sub_code,
+                1,
+                self.get_error_message(),
+                None,
+            )),
+            Self::DuplicateRoleName => ApiErrorResponse::Conflict(ApiError::new(
+                sub_code,
+                2,
+                self.get_error_message(),
+                None,
+            )),
+            Self::PredefinedRoleImmutable => ApiErrorResponse::ForbiddenCommonResource(
+                ApiError::new(sub_code, 3, self.get_error_message(), None),
+            ),
+            Self::PermissionEscalation(_) => ApiErrorResponse::ForbiddenCommonResource(
+                ApiError::new(sub_code, 4, self.get_error_message(), None),
+            ),
+            Self::InvalidRoleOperation(_) => ApiErrorResponse::BadRequest(ApiError::new(
+                sub_code,
+                5,
+                self.get_error_message(),
+                None,
+            )),
+            Self::RoleVersionMismatch => ApiErrorResponse::Conflict(ApiError::new(
+                sub_code,
+                6,
+                self.get_error_message(),
+                None,
+            )),
+            Self::DuplicatePermissionGroupName => ApiErrorResponse::Conflict(ApiError::new(
+                sub_code,
+                7,
+                self.get_error_message(),
+                None,
+            )),
+            Self::RoleTemplateNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                sub_code,
+                8,
+                self.get_error_message(),
+                None,
+            )),
+            Self::DuplicateRoleTemplateName => ApiErrorResponse::Conflict(ApiError::new(
+                sub_code,
+                9,
+                self.get_error_message(),
+                None,
+            )),
+            Self::User(error) => error.switch(),
+        }
+    }
+}
+
+impl RoleError {
+    pub fn get_error_message(&self) -> String {
+        match self {
+            Self::InternalServerError => "Something went wrong".to_string(),
+            Self::RoleNotFound => "Role not found".to_string(),
+            Self::DuplicateRoleName => "Role name already exists".to_string(),
+            Self::PredefinedRoleImmutable => {
+                "Predefined roles cannot be updated, deleted or restored".to_string()
+            }
+            Self::PermissionEscalation(message) | Self::InvalidRoleOperation(message) => {
+                message.to_string()
+            }
+            Self::RoleVersionMismatch => {
+                "Role has been modified since it was fetched, please fetch it again and retry"
+                    .to_string()
+            }
+            Self::DuplicatePermissionGroupName => {
+                "Permission group name already exists".to_string()
+            }
+            Self::RoleTemplateNotFound => "Role template not found".to_string(),
+            Self::DuplicateRoleTemplateName => "Role template name already exists".to_string(),
+            Self::User(error) => error.get_error_message(),
+        }
+    }
+}
+
+impl ErrorSwitchFrom<UserErrors> for RoleError {
+    fn switch_from(error: &UserErrors) -> Self {
+        match error {
+            UserErrors::InternalServerError => Self::InternalServerError,
+            UserErrors::RoleNotFound => Self::RoleNotFound,
+            UserErrors::RoleNameAlreadyExists => Self::DuplicateRoleName,
+            error => Self::User(error.clone()),
+        }
+    }
+}
@@ -565,3 +565,36 @@ impl<T> StorageErrorExt<T, errors::UserErrors> for error_stack::Result<T, errors
         })
     }
 }
+
+#[cfg(feature = "olap")]
+impl<T> StorageErrorExt<T, errors::role::RoleError>
+    for error_stack::Result<T, errors::StorageError>
+{
+    #[track_caller]
+    fn to_not_found_response(
+        self,
+        not_found_response: errors::role::RoleError,
+    ) -> error_stack::Result<T, errors::role::RoleError> {
+        self.map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(not_found_response)
+            } else {
+                e.change_context(errors::role::RoleError::InternalServerError)
+            }
+        })
+    }
+
+    #[track_caller]
+    fn to_duplicate_response(
+        self,
+        duplicate_response: errors::role::RoleError,
+    ) -> error_stack::Result<T, errors::role::RoleError> {
+        self.map_err(|e| {
+            if e.current_context().is_db_unique_violation() {
+                e.change_context(duplicate_response)
+            } else {
+                e.change_context(errors::role::RoleError::InternalServerError)
+            }
+        })
+    }
+}
@@ -190,6 +190,7 @@ pub async fn call_to_locker(
             billing: None,
             connector_mandate_details: None,
             network_transaction_id: None,
+            force_new_entry: false,
         };
 
         let add_card_result = cards::add_card_hs(
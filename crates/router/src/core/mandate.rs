@@ -78,88 +78,95 @@ pub async fn revoke_mandate(
         common_enums::MandateStatus::Active
         | common_enums::MandateStatus::Inactive
         | common_enums::MandateStatus::Pending => {
-            let profile_id = helpers::get_profile_id_for_mandate(
-                &state,
-                &merchant_account,
-                &key_store,
-                mandate.clone(),
-            )
-            .await?;
-
-            let merchant_connector_account = payment_helper::get_merchant_connector_account(
-                &state,
-                merchant_account.get_id(),
-                None,
-                &key_store,
-                &profile_id,
-                &mandate.connector.clone(),
-                mandate.merchant_connector_id.as_ref(),
-            )
-            .await?;
+            if helpers::is_mandate_revoke_supported_by_connector(&state, &mandate.connector) {
+                let profile_id = helpers::get_profile_id_for_mandate(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    mandate.clone(),
+                )
+                .await?;
 
-            let connector_data = ConnectorData::get_connector_by_name(
-                &state.conf.connectors,
-                &mandate.connector,
-                GetToken::Connector,
-                mandate.merchant_connector_id.clone(),
-            )?;
-            let connector_integration: services::BoxedMandateRevokeConnectorIntegrationInterface<
-                types::api::MandateRevoke,
-                types::MandateRevokeRequestData,
-                types::MandateRevokeResponseData,
-            > = connector_data.connector.get_connector_integration();
+                let merchant_connector_account = payment_helper::get_merchant_connector_account(
+                    &state,
+                    merchant_account.get_id(),
+                    None,
+                    &key_store,
+                    &profile_id,
+                    &mandate.connector.clone(),
+                    mandate.merchant_connector_id.as_ref(),
+                )
+                .await?;
 
-            let router_data = utils::construct_mandate_revoke_router_data(
-                &state,
-                merchant_connector_account,
-                &merchant_account,
-                mandate.clone(),
-            )
-            .await?;
+                let connector_data = ConnectorData::get_connector_by_name(
+                    &state.conf.connectors,
+                    &mandate.connector,
+                    GetToken::Connector,
+                    mandate.merchant_connector_id.clone(),
+                )?;
+                let connector_integration: services::BoxedMandateRevokeConnectorIntegrationInterface<
+                    types::api::MandateRevoke,
+                    types::MandateRevokeRequestData,
+                    types::MandateRevokeResponseData,
+                > = connector_data.connector.get_connector_integration();
 
-            let response = services::execute_connector_processing_step(
-                &state,
-                connector_integration,
-                &router_data,
-                CallConnectorAction::Trigger,
-                None,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+                let router_data = utils::construct_mandate_revoke_router_data(
+                    &state,
+                    merchant_connector_account,
+                    &merchant_account,
+                    mandate.clone(),
+                )
+                .await?;
 
-            match response.response {
-                Ok(_) => {
-                    let update_mandate = db
-                        .update_mandate_by_merchant_id_mandate_id(
-                            merchant_account.get_id(),
-                            &req.mandate_id,
-                            storage::MandateUpdate::StatusUpdate {
-                                mandate_status: storage::enums::MandateStatus::Revoked,
-                            },
-                            mandate,
-                            merchant_account.storage_scheme,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
-                    Ok(services::ApplicationResponse::Json(
-                        mandates::MandateRevokedResponse {
-                            mandate_id: update_mandate.mandate_id,
-                            status: update_mandate.mandate_status,
-                            error_code: None,
-                            error_message: None,
-                        },
-                    ))
-                }
+                let response = services::execute_connector_processing_step(
+                    &state,
+                    connector_integration,
+                    &router_data,
+                    CallConnectorAction::Trigger,
+                    None,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
-                Err(err) => Err(errors::ApiErrorResponse::ExternalConnectorError {
-                    code: err.code,
-                    message: err.message,
-                    connector: mandate.connector,
-                    status_code: err.status_code,
-                    reason: err.reason,
+                if let Err(err) = response.response {
+                    return Err(errors::ApiErrorResponse::ExternalConnectorError {
+                        code: err.code,
+                        message: err.message,
+                        connector: mandate.connector,
+                        status_code: err.status_code,
+                        reason: err.reason,
+                    }
+                    .into());
                 }
-                .into()),
+            } else {
+                // The mandate is only revoked locally, which prevents it from being used for
+                // any further payments
+                logger::info!(
+                    "Connector {} does not support revoking mandates, revoking mandate locally",
+                    mandate.connector
+                );
             }
+
+            let update_mandate = db
+                .update_mandate_by_merchant_id_mandate_id(
+                    merchant_account.get_id(),
+                    &req.mandate_id,
+                    storage::MandateUpdate::StatusUpdate {
+                        mandate_status: storage::enums::MandateStatus::Revoked,
+                    },
+                    mandate,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+            Ok(services::ApplicationResponse::Json(
+                mandates::MandateRevokedResponse {
+                    mandate_id: update_mandate.mandate_id,
+                    status: update_mandate.mandate_status,
+                    error_code: None,
+                    error_message: None,
+                },
+            ))
         }
         common_enums::MandateStatus::Revoked => {
             Err(errors::ApiErrorResponse::MandateValidationFailed {
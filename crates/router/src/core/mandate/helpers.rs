@@ -11,6 +11,21 @@ use crate::{
     types::{api, domain},
 };
 
+/// Checks whether the connector supports revoking a mandate, mandates of other connectors are
+/// only revoked locally
+pub fn is_mandate_revoke_supported_by_connector(state: &SessionState, connector: &str) -> bool {
+    connector
+        .parse::<api_models::enums::Connector>()
+        .map(|connector| {
+            state
+                .conf
+                .mandate_revoke_supported_connectors
+                .connector_list
+                .contains(&connector)
+        })
+        .unwrap_or(false)
+}
+
 #[cfg(feature = "v1")]
 pub async fn get_profile_id_for_mandate(
     state: &SessionState,
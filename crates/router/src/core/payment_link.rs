@@ -1,9 +1,14 @@
 pub mod validator;
+#[cfg(feature = "v1")]
+use std::collections::HashSet;
+
 use actix_web::http::header;
 use api_models::{
     admin::PaymentLinkConfig,
     payments::{PaymentLinkData, PaymentLinkStatusWrap},
 };
+#[cfg(feature = "v1")]
+use common_utils::crypto::{HmacSha256, SignMessage, VerifySignature};
 use common_utils::{
     consts::{DEFAULT_LOCALE, DEFAULT_SESSION_EXPIRY},
     ext_traits::{OptionExt, ValueExt},
@@ -13,6 +18,8 @@ use error_stack::{report, ResultExt};
 use futures::future;
 use hyperswitch_domain_models::api::{GenericLinks, GenericLinksData};
 use masking::{PeekInterface, Secret};
+#[cfg(feature = "v1")]
+use redis_interface::{errors::RedisError, DelReply};
 use router_env::logger;
 use time::PrimitiveDateTime;
 
@@ -20,6 +27,8 @@ use super::{
     errors::{self, RouterResult, StorageErrorExt},
     payments::helpers,
 };
+#[cfg(feature = "v1")]
+use crate::utils;
 use crate::{
     consts::{
         self, DEFAULT_ALLOWED_DOMAINS, DEFAULT_BACKGROUND_COLOR, DEFAULT_DISPLAY_SDK_ONLY,
@@ -199,6 +208,7 @@ pub async fn form_payment_link_data(
             storage_enums::IntentStatus::RequiresMerchantAction,
             storage_enums::IntentStatus::Succeeded,
             storage_enums::IntentStatus::PartiallyCaptured,
+            storage_enums::IntentStatus::Expired,
         ],
     );
     if is_terminal_state || payment_link_status == api_models::payments::PaymentLinkStatus::Expired
@@ -906,3 +916,305 @@ pub async fn get_payment_link_status(
         services::api::PaymentLinkAction::PaymentLinkStatus(payment_link_status_data),
     )))
 }
+
+/// Details of the payment a signed payment link is tied to, stored until the link is opened
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct SignedPaymentLinkData {
+    merchant_id: common_utils::id_type::MerchantId,
+    payment_id: common_utils::id_type::PaymentId,
+}
+
+#[cfg(feature = "v1")]
+fn get_signed_payment_link_key(link_token: &str) -> String {
+    format!("signed_payment_link_{link_token}")
+}
+
+#[cfg(feature = "v1")]
+fn get_signed_payment_link_message(link_token: &str, expires_at: i64) -> String {
+    format!("{link_token}.{expires_at}")
+}
+
+/// The signing secret of the signed payment links is the payment response hash key of the profile
+#[cfg(feature = "v1")]
+fn get_signed_payment_link_secret(business_profile: &domain::Profile) -> RouterResult<&str> {
+    business_profile
+        .payment_response_hash_key
+        .as_deref()
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message:
+                "payment_response_hash_key is required in the profile for signing payment links"
+                    .to_string(),
+        }))
+}
+
+#[cfg(feature = "v1")]
+async fn get_signed_payment_link_ttl(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<i64> {
+    let ttl_config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_signed_payment_link_ttl_key(),
+            Some(consts::DEFAULT_SIGNED_PAYMENT_LINK_TTL.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the signed payment link ttl of the merchant")?;
+
+    ttl_config
+        .config
+        .trim()
+        .parse::<i64>()
+        .ok()
+        .filter(|ttl| *ttl > 0)
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Invalid signed payment link ttl configured for the merchant")
+}
+
+#[cfg(feature = "v1")]
+pub async fn create_signed_payment_link_for_payment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: common_utils::id_type::PaymentId,
+) -> RouterResponse<api_models::payments::SignedPaymentLinkResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &payment_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    helpers::validate_payment_status_against_allowed_statuses(
+        payment_intent.status,
+        &[
+            storage_enums::IntentStatus::RequiresPaymentMethod,
+            storage_enums::IntentStatus::RequiresConfirmation,
+        ],
+        "create a payment link for",
+    )?;
+
+    let payment_link_id = payment_intent.payment_link_id.clone().ok_or(report!(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Payment link is not enabled for the payment".to_string(),
+        }
+    ))?;
+
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(&payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let profile_id = payment_link
+        .profile_id
+        .clone()
+        .or(payment_intent.profile_id.clone())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id missing in payment link and payment intent")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let secret = get_signed_payment_link_secret(&business_profile)?;
+    let ttl = get_signed_payment_link_ttl(&state, merchant_id).await?;
+
+    // The link cannot outlive the session of the payment
+    let current_time = common_utils::date_time::now();
+    let session_expiry = payment_link.fulfilment_time.unwrap_or_else(|| {
+        payment_intent
+            .created_at
+            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
+    });
+    let expires_at = current_time
+        .saturating_add(time::Duration::seconds(ttl))
+        .min(session_expiry);
+    let ttl = (expires_at - current_time).whole_seconds();
+
+    utils::when(ttl <= 0, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The session of the payment has expired".to_string(),
+        }))
+    })?;
+
+    let link_token = common_utils::generate_id(consts::ID_LENGTH, "spl");
+    let expires_at_timestamp = expires_at.assume_utc().unix_timestamp();
+
+    let redis_conn = db
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_signed_payment_link_key(&link_token).as_str().into(),
+            SignedPaymentLinkData {
+                merchant_id: merchant_id.clone(),
+                payment_id: payment_id.clone(),
+            },
+            ttl,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the signed payment link in redis")?;
+
+    let signature = HmacSha256
+        .sign_message(
+            secret.as_bytes(),
+            get_signed_payment_link_message(&link_token, expires_at_timestamp).as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sign the payment link")?;
+
+    let link = format!(
+        "{}/payment_link/signed/{link_token}?expires_at={expires_at_timestamp}&signature={}",
+        state.base_url,
+        hex::encode(signature),
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::SignedPaymentLinkResponse {
+            payment_id,
+            link,
+            expires_at,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+fn get_expired_signed_payment_link_response(
+    state: &SessionState,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
+        GenericLinks {
+            allowed_domains: HashSet::new(),
+            data: GenericLinksData::ExpiredLink(services::GenericExpiredLinkData {
+                title: "Payment Link Expired".to_string(),
+                message: "This payment link has expired or has already been used.".to_string(),
+                theme: DEFAULT_BACKGROUND_COLOR.to_string(),
+            }),
+            locale: state.locale.clone(),
+        },
+    )))
+}
+
+/// Opens a signed payment link. The link can be opened only once, after which it renders the
+/// same terminal page as an expired link.
+#[cfg(feature = "v1")]
+pub async fn initiate_signed_payment_link_flow(
+    state: SessionState,
+    link_token: String,
+    query: api_models::payments::SignedPaymentLinkQuery,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    if common_utils::date_time::now_unix_timestamp() > query.expires_at {
+        return get_expired_signed_payment_link_response(&state);
+    }
+
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let redis_conn = db
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let signed_payment_link_key = get_signed_payment_link_key(&link_token);
+
+    let link_data = match redis_conn
+        .get_and_deserialize_key::<SignedPaymentLinkData>(
+            &signed_payment_link_key.as_str().into(),
+            "SignedPaymentLinkData",
+        )
+        .await
+    {
+        Ok(link_data) => link_data,
+        Err(error) if matches!(error.current_context(), RedisError::NotFound) => {
+            return get_expired_signed_payment_link_response(&state);
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the signed payment link from redis"))
+        }
+    };
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &link_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &link_data.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &link_data.payment_id,
+            &link_data.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let profile_id = payment_intent
+        .profile_id
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id missing in payment intent")?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let signature = hex::decode(&query.signature)
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Invalid signature of the payment link")?;
+    let is_signature_valid = HmacSha256
+        .verify_signature(
+            get_signed_payment_link_secret(&business_profile)?.as_bytes(),
+            &signature,
+            get_signed_payment_link_message(&link_token, query.expires_at).as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to verify the signature of the payment link")?;
+    utils::when(!is_signature_valid, || {
+        Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Signature of the payment link does not match")
+    })?;
+
+    // The link is consumed on being opened, a concurrent request which could not delete the key
+    // is treated as a link that has already been used
+    let delete_reply = redis_conn
+        .delete_key(&signed_payment_link_key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to consume the signed payment link")?;
+    if matches!(delete_reply, DelReply::KeyNotDeleted) {
+        return get_expired_signed_payment_link_response(&state);
+    }
+
+    initiate_payment_link_flow(
+        state,
+        merchant_account,
+        key_store,
+        link_data.merchant_id,
+        link_data.payment_id,
+    )
+    .await
+}
@@ -748,6 +748,7 @@ pub(crate) fn get_payment_method_create_request(
                     .and_then(|pm_session| pm_session.psp_tokenization.clone()),
                 network_tokenization: payment_method_session
                     .and_then(|pm_session| pm_session.network_tokenization.clone()),
+                force_new_entry: false,
             };
             Ok(payment_method_request)
         }
@@ -808,6 +809,7 @@ pub(crate) async fn get_payment_method_create_request(
                         billing: payment_method_billing_address.cloned().map(From::from),
                         connector_mandate_details: None,
                         network_transaction_id: None,
+                        force_new_entry: false,
                     };
                     Ok(payment_method_request)
                 }
@@ -830,6 +832,7 @@ pub(crate) async fn get_payment_method_create_request(
                         billing: None,
                         connector_mandate_details: None,
                         network_transaction_id: None,
+                        force_new_entry: false,
                     };
 
                     Ok(payment_method_request)
@@ -915,6 +918,25 @@ pub async fn create_payment_method_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to parse Payment method billing address")?;
 
+    let payment_method_data =
+        pm_types::PaymentMethodVaultingData::from(req.payment_method_data.clone());
+
+    let payment_method_data =
+        populate_bin_details_for_payment_method(state, &payment_method_data).await;
+
+    if !req.force_new_entry {
+        if let Some(existing_payment_method) =
+            find_duplicate_payment_method(state, &payment_method_data, key_store, &customer_id)
+                .await?
+        {
+            logger::info!(
+                "Payment method already saved for the customer, returning payment method: {}",
+                existing_payment_method.id.get_string_repr()
+            );
+            return pm_transforms::generate_payment_method_response(&existing_payment_method);
+        }
+    }
+
     let payment_method_id =
         id_type::GlobalPaymentMethodId::generate(&state.conf.cell_information.id)
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -933,12 +955,6 @@ pub async fn create_payment_method_core(
     .await
     .attach_printable("Failed to add Payment method to DB")?;
 
-    let payment_method_data =
-        pm_types::PaymentMethodVaultingData::from(req.payment_method_data.clone());
-
-    let payment_method_data =
-        populate_bin_details_for_payment_method(state, &payment_method_data).await;
-
     let vaulting_result = vault_payment_method(
         state,
         &payment_method_data,
@@ -946,6 +962,7 @@ pub async fn create_payment_method_core(
         key_store,
         None,
         &customer_id,
+        req.force_new_entry,
     )
     .await;
 
@@ -1616,6 +1633,7 @@ pub async fn vault_payment_method(
     key_store: &domain::MerchantKeyStore,
     existing_vault_id: Option<domain::VaultId>,
     customer_id: &id_type::GlobalCustomerId,
+    allow_duplicate: bool,
 ) -> RouterResult<(pm_types::AddVaultResponse, String)> {
     let db = &*state.store;
 
@@ -1628,16 +1646,18 @@ pub async fn vault_payment_method(
 
     // throw back error if payment method is duplicated
     when(
-        db.find_payment_method_by_fingerprint_id(
-            &(state.into()),
-            key_store,
-            &fingerprint_id_from_vault,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to find payment method by fingerprint_id")
-        .inspect_err(|e| logger::error!("Vault Fingerprint_id error: {:?}", e))
-        .is_ok(),
+        !allow_duplicate
+            && db
+                .find_payment_method_by_fingerprint_id(
+                    &(state.into()),
+                    key_store,
+                    &fingerprint_id_from_vault,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find payment method by fingerprint_id")
+                .inspect_err(|e| logger::error!("Vault Fingerprint_id error: {:?}", e))
+                .is_ok(),
         || {
             Err(report!(errors::ApiErrorResponse::DuplicatePaymentMethod)
                 .attach_printable("Cannot vault duplicate payment method"))
@@ -1653,6 +1673,39 @@ pub async fn vault_payment_method(
     Ok((resp_from_vault, fingerprint_id_from_vault))
 }
 
+/// Returns the active payment method of the customer which has the same vault fingerprint as the
+/// given payment method data. The fingerprint is generated by the vault, so the raw card number is
+/// never used for the lookup.
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+#[instrument(skip_all)]
+async fn find_duplicate_payment_method(
+    state: &SessionState,
+    pmd: &pm_types::PaymentMethodVaultingData,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::GlobalCustomerId,
+) -> RouterResult<Option<domain::PaymentMethod>> {
+    let fingerprint_id =
+        vault::get_fingerprint_id_from_vault(state, pmd, customer_id.get_string_repr().to_owned())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get fingerprint_id from vault")?;
+
+    match state
+        .store
+        .find_payment_method_by_fingerprint_id(&(state.into()), key_store, &fingerprint_id)
+        .await
+    {
+        Ok(payment_method) => Ok(Some(payment_method).filter(|payment_method| {
+            payment_method.customer_id == *customer_id
+                && payment_method.status == enums::PaymentMethodStatus::Active
+        })),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment method by fingerprint_id")),
+    }
+}
+
 // TODO: check if this function will be used for listing the customer payment methods for payments
 #[allow(unused)]
 #[cfg(all(
@@ -1882,6 +1935,7 @@ pub async fn update_payment_method_core(
                     // will have to refactor this to generate new one on each vaulting later on
                     current_vault_id,
                     &payment_method.customer_id,
+                    false,
                 )
                 .await
                 .attach_printable("Failed to add payment method in vault")?,
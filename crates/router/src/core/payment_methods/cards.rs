@@ -208,6 +208,7 @@ pub async fn create_payment_method(
                 network_token_requestor_reference_id,
                 network_token_locker_id,
                 network_token_payment_method_data,
+                verified_at: None,
             },
             storage_scheme,
         )
@@ -831,6 +832,7 @@ pub async fn skip_locker_call_and_migrate_payment_method(
                 network_token_requestor_reference_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                verified_at: None,
             },
             merchant_account.storage_scheme,
         )
@@ -1537,7 +1539,10 @@ pub async fn add_payment_method(
 
     let (mut resp, duplication_check) = response?;
 
-    match duplication_check {
+    // The locker deduplicates the cards of a customer by their fingerprint, a card which is already
+    // saved gets a new payment method pointing to the same card in the locker when a new entry is
+    // forced
+    match duplication_check.filter(|_| !req.force_new_entry) {
         Some(duplication_check) => match duplication_check {
             payment_methods::DataDuplicationCheck::Duplicated => {
                 let existing_pm = get_or_insert_payment_method(
@@ -2181,6 +2186,7 @@ pub async fn update_customer_payment_method(
                 billing: None,
                 connector_mandate_details: None,
                 network_transaction_id: None,
+                force_new_entry: false,
             };
             new_pm.validate()?;
 
@@ -3467,6 +3473,18 @@ pub async fn list_payment_methods(
             .await?;
         }
     }
+
+    // Payments carry the restriction of the profile from the time they were created, which is
+    // already applied while filtering. The restriction of the profile is applied otherwise.
+    if let Some(business_profile) = business_profile
+        .as_ref()
+        .filter(|_| payment_intent.is_none())
+    {
+        response.retain(|intermediate| {
+            business_profile.is_payment_method_type_allowed(intermediate.payment_method_type)
+        });
+    }
+
     logger::info!(
         "The Payment Methods available after Constraint Graph filtering are {:?}",
         response
@@ -5046,6 +5064,9 @@ pub async fn list_customer_payment_method(
         }
     }
 
+    // The default payment method of the customer is listed first, retaining the order of the rest
+    customer_pms.sort_by_key(|pm| !pm.default_payment_method_set);
+
     let mut response = api::CustomerPaymentMethodsListResponse {
         customer_payment_methods: customer_pms,
         is_guest_customer: payment_intent.as_ref().map(|_| false), //to return this key only when the request is tied to a payment intent
@@ -5560,6 +5581,15 @@ pub async fn set_default_payment_method(
         },
     )?;
 
+    utils::when(
+        payment_method.status != enums::PaymentMethodStatus::Active,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only an active payment method can be set as default".to_string(),
+            })
+        },
+    )?;
+
     utils::when(
         Some(payment_method_id.clone()) == customer.default_payment_method_id,
         || {
@@ -5600,6 +5630,89 @@ pub async fn set_default_payment_method(
     Ok(services::ApplicationResponse::Json(resp))
 }
 
+/// Creates a payment token for the default payment method of the customer, so that it can be used
+/// for a payment in place of a token provided in the request
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+pub async fn create_payment_token_for_default_payment_method(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> errors::RouterResult<(String, domain::PaymentMethod)> {
+    let db = &*state.store;
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &state.into(),
+            customer_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let default_payment_method_id =
+        customer
+            .default_payment_method_id
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "No default payment method is set for the customer".to_string(),
+            })?;
+
+    let payment_method = db
+        .find_payment_method(
+            &state.into(),
+            key_store,
+            &default_payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        payment_method.status != enums::PaymentMethodStatus::Active,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The default payment method of the customer is not active".to_string(),
+            })
+        },
+    )?;
+
+    let payment_method_type = payment_method
+        .get_payment_method_type()
+        .get_required_value("payment_method")?;
+
+    let payment_token = generate_id(consts::ID_LENGTH, "token");
+
+    let hyperswitch_token_data = get_pm_list_context(
+        state,
+        &payment_method_type,
+        key_store,
+        &payment_method,
+        Some(payment_token.clone()),
+        true,
+    )
+    .await?
+    .and_then(|pm_list_context| pm_list_context.hyperswitch_token_data)
+    .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+        message: "The default payment method of the customer cannot be used for payments"
+            .to_string(),
+    })?;
+
+    ParentPaymentMethodToken::create_key_for_token((&payment_token, payment_method_type))
+        .insert(
+            consts::DEFAULT_INTENT_FULFILLMENT_TIME,
+            hyperswitch_token_data,
+            state,
+        )
+        .await?;
+
+    Ok((payment_token, payment_method))
+}
+
 pub async fn update_last_used_at(
     payment_method: &domain::PaymentMethod,
     state: &routes::SessionState,
@@ -5833,6 +5946,84 @@ pub async fn retrieve_payment_method(
     ))
 }
 
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all)]
+pub async fn list_payment_method_contexts(
+    state: routes::SessionState,
+    pm: api::PaymentMethodId,
+    key_store: domain::MerchantKeyStore,
+    merchant_account: domain::MerchantAccount,
+) -> errors::RouterResponse<api::PaymentMethodContextsResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let pm = db
+        .find_payment_method(
+            key_manager_state,
+            &key_store,
+            &pm.payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let connector_mandate_details = pm
+        .get_common_mandate_reference()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the common mandate reference")?
+        .payments;
+
+    let enabled_mcas = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_account.get_id(),
+            false,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let contexts = connector_mandate_details
+        .map(|connector_mandate_details| {
+            enabled_mcas
+                .into_iter()
+                .filter_map(|mca| {
+                    let mandate_reference_record = connector_mandate_details.get(&mca.get_id())?;
+                    // Mandates which were revoked with the connector can not be reused
+                    if mandate_reference_record.connector_mandate_status
+                        == Some(common_enums::ConnectorMandateStatus::Inactive)
+                    {
+                        return None;
+                    }
+                    let connector = api_enums::Connector::from_str(&mca.connector_name)
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to parse connector name");
+                        })
+                        .ok()?;
+
+                    Some(api::PaymentMethodContext {
+                        merchant_connector_id: mca.get_id(),
+                        connector,
+                        currency: mandate_reference_record.original_payment_authorized_currency,
+                        is_currency_bound: helpers::is_connector_mandate_currency_bound(
+                            &state, connector,
+                        ),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentMethodContextsResponse {
+            payment_method_id: pm.payment_method_id,
+            contexts,
+        },
+    ))
+}
+
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 #[instrument(skip_all)]
 pub async fn delete_payment_method(
@@ -5866,13 +6057,48 @@ pub async fn delete_payment_method(
         .attach_printable("Customer not found for the payment method")?;
 
     if key.get_payment_method_type() == Some(enums::PaymentMethod::Card) {
-        let response = delete_card_from_locker(
-            &state,
-            &key.customer_id,
-            &key.merchant_id,
-            key.locker_id.as_ref().unwrap_or(&key.payment_method_id),
-        )
-        .await?;
+        let locker_id = key.locker_id.as_ref().unwrap_or(&key.payment_method_id);
+        // Payment methods saved as a new entry share the card in the locker with the payment
+        // method saved earlier, so the card is deleted from the locker along with the last of them
+        let is_card_shared = db
+            .find_payment_method_by_customer_id_merchant_id_list(
+                key_manager_state,
+                &key_store,
+                &key.customer_id,
+                &key.merchant_id,
+                None,
+            )
+            .await
+            .or_else(|error| {
+                if error.current_context().is_db_not_found() {
+                    Ok(Vec::new())
+                } else {
+                    Err(error)
+                }
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment methods of the customer")?
+            .iter()
+            .any(|payment_method| {
+                payment_method.payment_method_id != key.payment_method_id
+                    && payment_method
+                        .locker_id
+                        .as_ref()
+                        .unwrap_or(&payment_method.payment_method_id)
+                        == locker_id
+            });
+
+        let response = if is_card_shared {
+            logger::info!(
+                "Card in the locker is shared with another payment method, skipping its deletion"
+            );
+            None
+        } else {
+            Some(
+                delete_card_from_locker(&state, &key.customer_id, &key.merchant_id, locker_id)
+                    .await?,
+            )
+        };
 
         if let Some(network_token_ref_id) = key.network_token_requestor_reference_id {
             let resp = network_tokenization::delete_network_token_from_locker_and_token_service(
@@ -5892,11 +6118,15 @@ pub async fn delete_payment_method(
             }
         }
 
-        if response.status == "Ok" {
-            logger::info!("Card From locker deleted Successfully!");
-        } else {
-            logger::error!("Error: Deleting Card From Locker!\n{:#?}", response);
-            Err(errors::ApiErrorResponse::InternalServerError)?
+        match response {
+            Some(response) if response.status == "Ok" => {
+                logger::info!("Card From locker deleted Successfully!");
+            }
+            Some(response) => {
+                logger::error!("Error: Deleting Card From Locker!\n{:#?}", response);
+                Err(errors::ApiErrorResponse::InternalServerError)?
+            }
+            None => (),
         }
     }
 
@@ -5910,8 +6140,33 @@ pub async fn delete_payment_method(
     .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
     if customer.default_payment_method_id.as_ref() == Some(&pm_id.payment_method_id) {
+        // The most recently used active payment method of the customer is promoted as the default
+        let next_default_payment_method_id = db
+            .find_payment_method_by_customer_id_merchant_id_status(
+                key_manager_state,
+                &key_store,
+                &key.customer_id,
+                merchant_account.get_id(),
+                enums::PaymentMethodStatus::Active,
+                Some(1),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .or_else(|error| {
+                if error.current_context().is_db_not_found() {
+                    Ok(Vec::new())
+                } else {
+                    Err(error)
+                }
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment methods of the customer")?
+            .into_iter()
+            .next()
+            .map(|payment_method| payment_method.payment_method_id);
+
         let customer_update = CustomerUpdate::UpdateDefaultPaymentMethod {
-            default_payment_method_id: Some(None),
+            default_payment_method_id: Some(next_default_payment_method_id),
         };
         db.update_customer_by_customer_id_merchant_id(
             key_manager_state,
@@ -641,6 +641,248 @@ pub async fn do_status_check_for_network_token(
     }
 }
 
+/// Checks whether the stored expiry of the network token is yet to pass
+#[cfg(feature = "v1")]
+fn has_valid_stored_expiry(
+    network_token_details: Option<api_payment_methods::PaymentMethodsData>,
+) -> bool {
+    network_token_details
+        .and_then(|pmd| match pmd {
+            api_payment_methods::PaymentMethodsData::Card(token) => {
+                token.expiry_month.zip(token.expiry_year)
+            }
+            _ => None,
+        })
+        .and_then(|(exp_month, exp_year)| helpers::validate_card_expiry(&exp_month, &exp_year).ok())
+        .is_some()
+}
+
+/// Checks whether the tokenization service reports the network token inactive. The token is
+/// assumed to be usable when its status is unknown, so that an outage of the tokenization service
+/// does not lead to new network tokens being provisioned for every payment.
+#[cfg(feature = "v1")]
+fn is_network_token_inactive(
+    token_status: Option<
+        CustomResult<
+            (Option<Secret<String>>, Option<Secret<String>>),
+            errors::NetworkTokenizationError,
+        >,
+    >,
+) -> bool {
+    match token_status {
+        Some(Ok((token_exp_month, token_exp_year))) => {
+            token_exp_month.is_none() || token_exp_year.is_none()
+        }
+        Some(Err(error)) => {
+            logger::error!(?error, "Error while checking network token status");
+            false
+        }
+        None => false,
+    }
+}
+
+/// Checks whether the network token of the payment method can no longer be used. A token past its
+/// stored expiry is considered expired only if the tokenization service reports it inactive.
+#[cfg(feature = "v1")]
+pub async fn is_network_token_expired(
+    state: &routes::SessionState,
+    payment_method_info: &domain::PaymentMethod,
+) -> bool {
+    let network_token_details = payment_method_info
+        .network_token_payment_method_data
+        .clone()
+        .map(|x| x.into_inner().expose())
+        .and_then(|v| serde_json::from_value::<api_payment_methods::PaymentMethodsData>(v).ok());
+
+    if has_valid_stored_expiry(network_token_details) {
+        return false;
+    }
+
+    let token_status = match (
+        payment_method_info
+            .network_token_requestor_reference_id
+            .clone(),
+        &state.conf.network_tokenization_service,
+    ) {
+        (Some(ref_id), Some(network_tokenization_service)) => Some(
+            check_token_status_with_tokenization_service(
+                state,
+                &payment_method_info.customer_id,
+                ref_id,
+                network_tokenization_service.get_inner(),
+            )
+            .await,
+        ),
+        _ => None,
+    };
+
+    is_network_token_inactive(token_status)
+}
+
+/// Provisions a new network token for the card of the payment method and stores it against the
+/// payment method, replacing the network token which is no longer usable. The old network token
+/// is deleted once the new one is stored.
+#[cfg(feature = "v1")]
+pub async fn reprovision_network_token(
+    state: &routes::SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: common_enums::MerchantStorageScheme,
+    payment_method_info: domain::PaymentMethod,
+    card: &domain::Card,
+) -> errors::RouterResult<domain::PaymentMethod> {
+    let (token_response, network_token_requestor_ref_id) = make_card_network_tokenization_request(
+        state,
+        &domain::CardDetail::from(card),
+        None,
+        &payment_method_info.customer_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to provision a new network token")?;
+
+    let network_token_requestor_ref_id = network_token_requestor_ref_id
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing card reference in the network token response")?;
+
+    let store_network_token_request = payment_methods::transformers::StoreLockerReq::LockerCard(
+        payment_methods::transformers::StoreCardReq {
+            merchant_id: payment_method_info.merchant_id.clone(),
+            merchant_customer_id: payment_method_info.customer_id.clone(),
+            requestor_card_reference: None,
+            card: api_payment_methods::Card {
+                card_number: token_response.token,
+                name_on_card: None,
+                card_exp_month: token_response.token_expiry_month.clone(),
+                card_exp_year: token_response.token_expiry_year.clone(),
+                card_brand: Some(token_response.card_brand.to_string()),
+                card_isin: None,
+                nick_name: None,
+            },
+            ttl: state.conf.locker.ttl_for_storage_in_secs,
+        },
+    );
+    let network_token_locker_id = payment_methods::cards::add_card_to_hs_locker(
+        state,
+        &store_network_token_request,
+        &payment_method_info.customer_id,
+        api::enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the new network token in the locker")?
+    .card_reference;
+
+    let network_token_details = api_payment_methods::PaymentMethodsData::Card(
+        api_payment_methods::CardDetailsPaymentMethod {
+            last4_digits: Some(token_response.token_last_four),
+            issuer_country: None,
+            expiry_month: Some(token_response.token_expiry_month),
+            expiry_year: Some(token_response.token_expiry_year),
+            nick_name: card.nick_name.clone(),
+            card_holder_name: card.card_holder_name.clone(),
+            card_isin: Some(token_response.token_isin),
+            card_issuer: None,
+            card_network: Some(token_response.card_brand),
+            card_type: None,
+            saved_to_locker: true,
+        },
+    );
+
+    let key_manager_state = &state.into();
+    let network_token_payment_method_data = payment_methods::cards::create_encrypted_data(
+        key_manager_state,
+        key_store,
+        network_token_details,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt the network token data")?;
+
+    let payment_method_update =
+        crate::types::storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+            network_token_requestor_reference_id: Some(network_token_requestor_ref_id),
+            network_token_locker_id: Some(network_token_locker_id),
+            network_token_payment_method_data: Some(network_token_payment_method_data.into()),
+        };
+
+    let payment_method_id = payment_method_info.get_id().clone();
+    let old_network_token_locker_id = payment_method_info.network_token_locker_id.clone();
+    let old_network_token_requestor_ref_id = payment_method_info
+        .network_token_requestor_reference_id
+        .clone();
+    let updated_payment_method = state
+        .store
+        .update_payment_method(
+            key_manager_state,
+            key_store,
+            payment_method_info,
+            payment_method_update,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed to store the new network token of payment method {payment_method_id}")
+        })?;
+
+    delete_old_network_token(
+        state,
+        &updated_payment_method.customer_id,
+        &updated_payment_method.merchant_id,
+        old_network_token_locker_id,
+        old_network_token_requestor_ref_id,
+    )
+    .await;
+
+    Ok(updated_payment_method)
+}
+
+/// Deletes a network token which has been replaced from the locker and the tokenization service.
+/// The payment method no longer refers to the token, so failures are only logged.
+#[cfg(feature = "v1")]
+async fn delete_old_network_token(
+    state: &routes::SessionState,
+    customer_id: &id_type::CustomerId,
+    merchant_id: &id_type::MerchantId,
+    network_token_locker_id: Option<String>,
+    network_token_requestor_reference_id: Option<String>,
+) {
+    if let Some(network_token_locker_id) = network_token_locker_id {
+        if let Err(error) = payment_methods::cards::delete_card_from_locker(
+            state,
+            customer_id,
+            merchant_id,
+            &network_token_locker_id,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to delete the old network token from the locker"
+            );
+        }
+    }
+
+    if let (Some(network_token_requestor_reference_id), Some(tokenization_service)) = (
+        network_token_requestor_reference_id,
+        &state.conf.network_tokenization_service,
+    ) {
+        if let Err(error) = delete_network_token_from_tokenization_service(
+            state,
+            network_token_requestor_reference_id,
+            customer_id,
+            tokenization_service.get_inner(),
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to delete the old network token from the tokenization service"
+            );
+        }
+    }
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -867,3 +1109,58 @@ pub async fn delete_network_token_from_locker_and_token_service(
 ) -> errors::RouterResult<DeleteCardResp> {
     todo!()
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    fn get_network_token_details(
+        expiry_month: &str,
+        expiry_year: &str,
+    ) -> api_payment_methods::PaymentMethodsData {
+        api_payment_methods::PaymentMethodsData::Card(
+            api_payment_methods::CardDetailsPaymentMethod {
+                last4_digits: Some("4242".to_string()),
+                issuer_country: None,
+                expiry_month: Some(Secret::new(expiry_month.to_string())),
+                expiry_year: Some(Secret::new(expiry_year.to_string())),
+                nick_name: None,
+                card_holder_name: None,
+                card_isin: None,
+                card_issuer: None,
+                card_network: None,
+                card_type: None,
+                saved_to_locker: true,
+            },
+        )
+    }
+
+    #[test]
+    fn test_stored_expiry_validity() {
+        assert!(has_valid_stored_expiry(Some(get_network_token_details(
+            "12", "2099"
+        ))));
+        assert!(!has_valid_stored_expiry(Some(get_network_token_details(
+            "12", "2000"
+        ))));
+        assert!(!has_valid_stored_expiry(None));
+    }
+
+    #[test]
+    fn test_network_token_is_inactive_only_when_reported_inactive() {
+        let token_expiry = (
+            Some(Secret::new("12".to_string())),
+            Some(Secret::new("2099".to_string())),
+        );
+        assert!(!is_network_token_inactive(Some(Ok(token_expiry))));
+        assert!(is_network_token_inactive(Some(Ok((None, None)))));
+    }
+
+    #[test]
+    fn test_network_token_is_not_inactive_when_status_is_unknown() {
+        assert!(!is_network_token_inactive(Some(Err(
+            errors::NetworkTokenizationError::ApiError.into()
+        ))));
+        assert!(!is_network_token_inactive(None));
+    }
+}
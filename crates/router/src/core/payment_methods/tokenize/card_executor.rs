@@ -421,6 +421,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizeCardRequest> {
             modified_at: common_utils::date_time::now(),
             address_id: None,
             default_payment_method_id: None,
+            merged_into_customer_id: None,
             updated_by: None,
             version: hyperswitch_domain_models::consts::API_VERSION,
         };
@@ -562,6 +563,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizeCardRequest> {
             billing: None,
             connector_mandate_details: None,
             network_transaction_id: None,
+            force_new_entry: false,
         };
         create_payment_method(
             self.state,
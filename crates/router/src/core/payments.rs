@@ -1,8 +1,14 @@
 pub mod access_token;
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+pub mod card_verification;
 pub mod conditional_configs;
 pub mod connector_integration_v2_impls;
 pub mod customers;
 pub mod flows;
+#[cfg(feature = "v1")]
+pub mod fraud_check_hook;
+#[cfg(feature = "v1")]
+pub mod fx_details;
 pub mod helpers;
 pub mod operations;
 
@@ -11,6 +17,10 @@ pub mod retry;
 pub mod routing;
 #[cfg(feature = "v2")]
 pub mod session_operation;
+#[cfg(feature = "v1")]
+pub mod status_transitions;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod timeline;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -32,6 +42,7 @@ use api_models::{
 };
 pub use common_enums::enums::CallConnectorAction;
 use common_utils::{
+    errors::ReportSwitchExt,
     ext_traits::{AsyncExt, StringExt},
     id_type, pii,
     types::{AmountConvertor, MinorUnit, Surcharge},
@@ -94,6 +105,7 @@ use crate::{
     configs::settings::{ApplePayPreDecryptFlow, PaymentMethodTypeTokenFilter},
     connector::utils::missing_field_err,
     core::{
+        circuit_breaker, connector_latency,
         errors::{self, CustomResult, RouterResponse, RouterResult},
         payment_methods::{cards, network_tokenization},
         payouts,
@@ -339,10 +351,12 @@ where
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
         .attach_printable("Failed while fetching/creating customer")?;
 
-    let authentication_type =
+    let (authentication_type, psd2_sca_exemption_type, three_ds_challenge_flow) =
         call_decision_manager(state, &merchant_account, &business_profile, &payment_data).await?;
 
     payment_data.set_authentication_type_in_attempt(authentication_type);
+    payment_data.set_psd2_sca_exemption_type_in_payment_intent(psd2_sca_exemption_type);
+    payment_data.set_three_ds_challenge_flow(three_ds_challenge_flow);
 
     let connector = get_connector_choice(
         &operation,
@@ -407,6 +421,19 @@ where
             should_continue_capture,
         );
 
+        // The fraud check hook is consulted only if the payment has not already been stopped by FRM
+        let fraud_check_hook_suggestion =
+            if should_continue_transaction && is_operation_confirm(&operation) {
+                fraud_check_hook::call_fraud_check_hook(state, &payment_data)
+                    .await
+                    .get_frm_suggestion()
+            } else {
+                None
+            };
+        if fraud_check_hook_suggestion.is_some() {
+            should_continue_transaction = false;
+        }
+
         let is_eligible_for_uas =
             helpers::is_merchant_eligible_authentication_service(merchant_account.get_id(), state)
                 .await?;
@@ -814,9 +841,9 @@ where
                     None,
                     &key_store,
                     #[cfg(feature = "frm")]
-                    frm_info.and_then(|info| info.suggested_action),
+                    fraud_check_hook_suggestion.or(frm_info.and_then(|info| info.suggested_action)),
                     #[cfg(not(feature = "frm"))]
-                    None,
+                    fraud_check_hook_suggestion,
                     header_payload.clone(),
                 )
                 .await?;
@@ -1256,6 +1283,9 @@ where
     Ok((payment_data, req, customer))
 }
 
+/// Evaluate the merchant's decision manager rules to get the authentication type, the PSD2 SCA
+/// exemption and the 3DS challenge flow to be requested for the payment. Values provided in the
+/// request take precedence over the rules.
 #[instrument(skip_all)]
 #[cfg(feature = "v1")]
 pub async fn call_decision_manager<F, D>(
@@ -1263,7 +1293,11 @@ pub async fn call_decision_manager<F, D>(
     merchant_account: &domain::MerchantAccount,
     _business_profile: &domain::Profile,
     payment_data: &D,
-) -> RouterResult<Option<enums::AuthenticationType>>
+) -> RouterResult<(
+    Option<enums::AuthenticationType>,
+    Option<storage_enums::ScaExemptionType>,
+    Option<common_enums::DecoupledAuthenticationType>,
+)>
 where
     F: Clone,
     D: OperationSessionGetters<F>,
@@ -1279,6 +1313,34 @@ where
         payment_data.get_recurring_details(),
         payment_data.get_currency(),
     );
+
+    let output = get_decision_manager_output(state, merchant_account, &payment_dsl_data).await?;
+    let authentication_type = payment_dsl_data
+        .payment_attempt
+        .authentication_type
+        .or(output.get_authentication_type())
+        .or(Some(storage_enums::AuthenticationType::NoThreeDs));
+    let psd2_sca_exemption_type = helpers::get_psd2_sca_exemption_type(
+        payment_dsl_data.payment_intent.psd2_sca_exemption_type,
+        output.sca_exemption,
+        payment_dsl_data.payment_intent.off_session,
+    )?;
+    Ok((
+        authentication_type,
+        psd2_sca_exemption_type,
+        output.override_3ds_challenge_flow,
+    ))
+}
+
+/// Evaluate the merchant's decision manager rules against the payment. The default config, which
+/// overrides nothing, is returned when the merchant has no rules configured or no rule matches.
+#[instrument(skip_all)]
+#[cfg(feature = "v1")]
+pub async fn get_decision_manager_output(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_dsl_data: &core_routing::PaymentsDslInput<'_>,
+) -> RouterResult<common_types::payments::ConditionalConfigs> {
     let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_account
         .routing_algorithm
         .clone()
@@ -1288,20 +1350,15 @@ where
         .attach_printable("Could not decode the routing algorithm")?
         .unwrap_or_default();
 
-    let output = perform_decision_management(
+    perform_decision_management(
         state,
         algorithm_ref,
         merchant_account.get_id(),
-        &payment_dsl_data,
+        payment_dsl_data,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Could not decode the conditional config")?;
-    Ok(payment_dsl_data
-        .payment_attempt
-        .authentication_type
-        .or(output.override_3ds)
-        .or(Some(storage_enums::AuthenticationType::NoThreeDs)))
+    .attach_printable("Could not decode the conditional config")
 }
 
 // TODO: Move to business profile surcharge column
@@ -1330,7 +1387,7 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Could not decode the conditional config")?;
 
-    Ok(output.override_3ds)
+    Ok(output.get_authentication_type())
 }
 
 #[cfg(feature = "v2")]
@@ -2560,7 +2617,8 @@ impl ValidateStatusForOperation for &PaymentRedirectSync {
             | common_enums::IntentStatus::RequiresCapture
             | common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::RequiresConfirmation
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => {
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => {
                 Err(errors::ApiErrorResponse::PaymentUnexpectedState {
                     current_flow: format!("{self:?}"),
                     field_name: "status".to_string(),
@@ -2999,6 +3057,11 @@ where
 {
     let stime_connector = Instant::now();
 
+    let is_circuit_breaker_applicable = is_operation_confirm(operation);
+    if is_circuit_breaker_applicable {
+        circuit_breaker::validate_connector_circuit(state, connector.connector_name).await?;
+    }
+
     let merchant_connector_account = construct_profile_id_and_get_mca(
         state,
         merchant_account,
@@ -3195,7 +3258,9 @@ where
         // This is added because few connector integrations do not update the status,
         // and rely on previous status set in router_data
         router_data.status = payment_data.get_payment_attempt().status;
-        router_data
+        let is_connector_called = matches!(call_connector_action, CallConnectorAction::Trigger);
+        let stime_connector_call = Instant::now();
+        let router_data = router_data
             .decide_flows(
                 state,
                 &connector,
@@ -3204,7 +3269,41 @@ where
                 business_profile,
                 header_payload.clone(),
             )
-            .await
+            .await;
+
+        // Failed calls are recorded as well, so that a connector which errors out or hangs is not
+        // ranked by the response times of its successful calls alone
+        if is_circuit_breaker_applicable && is_connector_called {
+            let is_timeout = router_data.as_ref().is_ok_and(|router_data| {
+                router_data.response.as_ref().is_err_and(|error_response| {
+                    error_response.code == crate::consts::REQUEST_TIMEOUT_ERROR_CODE
+                })
+            });
+            connector_latency::record_connector_latency(
+                state,
+                connector.connector_name,
+                connector_latency::get_recorded_latency(stime_connector_call.elapsed(), is_timeout),
+            )
+            .await;
+        }
+
+        // Requests which never reached the connector say nothing about its health
+        if is_circuit_breaker_applicable && is_connector_called {
+            let is_failure = router_data.as_ref().map_or(true, |router_data| {
+                router_data
+                    .response
+                    .as_ref()
+                    .is_err_and(|error_response| error_response.status_code >= 500)
+            });
+            circuit_breaker::record_connector_call_outcome(
+                state,
+                connector.connector_name,
+                is_failure,
+            )
+            .await;
+        }
+
+        router_data
     } else {
         Ok(router_data)
     }?;
@@ -3665,6 +3764,7 @@ where
     Ok(router_data)
 }
 
+/// Decrypts the wallet token in the router so that the decrypted card can be sent to any connector
 pub async fn add_decrypted_payment_method_token<F, D>(
     tokenization_action: TokenizationAction,
     payment_data: &D,
@@ -3685,14 +3785,13 @@ where
                     wallet_data,
                 ))) => Some(
                     ApplePayData::token_json(domain::WalletData::ApplePay(wallet_data.clone()))
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .change_context(errors::ApiErrorResponse::InvalidWalletToken {
+                            wallet_name: "Apple Pay".to_string(),
+                        })
                         .attach_printable("failed to parse apple pay token to json")?
-                        .decrypt(
-                            &payment_processing_details.payment_processing_certificate,
-                            &payment_processing_details.payment_processing_certificate_key,
-                        )
+                        .decrypt_with_matching_certificate(payment_processing_details)
                         .await
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .switch()
                         .attach_printable("failed to decrypt apple pay token")?,
                 ),
                 _ => None,
@@ -3750,14 +3849,14 @@ where
                         payment_processing_details.google_pay_recipient_id.clone(),
                         payment_processing_details.google_pay_private_key.clone(),
                     )
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .switch()
                     .attach_printable("failed to create google pay token decryptor")?;
 
                     // should_verify_token is set to false to disable verification of token
                     Some(
                         decryptor
                             .decrypt_token(wallet_data.tokenization_data.token.clone(), false)
-                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .switch()
                             .attach_printable("failed to decrypt google pay token")?,
                     )
                 }
@@ -4543,10 +4642,12 @@ where
                 router_data = router_data.preprocessing_steps(state, connector).await?;
                 (router_data, should_continue_payment)
             } else if connector.connector_name == router_types::Connector::Xendit {
+                // Generic split instructions are sent to xendit as multiple splits
                 match payment_data.get_payment_intent().split_payments {
                     Some(common_types::payments::SplitPaymentsRequest::XenditSplitPayment(
                         common_types::payments::XenditSplitRequest::MultipleSplits(_),
-                    )) => {
+                    ))
+                    | Some(common_types::payments::SplitPaymentsRequest::GenericSplitPayment(_)) => {
                         router_data = router_data.preprocessing_steps(state, connector).await?;
                         let is_error_in_response = router_data.response.is_err();
                         (router_data, !is_error_in_response)
@@ -4805,19 +4906,20 @@ fn check_apple_pay_metadata(
                     apple_pay_combined,
                 ) => match apple_pay_combined {
                     api_models::payments::ApplePayCombinedMetadata::Simplified { .. } => {
+                        let applepay_decrypt_keys = state.conf.applepay_decrypt_keys.get_inner();
                         domain::ApplePayFlow::Simplified(payments_api::PaymentProcessingDetails {
-                            payment_processing_certificate: state
-                                .conf
-                                .applepay_decrypt_keys
-                                .get_inner()
+                            payment_processing_certificate: applepay_decrypt_keys
                                 .apple_pay_ppc
                                 .clone(),
-                            payment_processing_certificate_key: state
-                                .conf
-                                .applepay_decrypt_keys
-                                .get_inner()
+                            payment_processing_certificate_key: applepay_decrypt_keys
                                 .apple_pay_ppc_key
                                 .clone(),
+                            previous_payment_processing_certificate: applepay_decrypt_keys
+                                .apple_pay_previous_ppc
+                                .clone(),
+                            previous_payment_processing_certificate_key: applepay_decrypt_keys
+                                .apple_pay_previous_ppc_key
+                                .clone(),
                         })
                     }
                     api_models::payments::ApplePayCombinedMetadata::Manual {
@@ -5329,6 +5431,12 @@ where
     pub card_testing_guard_data:
         Option<hyperswitch_domain_models::card_testing_guard_data::CardTestingGuardData>,
     pub vault_operation: Option<domain_payments::VaultOperation>,
+    /// 3DS challenge flow requested from the connector, as decided by the decision manager rules
+    pub three_ds_challenge_flow: Option<common_enums::DecoupledAuthenticationType>,
+    /// Amount of the authorization to be released in a partial void
+    pub amount_to_void: Option<MinorUnit>,
+    /// Conversion of the amount of the payment attempt to the settlement currency of the merchant
+    pub fx_details: Option<storage::PaymentFxDetails>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -5544,15 +5652,76 @@ pub async fn list_payments(
     key_store: domain::MerchantKeyStore,
     constraints: api::PaymentListConstraints,
 ) -> RouterResponse<api::PaymentListResponse> {
-    use hyperswitch_domain_models::errors::StorageError;
     helpers::validate_payment_list_request(&constraints)?;
+    list_payments_by_fetch_constraints(
+        &state,
+        &merchant,
+        &key_store,
+        &(constraints, profile_id_list).try_into()?,
+    )
+    .await
+}
+
+/// Lists the payments whose metadata holds the value against an indexed metadata key. The value is
+/// coerced to the requested type, and is matched on its text representation so that values stored
+/// as strings and as numbers are both found.
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn search_payments_by_metadata(
+    state: SessionState,
+    merchant: domain::MerchantAccount,
+    profile_id_list: Option<Vec<id_type::ProfileId>>,
+    key_store: domain::MerchantKeyStore,
+    mut req: api_models::payments::PaymentMetadataSearchRequest,
+) -> RouterResponse<api::PaymentListResponse> {
+    if !state
+        .conf
+        .payment_metadata_search
+        .indexed_keys
+        .contains(&req.key)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payments cannot be searched by the metadata key `{}` as it is not indexed",
+                req.key
+            ),
+        }
+        .into());
+    }
+
+    req.value = req.value_type.coerce(&req.value).ok_or_else(|| {
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: common_utils::metadata::MetadataError::InvalidValue {
+                key: req.key.clone(),
+                value_type: req.value_type,
+            }
+            .to_string(),
+        }
+    })?;
+
+    list_payments_by_fetch_constraints(
+        &state,
+        &merchant,
+        &key_store,
+        &(req, profile_id_list).try_into()?,
+    )
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+async fn list_payments_by_fetch_constraints(
+    state: &SessionState,
+    merchant: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    fetch_constraints: &domain_payments::payment_intent::PaymentIntentFetchConstraints,
+) -> RouterResponse<api::PaymentListResponse> {
+    use hyperswitch_domain_models::errors::StorageError;
     let merchant_id = merchant.get_id();
     let db = state.store.as_ref();
     let payment_intents = helpers::filter_by_constraints(
-        &state,
-        &(constraints, profile_id_list).try_into()?,
+        state,
+        fetch_constraints,
         merchant_id,
-        &key_store,
+        key_store,
         merchant.storage_scheme,
     )
     .await
@@ -5983,6 +6152,150 @@ pub async fn reset_process_sync_task(
     Ok(())
 }
 
+#[cfg(feature = "v1")]
+const SCHEDULED_CAPTURE_TASK: &str = "SCHEDULED_CAPTURE";
+
+#[cfg(feature = "v1")]
+fn get_scheduled_capture_process_tracker_id(payment_attempt: &storage::PaymentAttempt) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+        SCHEDULED_CAPTURE_TASK,
+        payment_attempt.get_id(),
+        &payment_attempt.merchant_id,
+    )
+}
+
+#[cfg(feature = "v1")]
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::payment_attempt::ScheduledCaptureTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.get_id().to_owned(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::ScheduledCaptureWorkflow;
+    let tag = ["CAPTURE", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_scheduled_capture_process_tracker_id(payment_attempt),
+        SCHEDULED_CAPTURE_TASK,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Finishes the scheduled capture task of the payment attempt, if any, as the payment is being
+/// captured before the scheduled time
+#[cfg(feature = "v1")]
+pub async fn revoke_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> CustomResult<(), errors::StorageError> {
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_scheduled_capture_process_tracker_id(payment_attempt)],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: storage_enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(storage::business_status::REVOKED)),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Adds the task which expires the payment if it is not confirmed by the scheduled time. The task
+/// is rescheduled if the payment already has one, as is the case for a payment which is awaiting
+/// the customer again after a failed confirmation.
+#[cfg(feature = "v1")]
+pub async fn add_payment_intent_expiry_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow;
+    let task = "PAYMENT_INTENT_EXPIRY";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        payment_intent.payment_id.get_string_repr(),
+        &payment_intent.merchant_id,
+    );
+
+    if let Some(expiry_process) = db.find_process_by_id(&process_tracker_id).await? {
+        db.as_scheduler()
+            .reset_process(expiry_process, schedule_time)
+            .await?;
+        return Ok(());
+    }
+
+    let tracking_data = storage::PaymentIntentExpiryTrackingData {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+    };
+    let tag = ["EXPIRY", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Adds the recurring task which syncs the status of stale payments, of which there is a single
+/// one for a tenant. The task is rescheduled if it was finished while the job was disabled.
+#[cfg(feature = "v1")]
+pub async fn add_stale_payment_sync_task(
+    db: &dyn StorageInterface,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::StalePaymentSyncWorkflow;
+    let task = "STALE_PAYMENT_SYNC";
+    let tag = ["SYNC", "PAYMENT"];
+    let process_tracker_id = format!("{runner}_{task}");
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        task,
+        runner,
+        tag,
+        storage::StalePaymentSyncTrackingData::default(),
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            let process = db
+                .find_process_by_id(&process_tracker_id)
+                .await?
+                .ok_or(errors::StorageError::ValueNotFound(process_tracker_id))?;
+            if process.status == storage_enums::ProcessTrackerStatus::Finish {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            Ok(())
+        }
+        Err(error) => Err(error),
+    }
+}
+
 #[cfg(feature = "v1")]
 pub fn update_straight_through_routing<F, D>(
     payment_data: &mut D,
@@ -6755,6 +7068,9 @@ where
         _ => {
             helpers::override_setup_future_usage_to_on_session(&*state.store, payment_data).await?;
 
+            let connectors =
+                circuit_breaker::filter_connectors_with_open_circuit(state, connectors).await;
+
             let first_choice = connectors
                 .first()
                 .ok_or(errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration)
@@ -6836,10 +7152,17 @@ where
                                 mandate_reference_record
                                     .original_payment_authorized_currency
                                     .map(|mandate_currency| mandate_currency != payment_data.get_currency())
-                                    .unwrap_or(false),
+                                    .unwrap_or(false)
+                                    && helpers::is_connector_mandate_currency_bound(
+                                        state,
+                                        connector_data.connector_name,
+                                    ),
                                 || {
                                     Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
-                                        reason: "cross currency mandates not supported".into()
+                                        reason: format!(
+                                            "cross currency mandates not supported by {}",
+                                            connector_data.connector_name
+                                        )
                                     }))
                                 },
                             )?;
@@ -7574,6 +7897,32 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
     .await?
     .ok_or(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("missing payment_method_details")?;
+
+    let payment_address = PaymentAddress::new(
+        shipping_address.as_ref().map(From::from),
+        billing_address.as_ref().map(From::from),
+        None,
+        business_profile.use_billing_as_payment_method_billing,
+    );
+    let three_ds_challenge_flow = get_decision_manager_output(
+        &state,
+        &merchant_account,
+        &core_routing::PaymentsDslInput::new(
+            None,
+            &payment_attempt,
+            &payment_intent,
+            Some(&payment_method_details.0),
+            &payment_address,
+            None,
+            currency,
+        ),
+    )
+    .await?
+    .override_3ds_challenge_flow;
+    // Fall back to the profile level configuration when none of the rules match
+    let force_3ds_challenge = three_ds_challenge_flow
+        .map(|flow| flow == common_enums::DecoupledAuthenticationType::Challenge)
+        .unwrap_or(business_profile.force_3ds_challenge);
     let browser_info: Option<BrowserInformation> = payment_attempt
         .browser_info
         .clone()
@@ -7680,7 +8029,7 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
                 authentication_details.three_ds_requestor_url.clone(),
                 payment_intent.psd2_sca_exemption_type,
                 payment_intent.payment_id,
-                business_profile.force_3ds_challenge,
+                force_3ds_challenge,
             ))
             .await?
         };
@@ -7916,6 +8265,40 @@ pub async fn payments_manual_update(
     ))
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn payments_raw_connector_payloads_retrieve(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api_models::payments::PaymentsRawConnectorPayloadsRequest,
+) -> RouterResponse<api_models::payments::PaymentsRawConnectorPayloadsResponse> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payloads = helpers::get_raw_connector_payloads(
+        &state,
+        merchant_account.get_id(),
+        payment_intent.payment_id.get_string_repr(),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::PaymentsRawConnectorPayloadsResponse {
+            payment_id: payment_intent.payment_id,
+            payloads,
+        },
+    ))
+}
+
 pub trait PaymentMethodChecker<F> {
     fn should_update_in_post_update_tracker(&self) -> bool;
     fn should_update_in_update_tracker(&self) -> bool;
@@ -7988,6 +8371,9 @@ pub trait OperationSessionGetters<F> {
     #[cfg(feature = "v1")]
     fn get_vault_operation(&self) -> Option<&domain_payments::VaultOperation>;
 
+    #[cfg(feature = "v1")]
+    fn get_fx_details(&self) -> Option<&storage::PaymentFxDetails>;
+
     #[cfg(feature = "v2")]
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt>;
 }
@@ -8031,10 +8417,22 @@ pub trait OperationSessionSetters<F> {
         &mut self,
         straight_through_algorithm: serde_json::Value,
     );
+
+    #[cfg(feature = "v1")]
+    fn set_psd2_sca_exemption_type_in_payment_intent(
+        &mut self,
+        psd2_sca_exemption_type: Option<storage_enums::ScaExemptionType>,
+    );
     fn set_connector_in_payment_attempt(&mut self, connector: Option<String>);
 
     #[cfg(feature = "v1")]
     fn set_vault_operation(&mut self, vault_operation: domain_payments::VaultOperation);
+
+    #[cfg(feature = "v1")]
+    fn set_three_ds_challenge_flow(
+        &mut self,
+        three_ds_challenge_flow: Option<common_enums::DecoupledAuthenticationType>,
+    );
 }
 
 #[cfg(feature = "v1")]
@@ -8174,6 +8572,11 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         self.vault_operation.as_ref()
     }
 
+    #[cfg(feature = "v1")]
+    fn get_fx_details(&self) -> Option<&storage::PaymentFxDetails> {
+        self.fx_details.as_ref()
+    }
+
     // #[cfg(feature = "v2")]
     // fn get_capture_method(&self) -> Option<enums::CaptureMethod> {
     //     Some(self.payment_intent.capture_method)
@@ -8287,6 +8690,14 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
         self.payment_attempt.straight_through_algorithm = Some(straight_through_algorithm);
     }
 
+    #[cfg(feature = "v1")]
+    fn set_psd2_sca_exemption_type_in_payment_intent(
+        &mut self,
+        psd2_sca_exemption_type: Option<storage_enums::ScaExemptionType>,
+    ) {
+        self.payment_intent.psd2_sca_exemption_type = psd2_sca_exemption_type;
+    }
+
     fn set_connector_in_payment_attempt(&mut self, connector: Option<String>) {
         self.payment_attempt.connector = connector;
     }
@@ -8294,6 +8705,13 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
     fn set_vault_operation(&mut self, vault_operation: domain_payments::VaultOperation) {
         self.vault_operation = Some(vault_operation);
     }
+
+    fn set_three_ds_challenge_flow(
+        &mut self,
+        three_ds_challenge_flow: Option<common_enums::DecoupledAuthenticationType>,
+    ) {
+        self.three_ds_challenge_flow = three_ds_challenge_flow;
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -0,0 +1,432 @@
+use api_models::payments::{CardVerificationMethod, PaymentsVerifyRequest, PaymentsVerifyResponse};
+use common_utils::{errors::CustomResult, id_type};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use scheduler::{consumer::types::process_data, utils as pt_utils};
+
+use super::{operations, payments_core, CallConnectorAction, HeaderPayload, PaymentData};
+use crate::{
+    configs::settings::CardVerificationConfig,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    logger,
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+const CARD_VERIFICATION_VOID_TASK: &str = "CARD_VERIFICATION_VOID";
+
+/// Verifies a card with the connector without charging the customer, and saves it for the
+/// customer. Connectors which support it are sent a zero amount authorization, the other
+/// connectors are sent an authorization of the configured amount which is voided right after.
+/// The verification is rejected for the other connectors if no such amount is configured.
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+pub async fn verify_card(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsVerifyRequest,
+    platform_merchant_account: Option<domain::MerchantAccount>,
+) -> RouterResponse<PaymentsVerifyResponse> {
+    let (verification_method, amount) =
+        get_verification_method(&state.conf.card_verification, req.connector)?;
+
+    let payment_id = id_type::PaymentId::default();
+    let payments_request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.clone())),
+        amount: Some(amount),
+        currency: Some(req.currency),
+        connector: Some(vec![req.connector]),
+        customer_id: Some(req.customer_id),
+        confirm: Some(true),
+        setup_future_usage: Some(enums::FutureUsage::OffSession),
+        customer_acceptance: Some(req.customer_acceptance),
+        payment_method: Some(enums::PaymentMethod::Card),
+        payment_method_data: Some(api::PaymentMethodDataRequest {
+            payment_method_data: Some(api::PaymentMethodData::Card(req.card)),
+            billing: None,
+        }),
+        billing: req.billing,
+        // The card is verified without the customer being present
+        authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+        profile_id: req.profile_id,
+        ..Default::default()
+    };
+
+    let authorization = match verification_method {
+        CardVerificationMethod::ZeroAmountAuthorization => {
+            Box::pin(payments_core::<
+                api::SetupMandate,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+                PaymentData<api::SetupMandate>,
+            >(
+                state.clone(),
+                req_state.clone(),
+                merchant_account.clone(),
+                profile_id.clone(),
+                key_store.clone(),
+                operations::PaymentCreate,
+                api::PaymentsRequest {
+                    payment_type: Some(enums::PaymentType::SetupMandate),
+                    ..payments_request
+                },
+                services::api::AuthFlow::Merchant,
+                CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+                platform_merchant_account.clone(),
+            ))
+            .await?
+        }
+        CardVerificationMethod::AuthorizationAndVoid => {
+            Box::pin(payments_core::<
+                api::Authorize,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+                PaymentData<api::Authorize>,
+            >(
+                state.clone(),
+                req_state.clone(),
+                merchant_account.clone(),
+                profile_id.clone(),
+                key_store.clone(),
+                operations::PaymentCreate,
+                api::PaymentsRequest {
+                    capture_method: Some(enums::CaptureMethod::Manual),
+                    ..payments_request
+                },
+                services::api::AuthFlow::Merchant,
+                CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+                platform_merchant_account.clone(),
+            ))
+            .await?
+        }
+    }
+    .get_json_body()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unexpected response from payments core")?;
+
+    let is_verified = is_card_verified(verification_method, authorization.status);
+
+    let status =
+        if verification_method == CardVerificationMethod::AuthorizationAndVoid && is_verified {
+            // The card is verified once it is authorized, so a failure to void the authorization is
+            // not a failure of the verification. The void is retried by the scheduler, and the
+            // status of the payment tells the merchant that the authorization is yet to be voided.
+            let void_result = void_authorization(
+                &state,
+                req_state,
+                &merchant_account,
+                profile_id.clone(),
+                &key_store,
+                payment_id.clone(),
+                platform_merchant_account,
+            )
+            .await;
+
+            if !is_authorization_voided(&void_result) {
+                if let Err(error) = &void_result {
+                    logger::error!(
+                        ?error,
+                        "Failed to void the authorization made to verify the card"
+                    );
+                }
+                add_void_retry_task(
+                    state.store.as_ref(),
+                    &payment_id,
+                    merchant_account.get_id(),
+                    profile_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to schedule a retry of the void of the authorization")?;
+            }
+
+            void_result.unwrap_or(authorization.status)
+        } else {
+            authorization.status
+        };
+
+    if is_verified {
+        if let Some(payment_method_id) = authorization.payment_method_id.as_deref() {
+            mark_payment_method_as_verified(
+                &state,
+                &merchant_account,
+                &key_store,
+                payment_method_id,
+            )
+            .await?;
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsVerifyResponse {
+            payment_id: authorization.payment_id,
+            status,
+            is_verified,
+            verification_method,
+            connector: authorization.connector,
+            payment_method_id: authorization.payment_method_id,
+            error_code: authorization.error_code,
+            error_message: authorization.error_message,
+        },
+    ))
+}
+
+/// Returns the method with which a card is verified with the connector, along with the amount
+/// authorized to verify it
+fn get_verification_method(
+    config: &CardVerificationConfig,
+    connector: api_models::enums::Connector,
+) -> RouterResult<(CardVerificationMethod, api::Amount)> {
+    if config.zero_auth_supported_connectors.contains(&connector) {
+        return Ok((
+            CardVerificationMethod::ZeroAmountAuthorization,
+            api::Amount::Zero,
+        ));
+    }
+
+    let amount = config.auth_and_void_amount.ok_or_else(|| {
+        report!(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Verifying a card without charging it is not supported by {connector}"
+            ),
+        })
+    })?;
+    Ok((
+        CardVerificationMethod::AuthorizationAndVoid,
+        api::Amount::from(amount),
+    ))
+}
+
+fn is_card_verified(
+    verification_method: CardVerificationMethod,
+    authorization_status: enums::IntentStatus,
+) -> bool {
+    match verification_method {
+        CardVerificationMethod::ZeroAmountAuthorization => {
+            authorization_status == enums::IntentStatus::Succeeded
+        }
+        CardVerificationMethod::AuthorizationAndVoid => {
+            authorization_status == enums::IntentStatus::RequiresCapture
+        }
+    }
+}
+
+pub(crate) fn is_authorization_voided(void_result: &RouterResult<enums::IntentStatus>) -> bool {
+    matches!(void_result, Ok(enums::IntentStatus::Cancelled))
+}
+
+pub(crate) async fn void_authorization(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+    platform_merchant_account: Option<domain::MerchantAccount>,
+) -> RouterResult<enums::IntentStatus> {
+    let response = Box::pin(payments_core::<
+        api::Void,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::Void>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        profile_id,
+        key_store.clone(),
+        operations::PaymentCancel,
+        api::PaymentsCancelRequest {
+            payment_id,
+            cancellation_reason: Some("Card verification".to_string()),
+            ..Default::default()
+        },
+        services::api::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+        platform_merchant_account,
+    ))
+    .await?
+    .get_json_body()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unexpected response from payments core")?;
+
+    Ok(response.status)
+}
+
+async fn add_void_retry_task(
+    db: &dyn StorageInterface,
+    payment_id: &id_type::PaymentId,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::CardVerificationVoidWorkflow;
+    let schedule_time = pt_utils::get_time_from_delta(pt_utils::get_schedule_time(
+        process_data::ConnectorPTMapping::default(),
+        merchant_id,
+        0,
+    ))
+    .unwrap_or_else(common_utils::date_time::now);
+    let tracking_data = storage::payment_attempt::CardVerificationVoidTrackingData {
+        payment_id: payment_id.clone(),
+        merchant_id: merchant_id.clone(),
+        profile_id,
+    };
+    let tag = ["VOID", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        pt_utils::get_process_tracker_id(
+            runner,
+            CARD_VERIFICATION_VOID_TASK,
+            payment_id.get_string_repr(),
+            merchant_id,
+        ),
+        CARD_VERIFICATION_VOID_TASK,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+async fn mark_payment_method_as_verified(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_id: &str,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let payment_method = db
+        .find_payment_method(
+            &(state.into()),
+            key_store,
+            payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    db.update_payment_method(
+        &(state.into()),
+        key_store,
+        payment_method,
+        storage::PaymentMethodUpdate::VerifiedUpdate {
+            verified_at: common_utils::date_time::now(),
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to mark the payment method as verified")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn get_config(auth_and_void_amount: Option<MinorUnit>) -> CardVerificationConfig {
+        CardVerificationConfig {
+            zero_auth_supported_connectors: [api_models::enums::Connector::Stripe]
+                .into_iter()
+                .collect(),
+            auth_and_void_amount,
+        }
+    }
+
+    #[test]
+    fn test_zero_auth_supported_connector_is_verified_with_zero_amount() {
+        let (verification_method, amount) = get_verification_method(
+            &get_config(Some(MinorUnit::new(100))),
+            api_models::enums::Connector::Stripe,
+        )
+        .unwrap();
+
+        assert_eq!(
+            verification_method,
+            CardVerificationMethod::ZeroAmountAuthorization
+        );
+        assert_eq!(amount, api::Amount::Zero);
+    }
+
+    #[test]
+    fn test_other_connectors_are_verified_with_auth_and_void_of_configured_amount() {
+        let (verification_method, amount) = get_verification_method(
+            &get_config(Some(MinorUnit::new(100))),
+            api_models::enums::Connector::Adyen,
+        )
+        .unwrap();
+
+        assert_eq!(
+            verification_method,
+            CardVerificationMethod::AuthorizationAndVoid
+        );
+        assert_eq!(amount, api::Amount::from(MinorUnit::new(100)));
+    }
+
+    #[test]
+    fn test_verification_is_rejected_without_auth_and_void_amount() {
+        assert!(
+            get_verification_method(&get_config(None), api_models::enums::Connector::Adyen)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_card_is_verified_only_on_successful_authorization() {
+        assert!(is_card_verified(
+            CardVerificationMethod::ZeroAmountAuthorization,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(!is_card_verified(
+            CardVerificationMethod::ZeroAmountAuthorization,
+            enums::IntentStatus::Failed
+        ));
+        assert!(is_card_verified(
+            CardVerificationMethod::AuthorizationAndVoid,
+            enums::IntentStatus::RequiresCapture
+        ));
+        assert!(!is_card_verified(
+            CardVerificationMethod::AuthorizationAndVoid,
+            enums::IntentStatus::Succeeded
+        ));
+    }
+
+    #[test]
+    fn test_void_is_retried_unless_authorization_is_cancelled() {
+        assert!(is_authorization_voided(&Ok(enums::IntentStatus::Cancelled)));
+        assert!(!is_authorization_voided(&Ok(
+            enums::IntentStatus::RequiresCapture
+        )));
+        assert!(!is_authorization_voided(&Err(report!(
+            errors::ApiErrorResponse::InternalServerError
+        ))));
+    }
+}
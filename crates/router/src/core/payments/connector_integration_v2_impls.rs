@@ -144,6 +144,9 @@ mod dummy_connector_default_impl {
             types::PaymentsResponseData,
         > for connector::DummyConnector<T>
     {
+        fn is_flow_implemented(&self) -> bool {
+            false
+        }
     }
 
     impl<const T: u8>
@@ -632,7 +635,11 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 types::PaymentsIncrementalAuthorizationData,
                 types::PaymentsResponseData,
             >
-            for $path::$connector{}
+            for $path::$connector{
+                fn is_flow_implemented(&self) -> bool {
+                    false
+                }
+            }
             impl
             services::ConnectorIntegrationV2<
             api::CompleteAuthorize,
@@ -1554,7 +1554,11 @@ macro_rules! default_imp_for_incremental_authorization {
             types::PaymentsIncrementalAuthorizationData,
             types::PaymentsResponseData,
         > for $path::$connector
-        {}
+        {
+            fn is_flow_implemented(&self) -> bool {
+                false
+            }
+        }
     )*
     };
 }
@@ -1569,6 +1573,9 @@ impl<const T: u8>
         types::PaymentsResponseData,
     > for connector::DummyConnector<T>
 {
+    fn is_flow_implemented(&self) -> bool {
+        false
+    }
 }
 
 default_imp_for_incremental_authorization!(
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use common_enums as enums;
+use error_stack::report;
 use hyperswitch_domain_models::errors::api_error_response::ApiErrorResponse;
 #[cfg(feature = "v2")]
 use hyperswitch_domain_models::payments::PaymentConfirmData;
@@ -17,12 +18,15 @@ use crate::{
     },
     logger,
     routes::{metrics, SessionState},
-    services::{self, api::ConnectorValidation},
+    services::{
+        self,
+        api::{ConnectorSpecifications, ConnectorValidation},
+    },
     types::{
         self, api, domain,
         transformers::{ForeignFrom, ForeignTryFrom},
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[cfg(feature = "v2")]
@@ -344,6 +348,8 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                     )
                     .to_payment_failed_response()?;
 
+                validate_statement_descriptors_for_connector(connector, &mut self.request)?;
+
                 if crate::connector::utils::PaymentsAuthorizeRequestData::is_customer_initiated_mandate_payment(
                     &self.request,
                 ) {
@@ -391,6 +397,53 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
     }
 }
 
+/// Validates the length of the statement descriptors against the maximum length accepted by the
+/// connector. The statement descriptors are dropped if the connector does not support them.
+fn validate_statement_descriptors_for_connector(
+    connector: &api::ConnectorData,
+    request: &mut types::PaymentsAuthorizeData,
+) -> RouterResult<()> {
+    if request.statement_descriptor.is_none() && request.statement_descriptor_suffix.is_none() {
+        return Ok(());
+    }
+
+    let Some(max_length) = connector.connector.get_statement_descriptor_max_length() else {
+        logger::warn!(
+            "Statement descriptors are not supported by {}, ignoring them",
+            connector.connector_name
+        );
+        request.statement_descriptor = None;
+        request.statement_descriptor_suffix = None;
+        return Ok(());
+    };
+
+    let statement_descriptors = [
+        (
+            "statement_descriptor_name",
+            request.statement_descriptor.as_ref(),
+        ),
+        (
+            "statement_descriptor_suffix",
+            request.statement_descriptor_suffix.as_ref(),
+        ),
+    ];
+    for (field_name, statement_descriptor) in statement_descriptors {
+        utils::when(
+            statement_descriptor.is_some_and(|descriptor| descriptor.chars().count() > max_length),
+            || {
+                Err(report!(ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "{field_name} should not exceed {max_length} characters for {}",
+                        connector.connector_name
+                    ),
+                }))
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
 pub trait RouterDataAuthorize {
     fn decide_authentication_type(&mut self);
 
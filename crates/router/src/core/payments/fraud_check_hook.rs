@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use common_utils::{errors::CustomResult, id_type, types::MinorUnit};
+use router_env::{instrument, tracing};
+use serde::Serialize;
+
+use crate::{
+    configs::settings::{FraudCheckHookConfig, FraudCheckHookType},
+    core::{errors, payments::OperationSessionGetters},
+    logger,
+    routes::{metrics, SessionState},
+    types::{domain, storage::enums},
+};
+
+/// Details of the transaction shared with the fraud check hook before it is authorized
+#[derive(Debug, Clone, Serialize)]
+pub struct FraudCheckHookContext {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub payment_id: id_type::PaymentId,
+    pub attempt_id: String,
+    pub amount: MinorUnit,
+    pub currency: Option<enums::Currency>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payment_method: Option<enums::PaymentMethod>,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    pub card_isin: Option<String>,
+    pub card_last_four: Option<String>,
+    pub billing_country: Option<enums::CountryAlpha2>,
+    pub connector: Option<String>,
+    pub browser_info: Option<serde_json::Value>,
+}
+
+impl FraudCheckHookContext {
+    pub fn from_payment_data<F, D>(payment_data: &D) -> Self
+    where
+        D: OperationSessionGetters<F>,
+    {
+        let payment_intent = payment_data.get_payment_intent();
+        let payment_attempt = payment_data.get_payment_attempt();
+        let card = match payment_data.get_payment_method_data() {
+            Some(domain::PaymentMethodData::Card(card)) => Some(card),
+            _ => None,
+        };
+
+        Self {
+            merchant_id: payment_attempt.merchant_id.clone(),
+            profile_id: payment_intent.profile_id.clone(),
+            payment_id: payment_attempt.payment_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+            amount: payment_attempt.net_amount.get_total_amount(),
+            currency: payment_attempt.currency,
+            customer_id: payment_intent.customer_id.clone(),
+            payment_method: payment_attempt.payment_method,
+            payment_method_type: payment_attempt.payment_method_type,
+            card_isin: card.map(|card| card.card_number.get_card_isin()),
+            card_last_four: card.map(|card| card.card_number.get_last4()),
+            billing_country: payment_data
+                .get_address()
+                .get_payment_billing()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country),
+            connector: payment_attempt.connector.clone(),
+            browser_info: payment_attempt.browser_info.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckHookDecision {
+    /// The payment is authorized
+    Accept,
+    /// The payment is failed without being authorized
+    Reject,
+    /// The payment is not authorized and is marked for manual review by the merchant
+    Review,
+}
+
+impl FraudCheckHookDecision {
+    /// The FRM suggestion with which the trackers of the payment are updated, the payment is not
+    /// sent to the connector if there is a suggestion
+    pub fn get_frm_suggestion(self) -> Option<enums::FrmSuggestion> {
+        match self {
+            Self::Accept => None,
+            Self::Reject => Some(enums::FrmSuggestion::FrmCancelTransaction),
+            Self::Review => Some(enums::FrmSuggestion::FrmManualReview),
+        }
+    }
+}
+
+/// Integration point for an external fraud provider, consulted before a payment is authorized
+#[async_trait::async_trait]
+pub trait PreAuthorizationFraudCheckHook: Send + Sync {
+    fn get_hook_name(&self) -> String;
+
+    async fn check_transaction(
+        &self,
+        state: &SessionState,
+        context: &FraudCheckHookContext,
+    ) -> CustomResult<FraudCheckHookDecision, errors::ApiErrorResponse>;
+}
+
+/// Accepts every transaction
+pub struct NoopFraudCheckHook;
+
+#[async_trait::async_trait]
+impl PreAuthorizationFraudCheckHook for NoopFraudCheckHook {
+    fn get_hook_name(&self) -> String {
+        FraudCheckHookType::Noop.to_string()
+    }
+
+    async fn check_transaction(
+        &self,
+        _state: &SessionState,
+        _context: &FraudCheckHookContext,
+    ) -> CustomResult<FraudCheckHookDecision, errors::ApiErrorResponse> {
+        Ok(FraudCheckHookDecision::Accept)
+    }
+}
+
+pub fn get_fraud_check_hook(
+    config: &FraudCheckHookConfig,
+) -> Box<dyn PreAuthorizationFraudCheckHook> {
+    match config.hook {
+        FraudCheckHookType::Noop => Box::new(NoopFraudCheckHook),
+    }
+}
+
+/// Consults the fraud check hook before the payment is authorized. The payment is accepted if the
+/// hook fails or does not respond within the configured timeout, so that a slow or unavailable
+/// fraud provider does not block payments.
+#[instrument(skip_all)]
+pub async fn call_fraud_check_hook<F, D>(
+    state: &SessionState,
+    payment_data: &D,
+) -> FraudCheckHookDecision
+where
+    D: OperationSessionGetters<F>,
+{
+    let config = &state.conf.fraud_check_hook;
+    let hook = get_fraud_check_hook(config);
+    let hook_name = hook.get_hook_name();
+    let context = FraudCheckHookContext::from_payment_data(payment_data);
+
+    let decision = match tokio::time::timeout(
+        Duration::from_millis(config.timeout_in_millis),
+        hook.check_transaction(state, &context),
+    )
+    .await
+    {
+        Ok(Ok(decision)) => decision,
+        Ok(Err(error)) => {
+            logger::error!(
+                ?error,
+                "Fraud check hook {hook_name} failed, accepting the payment"
+            );
+            FraudCheckHookDecision::Accept
+        }
+        Err(_) => {
+            logger::warn!(
+                "Fraud check hook {hook_name} did not respond within {} ms, accepting the payment",
+                config.timeout_in_millis
+            );
+            metrics::FRAUD_CHECK_HOOK_TIMEOUT_COUNT.add(
+                1,
+                router_env::metric_attributes!(("hook", hook_name.clone())),
+            );
+            FraudCheckHookDecision::Accept
+        }
+    };
+
+    logger::debug!("Fraud check hook {hook_name} decision: {decision}");
+    metrics::FRAUD_CHECK_HOOK_DECISION_COUNT.add(
+        1,
+        router_env::metric_attributes!(("hook", hook_name), ("decision", decision.to_string())),
+    );
+
+    decision
+}
@@ -0,0 +1,209 @@
+use std::str::FromStr;
+
+use common_utils::{errors::CustomResult, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use time::PrimitiveDateTime;
+use tracing_futures::Instrument;
+
+use crate::{
+    configs::settings::{FxRateProviderConfig, FxRateProviderType},
+    core::errors::{self, RouterResult},
+    logger,
+    routes::SessionState,
+    types::storage,
+    utils::currency::{self, ForexCacheError},
+};
+
+/// Exchange rate between two currencies, expressed as the number of major units of the target
+/// currency for one major unit of the source currency
+#[derive(Debug, Clone, Copy)]
+pub struct FxRate {
+    pub rate: Decimal,
+    pub fetched_at: PrimitiveDateTime,
+}
+
+#[async_trait::async_trait]
+pub trait FxRateProvider: Send + Sync {
+    fn get_provider_name(&self) -> String;
+
+    async fn get_exchange_rate(
+        &self,
+        state: &SessionState,
+        from_currency: storage::enums::Currency,
+        to_currency: storage::enums::Currency,
+    ) -> CustomResult<FxRate, ForexCacheError>;
+}
+
+pub struct ForexApiRateProvider;
+
+#[async_trait::async_trait]
+impl FxRateProvider for ForexApiRateProvider {
+    fn get_provider_name(&self) -> String {
+        FxRateProviderType::ForexApi.to_string()
+    }
+
+    async fn get_exchange_rate(
+        &self,
+        state: &SessionState,
+        from_currency: storage::enums::Currency,
+        to_currency: storage::enums::Currency,
+    ) -> CustomResult<FxRate, ForexCacheError> {
+        let forex_api = state.conf.forex_api.get_inner();
+        let rates = currency::get_forex_rates(state, forex_api.call_delay).await?;
+
+        let one_major_unit = 10_i64.pow(u32::from(
+            from_currency.number_of_digits_after_decimal_point(),
+        ));
+        let rate = currency_conversion::conversion::convert(
+            &rates.data,
+            from_currency,
+            to_currency,
+            one_major_unit,
+        )
+        .change_context(ForexCacheError::ConversionError)
+        .attach_printable("Unable to derive the exchange rate")?;
+
+        let fetched_at = rates
+            .get_fetched_at()
+            .ok_or(ForexCacheError::ParsingError)
+            .attach_printable("Invalid timestamp of the cached exchange rates")?;
+
+        Ok(FxRate { rate, fetched_at })
+    }
+}
+
+pub fn get_fx_rate_provider(config: &FxRateProviderConfig) -> Option<Box<dyn FxRateProvider>> {
+    config.provider.map(|provider| match provider {
+        FxRateProviderType::ForexApi => Box::new(ForexApiRateProvider) as Box<dyn FxRateProvider>,
+    })
+}
+
+/// Settlement currency configured for the merchant, if any
+async fn get_settlement_currency(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<Option<storage::enums::Currency>> {
+    match state
+        .store
+        .find_config_by_key(&merchant_id.get_settlement_currency_key())
+        .await
+    {
+        Ok(config) => storage::enums::Currency::from_str(config.config.trim())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid settlement currency configured for the merchant")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the settlement currency of the merchant")),
+    }
+}
+
+/// Converts a minor unit amount using an exchange rate expressed in major units
+fn convert_amount(
+    amount: MinorUnit,
+    rate: Decimal,
+    from_currency: storage::enums::Currency,
+    to_currency: storage::enums::Currency,
+) -> Option<MinorUnit> {
+    let from_factor = Decimal::from(10_i64.pow(u32::from(
+        from_currency.number_of_digits_after_decimal_point(),
+    )));
+    let to_factor = Decimal::from(10_i64.pow(u32::from(
+        to_currency.number_of_digits_after_decimal_point(),
+    )));
+
+    (Decimal::from(amount.get_amount_as_i64()) * rate * to_factor / from_factor)
+        .round()
+        .to_i64()
+        .map(MinorUnit::new)
+}
+
+/// Records the exchange rate used to derive the settlement amount of an authorized payment attempt.
+/// Nothing is recorded when no rate provider is configured, when the merchant has no settlement
+/// currency or when the payment is already in the settlement currency.
+#[instrument(skip_all)]
+pub async fn record_payment_fx_details(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<storage::PaymentFxDetails>> {
+    let Some(provider) = get_fx_rate_provider(&state.conf.fx_rate_provider) else {
+        return Ok(None);
+    };
+
+    let Some(currency) = payment_attempt.currency else {
+        return Ok(None);
+    };
+
+    let settlement_currency =
+        match get_settlement_currency(state, &payment_attempt.merchant_id).await? {
+            Some(settlement_currency) if settlement_currency != currency => settlement_currency,
+            _ => return Ok(None),
+        };
+
+    let fx_rate = provider
+        .get_exchange_rate(state, currency, settlement_currency)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to fetch the exchange rate from {currency} to {settlement_currency} using {}",
+                provider.get_provider_name()
+            )
+        })?;
+
+    let amount = payment_attempt.net_amount.get_total_amount();
+    let settlement_amount = convert_amount(amount, fx_rate.rate, currency, settlement_currency)
+        .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Settlement amount overflowed while converting the payment amount")?;
+
+    let payment_fx_details = storage::PaymentFxDetailsNew {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        provider: provider.get_provider_name(),
+        amount,
+        currency,
+        settlement_amount,
+        settlement_currency,
+        exchange_rate: fx_rate.rate.normalize().to_string(),
+        rate_fetched_at: fx_rate.fetched_at,
+        created_at: common_utils::date_time::now(),
+    };
+
+    let payment_fx_details = state
+        .store
+        .insert_payment_fx_details(payment_fx_details)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the fx details of the payment attempt")?;
+
+    logger::debug!(
+        "Recorded settlement amount {} {} for attempt {}",
+        payment_fx_details.settlement_amount.get_amount_as_i64(),
+        payment_fx_details.settlement_currency,
+        payment_fx_details.attempt_id
+    );
+
+    Ok(Some(payment_fx_details))
+}
+
+/// Spawns the recording of the fx details so that the payment is never affected by the
+/// unavailability of exchange rates
+pub fn spawn_record_payment_fx_details(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    let state = state.clone();
+    let payment_attempt = payment_attempt.clone();
+    tokio::spawn(
+        async move {
+            if let Err(error) = record_payment_fx_details(&state, &payment_attempt).await {
+                logger::error!(?error, "Failed to record the fx details of the payment");
+            }
+        }
+        .in_current_span(),
+    );
+}
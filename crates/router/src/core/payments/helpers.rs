@@ -73,6 +73,7 @@ use crate::{
         pm_auth::retrieve_payment_method_from_auth_service,
     },
     db::StorageInterface,
+    events::connector_api_logs::ConnectorEvent,
     routes::{metrics, payment_methods as payment_methods_handler, SessionState},
     services,
     types::{
@@ -687,6 +688,34 @@ pub async fn get_token_pm_type_mandate_details(
                 }
             }
         }
+        None if request.payment_token.is_none()
+            && request.payment_method_data.is_none()
+            && request.use_default_payment_method == Some(true) =>
+        {
+            let customer_id = request
+                .get_customer_id()
+                .or(payment_intent_customer_id)
+                .get_required_value("customer_id")?;
+
+            let (payment_token, payment_method_info) =
+                cards::create_payment_token_for_default_payment_method(
+                    state,
+                    merchant_account,
+                    merchant_key_store,
+                    customer_id,
+                )
+                .await?;
+
+            (
+                Some(payment_token),
+                payment_method_info.get_payment_method_type(),
+                payment_method_info.get_payment_method_subtype(),
+                mandate_data,
+                None,
+                None,
+                Some(payment_method_info),
+            )
+        }
         None => {
             let payment_method_info = payment_method_id
                 .async_map(|payment_method_id| async move {
@@ -793,6 +822,11 @@ pub async fn get_token_for_recurring_mandate(
                 message: "customer_id must match mandate customer_id".into()
             }))?
         }
+        if mandate.mandate_status == storage_enums::MandateStatus::Revoked {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate has been revoked and can no longer be used for payments".into()
+            }))?
+        }
         if mandate.mandate_status != storage_enums::MandateStatus::Active {
             Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                 message: "mandate is not active".into()
@@ -1346,6 +1380,18 @@ pub fn verify_mandate_details(
     )
 }
 
+/// Whether a payment method saved with the connector can only be reused in the currency it was saved in
+pub fn is_connector_mandate_currency_bound(
+    state: &SessionState,
+    connector: api_enums::Connector,
+) -> bool {
+    !state
+        .conf
+        .cross_currency_mandate_supported_connectors
+        .connector_list
+        .contains(&connector)
+}
+
 pub fn verify_mandate_details_for_recurring_payments(
     mandate_merchant_id: &id_type::MerchantId,
     merchant_id: &id_type::MerchantId,
@@ -1364,6 +1410,30 @@ pub fn verify_mandate_details_for_recurring_payments(
     Ok(())
 }
 
+/// Resolves the PSD2 SCA exemption to be requested for the payment, the exemption in the request
+/// taking precedence over the one from the decision manager rules. Only merchant initiated
+/// payments are exempted as recurring, so a recurring exemption matched by the rules for any other
+/// payment is not requested.
+pub fn get_psd2_sca_exemption_type(
+    requested_exemption: Option<storage_enums::ScaExemptionType>,
+    exemption_from_rules: Option<storage_enums::ScaExemptionType>,
+    off_session: Option<bool>,
+) -> RouterResult<Option<storage_enums::ScaExemptionType>> {
+    let is_off_session = off_session.unwrap_or(false);
+    match requested_exemption {
+        Some(storage_enums::ScaExemptionType::Recurring) if !is_off_session => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "recurring sca exemption can only be requested for off session payments"
+                    .into()
+            }))
+        }
+        Some(requested_exemption) => Ok(Some(requested_exemption)),
+        None => Ok(exemption_from_rules.filter(|exemption_from_rules| {
+            is_off_session || *exemption_from_rules != storage_enums::ScaExemptionType::Recurring
+        })),
+    }
+}
+
 #[instrument(skip_all)]
 pub fn payment_attempt_status_fsm(
     payment_method_data: Option<&api::payments::PaymentMethodData>,
@@ -1806,6 +1876,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                         connector_customer: None,
                         address_id: None,
                         default_payment_method_id: None,
+                        merged_into_customer_id: None,
                         updated_by: None,
                         version: hyperswitch_domain_models::consts::API_VERSION,
                     };
@@ -2083,8 +2154,8 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
     _payment_method_id: &str,
     payment_intent: &PaymentIntent,
     card_token_data: Option<&domain::CardToken>,
-    _merchant_key_store: &domain::MerchantKeyStore,
-    _storage_scheme: enums::MerchantStorageScheme,
+    merchant_key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
     mandate_id: Option<api_models::payments::MandateIds>,
     payment_method_info: domain::PaymentMethod,
     business_profile: &domain::Profile,
@@ -2159,6 +2230,44 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
         VaultFetchAction::FetchNetworkTokenDataFromTokenizationService(
             network_token_requestor_ref_id,
         ) => {
+            let (network_token_requestor_ref_id, payment_method_info) =
+                if network_tokenization::is_network_token_expired(state, &payment_method_info).await
+                {
+                    logger::info!("Network token has expired, provisioning a new network token");
+                    let reprovisioned_payment_method = fetch_card_details_from_locker(
+                        state,
+                        customer_id,
+                        &payment_intent.merchant_id,
+                        locker_id,
+                        card_token_data,
+                    )
+                    .async_and_then(|card| {
+                        network_tokenization::reprovision_network_token(
+                            state,
+                            merchant_key_store,
+                            storage_scheme,
+                            payment_method_info.clone(),
+                            &card,
+                        )
+                    })
+                    .await;
+                    match reprovisioned_payment_method {
+                        Ok(payment_method) => (
+                            payment_method
+                                .network_token_requestor_reference_id
+                                .clone()
+                                .unwrap_or(network_token_requestor_ref_id),
+                            payment_method,
+                        ),
+                        Err(err) => {
+                            logger::error!(?err, "Failed to provision a new network token");
+                            (network_token_requestor_ref_id, payment_method_info)
+                        }
+                    }
+                } else {
+                    (network_token_requestor_ref_id, payment_method_info)
+                };
+
             logger::info!("Fetching network token data from tokenization service");
             match network_tokenization::get_token_from_tokenization_service(
                 state,
@@ -2852,6 +2961,77 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// Validates a partial capture against the cumulative amount of the previous captures on the
+/// authorization. Captures which are still in flight are counted as well, so that concurrent
+/// capture calls cannot capture more than the authorized amount.
+#[instrument(skip_all)]
+pub(crate) fn validate_amount_to_capture_against_previous_captures(
+    authorized_amount: MinorUnit,
+    previous_captures: &[storage::Capture],
+    amount_to_capture: MinorUnit,
+) -> RouterResult<()> {
+    utils::when(amount_to_capture <= MinorUnit::new(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount_to_capture should be greater than 0".to_string()
+        }))
+    })?;
+
+    let total_blocked_amount: MinorUnit = previous_captures
+        .iter()
+        .filter(|capture| capture.status != storage_enums::CaptureStatus::Failed)
+        .map(|capture| capture.amount)
+        .sum();
+    let remaining_capturable_amount = authorized_amount - total_blocked_amount;
+
+    utils::when(amount_to_capture > remaining_capturable_amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount_to_capture is greater than the remaining capturable amount of {}",
+                remaining_capturable_amount.get_amount_as_i64()
+            )
+        }))
+    })
+}
+
+/// Validates a partial void against the amount which is still authorized and not yet captured.
+/// Releasing the entire remaining amount is a full void, which is requested without an amount.
+#[instrument(skip_all)]
+pub(crate) fn validate_amount_to_void(
+    intent_status: storage_enums::IntentStatus,
+    amount_capturable: MinorUnit,
+    amount_to_void: MinorUnit,
+) -> RouterResult<()> {
+    utils::when(
+        !matches!(
+            intent_status,
+            storage_enums::IntentStatus::RequiresCapture
+                | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "A partial void can only be performed on an authorized payment, the payment is in {intent_status} status"
+                )
+            }))
+        },
+    )?;
+
+    utils::when(amount_to_void <= MinorUnit::new(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount_to_void should be greater than 0".to_string()
+        }))
+    })?;
+
+    utils::when(amount_to_void >= amount_capturable, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount_to_void should be less than the remaining authorized amount of {}, void the payment without an amount to release the entire authorization",
+                amount_capturable.get_amount_as_i64()
+            )
+        }))
+    })
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
@@ -3992,6 +4172,165 @@ mod tests {
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    #[test]
+    fn test_psd2_sca_exemption_type_from_request_takes_precedence() {
+        assert_eq!(
+            get_psd2_sca_exemption_type(
+                Some(storage_enums::ScaExemptionType::LowValue),
+                Some(storage_enums::ScaExemptionType::TransactionRiskAnalysis),
+                None,
+            )
+            .unwrap(),
+            Some(storage_enums::ScaExemptionType::LowValue)
+        );
+        assert_eq!(
+            get_psd2_sca_exemption_type(
+                None,
+                Some(storage_enums::ScaExemptionType::TransactionRiskAnalysis),
+                None,
+            )
+            .unwrap(),
+            Some(storage_enums::ScaExemptionType::TransactionRiskAnalysis)
+        );
+    }
+
+    #[test]
+    fn test_recurring_sca_exemption_type_is_only_requested_off_session() {
+        assert_eq!(
+            get_psd2_sca_exemption_type(
+                Some(storage_enums::ScaExemptionType::Recurring),
+                None,
+                Some(true),
+            )
+            .unwrap(),
+            Some(storage_enums::ScaExemptionType::Recurring)
+        );
+        assert!(get_psd2_sca_exemption_type(
+            Some(storage_enums::ScaExemptionType::Recurring),
+            None,
+            Some(false),
+        )
+        .is_err());
+        assert_eq!(
+            get_psd2_sca_exemption_type(
+                None,
+                Some(storage_enums::ScaExemptionType::Recurring),
+                None,
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    /// Returns a base64 encoded self signed certificate, valid for the duration around now, and
+    /// the base64 encoded hash of its public key
+    fn get_test_certificate(
+        valid_from: time::Duration,
+        valid_till: time::Duration,
+    ) -> (masking::Secret<String>, String) {
+        let group =
+            openssl::ec::EcGroup::from_curve_name(openssl::nid::Nid::X9_62_PRIME256V1).unwrap();
+        let key = PKey::from_ec_key(openssl::ec::EcKey::generate(&group).unwrap()).unwrap();
+        let now = common_utils::date_time::now().assume_utc().unix_timestamp();
+
+        let mut builder = openssl::x509::X509Builder::new().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(
+                &openssl::asn1::Asn1Time::from_unix(now + valid_from.whole_seconds()).unwrap(),
+            )
+            .unwrap();
+        builder
+            .set_not_after(
+                &openssl::asn1::Asn1Time::from_unix(now + valid_till.whole_seconds()).unwrap(),
+            )
+            .unwrap();
+        builder
+            .sign(&key, openssl::hash::MessageDigest::sha256())
+            .unwrap();
+
+        (
+            masking::Secret::new(BASE64_ENGINE.encode(builder.build().to_der().unwrap())),
+            BASE64_ENGINE.encode(openssl::sha::sha256(&key.public_key_to_der().unwrap())),
+        )
+    }
+
+    fn get_test_apple_pay_data(public_key_hash: String) -> ApplePayData {
+        ApplePayData {
+            version: masking::Secret::new("EC_v1".to_string()),
+            data: masking::Secret::new(String::new()),
+            signature: masking::Secret::new(String::new()),
+            header: ApplePayHeader {
+                ephemeral_public_key: masking::Secret::new(String::new()),
+                public_key_hash: masking::Secret::new(public_key_hash),
+                transaction_id: masking::Secret::new(String::new()),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apple_pay_token_is_decrypted_with_the_certificate_being_rotated_out() {
+        let (current_certificate, _) =
+            get_test_certificate(time::Duration::days(-1), time::Duration::days(30));
+        let (previous_certificate, previous_public_key_hash) =
+            get_test_certificate(time::Duration::days(-30), time::Duration::days(1));
+        let apple_pay_data = get_test_apple_pay_data(previous_public_key_hash);
+
+        assert!(!apple_pay_data
+            .is_encrypted_with_certificate(&current_certificate)
+            .unwrap());
+        assert!(apple_pay_data
+            .is_encrypted_with_certificate(&previous_certificate)
+            .unwrap());
+
+        let payment_processing_details = api_models::payments::PaymentProcessingDetails {
+            payment_processing_certificate: current_certificate,
+            payment_processing_certificate_key: masking::Secret::new(String::new()),
+            previous_payment_processing_certificate: None,
+            previous_payment_processing_certificate_key: None,
+        };
+        let error = apple_pay_data
+            .decrypt_with_matching_certificate(&payment_processing_details)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApplePayDecryptionError::CertificateNotFound
+        ));
+
+        // The previous certificate is picked, which has no merchant id as it is not issued by Apple
+        let error = apple_pay_data
+            .decrypt_with_matching_certificate(&api_models::payments::PaymentProcessingDetails {
+                previous_payment_processing_certificate: Some(previous_certificate),
+                previous_payment_processing_certificate_key: Some(masking::Secret::new(
+                    String::new(),
+                )),
+                ..payment_processing_details
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApplePayDecryptionError::MissingMerchantId
+        ));
+    }
+
+    #[test]
+    fn test_expired_apple_pay_certificate_is_rejected() {
+        let (expired_certificate, public_key_hash) =
+            get_test_certificate(time::Duration::days(-30), time::Duration::days(-1));
+        let apple_pay_data = get_test_apple_pay_data(public_key_hash);
+
+        let error = apple_pay_data
+            .merchant_id(&expired_certificate)
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApplePayDecryptionError::CertificateExpired
+        ));
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -4409,7 +4748,8 @@ pub fn get_attempt_type(
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
         | enums::IntentStatus::Processing
-        | enums::IntentStatus::Succeeded => {
+        | enums::IntentStatus::Succeeded
+        | enums::IntentStatus::Expired => {
             Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                 message: format!(
                     "You cannot {action} this payment because it has status {}",
@@ -4668,7 +5008,8 @@ pub fn is_manual_retry_allowed(
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
         | enums::IntentStatus::Processing
-        | enums::IntentStatus::Succeeded => Some(false),
+        | enums::IntentStatus::Succeeded
+        | enums::IntentStatus::Expired => Some(false),
 
         enums::IntentStatus::RequiresCustomerAction
         | enums::IntentStatus::RequiresMerchantAction
@@ -4705,6 +5046,167 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    fn get_capture(
+        amount: i64,
+        status: super::storage_enums::CaptureStatus,
+    ) -> super::storage::Capture {
+        let now = common_utils::date_time::now();
+        super::storage::Capture {
+            capture_id: "capture_id".to_string(),
+            payment_id: super::id_type::PaymentId::default(),
+            merchant_id: super::id_type::MerchantId::default(),
+            status,
+            amount: super::MinorUnit::new(amount),
+            currency: None,
+            connector: "stripe".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: now,
+            modified_at: now,
+            authorized_attempt_id: "attempt_id".to_string(),
+            connector_capture_id: None,
+            capture_sequence: 1,
+            connector_response_reference_id: None,
+            connector_capture_data: None,
+            processor_capture_data: None,
+        }
+    }
+
+    #[test]
+    fn test_partial_capture_within_remaining_amount() {
+        let previous_captures = vec![
+            get_capture(300, super::storage_enums::CaptureStatus::Charged),
+            get_capture(500, super::storage_enums::CaptureStatus::Failed),
+        ];
+
+        assert!(super::validate_amount_to_capture_against_previous_captures(
+            super::MinorUnit::new(1000),
+            &previous_captures,
+            super::MinorUnit::new(700),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_partial_capture_exceeding_remaining_amount() {
+        let previous_captures = vec![
+            get_capture(300, super::storage_enums::CaptureStatus::Charged),
+            get_capture(500, super::storage_enums::CaptureStatus::Pending),
+        ];
+
+        assert!(super::validate_amount_to_capture_against_previous_captures(
+            super::MinorUnit::new(1000),
+            &previous_captures,
+            super::MinorUnit::new(300),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_partial_void_within_remaining_amount() {
+        assert!(super::validate_amount_to_void(
+            super::storage_enums::IntentStatus::PartiallyCapturedAndCapturable,
+            super::MinorUnit::new(700),
+            super::MinorUnit::new(200),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_partial_void_of_entire_or_uncapturable_amount() {
+        assert!(super::validate_amount_to_void(
+            super::storage_enums::IntentStatus::RequiresCapture,
+            super::MinorUnit::new(700),
+            super::MinorUnit::new(700),
+        )
+        .is_err());
+        assert!(super::validate_amount_to_void(
+            super::storage_enums::IntentStatus::RequiresCapture,
+            super::MinorUnit::new(700),
+            super::MinorUnit::new(0),
+        )
+        .is_err());
+        assert!(super::validate_amount_to_void(
+            super::storage_enums::IntentStatus::RequiresConfirmation,
+            super::MinorUnit::new(700),
+            super::MinorUnit::new(200),
+        )
+        .is_err());
+    }
+
+    fn get_generic_split_payment(
+        split_items: &[(i64, &str)],
+    ) -> Option<common_types::payments::SplitPaymentsRequest> {
+        Some(
+            common_types::payments::SplitPaymentsRequest::GenericSplitPayment(
+                common_types::payments::GenericSplitPaymentRequest {
+                    split_items: split_items
+                        .iter()
+                        .map(
+                            |(amount, reference)| common_types::payments::GenericSplitItem {
+                                recipient_id: format!("recipient_{reference}"),
+                                amount: super::MinorUnit::new(*amount),
+                                reference: reference.to_string(),
+                                description: None,
+                            },
+                        )
+                        .collect(),
+                },
+            ),
+        )
+    }
+
+    fn get_amount(amount: i64) -> super::api::Amount {
+        super::api::Amount::Value(std::num::NonZeroI64::new(amount).unwrap())
+    }
+
+    #[test]
+    fn test_generic_split_payment_summing_up_to_the_amount() {
+        assert!(super::validate_platform_request_for_marketplace(
+            get_amount(1000),
+            get_generic_split_payment(&[(700, "split_1"), (300, "split_2")]),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_generic_split_payment_not_summing_up_to_the_amount() {
+        for split_items in [
+            [(700, "split_1"), (200, "split_2")],
+            [(700, "split_1"), (400, "split_2")],
+        ] {
+            assert!(matches!(
+                super::validate_platform_request_for_marketplace(
+                    get_amount(1000),
+                    get_generic_split_payment(&split_items),
+                ),
+                Err(super::errors::ApiErrorResponse::PreconditionFailed { message })
+                    if message == "Sum of split amounts should be equal to the total amount"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_generic_split_payment_with_invalid_split_items() {
+        assert!(super::validate_platform_request_for_marketplace(
+            get_amount(1000),
+            get_generic_split_payment(&[]),
+        )
+        .is_err());
+        assert!(super::validate_platform_request_for_marketplace(
+            get_amount(1000),
+            get_generic_split_payment(&[(1000, "split_1"), (0, "split_2")]),
+        )
+        .is_err());
+        assert!(super::validate_platform_request_for_marketplace(
+            get_amount(1000),
+            get_generic_split_payment(&[(500, "split_1"), (500, "split_1")]),
+        )
+        .is_err());
+    }
 }
 
 #[instrument(skip_all)]
@@ -5550,6 +6052,56 @@ impl ApplePayData {
         Ok(parsed_decrypted)
     }
 
+    /// Decrypts the token with the payment processing certificate it was encrypted with. The
+    /// previous certificate, if any, is tried as well so that tokens encrypted with it can still
+    /// be decrypted while the certificate is being rotated.
+    pub async fn decrypt_with_matching_certificate(
+        &self,
+        payment_processing_details: &api_models::payments::PaymentProcessingDetails,
+    ) -> CustomResult<serde_json::Value, errors::ApplePayDecryptionError> {
+        let previous_certificate = payment_processing_details
+            .previous_payment_processing_certificate
+            .as_ref()
+            .zip(
+                payment_processing_details
+                    .previous_payment_processing_certificate_key
+                    .as_ref(),
+            );
+        let certificates = std::iter::once((
+            &payment_processing_details.payment_processing_certificate,
+            &payment_processing_details.payment_processing_certificate_key,
+        ))
+        .chain(previous_certificate);
+
+        for (certificate, certificate_key) in certificates {
+            if self.is_encrypted_with_certificate(certificate)? {
+                return self.decrypt(certificate, certificate_key).await;
+            }
+        }
+
+        Err(errors::ApplePayDecryptionError::CertificateNotFound.into())
+    }
+
+    /// Apple Pay shares the base64 encoded SHA-256 hash of the public key of the payment processing
+    /// certificate, with which the token was encrypted, in the header of the token
+    pub fn is_encrypted_with_certificate(
+        &self,
+        payment_processing_certificate: &masking::Secret<String>,
+    ) -> CustomResult<bool, errors::ApplePayDecryptionError> {
+        let base64_decode_cert_data = BASE64_ENGINE
+            .decode(payment_processing_certificate.peek())
+            .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)?;
+
+        let (_, certificate) = parse_x509_certificate(&base64_decode_cert_data)
+            .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)
+            .attach_printable("Error parsing apple pay PPC")?;
+
+        let public_key_hash =
+            BASE64_ENGINE.encode(openssl::sha::sha256(certificate.public_key().raw));
+
+        Ok(public_key_hash == *self.header.public_key_hash.peek())
+    }
+
     pub fn merchant_id(
         &self,
         payment_processing_certificate: &masking::Secret<String>,
@@ -5565,6 +6117,16 @@ impl ApplePayData {
             .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)
             .attach_printable("Error parsing apple pay PPC")?;
 
+        if !certificate.validity().is_valid() {
+            return Err(errors::ApplePayDecryptionError::CertificateExpired).attach_printable(
+                format!(
+                    "Apple pay PPC is valid from {} to {}",
+                    certificate.validity().not_before,
+                    certificate.validity().not_after
+                ),
+            );
+        }
+
         // Finding the merchant ID extension
         let apple_pay_m_id = certificate
             .extensions()
@@ -6476,6 +7038,63 @@ pub fn validate_order_details_amount(
     }
 }
 
+/// Validates the characters of the statement descriptors in the request. The length of the
+/// statement descriptors is validated against the constraints of the connector the payment is
+/// routed to.
+#[cfg(feature = "v1")]
+pub fn validate_statement_descriptors(request: &api::PaymentsRequest) -> RouterResult<()> {
+    let statement_descriptors = [
+        (
+            "statement_descriptor_name",
+            request.statement_descriptor_name.as_ref(),
+        ),
+        (
+            "statement_descriptor_suffix",
+            request.statement_descriptor_suffix.as_ref(),
+        ),
+    ];
+
+    for (field_name, statement_descriptor) in
+        statement_descriptors
+            .into_iter()
+            .filter_map(|(field_name, descriptor)| {
+                descriptor.map(|descriptor| (field_name, descriptor))
+            })
+    {
+        utils::when(
+            !statement_descriptor
+                .chars()
+                .all(|character| character.is_ascii() && !character.is_ascii_control())
+                || statement_descriptor.chars().any(|character| {
+                    consts::STATEMENT_DESCRIPTOR_DISALLOWED_CHARACTERS.contains(&character)
+                }),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "{field_name} should contain only latin characters, excluding < > \\ ' \" *"
+                    ),
+                }))
+            },
+        )?;
+    }
+
+    utils::when(
+        request
+            .statement_descriptor_name
+            .as_ref()
+            .is_some_and(|name| {
+                !name
+                    .chars()
+                    .any(|character| character.is_ascii_alphabetic())
+            }),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "statement_descriptor_name should contain at least one letter".to_string(),
+            }))
+        },
+    )
+}
+
 // This function validates the client secret expiry set by the merchant in the request
 pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErrorResponse> {
     if !(consts::MIN_SESSION_EXPIRY..=consts::MAX_SESSION_EXPIRY).contains(&session_expiry) {
@@ -6487,6 +7106,78 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+/// Validates the delayed capture requested for the payment, and returns the time at which the
+/// payment is to be captured
+#[cfg(feature = "v1")]
+pub fn get_delayed_capture_time(
+    state: &SessionState,
+    request: &api::PaymentsRequest,
+    created_at: time::PrimitiveDateTime,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let Some(capture_delay_in_hours) = request.capture_delay_in_hours else {
+        return Ok(None);
+    };
+
+    utils::when(
+        request.capture_method != Some(api_enums::CaptureMethod::Manual),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "capture_delay_in_hours can be used only when capture_method is manual"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(request.capture_on.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "capture_delay_in_hours cannot be used along with capture_on".to_string(),
+        }))
+    })?;
+
+    let authorization_validity_in_hours =
+        state.conf.delayed_capture.authorization_validity_in_hours;
+    utils::when(
+        !(1..=authorization_validity_in_hours).contains(&capture_delay_in_hours),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "capture_delay_in_hours should be between 1 and {authorization_validity_in_hours}"
+                ),
+            }))
+        },
+    )?;
+
+    Ok(Some(created_at.saturating_add(time::Duration::hours(
+        i64::from(capture_delay_in_hours),
+    ))))
+}
+
+/// Returns the time after which the unconfirmed payments of the merchant expire. Payments of
+/// merchants who have not configured it do not expire.
+#[cfg(feature = "v1")]
+pub async fn get_merchant_payment_intent_expiry(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> Option<time::Duration> {
+    let key = merchant_id.get_payment_intent_expiry_key();
+
+    db.find_config_by_key(key.as_str())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|expiry_config| {
+            expiry_config
+                .config
+                .parse::<u32>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Payment intent expiry config parsing failed")
+        })
+        .map_err(|err| {
+            logger::debug!(payment_intent_expiry_error=?err);
+        })
+        .ok()
+        .map(|expiry_in_seconds| time::Duration::seconds(i64::from(expiry_in_seconds)))
+}
+
 pub fn get_recipient_id_for_open_banking(
     merchant_data: &AdditionalMerchantData,
 ) -> Result<Option<String>, errors::ApiErrorResponse> {
@@ -7288,11 +7979,139 @@ pub fn validate_platform_request_for_marketplace(
             }
             common_types::payments::XenditSplitRequest::SingleSplit(_) => (),
         },
+        Some(common_types::payments::SplitPaymentsRequest::GenericSplitPayment(
+            generic_split_payment,
+        )) => {
+            if generic_split_payment.split_items.is_empty() {
+                return Err(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "split_payments.generic_split_payment.split_items",
+                });
+            }
+
+            let mut references = HashSet::new();
+            for split_item in generic_split_payment.split_items.iter() {
+                if split_item.amount <= MinorUnit::new(0) {
+                    return Err(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "split_payments.generic_split_payment.split_items.amount",
+                    });
+                }
+                if !references.insert(&split_item.reference) {
+                    return Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "Duplicate split item reference: {}",
+                            split_item.reference
+                        ),
+                    });
+                }
+            }
+
+            let total_split_amount = generic_split_payment
+                .split_items
+                .iter()
+                .try_fold(0_i64, |total, split_item| {
+                    total.checked_add(split_item.amount.get_amount_as_i64())
+                })
+                .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "split_payments.generic_split_payment.split_items.amount",
+                })?;
+
+            let total_payment_amount: i64 = match amount {
+                api::Amount::Zero => 0,
+                api::Amount::Value(amount) => amount.into(),
+            };
+
+            if total_split_amount != total_payment_amount {
+                return Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Sum of split amounts should be equal to the total amount".to_string(),
+                });
+            }
+        }
         None => (),
     }
     Ok(())
 }
 
+/// Checks whether the merchant has opted in to store the raw connector payloads of their payments
+pub async fn should_store_raw_connector_payloads(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> bool {
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_store_raw_connector_payloads_key(),
+            Some("false".to_string()),
+        )
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(error) => {
+            logger::error!(?error);
+            false
+        }
+    }
+}
+
+/// Stores the connector request and response of a payment in redis for a limited time.
+/// Sensitive data like card number and cvc is already masked in the connector event.
+pub async fn store_raw_connector_payload(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &str,
+    connector_event: &ConnectorEvent,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = merchant_id.get_raw_connector_payloads_key(payment_id);
+    let payload = serde_json::to_string(connector_event)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the connector event")?;
+
+    redis_conn
+        .append_elements_to_list(&key.as_str().into(), vec![payload])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the raw connector payload in redis")?;
+    redis_conn
+        .set_expiry(
+            &key.as_str().into(),
+            state.conf.raw_connector_payloads.ttl_in_seconds,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set expiry for the raw connector payloads")
+}
+
+/// Fetches the raw connector payloads stored for a payment, oldest first
+pub async fn get_raw_connector_payloads(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &str,
+) -> RouterResult<Vec<serde_json::Value>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = merchant_id.get_raw_connector_payloads_key(payment_id);
+
+    redis_conn
+        .get_list_elements(&key.as_str().into(), 0, -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the raw connector payloads from redis")?
+        .iter()
+        .map(|payload| {
+            serde_json::from_str(payload)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize the raw connector payload")
+        })
+        .collect()
+}
+
 pub async fn is_merchant_eligible_authentication_service(
     merchant_id: &id_type::MerchantId,
     state: &SessionState,
@@ -7409,3 +8228,81 @@ pub async fn validate_allowed_payment_method_types_request(
 
     Ok(())
 }
+
+/// Rejects the payment method types requested for a payment which are not accepted by the profile
+#[cfg(feature = "v1")]
+pub fn validate_allowed_payment_method_types_for_profile(
+    business_profile: &domain::Profile,
+    allowed_payment_method_types: Option<&Vec<common_enums::PaymentMethodType>>,
+) -> RouterResult<()> {
+    let disallowed_payment_method_types = allowed_payment_method_types
+        .map(|allowed_payment_method_types| {
+            allowed_payment_method_types
+                .iter()
+                .filter(|payment_method_type| {
+                    !business_profile.is_payment_method_type_allowed(**payment_method_type)
+                })
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    fp_utils::when(!disallowed_payment_method_types.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payment method types {} are not allowed for the profile",
+                disallowed_payment_method_types.join(", ")
+            ),
+        }))
+    })
+}
+
+/// Payment method types a new payment is restricted to. The restriction of the profile is stored
+/// on the payment, so that changing the restriction later does not affect payments in progress.
+#[cfg(feature = "v1")]
+pub fn get_allowed_payment_method_types_for_payment(
+    business_profile: &domain::Profile,
+    allowed_payment_method_types: Option<&Vec<common_enums::PaymentMethodType>>,
+) -> RouterResult<Option<serde_json::Value>> {
+    allowed_payment_method_types
+        .or(business_profile
+            .allowed_payment_method_types
+            .as_ref()
+            .filter(|allowed_payment_method_types| !allowed_payment_method_types.is_empty()))
+        .map(Encode::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error converting allowed_payment_types to Value")
+}
+
+/// Rejects a payment method type which is not among the payment method types the payment is
+/// restricted to
+#[cfg(feature = "v1")]
+pub fn validate_payment_method_type_for_payment(
+    payment_intent: &PaymentIntent,
+    payment_method_type: Option<common_enums::PaymentMethodType>,
+) -> RouterResult<()> {
+    let allowed_payment_method_types = payment_intent
+        .allowed_payment_method_types
+        .clone()
+        .map(|allowed_payment_method_types| {
+            allowed_payment_method_types
+                .parse_value::<Vec<common_enums::PaymentMethodType>>("Vec<PaymentMethodType>")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize PaymentIntent allowed_payment_method_types")?;
+
+    match (allowed_payment_method_types, payment_method_type) {
+        (Some(allowed_payment_method_types), Some(payment_method_type))
+            if !allowed_payment_method_types.contains(&payment_method_type) =>
+        {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Payment method type {payment_method_type} is not allowed for this payment"
+                ),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
@@ -80,6 +80,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
                 enums::IntentStatus::Failed,
                 enums::IntentStatus::Succeeded,
                 enums::IntentStatus::Cancelled,
+                enums::IntentStatus::Expired,
                 enums::IntentStatus::Processing,
                 enums::IntentStatus::RequiresMerchantAction,
             ],
@@ -96,6 +97,30 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        if let Some(amount_to_void) = request.amount_to_void {
+            helpers::validate_amount_to_void(
+                payment_intent.status,
+                payment_attempt.amount_capturable,
+                amount_to_void,
+            )?;
+
+            let connector = payment_attempt
+                .connector
+                .as_ref()
+                .get_required_value("connector")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("'connector' not set in payment attempt")?
+                .parse::<api_models::enums::Connector>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse connector from payment attempt")?;
+
+            if !state.conf.partial_void.connector_list.contains(&connector) {
+                Err(errors::ApiErrorResponse::NotSupported {
+                    message: format!("Partial void is not supported by {connector}"),
+                })?
+            }
+        }
+
         let shipping_address = helpers::get_address_by_id(
             state,
             payment_intent.shipping_address_id.clone(),
@@ -213,6 +238,9 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
             service_details: None,
             card_testing_guard_data: None,
             vault_operation: None,
+            three_ds_challenge_flow: None,
+            amount_to_void: request.amount_to_void,
+            fx_details: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -247,6 +275,12 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelReques
     where
         F: 'b + Send,
     {
+        // The payment remains authorized after a partial void, its trackers are updated once the
+        // connector has released the amount
+        if payment_data.amount_to_void.is_some() {
+            return Ok((Box::new(self), payment_data));
+        }
+
         let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
         let (intent_status_update, attempt_status_update) =
             if payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture {
@@ -129,6 +129,12 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+            helpers::validate_amount_to_capture_against_previous_captures(
+                payment_attempt.get_total_amount(),
+                &previous_captures,
+                amount_to_capture,
+            )?;
+
             let capture = db
                 .insert_capture(
                     payment_attempt
@@ -262,6 +268,9 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
             service_details: None,
             card_testing_guard_data: None,
             vault_operation: None,
+            three_ds_challenge_flow: None,
+            amount_to_void: None,
+            fx_details: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
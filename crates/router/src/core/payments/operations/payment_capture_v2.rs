@@ -43,7 +43,8 @@ impl ValidateStatusForOperation for PaymentsCapture {
             | common_enums::IntentStatus::RequiresMerchantAction
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::RequiresConfirmation => {
+            | common_enums::IntentStatus::RequiresConfirmation
+            | common_enums::IntentStatus::Expired => {
                 Err(errors::ApiErrorResponse::PaymentUnexpectedState {
                     current_flow: format!("{self:?}"),
                     field_name: "status".to_string(),
@@ -356,6 +356,9 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             service_details: None,
             card_testing_guard_data: None,
             vault_operation: None,
+            three_ds_challenge_flow: None,
+            amount_to_void: None,
+            fx_details: None,
         };
 
         let customer_details = Some(CustomerDetails {
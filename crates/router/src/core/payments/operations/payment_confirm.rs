@@ -119,6 +119,16 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         helpers::validate_customer_access(&payment_intent, auth_flow, request)?;
 
+        // An expired payment can no longer be confirmed, a new payment has to be created instead
+        utils::when(
+            payment_intent.status == storage_enums::IntentStatus::Expired,
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "You cannot confirm this payment because it has expired, create a new payment instead".to_string(),
+                })
+            },
+        )?;
+
         if [
             Some(common_enums::PaymentSource::Webhook),
             Some(common_enums::PaymentSource::ExternalAuthenticator),
@@ -442,6 +452,11 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .map(|a| a.to_string())
             .or(payment_intent.return_url);
 
+        helpers::validate_allowed_payment_method_types_for_profile(
+            &business_profile,
+            request.allowed_payment_method_types.as_ref(),
+        )?;
+
         payment_intent.allowed_payment_method_types = request
             .get_allowed_payment_method_types_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -673,6 +688,11 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 .as_ref()
                 .and_then(|pm_info| pm_info.get_payment_method_subtype()));
 
+        helpers::validate_payment_method_type_for_payment(
+            &payment_intent,
+            payment_attempt.payment_method_type,
+        )?;
+
         // The operation merges mandate data from both request and payment_attempt
         let setup_mandate = mandate_data.map(|mut sm| {
             sm.mandate_type = payment_attempt.mandate_details.clone().or(sm.mandate_type);
@@ -828,6 +848,9 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             service_details: request.ctp_service_details.clone(),
             card_testing_guard_data: None,
             vault_operation: None,
+            three_ds_challenge_flow: None,
+            amount_to_void: None,
+            fx_details: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -190,6 +190,11 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         )
         .await?;
 
+        helpers::validate_allowed_payment_method_types_for_profile(
+            &business_profile,
+            request.allowed_payment_method_types.as_ref(),
+        )?;
+
         let customer_details = helpers::get_customer_details_from_request(request);
 
         let shipping_address = helpers::create_or_find_address_for_payment_by_request(
@@ -313,11 +318,17 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 .map(|address| address.address_id.clone()),
             attempt_id,
             profile_id.clone(),
+            &business_profile,
             session_expiry,
             platform_merchant_account,
         )
         .await?;
 
+        helpers::validate_payment_method_type_for_payment(
+            &payment_intent_new,
+            payment_method_type,
+        )?;
+
         let (payment_attempt_new, additional_payment_data) = Self::make_payment_attempt(
             &payment_id,
             merchant_id,
@@ -367,6 +378,32 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 payment_id: payment_id.clone(),
             })?;
 
+        #[cfg(feature = "v1")]
+        if let Some(capture_on) = payment_attempt
+            .capture_on
+            .filter(|_| request.capture_delay_in_hours.is_some())
+        {
+            payments::add_scheduled_capture_task(db, &payment_attempt, capture_on)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add scheduled capture task to process tracker")?;
+        }
+
+        #[cfg(feature = "v1")]
+        if !request.confirm.unwrap_or(false) {
+            if let Some(expiry) = helpers::get_merchant_payment_intent_expiry(db, merchant_id).await
+            {
+                payments::add_payment_intent_expiry_task(
+                    db,
+                    &payment_intent,
+                    payment_intent.created_at.saturating_add(expiry),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add payment intent expiry task to process tracker")?;
+            }
+        }
+
         #[cfg(feature = "v2")]
         let payment_attempt = db
             .insert_payment_attempt(
@@ -628,6 +665,9 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             service_details: None,
             card_testing_guard_data: None,
             vault_operation: None,
+            three_ds_challenge_flow: None,
+            amount_to_void: None,
+            fx_details: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -967,6 +1007,7 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
         merchant_account: &'a domain::MerchantAccount,
     ) -> RouterResult<(PaymentCreateOperation<'b, F>, operations::ValidateResult)> {
         helpers::validate_customer_information(request)?;
+        helpers::validate_statement_descriptors(request)?;
 
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
@@ -1019,17 +1060,21 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
         )?;
 
         if request.confirm.unwrap_or(false) {
-            helpers::validate_pm_or_token_given(
-                &request.payment_method,
-                &request
-                    .payment_method_data
-                    .as_ref()
-                    .and_then(|pmd| pmd.payment_method_data.clone()),
-                &request.payment_method_type,
-                &mandate_type,
-                &request.payment_token,
-                &request.ctp_service_details,
-            )?;
+            // The token of the default payment method of the customer is created while fetching
+            // the trackers
+            if request.use_default_payment_method != Some(true) {
+                helpers::validate_pm_or_token_given(
+                    &request.payment_method,
+                    &request
+                        .payment_method_data
+                        .as_ref()
+                        .and_then(|pmd| pmd.payment_method_data.clone()),
+                    &request.payment_method_type,
+                    &mandate_type,
+                    &request.payment_token,
+                    &request.ctp_service_details,
+                )?;
+            }
 
             helpers::validate_customer_id_mandatory_cases(
                 request.setup_future_usage.is_some(),
@@ -1137,6 +1182,11 @@ impl PaymentCreate {
 
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
         let status = helpers::payment_attempt_status_fsm(payment_method_data, request.confirm);
+        let capture_on = created_at
+            .map(|created_at| helpers::get_delayed_capture_time(state, request, created_at))
+            .transpose()?
+            .flatten()
+            .or(request.capture_on);
         let (amount, currency) = (money.0, Some(money.1));
 
         let mut additional_pm_data = request
@@ -1298,7 +1348,7 @@ impl PaymentCreate {
                 currency,
                 payment_method,
                 capture_method: request.capture_method,
-                capture_on: request.capture_on,
+                capture_on,
                 confirm: request.confirm.unwrap_or(false),
                 created_at,
                 modified_at,
@@ -1384,6 +1434,7 @@ impl PaymentCreate {
         billing_address_id: Option<String>,
         active_attempt_id: String,
         profile_id: common_utils::id_type::ProfileId,
+        business_profile: &domain::Profile,
         session_expiry: PrimitiveDateTime,
         platform_merchant_account: Option<&domain::MerchantAccount>,
     ) -> RouterResult<storage::PaymentIntent> {
@@ -1406,10 +1457,10 @@ impl PaymentCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to convert order details to value")?;
 
-        let allowed_payment_method_types = request
-            .get_allowed_payment_method_types_as_value()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Error converting allowed_payment_types to Value")?;
+        let allowed_payment_method_types = helpers::get_allowed_payment_method_types_for_payment(
+            business_profile,
+            request.allowed_payment_method_types.as_ref(),
+        )?;
 
         let connector_metadata = request
             .get_connector_metadata_as_value()
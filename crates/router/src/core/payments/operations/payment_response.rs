@@ -24,10 +24,14 @@ use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
+#[cfg(feature = "v1")]
+use crate::core::payments::status_transitions;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
 #[cfg(feature = "v2")]
 use crate::utils::OptionExt;
+#[cfg(feature = "v1")]
+use crate::workflows::payment_intent_expiry;
 use crate::{
     connector::utils::PaymentResponseRouterData,
     consts,
@@ -37,6 +41,7 @@ use crate::{
         mandate,
         payment_methods::{self, cards::create_encrypted_data},
         payments::{
+            self,
             helpers::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
@@ -887,6 +892,29 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
         ))
         .await?;
 
+        // The scheduled capture is revoked only once the payment is captured, so that it still
+        // captures the payment if this capture fails. A scheduled capture which could not be
+        // revoked skips the payment, as it is no longer authorized.
+        if payment_data.payment_attempt.capture_on.is_some()
+            && matches!(
+                payment_data.payment_attempt.status,
+                enums::AttemptStatus::Charged
+                    | enums::AttemptStatus::PartialCharged
+                    | enums::AttemptStatus::PartialChargedAndChargeable
+                    | enums::AttemptStatus::CaptureInitiated
+            )
+        {
+            payments::revoke_scheduled_capture_task(&*db.store, &payment_data.payment_attempt)
+                .await
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to revoke scheduled capture task in process tracker"
+                    )
+                })
+                .ok();
+        }
+
         Ok(payment_data)
     }
 }
@@ -910,6 +938,17 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
     where
         F: 'b + Send,
     {
+        if let Some(amount_to_void) = router_data.request.minor_amount_to_void {
+            return partial_void_update_tracker(
+                db,
+                payment_data,
+                router_data,
+                amount_to_void,
+                storage_scheme,
+            )
+            .await;
+        }
+
         payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_data,
@@ -928,6 +967,71 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
     }
 }
 
+/// Releases the voided amount from the amount which can still be captured, the payment remains
+/// authorized for the rest of the amount. The amount to capture is lowered as well, so that a
+/// capture without an amount does not ask for more than is still authorized. A void which is yet
+/// to be confirmed by the connector releases the amount all the same, as the funds may have been
+/// released already, and its status is persisted for the payment to be synced. The payment is left
+/// untouched if the connector did not void the amount.
+#[cfg(feature = "v1")]
+async fn partial_void_update_tracker<F: Clone>(
+    state: &SessionState,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, types::PaymentsCancelData, types::PaymentsResponseData>,
+    amount_to_void: MinorUnit,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    if let Err(err) = router_data.response {
+        return Err(report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: router_data.connector,
+            status_code: err.status_code,
+            reason: err.reason,
+        }))
+        .attach_printable("Partial void failed at the connector");
+    }
+
+    let status = match router_data.status {
+        enums::AttemptStatus::Voided => payment_data.payment_attempt.status,
+        enums::AttemptStatus::Pending | enums::AttemptStatus::VoidInitiated => {
+            enums::AttemptStatus::VoidInitiated
+        }
+        status => {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+                format!(
+                    "Unexpected status {status} received from the connector for a partial void"
+                ),
+            );
+        }
+    };
+
+    let amount_capturable = payment_data.payment_attempt.amount_capturable - amount_to_void;
+    let amount_to_capture = payment_data
+        .payment_attempt
+        .amount_to_capture
+        .filter(|amount_to_capture| *amount_to_capture < amount_capturable)
+        .unwrap_or(amount_capturable);
+    let payment_attempt_update = storage::PaymentAttemptUpdate::AmountToCaptureUpdate {
+        status,
+        amount_capturable,
+        amount_to_capture: Some(amount_to_capture),
+        updated_by: storage_scheme.to_string(),
+    };
+
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            payment_attempt_update,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(payment_data)
+}
+
 #[cfg(feature = "v1")]
 #[async_trait]
 impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsApproveData>
@@ -1270,6 +1374,9 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     // This is for details like whether 3ds was upgraded and which version of 3ds was used
     // also some connectors might send card network details in the response, which is captured and stored
 
+    let previous_attempt_status = payment_data.payment_attempt.status;
+    let previous_intent_status = payment_data.payment_intent.status;
+
     let additional_payment_method_data = match payment_data.payment_method_data.clone() {
         Some(payment_method_data) => match payment_method_data {
             hyperswitch_domain_models::payment_method_data::PaymentMethodData::Card(_)
@@ -1418,6 +1525,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             }
                         }
                     };
+                    let status = status_transitions::get_attempt_status_after_transition(
+                        payment_data.payment_attempt.status,
+                        status,
+                        &router_data.connector,
+                    );
                     (
                         None,
                         Some(storage::PaymentAttemptUpdate::ErrorUpdate {
@@ -1460,7 +1572,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         None,
                         Some(storage::PaymentAttemptUpdate::ErrorUpdate {
                             connector: None,
-                            status: enums::AttemptStatus::Pending,
+                            status: status_transitions::get_attempt_status_after_transition(
+                                payment_data.payment_attempt.status,
+                                enums::AttemptStatus::Pending,
+                                &router_data.connector,
+                            ),
                             error_message: Some(Some("Integrity Check Failed!".to_string())),
                             error_code: Some(Some("IE".to_string())),
                             error_reason: Some(Some(format!(
@@ -1495,6 +1611,12 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         },
                         _ => router_data.get_attempt_status_for_db_update(&payment_data),
                     };
+                    let updated_attempt_status =
+                        status_transitions::get_attempt_status_after_transition(
+                            attempt_status,
+                            updated_attempt_status,
+                            &router_data.connector,
+                        );
                     match payments_response {
                         types::PaymentsResponseData::PreProcessingResponse {
                             pre_processing_id,
@@ -1556,6 +1678,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             };
 
                             let encoded_data = payment_data.payment_attempt.encoded_data.clone();
+                            let charges = charges.or_else(|| {
+                                core_utils::get_recorded_split_payments(
+                                    payment_data.payment_intent.split_payments.as_ref(),
+                                )
+                            });
 
                             let authentication_data = (*redirection_data)
                                 .as_ref()
@@ -1810,6 +1937,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                 status: multiple_capture_data.get_attempt_status(authorized_amount),
                 amount_capturable: authorized_amount
                     - multiple_capture_data.get_total_blocked_amount(),
+                amount_to_capture: None,
                 updated_by: storage_scheme.to_string(),
             });
             Some(multiple_capture_data)
@@ -2050,6 +2178,50 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             .map(|info| info.status = status)
     });
 
+    let is_authorized = |status: enums::AttemptStatus| {
+        matches!(
+            status,
+            enums::AttemptStatus::Authorized
+                | enums::AttemptStatus::Charged
+                | enums::AttemptStatus::PartialCharged
+                | enums::AttemptStatus::PartialChargedAndChargeable
+        )
+    };
+    if is_authorized(payment_data.payment_attempt.status) && !is_authorized(previous_attempt_status)
+    {
+        crate::core::payments::fx_details::spawn_record_payment_fx_details(
+            state,
+            &payment_data.payment_attempt,
+        );
+    }
+
+    // A payment which is awaiting the customer again after a failed confirmation expires the same
+    // way as a payment which has not been confirmed yet
+    if payment_intent_expiry::is_payment_intent_expirable(payment_data.payment_intent.status)
+        && !payment_intent_expiry::is_payment_intent_expirable(previous_intent_status)
+    {
+        if let Some(expiry) = payments_helpers::get_merchant_payment_intent_expiry(
+            &*state.store,
+            &payment_data.payment_intent.merchant_id,
+        )
+        .await
+        {
+            payments::add_payment_intent_expiry_task(
+                &*state.store,
+                &payment_data.payment_intent,
+                common_utils::date_time::now().saturating_add(expiry),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to add payment intent expiry task to process tracker"
+                )
+            })
+            .ok();
+        }
+    }
+
     if payment_data.payment_attempt.status == enums::AttemptStatus::Failure {
         let _ = card_testing_guard_utils::increment_blocked_count_in_cache(
             state,
@@ -403,6 +403,19 @@ async fn get_tracker_for_sync<
         None
     };
 
+    // The fx details are only informational, and are recorded asynchronously once the payment is
+    // authorized, hence the payment is retrieved without them if they could not be fetched
+    let fx_details = db
+        .find_payment_fx_details_by_merchant_id_payment_id(merchant_account.get_id(), &payment_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to retrieve the fx details of the payment"))
+        .ok()
+        .and_then(|fx_details| {
+            fx_details
+                .into_iter()
+                .find(|fx_details| fx_details.attempt_id == payment_attempt.attempt_id)
+        });
+
     let contains_encoded_data = payment_attempt.encoded_data.is_some();
 
     let creds_identifier = request
@@ -537,6 +550,9 @@ async fn get_tracker_for_sync<
         service_details: None,
         card_testing_guard_data: None,
         vault_operation: None,
+        three_ds_challenge_flow: None,
+        amount_to_void: None,
+        fx_details,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
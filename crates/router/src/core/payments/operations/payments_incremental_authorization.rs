@@ -105,14 +105,69 @@ impl<F: Send + Clone + Sync>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        let connector = payment_attempt
+            .connector
+            .as_ref()
+            .get_required_value("connector")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("'connector' not set in payment attempt")?
+            .parse::<api_models::enums::Connector>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse connector from payment attempt")?;
+
+        let incremental_authorization_config = &state.conf.incremental_authorization;
+        if !incremental_authorization_config
+            .connector_list
+            .contains(&connector)
+        {
+            Err(errors::ApiErrorResponse::NotSupported {
+                message: format!("Incremental authorization is not supported by {connector}"),
+            })?
+        }
+
         // Incremental authorization should be performed on an amount greater than the original authorized amount (in this case, greater than the net_amount which is sent for authorization)
         // request.amount is the total amount that should be authorized in incremental authorization which should be greater than the original authorized amount
-        if payment_attempt.get_total_amount() > request.amount {
+        if payment_attempt.get_total_amount() >= request.amount {
             Err(errors::ApiErrorResponse::PreconditionFailed {
                 message: "Amount should be greater than original authorized amount".to_owned(),
             })?
         }
 
+        if let Some(max_authorized_amount_percentage) = incremental_authorization_config
+            .max_authorized_amount_percentage
+            .get(&connector)
+        {
+            // The ceiling is computed on the amount of the first authorization, since the
+            // attempt amount is bumped after every successful increment
+            let original_authorized_amount = db
+                .find_all_authorizations_by_merchant_id_payment_id(
+                    merchant_id,
+                    &payment_intent.payment_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch authorizations for the payment")?
+                .into_iter()
+                .min_by_key(|authorization| authorization.created_at)
+                .map(|authorization| authorization.previously_authorized_amount)
+                .unwrap_or(payment_attempt.get_total_amount());
+
+            let max_authorized_amount = original_authorized_amount
+                .get_amount_as_i64()
+                .checked_mul(i64::from(*max_authorized_amount_percentage))
+                .map(|amount| amount / 100)
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Overflow while computing the maximum authorized amount")?;
+
+            if request.amount.get_amount_as_i64() > max_authorized_amount {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "Amount exceeds the maximum amount of {max_authorized_amount} that can be authorized with {connector}"
+                    ),
+                })?
+            }
+        }
+
         let currency = payment_attempt.currency.get_required_value("currency")?;
         let amount = payment_attempt.get_total_amount();
 
@@ -179,6 +234,9 @@ impl<F: Send + Clone + Sync>
             service_details: None,
             card_testing_guard_data: None,
             vault_operation: None,
+            three_ds_challenge_flow: None,
+            amount_to_void: None,
+            fx_details: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
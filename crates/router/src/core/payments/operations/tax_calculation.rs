@@ -185,6 +185,9 @@ impl<F: Send + Clone + Sync>
             service_details: None,
             card_testing_guard_data: None,
             vault_operation: None,
+            three_ds_challenge_flow: None,
+            amount_to_void: None,
+            fx_details: None,
         };
         let get_trackers_response = operations::GetTrackerResponse {
             operation: Box::new(self),
@@ -9,6 +9,7 @@ use router_env::{
 };
 
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{
@@ -34,7 +35,7 @@ pub async fn do_gsm_actions<F, ApiRequest, FData, D>(
     state: &app::SessionState,
     req_state: ReqState,
     payment_data: &mut D,
-    mut connectors: IntoIter<api::ConnectorData>,
+    connectors: IntoIter<api::ConnectorData>,
     original_connector_data: &api::ConnectorData,
     mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
     merchant_account: &domain::MerchantAccount,
@@ -63,6 +64,9 @@ where
 
     metrics::AUTO_RETRY_ELIGIBLE_REQUEST_COUNT.add(1, &[]);
 
+    let mut connectors =
+        get_failover_connectors(state, merchant_account.get_id(), connectors).await;
+
     let mut initial_gsm = get_gsm(state, &router_data).await?;
 
     //Check if step-up to threeDS is possible and merchant has enabled
@@ -83,6 +87,8 @@ where
         storage_enums::AuthenticationType::NoThreeDs
     );
 
+    // An issuer rejecting the requested SCA exemption is stepped up to 3DS only when the merchant
+    // has enabled step up for the connector
     let should_step_up = if step_up_possible && is_no_three_ds_payment {
         is_step_up_enabled_for_merchant_connector(
             state,
@@ -95,6 +101,8 @@ where
     };
 
     if should_step_up {
+        // The exemption is not requested again when retrying with 3DS
+        payment_data.set_psd2_sca_exemption_type_in_payment_intent(None);
         router_data = do_retry(
             &state.clone(),
             req_state.clone(),
@@ -123,7 +131,7 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
-            match get_gsm_decision(gsm) {
+            match get_gsm_decision(gsm.clone()) {
                 api_models::gsm::GsmDecision::Retry => {
                     retries =
                         get_retries(state, retries, merchant_account.get_id(), business_profile)
@@ -157,7 +165,45 @@ where
                         // If should_retry_with_pan is true, it indicates that we are retrying with PAN using the same connector.
                         original_connector_data.clone()
                     } else {
-                        super::get_connector_data(&mut connectors)?
+                        if let Some(skip_reason) = get_connector_failover_skip_reason(
+                            gsm.as_ref(),
+                            router_data.response.as_ref().err(),
+                        ) {
+                            logger::info!(
+                                "skipping connector failover for auto_retry payment: {skip_reason}"
+                            );
+                            add_connector_failover_reason(
+                                &mut router_data,
+                                &format!("connector failover skipped: {skip_reason}"),
+                            );
+                            metrics::AUTO_RETRY_CONNECTOR_FAILOVER_SKIPPED_COUNT.add(
+                                1,
+                                router_env::metric_attributes!(
+                                    ("connector", router_data.connector.clone()),
+                                    ("reason", skip_reason.to_string()),
+                                ),
+                            );
+                            break;
+                        }
+
+                        let next_connector = super::get_connector_data(&mut connectors)?;
+                        logger::info!(
+                            "failing over auto_retry payment from {} to {}",
+                            router_data.connector,
+                            next_connector.connector_name
+                        );
+                        metrics::AUTO_RETRY_CONNECTOR_FAILOVER_COUNT.add(
+                            1,
+                            router_env::metric_attributes!(
+                                ("from_connector", router_data.connector.clone()),
+                                ("to_connector", next_connector.connector_name.to_string()),
+                            ),
+                        );
+                        add_connector_failover_reason(
+                            &mut router_data,
+                            &format!("failed over to {}", next_connector.connector_name),
+                        );
+                        next_connector
                     };
 
                     router_data = do_retry(
@@ -221,6 +267,119 @@ pub async fn is_step_up_enabled_for_merchant_connector(
         .unwrap_or(false)
 }
 
+/// Orders the connectors to which a declined payment fails over as per the failover chain
+/// configured for the merchant. Connectors which are not part of the chain are not failed over to.
+/// The connectors are failed over to in the order of the routing output if no chain is configured.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn get_failover_connectors(
+    state: &app::SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connectors: IntoIter<api::ConnectorData>,
+) -> IntoIter<api::ConnectorData> {
+    let key = merchant_id.get_auto_retry_failover_chain_key();
+    let failover_chain = state
+        .store
+        .find_config_by_key(key.as_str())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|failover_chain_config| {
+            serde_json::from_str::<Vec<types::Connector>>(&failover_chain_config.config)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failover chain config parsing failed")
+        })
+        .map_err(|err| {
+            logger::debug!(failover_chain_error=?err);
+        })
+        .ok();
+
+    match failover_chain {
+        Some(failover_chain) => {
+            let mut connectors = connectors
+                .filter(|connector| failover_chain.contains(&connector.connector_name))
+                .collect::<Vec<_>>();
+            connectors.sort_by_key(|connector| {
+                failover_chain
+                    .iter()
+                    .position(|chain_connector| chain_connector == &connector.connector_name)
+            });
+            connectors.into_iter()
+        }
+        None => connectors,
+    }
+}
+
+/// Reasons for which a declined payment is not failed over to another connector
+#[derive(Debug, Clone, Copy, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorFailoverSkipReason {
+    /// The payment method would be declined by any connector, for example a stolen card
+    HardDecline,
+    /// The connector may have processed the payment, so failing over could charge the customer
+    /// twice
+    UnknownOutcome,
+}
+
+pub fn get_connector_failover_skip_reason(
+    gsm: Option<&storage::gsm::GatewayStatusMap>,
+    error_response: Option<&types::ErrorResponse>,
+) -> Option<ConnectorFailoverSkipReason> {
+    let is_hard_decline = gsm
+        .and_then(|gsm| gsm.error_category)
+        .is_some_and(|error_category| {
+            matches!(
+                error_category,
+                storage_enums::ErrorCategory::FrmDecline
+                    | storage_enums::ErrorCategory::IssueWithPaymentMethod
+            )
+        });
+
+    let is_outcome_unknown = error_response.is_some_and(|error| {
+        error.code == consts::REQUEST_TIMEOUT_ERROR_CODE
+            || error.attempt_status.is_some_and(|attempt_status| {
+                !matches!(
+                    attempt_status,
+                    storage_enums::AttemptStatus::Failure
+                        | storage_enums::AttemptStatus::AuthorizationFailed
+                        | storage_enums::AttemptStatus::AuthenticationFailed
+                        | storage_enums::AttemptStatus::RouterDeclined
+                )
+            })
+    });
+
+    if is_hard_decline {
+        Some(ConnectorFailoverSkipReason::HardDecline)
+    } else if is_outcome_unknown {
+        Some(ConnectorFailoverSkipReason::UnknownOutcome)
+    } else {
+        None
+    }
+}
+
+/// Records the outcome of the connector failover in the error reason of the declined attempt, so
+/// that it is persisted along with the attempt
+fn add_connector_failover_reason<F, FData>(
+    router_data: &mut types::RouterData<F, FData, types::PaymentsResponseData>,
+    failover_reason: &str,
+) {
+    if let Err(error_response) = router_data.response.as_mut() {
+        error_response.reason = Some(get_error_reason_with_failover_reason(
+            error_response.reason.take(),
+            failover_reason,
+        ));
+    }
+}
+
+fn get_error_reason_with_failover_reason(
+    error_reason: Option<String>,
+    failover_reason: &str,
+) -> String {
+    match error_reason {
+        Some(error_reason) => format!("{error_reason}; {failover_reason}"),
+        None => failover_reason.to_string(),
+    }
+}
+
 #[cfg(feature = "v1")]
 pub async fn get_merchant_max_auto_retries_enabled(
     db: &dyn StorageInterface,
@@ -767,3 +926,81 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_gsm(
+        error_category: Option<storage_enums::ErrorCategory>,
+    ) -> storage::gsm::GatewayStatusMap {
+        let now = common_utils::date_time::now();
+        storage::gsm::GatewayStatusMap {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: "card_declined".to_string(),
+            message: "Your card was declined".to_string(),
+            status: "Failure".to_string(),
+            router_error: None,
+            decision: "retry".to_string(),
+            created_at: now,
+            last_modified: now,
+            step_up_possible: false,
+            unified_code: None,
+            unified_message: None,
+            error_category,
+            clear_pan_possible: false,
+        }
+    }
+
+    #[test]
+    fn test_connector_failover_is_skipped_on_hard_declines() {
+        let gsm = get_gsm(Some(storage_enums::ErrorCategory::IssueWithPaymentMethod));
+
+        assert!(matches!(
+            get_connector_failover_skip_reason(Some(&gsm), Some(&types::ErrorResponse::default())),
+            Some(ConnectorFailoverSkipReason::HardDecline)
+        ));
+    }
+
+    #[test]
+    fn test_connector_failover_is_skipped_when_the_outcome_is_unknown() {
+        let gsm = get_gsm(None);
+        let error_response = types::ErrorResponse {
+            code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            get_connector_failover_skip_reason(Some(&gsm), Some(&error_response)),
+            Some(ConnectorFailoverSkipReason::UnknownOutcome)
+        ));
+    }
+
+    #[test]
+    fn test_connector_failover_of_soft_declines() {
+        let gsm = get_gsm(None);
+        let error_response = types::ErrorResponse {
+            attempt_status: Some(storage_enums::AttemptStatus::Failure),
+            ..Default::default()
+        };
+
+        assert!(get_connector_failover_skip_reason(Some(&gsm), Some(&error_response)).is_none());
+    }
+
+    #[test]
+    fn test_error_reason_with_failover_reason() {
+        assert_eq!(
+            get_error_reason_with_failover_reason(
+                Some("Insufficient funds".to_string()),
+                "failed over to adyen"
+            ),
+            "Insufficient funds; failed over to adyen"
+        );
+        assert_eq!(
+            get_error_reason_with_failover_reason(None, "connector failover skipped: hard_decline"),
+            "connector failover skipped: hard_decline"
+        );
+    }
+}
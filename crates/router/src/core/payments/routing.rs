@@ -52,7 +52,7 @@ use crate::core::admin;
 use crate::core::payouts;
 use crate::{
     core::{
-        errors, errors as oss_errors, payments as payments_oss,
+        connector_latency, errors, errors as oss_errors, payments as payments_oss,
         routing::{self},
     },
     logger,
@@ -70,6 +70,7 @@ pub enum CachedAlgorithm {
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    LowestLatency(Vec<routing_types::RoutableConnectorChoice>),
 }
 
 pub struct SessionFlowRoutingInput<'a> {
@@ -449,6 +450,10 @@ pub async fn perform_static_routing_v1(
 
             execute_dsl_and_get_connector_v1(backend_input, interpreter)?
         }
+
+        CachedAlgorithm::LowestLatency(plist) => {
+            connector_latency::order_connectors_by_latency(state, plist.clone()).await
+        }
     })
 }
 
@@ -583,6 +588,9 @@ pub async fn refresh_routing_cache_v1(
 
             CachedAlgorithm::Advanced(interpreter)
         }
+        routing_types::RoutingAlgorithm::LowestLatency(plist) => {
+            CachedAlgorithm::LowestLatency(plist)
+        }
     };
 
     let arc_cached_algorithm = Arc::new(cached_algorithm);
@@ -1202,6 +1210,13 @@ async fn perform_session_routing_for_pm_type(
                 session_pm_input.backend_input.clone(),
                 interpreter,
             )?,
+            CachedAlgorithm::LowestLatency(plist) => {
+                connector_latency::order_connectors_by_latency(
+                    session_pm_input.state,
+                    plist.clone(),
+                )
+                .await
+            }
         }
     } else {
         routing::helpers::get_merchant_default_config(
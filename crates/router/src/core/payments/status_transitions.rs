@@ -0,0 +1,188 @@
+use common_enums::AttemptStatus;
+
+use crate::{logger, routes::metrics};
+
+/// Whether an attempt in the `current` status is allowed to move to the `next` status. This is the
+/// single table of the legal status transitions of an attempt, any transition not listed here is
+/// illegal. Moving to the same status is always legal.
+pub fn is_valid_attempt_status_transition(current: AttemptStatus, next: AttemptStatus) -> bool {
+    if current == next {
+        return true;
+    }
+
+    // The match is exhaustive so that a new status has to be placed in the table explicitly
+    match current {
+        // Nothing has been authorized yet, the connector can move the attempt anywhere
+        AttemptStatus::Started
+        | AttemptStatus::AuthenticationPending
+        | AttemptStatus::AuthenticationSuccessful
+        | AttemptStatus::Authorizing
+        | AttemptStatus::CodInitiated
+        | AttemptStatus::PaymentMethodAwaited
+        | AttemptStatus::ConfirmationAwaited
+        | AttemptStatus::DeviceDataCollectionPending
+        | AttemptStatus::Unresolved
+        | AttemptStatus::Pending => true,
+        // A declined attempt can still be reported as successful by the connector later on, but
+        // it never goes back to an intermediate status
+        AttemptStatus::AuthenticationFailed
+        | AttemptStatus::AuthorizationFailed
+        | AttemptStatus::RouterDeclined
+        | AttemptStatus::Failure => matches!(
+            next,
+            AttemptStatus::AuthenticationFailed
+                | AttemptStatus::AuthorizationFailed
+                | AttemptStatus::RouterDeclined
+                | AttemptStatus::Failure
+                | AttemptStatus::Authorized
+                | AttemptStatus::Charged
+                | AttemptStatus::PartialCharged
+                | AttemptStatus::PartialChargedAndChargeable
+        ),
+        // Funds are authorized, the attempt can only be captured, voided or failed from here
+        AttemptStatus::Authorized
+        | AttemptStatus::CaptureInitiated
+        | AttemptStatus::CaptureFailed
+        | AttemptStatus::VoidFailed
+        | AttemptStatus::PartialChargedAndChargeable => matches!(
+            next,
+            AttemptStatus::Authorized
+                | AttemptStatus::Charged
+                | AttemptStatus::PartialCharged
+                | AttemptStatus::PartialChargedAndChargeable
+                | AttemptStatus::CaptureInitiated
+                | AttemptStatus::CaptureFailed
+                | AttemptStatus::VoidInitiated
+                | AttemptStatus::Voided
+                | AttemptStatus::VoidFailed
+                | AttemptStatus::AutoRefunded
+                | AttemptStatus::Pending
+                | AttemptStatus::Failure
+        ),
+        AttemptStatus::VoidInitiated => matches!(
+            next,
+            AttemptStatus::Authorized
+                | AttemptStatus::Voided
+                | AttemptStatus::VoidFailed
+                | AttemptStatus::Pending
+                | AttemptStatus::Failure
+        ),
+        AttemptStatus::PartialCharged => {
+            matches!(next, AttemptStatus::Charged | AttemptStatus::AutoRefunded)
+        }
+        AttemptStatus::Charged => next == AttemptStatus::AutoRefunded,
+        AttemptStatus::Voided | AttemptStatus::AutoRefunded => false,
+    }
+}
+
+/// Status to be stored for an attempt when the connector reports the `next` status. Illegal
+/// transitions, usually caused by webhooks arriving out of order, are ignored and the attempt
+/// keeps its `current` status.
+pub fn get_attempt_status_after_transition(
+    current: AttemptStatus,
+    next: AttemptStatus,
+    connector: &str,
+) -> AttemptStatus {
+    if is_valid_attempt_status_transition(current, next) {
+        next
+    } else {
+        logger::warn!(
+            "Ignoring illegal attempt status transition from {current} to {next} reported by {connector}"
+        );
+        metrics::ILLEGAL_PAYMENT_STATUS_TRANSITION.add(
+            1,
+            router_env::metric_attributes!(
+                ("connector", connector.to_string()),
+                ("current_status", current.to_string()),
+                ("next_status", next.to_string()),
+            ),
+        );
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONNECTOR: &str = "stripe";
+
+    #[test]
+    fn test_late_pending_webhook_does_not_move_charged_attempt_back() {
+        // A PSync or webhook reporting the payment as pending after it was charged
+        assert_eq!(
+            get_attempt_status_after_transition(
+                AttemptStatus::Charged,
+                AttemptStatus::Pending,
+                CONNECTOR
+            ),
+            AttemptStatus::Charged
+        );
+    }
+
+    #[test]
+    fn test_late_failure_does_not_fail_charged_attempt() {
+        // A capture erroring out with a 4xx on an attempt which was charged in the meantime
+        assert_eq!(
+            get_attempt_status_after_transition(
+                AttemptStatus::Charged,
+                AttemptStatus::Failure,
+                CONNECTOR
+            ),
+            AttemptStatus::Charged
+        );
+    }
+
+    #[test]
+    fn test_late_authorization_does_not_revive_voided_attempt() {
+        assert_eq!(
+            get_attempt_status_after_transition(
+                AttemptStatus::Voided,
+                AttemptStatus::Authorized,
+                CONNECTOR
+            ),
+            AttemptStatus::Voided
+        );
+    }
+
+    #[test]
+    fn test_declined_attempt_does_not_move_back_to_pending() {
+        // A 5xx error response is stored as pending, which must not override an earlier decline
+        assert_eq!(
+            get_attempt_status_after_transition(
+                AttemptStatus::AuthorizationFailed,
+                AttemptStatus::Pending,
+                CONNECTOR
+            ),
+            AttemptStatus::AuthorizationFailed
+        );
+    }
+
+    #[test]
+    fn test_legal_transitions_are_applied() {
+        assert_eq!(
+            get_attempt_status_after_transition(
+                AttemptStatus::Pending,
+                AttemptStatus::Charged,
+                CONNECTOR
+            ),
+            AttemptStatus::Charged
+        );
+        assert_eq!(
+            get_attempt_status_after_transition(
+                AttemptStatus::Failure,
+                AttemptStatus::Charged,
+                CONNECTOR
+            ),
+            AttemptStatus::Charged
+        );
+        assert_eq!(
+            get_attempt_status_after_transition(
+                AttemptStatus::Charged,
+                AttemptStatus::AutoRefunded,
+                CONNECTOR
+            ),
+            AttemptStatus::AutoRefunded
+        );
+    }
+}
@@ -0,0 +1,391 @@
+use analytics::api_event::{api_events_core, events::ApiLogsResult};
+use api_models::{
+    analytics::api_event::{ApiLogsRequest, QueryType},
+    payments::{
+        PaymentTimelineEvent, PaymentTimelineEventType, PaymentsTimelineRequest,
+        PaymentsTimelineResponse,
+    },
+};
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+        utils as core_utils,
+    },
+    logger,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+};
+
+/// A connector call stored as a raw connector payload. Only the fields which do not carry the
+/// request or the response body are read.
+#[derive(Debug, serde::Deserialize)]
+struct ConnectorCallDetails {
+    connector_name: String,
+    flow: String,
+    /// Unix timestamp in milliseconds
+    created_at: i128,
+    status_code: u16,
+    latency: u64,
+}
+
+/// Flows of the payment API events whose response carries the status of the payment
+const PAYMENT_STATUS_FLOWS: [Flow; 6] = [
+    Flow::PaymentsCreate,
+    Flow::PaymentsUpdate,
+    Flow::PaymentsConfirm,
+    Flow::PaymentsStart,
+    Flow::PaymentsCapture,
+    Flow::PaymentsCancel,
+];
+
+/// The status in the response of a payment API event, the rest of the response is not read
+#[derive(Debug, serde::Deserialize)]
+struct PaymentStatusResponse {
+    status: String,
+}
+
+/// Builds the chronological timeline of a payment from its attempts, the connector calls stored for
+/// it, the API events and incoming webhooks recorded for it and the outgoing webhooks sent for it.
+/// Request and response bodies are not part of the timeline, so that no sensitive data is exposed.
+#[instrument(skip_all)]
+pub async fn get_payment_timeline(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    req: PaymentsTimelineRequest,
+) -> RouterResponse<PaymentsTimelineResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &req.payment_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(
+            merchant_id,
+            &payment_intent.payment_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+
+    let mut events = vec![PaymentTimelineEvent::new(
+        PaymentTimelineEventType::PaymentCreated,
+        payment_intent.created_at,
+    )];
+    events.extend(payment_attempts.iter().flat_map(get_attempt_events));
+    events.extend(get_connector_call_events(&state, &merchant_account, &payment_intent).await);
+    events.extend(get_api_events(&state, &merchant_account, &payment_intent).await);
+    events.extend(
+        get_outgoing_webhook_events(&state, &merchant_account, &key_store, &payment_intent).await?,
+    );
+
+    // The sort is stable, so events occurring at the same time retain the order above
+    events.sort_by_key(|event| event.created_at);
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsTimelineResponse {
+            payment_id: payment_intent.payment_id,
+            events,
+        },
+    ))
+}
+
+fn get_attempt_events(payment_attempt: &storage::PaymentAttempt) -> [PaymentTimelineEvent; 2] {
+    [
+        PaymentTimelineEvent {
+            attempt_id: Some(payment_attempt.attempt_id.clone()),
+            connector: payment_attempt.connector.clone(),
+            ..PaymentTimelineEvent::new(
+                PaymentTimelineEventType::AttemptCreated,
+                payment_attempt.created_at,
+            )
+        },
+        PaymentTimelineEvent {
+            attempt_id: Some(payment_attempt.attempt_id.clone()),
+            connector: payment_attempt.connector.clone(),
+            status: Some(payment_attempt.status.to_string()),
+            error_code: payment_attempt.error_code.clone(),
+            error_message: payment_attempt.error_message.clone(),
+            ..PaymentTimelineEvent::new(
+                PaymentTimelineEventType::AttemptStatusUpdated,
+                payment_attempt.modified_at,
+            )
+        },
+    ]
+}
+
+/// Connector calls are only available for merchants who store the raw connector payloads of their
+/// payments, and only until the stored payloads expire
+async fn get_connector_call_events(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+) -> Vec<PaymentTimelineEvent> {
+    let payloads = match helpers::get_raw_connector_payloads(
+        state,
+        merchant_account.get_id(),
+        payment_intent.payment_id.get_string_repr(),
+    )
+    .await
+    {
+        Ok(payloads) => payloads,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the connector calls of the payment");
+            return Vec::new();
+        }
+    };
+
+    payloads
+        .into_iter()
+        .filter_map(|payload| {
+            serde_json::from_value::<ConnectorCallDetails>(payload)
+                .inspect_err(|error| {
+                    logger::warn!(?error, "Failed to parse the stored connector call")
+                })
+                .ok()
+        })
+        .filter_map(|connector_call| {
+            let created_at = time::OffsetDateTime::from_unix_timestamp_nanos(
+                connector_call.created_at * 1_000_000,
+            )
+            .inspect_err(|error| {
+                logger::warn!(?error, "Invalid creation time of the stored connector call")
+            })
+            .ok()?;
+
+            Some(PaymentTimelineEvent {
+                connector: Some(connector_call.connector_name),
+                flow: Some(connector_call.flow),
+                http_status_code: Some(connector_call.status_code),
+                latency_in_millis: Some(connector_call.latency),
+                ..PaymentTimelineEvent::new(
+                    PaymentTimelineEventType::ConnectorCall,
+                    date_time::convert_to_pdt(created_at),
+                )
+            })
+        })
+        .collect()
+}
+
+/// The status of the payment after every API call made on it and the incoming webhooks received
+/// for it, which are only available when API events are stored in analytics
+async fn get_api_events(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+) -> Vec<PaymentTimelineEvent> {
+    let api_events = match api_events_core(
+        &state.pool,
+        ApiLogsRequest {
+            query_param: QueryType::Payment {
+                payment_id: payment_intent.payment_id.clone(),
+            },
+        },
+        merchant_account.get_id(),
+    )
+    .await
+    {
+        Ok(api_events) => api_events,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the API events of the payment");
+            return Vec::new();
+        }
+    };
+
+    api_events
+        .into_iter()
+        .filter_map(get_api_event_timeline_event)
+        .collect()
+}
+
+fn get_api_event_timeline_event(api_event: ApiLogsResult) -> Option<PaymentTimelineEvent> {
+    if api_event.api_flow == Flow::IncomingWebhookReceive.to_string() {
+        return Some(PaymentTimelineEvent {
+            connector: api_event.connector,
+            http_status_code: Some(api_event.status_code),
+            ..PaymentTimelineEvent::new(
+                PaymentTimelineEventType::IncomingWebhook,
+                api_event.created_at,
+            )
+        });
+    }
+
+    // Refund and dispute events of the payment do not change the status of the payment
+    if !PAYMENT_STATUS_FLOWS
+        .iter()
+        .any(|flow| flow.to_string() == api_event.api_flow)
+    {
+        return None;
+    }
+
+    // Failed API calls have no payment in their response, and leave the status unchanged
+    let status = api_event
+        .response
+        .as_deref()
+        .and_then(|response| serde_json::from_str::<PaymentStatusResponse>(response).ok())?
+        .status;
+
+    Some(PaymentTimelineEvent {
+        flow: Some(api_event.api_flow),
+        status: Some(status),
+        http_status_code: Some(api_event.status_code),
+        ..PaymentTimelineEvent::new(
+            PaymentTimelineEventType::PaymentStatusUpdated,
+            api_event.created_at,
+        )
+    })
+}
+
+/// Every delivery attempt of the outgoing webhooks of the payment, including retries
+async fn get_outgoing_webhook_events(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Vec<PaymentTimelineEvent>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+
+    let initial_events = db
+        .list_initial_events_by_merchant_id_primary_object_id(
+            key_manager_state,
+            merchant_id,
+            payment_intent.payment_id.get_string_repr(),
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the outgoing webhooks of the payment")?;
+
+    let mut events = Vec::new();
+    for initial_event in initial_events {
+        let delivery_attempts = db
+            .list_events_by_merchant_id_initial_attempt_id(
+                key_manager_state,
+                merchant_id,
+                &initial_event.event_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the delivery attempts of the outgoing webhook")?;
+
+        events.extend(
+            delivery_attempts
+                .into_iter()
+                .map(|event| PaymentTimelineEvent {
+                    webhook_event_id: Some(event.event_id),
+                    webhook_event_type: Some(event.event_type),
+                    is_webhook_delivered: Some(event.is_webhook_notified),
+                    ..PaymentTimelineEvent::new(
+                        PaymentTimelineEventType::OutgoingWebhook,
+                        event.created_at,
+                    )
+                }),
+        );
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_api_event(api_flow: Flow, response: Option<&str>) -> ApiLogsResult {
+        ApiLogsResult {
+            merchant_id: id_type::MerchantId::default(),
+            payment_id: None,
+            refund_id: None,
+            payment_method_id: None,
+            payment_method: None,
+            payment_method_type: None,
+            customer_id: None,
+            user_id: None,
+            connector: Some("stripe".to_string()),
+            request_id: None,
+            flow_type: "Payment".to_string(),
+            api_flow: api_flow.to_string(),
+            api_auth_type: None,
+            request: "{\"card_number\":\"4242424242424242\"}".to_string(),
+            response: response.map(str::to_string),
+            error: None,
+            authentication_data: None,
+            status_code: 200,
+            latency: Some(120),
+            user_agent: None,
+            hs_latency: None,
+            ip_addr: None,
+            created_at: date_time::now(),
+            http_method: None,
+            url_path: None,
+        }
+    }
+
+    #[test]
+    fn test_payment_status_is_read_from_the_api_event_response() {
+        let event = get_api_event_timeline_event(get_api_event(
+            Flow::PaymentsConfirm,
+            Some(r#"{"status":"requires_customer_action","client_secret":"pay_secret"}"#),
+        ));
+
+        assert!(matches!(
+            event,
+            Some(PaymentTimelineEvent {
+                event_type: PaymentTimelineEventType::PaymentStatusUpdated,
+                status: Some(ref status),
+                flow: Some(ref flow),
+                ..
+            }) if status == "requires_customer_action" && flow == "PaymentsConfirm"
+        ));
+        assert!(get_api_event_timeline_event(get_api_event(
+            Flow::PaymentsConfirm,
+            Some(r#"{"error":{"code":"IR_16"}}"#),
+        ))
+        .is_none());
+        assert!(get_api_event_timeline_event(get_api_event(
+            Flow::RefundsCreate,
+            Some(r#"{"status":"pending"}"#),
+        ))
+        .is_none());
+    }
+
+    #[test]
+    fn test_incoming_webhooks_are_part_of_the_timeline() {
+        let event = get_api_event_timeline_event(get_api_event(
+            Flow::IncomingWebhookReceive,
+            Some(r#"{"status":"succeeded"}"#),
+        ));
+
+        assert!(matches!(
+            event,
+            Some(PaymentTimelineEvent {
+                event_type: PaymentTimelineEventType::IncomingWebhook,
+                connector: Some(ref connector),
+                http_status_code: Some(200),
+                status: None,
+                ..
+            }) if connector == "stripe"
+        ));
+    }
+}
@@ -306,6 +306,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         additional_payment_method_data: None,
         merchant_account_id: None,
         merchant_config_currency: None,
+        three_ds_challenge_flow: None,
     };
     let connector_mandate_request_reference_id = payment_data
         .payment_attempt
@@ -2569,6 +2570,10 @@ where
             capture_before: payment_attempt.capture_before,
             extended_authorization_applied: payment_attempt.extended_authorization_applied,
             card_discovery: payment_attempt.card_discovery,
+            fx_details: payment_data
+                .get_fx_details()
+                .cloned()
+                .map(ForeignInto::foreign_into),
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -2827,7 +2832,8 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             order_tax_amount: None,
             connector_mandate_id:None,
             shipping_cost: None,
-            card_discovery: pa.card_discovery
+            card_discovery: pa.card_discovery,
+            fx_details: None,
         }
     }
 }
@@ -3168,7 +3174,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             .as_ref()
             .map(|data| data.customer_id.clone());
 
-        let split_payments = payment_data.payment_intent.split_payments.clone();
+        let split_payments = core_utils::get_connector_split_payments(
+            connector_name,
+            payment_data.payment_intent.split_payments.clone(),
+            payment_data.currency,
+        );
 
         let merchant_order_reference_id = payment_data
             .payment_intent
@@ -3229,6 +3239,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             shipping_cost,
             merchant_account_id,
             merchant_config_currency,
+            three_ds_challenge_flow: payment_data.three_ds_challenge_flow,
         })
     }
 }
@@ -3277,7 +3288,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSyncData
             },
             payment_method_type,
             currency: payment_data.currency,
-            split_payments: payment_data.payment_intent.split_payments,
+            split_payments: core_utils::get_connector_split_payments(
+                &additional_data.connector_name,
+                payment_data.payment_intent.split_payments,
+                payment_data.currency,
+            ),
             payment_experience: payment_data.payment_attempt.payment_experience,
         })
     }
@@ -3491,7 +3506,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             browser_info,
             metadata: payment_data.payment_intent.metadata,
             integrity_object: None,
-            split_payments: payment_data.payment_intent.split_payments,
+            split_payments: core_utils::get_connector_split_payments(
+                &additional_data.connector_name,
+                payment_data.payment_intent.split_payments,
+                payment_data.currency,
+            ),
         })
     }
 }
@@ -3539,6 +3558,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             connector_meta: payment_data.payment_attempt.connector_metadata,
             browser_info,
             metadata: payment_data.payment_intent.metadata,
+            minor_amount_to_void: payment_data.amount_to_void,
         })
     }
 }
@@ -4229,7 +4249,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPreProce
             mandate_id: payment_data.mandate_id,
             related_transaction_id: None,
             enrolled_for_3ds: true,
-            split_payments: payment_data.payment_intent.split_payments,
+            split_payments: core_utils::get_connector_split_payments(
+                &additional_data.connector_name,
+                payment_data.payment_intent.split_payments,
+                payment_data.currency,
+            ),
             metadata: payment_data.payment_intent.metadata.map(Secret::new),
         })
     }
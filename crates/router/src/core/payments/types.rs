@@ -134,7 +134,7 @@ impl MultipleCaptureData {
         authorized_amount: common_types::MinorUnit,
     ) -> storage_enums::AttemptStatus {
         let total_captured_amount = self.get_total_charged_amount();
-        if authorized_amount == total_captured_amount {
+        if total_captured_amount >= authorized_amount {
             return storage_enums::AttemptStatus::Charged;
         }
         let status_count_map = self.get_status_count();
@@ -44,6 +44,7 @@ use crate::types::domain::behaviour::Conversion;
 use crate::types::PayoutActionData;
 use crate::{
     core::{
+        api_locking,
         errors::{
             self, ConnectorErrorExt, CustomResult, RouterResponse, RouterResult, StorageErrorExt,
         },
@@ -51,7 +52,7 @@ use crate::{
         utils as core_utils,
     },
     db::StorageInterface,
-    routes::SessionState,
+    routes::{lock_utils, SessionState},
     services,
     types::{
         self,
@@ -61,6 +62,7 @@ use crate::{
         transformers::ForeignFrom,
     },
     utils::{self, OptionExt},
+    workflows::payment_sync,
 };
 
 // ********************************************** TYPES **********************************************
@@ -581,6 +583,109 @@ pub async fn payouts_retrieve_core(
     response_handler(&state, &merchant_account, &payout_data).await
 }
 
+/// Lock held while applying a payout status reported by the connector through a webhook, so that
+/// webhooks arriving together do not apply the same transition twice
+pub fn get_payout_status_lock_action(payout_id: &str) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payout_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Payouts,
+            override_lock_retries: None,
+        },
+    }
+}
+
+/// Fetches the status of a payout from the connector for the payout status poller and notifies the
+/// merchant when the status has changed. The payout lock is not taken, as the lock is released
+/// against the request id, which is not available in the scheduler. A webhook applied in the
+/// meantime is not reverted, as webhooks are validated against the stored status.
+#[cfg(all(feature = "payouts", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn sync_payout_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: payouts::PayoutRetrieveRequest,
+) -> RouterResult<PayoutData> {
+    let mut payout_data = make_payout_data(
+        state,
+        merchant_account,
+        None,
+        key_store,
+        &payouts::PayoutRequest::PayoutRetrieveRequest(req),
+        consts::DEFAULT_LOCALE,
+    )
+    .await?;
+    let previous_status = payout_data.payout_attempt.status;
+
+    if helpers::should_call_retrieve(previous_status) {
+        let connector_call_type = get_connector_choice(
+            state,
+            merchant_account,
+            key_store,
+            payout_data.payout_attempt.connector.clone(),
+            None,
+            &mut payout_data,
+            None,
+        )
+        .await?;
+
+        complete_payout_retrieve(
+            state,
+            merchant_account,
+            connector_call_type,
+            &mut payout_data,
+        )
+        .await?;
+    }
+
+    if payout_data.payout_attempt.status != previous_status {
+        trigger_payout_status_webhook(state, merchant_account, key_store, &payout_data).await?;
+    }
+
+    Ok(payout_data)
+}
+
+/// Emits the `payout.*` event corresponding to the current status of the payout
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub async fn trigger_payout_status_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_data: &PayoutData,
+) -> RouterResult<()> {
+    let event_type =
+        Option::<storage_enums::EventType>::foreign_from(payout_data.payout_attempt.status);
+
+    // The status does not map to any event
+    let Some(event_type) = event_type else {
+        return Ok(());
+    };
+
+    let payout_create_response =
+        match response_handler(state, merchant_account, payout_data).await? {
+            services::ApplicationResponse::Json(response) => response,
+            _ => Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payout create response")?,
+        };
+
+    Box::pin(
+        crate::core::webhooks::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            payout_data.business_profile.clone(),
+            key_store,
+            event_type,
+            storage_enums::EventClass::Payouts,
+            payout_data.payout_attempt.payout_id.clone(),
+            storage_enums::EventObjectType::PayoutDetails,
+            api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_create_response)),
+            Some(payout_data.payout_attempt.created_at),
+        ),
+    )
+    .await
+}
+
 #[instrument(skip_all)]
 pub async fn payouts_cancel_core(
     state: SessionState,
@@ -1731,6 +1836,8 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            add_payout_sync_task_if_required(state, connector_data, &payout_data.payout_attempt)
+                .await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -2437,6 +2544,8 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            add_payout_sync_task_if_required(state, connector_data, &payout_data.payout_attempt)
+                .await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -3101,6 +3210,106 @@ pub async fn add_external_account_addition_task(
     Ok(())
 }
 
+const PAYOUTS_SYNC_TASK: &str = "PAYOUTS_SYNC";
+
+fn get_payout_sync_process_tracker_id(payout_attempt: &storage::PayoutAttempt) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PayoutsSyncWorkflow,
+        PAYOUTS_SYNC_TASK,
+        &payout_attempt.payout_attempt_id,
+        &payout_attempt.merchant_id,
+    )
+}
+
+pub async fn add_payout_sync_task(
+    db: &dyn StorageInterface,
+    payout_attempt: &storage::PayoutAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutsSyncWorkflow;
+    let task = PAYOUTS_SYNC_TASK;
+    let tag = ["SYNC", "PAYOUT"];
+    let process_tracker_id = get_payout_sync_process_tracker_id(payout_attempt);
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_attempt.payout_id.to_owned(),
+        force_sync: Some(true),
+        merchant_id: Some(payout_attempt.merchant_id.to_owned()),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Schedules the payout status poller of the payout attempt again, which may have stopped polling
+/// after an earlier step of the payout was settled by the connector
+pub async fn reset_payout_sync_task(
+    db: &dyn StorageInterface,
+    payout_attempt: &storage::PayoutAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = get_payout_sync_process_tracker_id(payout_attempt);
+    let payout_sync_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await?
+        .ok_or(errors::StorageError::ValueNotFound(process_tracker_id))?;
+    db.as_scheduler()
+        .reset_process(payout_sync_process, schedule_time)
+        .await
+}
+
+/// Schedules the payout status poller when the connector is yet to settle the payout. The payout
+/// is not failed if the poller could not be scheduled, as webhooks can still update its status.
+pub async fn add_payout_sync_task_if_required(
+    state: &SessionState,
+    connector_data: &api::ConnectorData,
+    payout_attempt: &storage::PayoutAttempt,
+) {
+    if !helpers::should_call_retrieve(payout_attempt.status) {
+        return;
+    }
+
+    let db = &*state.store;
+    let schedule_time = match payment_sync::get_sync_process_schedule_time(
+        db,
+        &connector_data.connector_name.to_string(),
+        &payout_attempt.merchant_id,
+        0,
+    )
+    .await
+    {
+        Ok(Some(schedule_time)) => schedule_time,
+        Ok(None) => return,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get the schedule time of the payout sync task"
+            );
+            return;
+        }
+    };
+
+    let result = match add_payout_sync_task(db, payout_attempt, schedule_time).await {
+        // The task added when the payout was created is reused when the payout is fulfilled
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            reset_payout_sync_task(db, payout_attempt, schedule_time).await
+        }
+        result => result,
+    };
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to schedule the payout sync task");
+    }
+}
+
 async fn validate_and_get_business_profile(
     state: &SessionState,
     merchant_key_store: &domain::MerchantKeyStore,
@@ -3310,3 +3519,92 @@ pub async fn get_mca_from_profile_id(
 
     Ok(merchant_connector_account)
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::db::MockDb;
+
+    fn get_payout_attempt(status: storage_enums::PayoutStatus) -> storage::PayoutAttempt {
+        let now = common_utils::date_time::now();
+        storage::PayoutAttempt {
+            payout_attempt_id: "payout_1_1".to_string(),
+            payout_id: "payout_1".to_string(),
+            customer_id: None,
+            merchant_id: common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .unwrap(),
+            address_id: None,
+            connector: Some("adyen".to_string()),
+            connector_payout_id: None,
+            payout_token: None,
+            status,
+            is_eligible: None,
+            error_message: None,
+            error_code: None,
+            business_country: None,
+            business_label: None,
+            created_at: now,
+            last_modified_at: now,
+            profile_id: common_utils::generate_profile_id_of_default_length(),
+            merchant_connector_id: None,
+            routing_info: None,
+            unified_code: None,
+            unified_message: None,
+            additional_payout_method_data: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_payout_sync_task() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let db: &dyn StorageInterface = &mockdb;
+        let payout_attempt = get_payout_attempt(storage_enums::PayoutStatus::Pending);
+        let schedule_time = common_utils::date_time::now();
+
+        add_payout_sync_task(db, &payout_attempt, schedule_time)
+            .await
+            .unwrap();
+
+        let process = db
+            .find_process_by_id(&get_payout_sync_process_tracker_id(&payout_attempt))
+            .await
+            .unwrap()
+            .expect("The payout sync task is not added");
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .parse_value("PayoutRetrieveRequest")
+            .unwrap();
+        assert_eq!(process.runner, Some("PAYOUTS_SYNC_WORKFLOW".to_string()));
+        assert_eq!(process.schedule_time, Some(schedule_time));
+        assert_eq!(tracking_data.payout_id, payout_attempt.payout_id);
+        assert_eq!(tracking_data.force_sync, Some(true));
+        assert_eq!(tracking_data.merchant_id, Some(payout_attempt.merchant_id));
+    }
+
+    #[test]
+    fn test_payout_status_transitions() {
+        use storage_enums::PayoutStatus;
+
+        assert!(helpers::is_valid_payout_status_transition(
+            PayoutStatus::Pending,
+            PayoutStatus::Success
+        ));
+        assert!(helpers::is_valid_payout_status_transition(
+            PayoutStatus::Success,
+            PayoutStatus::Reversed
+        ));
+        assert!(!helpers::is_valid_payout_status_transition(
+            PayoutStatus::Success,
+            PayoutStatus::Failed
+        ));
+        assert!(!helpers::is_valid_payout_status_transition(
+            PayoutStatus::Pending,
+            PayoutStatus::Pending
+        ));
+    }
+}
@@ -452,6 +452,7 @@ pub async fn save_payout_data_to_locker(
                 billing: None,
                 connector_mandate_details: None,
                 network_transaction_id: None,
+                force_new_entry: false,
             };
 
             let pm_data = card_isin
@@ -539,6 +540,7 @@ pub async fn save_payout_data_to_locker(
                     billing: None,
                     connector_mandate_details: None,
                     network_transaction_id: None,
+                    force_new_entry: false,
                 },
             )
         };
@@ -775,6 +777,7 @@ pub(super) async fn get_or_create_customer_details(
                     modified_at: common_utils::date_time::now(),
                     address_id: None,
                     default_payment_method_id: None,
+                    merged_into_customer_id: None,
                     updated_by: None,
                     version: hyperswitch_domain_models::consts::API_VERSION,
                 };
@@ -1104,6 +1107,23 @@ pub fn is_payout_terminal_state(status: api_enums::PayoutStatus) -> bool {
     )
 }
 
+/// Status updates reported by the connector never move a payout out of a terminal state, except
+/// for a successful payout being reversed
+pub fn is_valid_payout_status_transition(
+    current_status: api_enums::PayoutStatus,
+    next_status: api_enums::PayoutStatus,
+) -> bool {
+    current_status != next_status
+        && (!is_payout_terminal_state(current_status)
+            || matches!(
+                (current_status, next_status),
+                (
+                    api_enums::PayoutStatus::Success,
+                    api_enums::PayoutStatus::Reversed
+                )
+            ))
+}
+
 pub fn should_call_retrieve(status: api_enums::PayoutStatus) -> bool {
     matches!(
         status,
@@ -1,3 +1,6 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod report;
+
 use api_models::recon as recon_api;
 #[cfg(feature = "email")]
 use common_utils::{ext_traits::AsyncExt, types::theme::ThemeLineage};
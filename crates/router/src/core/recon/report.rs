@@ -0,0 +1,761 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use actix_multipart::form::{tempfile::TempFile, MultipartForm};
+use api_models::{
+    payments::{Order, SortBy, SortOn},
+    recon::{
+        ConnectorSettlementRecord, ReconRecordStatus, ReconReportFormat, ReconReportRecord,
+        ReconReportResponse, ReconReportSummary, ReconTransactionType,
+    },
+};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    types::{ConnectorTransactionIdTrait, MinorUnit, TimeRange},
+};
+use csv::Reader;
+use error_stack::{report, ResultExt};
+use futures::{StreamExt, TryStreamExt};
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+#[derive(Debug, MultipartForm)]
+pub struct ReconReportForm {
+    #[multipart(limit = "10MB")]
+    pub file: TempFile,
+}
+
+/// Request to generate a reconciliation report. The settlement file is spooled to disk while it
+/// is uploaded, and is parsed only after the request is authenticated.
+#[derive(Debug, serde::Serialize)]
+pub struct ReconReportRequest {
+    pub time_range: TimeRange,
+    pub format: ReconReportFormat,
+    #[serde(skip)]
+    pub settlement_file: TempFile,
+}
+
+impl ApiEventMetric for ReconReportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+/// Key used to match a transaction against the settlement records of the connector
+type SettlementKey = (ReconTransactionType, String, String);
+
+/// Settlement records which are yet to be matched, along with the number of times each of them
+/// appears in the settlement file
+type PendingSettlementRecords = HashMap<SettlementKey, (ConnectorSettlementRecord, usize)>;
+
+/// Reads the settlement records record by record, counting the occurrences of each of them
+fn get_settlement_records(
+    settlement_file: impl std::io::Read,
+) -> RouterResult<PendingSettlementRecords> {
+    let mut settlement_records = PendingSettlementRecords::new();
+    for settlement_record in Reader::from_reader(settlement_file).deserialize() {
+        let settlement_record: ConnectorSettlementRecord = settlement_record.map_err(|error| {
+            report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: error.to_string(),
+            })
+        })?;
+        match settlement_records.entry(get_settlement_key(
+            settlement_record.transaction_type,
+            &settlement_record.connector.to_string(),
+            &settlement_record.connector_transaction_id,
+        )) {
+            Entry::Occupied(mut entry) => entry.get_mut().1 += 1,
+            Entry::Vacant(entry) => {
+                entry.insert((settlement_record, 1));
+            }
+        }
+    }
+    Ok(settlement_records)
+}
+
+fn validate_time_range(time_range: &TimeRange) -> RouterResult<TimeRange> {
+    let end_time = time_range
+        .end_time
+        .unwrap_or_else(common_utils::date_time::now);
+
+    utils::when(time_range.start_time > end_time, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time should be before end_time".to_string(),
+        }))
+    })?;
+
+    utils::when(
+        (end_time - time_range.start_time).whole_days() > consts::MAX_RECON_REPORT_DAYS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The report can span at most {} days",
+                    consts::MAX_RECON_REPORT_DAYS
+                ),
+            }))
+        },
+    )?;
+
+    Ok(TimeRange {
+        start_time: time_range.start_time,
+        end_time: Some(end_time),
+    })
+}
+
+/// Compares a transaction against its settlement record, the checks are ordered by the severity
+/// of the discrepancy
+fn get_record_status(
+    is_transaction_successful: bool,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+    settlement_record: &ConnectorSettlementRecord,
+) -> ReconRecordStatus {
+    if !is_transaction_successful {
+        ReconRecordStatus::StatusMismatch
+    } else if currency != Some(settlement_record.currency) {
+        ReconRecordStatus::CurrencyMismatch
+    } else if amount != settlement_record.amount {
+        ReconRecordStatus::AmountMismatch
+    } else {
+        ReconRecordStatus::Matched
+    }
+}
+
+fn get_settlement_key(
+    transaction_type: ReconTransactionType,
+    connector: &str,
+    connector_transaction_id: &str,
+) -> SettlementKey {
+    (
+        transaction_type,
+        connector.to_owned(),
+        connector_transaction_id.to_owned(),
+    )
+}
+
+struct TransactionDetails {
+    transaction_type: ReconTransactionType,
+    connector: String,
+    connector_transaction_id: String,
+    payment_id: common_utils::id_type::PaymentId,
+    refund_id: Option<String>,
+    transaction_status: String,
+    is_transaction_successful: bool,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+}
+
+/// Matches a transaction against the pending settlement records. Unsuccessful transactions are
+/// reported only when the connector has settled them.
+fn reconcile_transaction(
+    transaction: TransactionDetails,
+    settlement_records: &mut PendingSettlementRecords,
+) -> Option<ReconReportRecord> {
+    let settlement_record = settlement_records
+        .remove(&get_settlement_key(
+            transaction.transaction_type,
+            &transaction.connector,
+            &transaction.connector_transaction_id,
+        ))
+        .map(|(settlement_record, occurrences)| {
+            let status = if occurrences > 1 {
+                ReconRecordStatus::DuplicateSettlementRecord
+            } else {
+                get_record_status(
+                    transaction.is_transaction_successful,
+                    transaction.amount,
+                    transaction.currency,
+                    &settlement_record,
+                )
+            };
+            (status, settlement_record)
+        });
+
+    let (status, settled_amount, settled_currency) = match settlement_record {
+        Some((status, settlement_record)) => (
+            status,
+            Some(settlement_record.amount),
+            Some(settlement_record.currency),
+        ),
+        None if transaction.is_transaction_successful => {
+            (ReconRecordStatus::MissingInConnectorSettlement, None, None)
+        }
+        None => return None,
+    };
+
+    Some(ReconReportRecord {
+        status,
+        transaction_type: transaction.transaction_type,
+        connector: Some(transaction.connector),
+        connector_transaction_id: Some(transaction.connector_transaction_id),
+        payment_id: Some(transaction.payment_id),
+        refund_id: transaction.refund_id,
+        transaction_status: Some(transaction.transaction_status),
+        amount: Some(transaction.amount),
+        currency: transaction.currency,
+        settled_amount,
+        settled_currency,
+    })
+}
+
+/// Position of the report generation, the transactions are fetched batch by batch after the
+/// last transaction of the previous batch
+enum ReconReportStage {
+    Start,
+    Payments(Option<(PrimitiveDateTime, common_utils::id_type::PaymentId)>),
+    Refunds(Option<(PrimitiveDateTime, String)>),
+    UnmatchedSettlementRecords,
+    Done,
+}
+
+/// Serializes the records of the report chunk by chunk, while keeping count of the summary of
+/// the report. The summary of a JSON report follows its records.
+struct ReconReportWriter {
+    format: ReconReportFormat,
+    time_range: TimeRange,
+    summary: ReconReportSummary,
+}
+
+impl ReconReportWriter {
+    fn new(format: ReconReportFormat, time_range: TimeRange) -> Self {
+        Self {
+            format,
+            time_range,
+            summary: ReconReportSummary::default(),
+        }
+    }
+
+    fn start(&self) -> RouterResult<Vec<u8>> {
+        match self.format {
+            ReconReportFormat::Json => {
+                let mut chunk = br#"{"time_range":"#.to_vec();
+                serde_json::to_writer(&mut chunk, &self.time_range)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to write the time range of the report")?;
+                chunk.extend_from_slice(br#","records":["#);
+                Ok(chunk)
+            }
+            ReconReportFormat::Csv => Ok(Vec::new()),
+        }
+    }
+
+    fn write_records(&mut self, records: &[ReconReportRecord]) -> RouterResult<Vec<u8>> {
+        match self.format {
+            ReconReportFormat::Json => {
+                let mut chunk = Vec::new();
+                for record in records {
+                    if self.summary.total_count > 0 {
+                        chunk.push(b',');
+                    }
+                    serde_json::to_writer(&mut chunk, record)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to write the reconciliation report record")?;
+                    update_report_summary(&mut self.summary, record);
+                }
+                Ok(chunk)
+            }
+            ReconReportFormat::Csv => {
+                // The header is written along with the first record of the report
+                let mut writer = csv::WriterBuilder::new()
+                    .has_headers(self.summary.total_count == 0)
+                    .from_writer(Vec::new());
+                for record in records {
+                    writer
+                        .serialize(record)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to write the reconciliation report record")?;
+                    update_report_summary(&mut self.summary, record);
+                }
+                writer
+                    .into_inner()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to generate the reconciliation report")
+            }
+        }
+    }
+
+    fn finish(&self) -> RouterResult<Vec<u8>> {
+        match self.format {
+            ReconReportFormat::Json => {
+                let mut chunk = br#"],"summary":"#.to_vec();
+                serde_json::to_writer(&mut chunk, &self.summary)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to write the summary of the report")?;
+                chunk.push(b'}');
+                Ok(chunk)
+            }
+            ReconReportFormat::Csv => Ok(Vec::new()),
+        }
+    }
+}
+
+fn update_report_summary(summary: &mut ReconReportSummary, record: &ReconReportRecord) {
+    summary.total_count += 1;
+    match record.status {
+        ReconRecordStatus::Matched => summary.matched += 1,
+        ReconRecordStatus::AmountMismatch => summary.amount_mismatch += 1,
+        ReconRecordStatus::CurrencyMismatch => summary.currency_mismatch += 1,
+        ReconRecordStatus::StatusMismatch => summary.status_mismatch += 1,
+        ReconRecordStatus::MissingInConnectorSettlement => {
+            summary.missing_in_connector_settlement += 1
+        }
+        ReconRecordStatus::MissingInHyperswitch => summary.missing_in_hyperswitch += 1,
+        ReconRecordStatus::DuplicateSettlementRecord => summary.duplicate_settlement_record += 1,
+    };
+}
+
+struct ReconReportGenerator {
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    time_range: TimeRange,
+    settlement_records: PendingSettlementRecords,
+    writer: ReconReportWriter,
+    stage: ReconReportStage,
+}
+
+impl ReconReportGenerator {
+    /// Generates the next chunk of the report, a chunk is generated for every batch of
+    /// transactions. Returns `None` once the report is complete.
+    async fn next_chunk(&mut self) -> RouterResult<Option<Vec<u8>>> {
+        match std::mem::replace(&mut self.stage, ReconReportStage::Done) {
+            ReconReportStage::Start => {
+                self.stage = ReconReportStage::Payments(None);
+                self.writer.start().map(Some)
+            }
+            ReconReportStage::Payments(page_after) => {
+                let (report_records, next_page_after) = self.reconcile_payments(page_after).await?;
+                self.stage = match next_page_after {
+                    Some(next_page_after) => ReconReportStage::Payments(Some(next_page_after)),
+                    None => ReconReportStage::Refunds(None),
+                };
+                self.writer.write_records(&report_records).map(Some)
+            }
+            ReconReportStage::Refunds(page_after) => {
+                let (report_records, next_page_after) = self.reconcile_refunds(page_after).await?;
+                self.stage = match next_page_after {
+                    Some(next_page_after) => ReconReportStage::Refunds(Some(next_page_after)),
+                    None => ReconReportStage::UnmatchedSettlementRecords,
+                };
+                self.writer.write_records(&report_records).map(Some)
+            }
+            ReconReportStage::UnmatchedSettlementRecords => {
+                // Settlement records left after matching have no corresponding transaction in
+                // the time range
+                let report_records = std::mem::take(&mut self.settlement_records)
+                    .into_values()
+                    .map(|(settlement_record, occurrences)| ReconReportRecord {
+                        status: if occurrences > 1 {
+                            ReconRecordStatus::DuplicateSettlementRecord
+                        } else {
+                            ReconRecordStatus::MissingInHyperswitch
+                        },
+                        transaction_type: settlement_record.transaction_type,
+                        connector: Some(settlement_record.connector.to_string()),
+                        connector_transaction_id: Some(settlement_record.connector_transaction_id),
+                        payment_id: None,
+                        refund_id: None,
+                        transaction_status: None,
+                        amount: None,
+                        currency: None,
+                        settled_amount: Some(settlement_record.amount),
+                        settled_currency: Some(settlement_record.currency),
+                    })
+                    .collect::<Vec<_>>();
+                let mut chunk = self.writer.write_records(&report_records)?;
+                chunk.extend(self.writer.finish()?);
+                Ok(Some(chunk))
+            }
+            ReconReportStage::Done => Ok(None),
+        }
+    }
+
+    /// Reconciles a batch of payments, and returns the position to fetch the next batch from if
+    /// there can be more payments in the time range
+    async fn reconcile_payments(
+        &mut self,
+        page_after: Option<(PrimitiveDateTime, common_utils::id_type::PaymentId)>,
+    ) -> RouterResult<(
+        Vec<ReconReportRecord>,
+        Option<(PrimitiveDateTime, common_utils::id_type::PaymentId)>,
+    )> {
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset: 0,
+            starting_at: Some(self.time_range.start_time),
+            ending_at: self.time_range.end_time,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: None,
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(consts::RECON_REPORT_BATCH_SIZE),
+            order: Order {
+                on: SortOn::Created,
+                by: SortBy::Asc,
+            },
+            card_network: None,
+            card_discovery: None,
+            merchant_order_reference_id: None,
+            metadata: None,
+            page_after,
+        }));
+
+        let payments = self
+            .state
+            .store
+            .get_filtered_payment_intents_attempt(
+                &(&self.state).into(),
+                self.merchant_account.get_id(),
+                &constraints,
+                &self.key_store,
+                self.merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        let next_page_after = payments
+            .last()
+            .filter(|_| {
+                payments.len()
+                    >= usize::try_from(consts::RECON_REPORT_BATCH_SIZE).unwrap_or(usize::MAX)
+            })
+            .map(|(payment_intent, _)| {
+                (payment_intent.created_at, payment_intent.payment_id.clone())
+            });
+
+        let report_records = payments
+            .into_iter()
+            .filter_map(|(payment_intent, payment_attempt)| {
+                let connector = payment_attempt.connector.clone()?;
+                let connector_transaction_id = payment_attempt.get_connector_payment_id()?;
+                let transaction = TransactionDetails {
+                    transaction_type: ReconTransactionType::Payment,
+                    connector,
+                    connector_transaction_id: connector_transaction_id.to_owned(),
+                    payment_id: payment_intent.payment_id,
+                    refund_id: None,
+                    transaction_status: payment_attempt.status.to_string(),
+                    is_transaction_successful: matches!(
+                        payment_attempt.status,
+                        enums::AttemptStatus::Charged
+                            | enums::AttemptStatus::PartialCharged
+                            | enums::AttemptStatus::PartialChargedAndChargeable
+                    ),
+                    amount: payment_intent
+                        .amount_captured
+                        .unwrap_or(payment_attempt.net_amount.get_total_amount()),
+                    currency: payment_attempt.currency.or(payment_intent.currency),
+                };
+                reconcile_transaction(transaction, &mut self.settlement_records)
+            })
+            .collect();
+
+        Ok((report_records, next_page_after))
+    }
+
+    /// Reconciles a batch of refunds, and returns the position to fetch the next batch from if
+    /// there can be more refunds in the time range
+    async fn reconcile_refunds(
+        &mut self,
+        page_after: Option<(PrimitiveDateTime, String)>,
+    ) -> RouterResult<(Vec<ReconReportRecord>, Option<(PrimitiveDateTime, String)>)> {
+        let constraints = hyperswitch_domain_models::refunds::RefundListConstraints {
+            payment_id: None,
+            refund_id: None,
+            profile_id: None,
+            limit: None,
+            offset: None,
+            time_range: Some(self.time_range),
+            amount_filter: None,
+            connector: None,
+            merchant_connector_id: None,
+            currency: None,
+            refund_status: None,
+            order: Some(Order {
+                on: SortOn::Created,
+                by: SortBy::Asc,
+            }),
+            page_after,
+        };
+        let limit = i64::from(consts::RECON_REPORT_BATCH_SIZE);
+
+        let refunds = self
+            .state
+            .store
+            .filter_refund_by_constraints(
+                self.merchant_account.get_id(),
+                &constraints,
+                self.merchant_account.storage_scheme,
+                limit,
+                0,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+        let next_page_after = refunds
+            .last()
+            .filter(|_| refunds.len() >= usize::try_from(limit).unwrap_or(usize::MAX))
+            .map(|refund| (refund.created_at, refund.refund_id.clone()));
+
+        let report_records = refunds
+            .into_iter()
+            .filter_map(|refund: storage::Refund| {
+                let connector_transaction_id = refund.get_optional_connector_refund_id()?.clone();
+                let transaction = TransactionDetails {
+                    transaction_type: ReconTransactionType::Refund,
+                    connector: refund.connector,
+                    connector_transaction_id,
+                    payment_id: refund.payment_id,
+                    refund_id: Some(refund.refund_id),
+                    transaction_status: refund.refund_status.to_string(),
+                    is_transaction_successful: refund.refund_status == enums::RefundStatus::Success,
+                    amount: refund.refund_amount,
+                    currency: Some(refund.currency),
+                };
+                reconcile_transaction(transaction, &mut self.settlement_records)
+            })
+            .collect();
+
+        Ok((report_records, next_page_after))
+    }
+}
+
+/// Generates a reconciliation report by matching the payments and refunds of the merchant in the
+/// given time range against the settlement records of the connectors. The transactions are
+/// fetched in batches, and the report is streamed to the client batch by batch, so that large
+/// reports are neither loaded nor held in memory at once.
+#[instrument(skip_all)]
+pub async fn generate_recon_report(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: ReconReportRequest,
+) -> RouterResponse<ReconReportResponse> {
+    let time_range = validate_time_range(&request.time_range)?;
+    let settlement_file = request
+        .settlement_file
+        .file
+        .reopen()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to open the settlement file")?;
+    let settlement_records = get_settlement_records(settlement_file)?;
+
+    let content_type = match request.format {
+        ReconReportFormat::Json => mime::APPLICATION_JSON,
+        ReconReportFormat::Csv => mime::TEXT_CSV,
+    };
+    let generator = ReconReportGenerator {
+        state,
+        merchant_account,
+        key_store,
+        time_range,
+        settlement_records,
+        writer: ReconReportWriter::new(request.format, time_range),
+        stage: ReconReportStage::Start,
+    };
+    let stream = futures::stream::try_unfold(generator, |mut generator| async move {
+        let chunk = generator.next_chunk().await;
+        chunk.map(|chunk| chunk.map(|chunk| (chunk, generator)))
+    })
+    .map_err(|error: error_stack::Report<errors::ApiErrorResponse>| {
+        // The status of the response has been sent by now, so the report is cut short
+        logger::error!(?error, "Failed to generate the reconciliation report");
+        std::io::Error::other("Failed to generate the reconciliation report")
+    })
+    .boxed();
+
+    Ok(services::ApplicationResponse::FileStream(
+        services::FileStream {
+            stream,
+            content_type,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    const SETTLEMENT_FILE: &str = "\
+connector,transaction_type,connector_transaction_id,amount,currency
+stripe,payment,pi_1,100,USD
+stripe,payment,pi_2,100,USD
+stripe,payment,pi_2,100,USD
+";
+
+    fn get_transaction(
+        connector_transaction_id: &str,
+        is_transaction_successful: bool,
+    ) -> TransactionDetails {
+        TransactionDetails {
+            transaction_type: ReconTransactionType::Payment,
+            connector: "stripe".to_string(),
+            connector_transaction_id: connector_transaction_id.to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            refund_id: None,
+            transaction_status: "charged".to_string(),
+            is_transaction_successful,
+            amount: MinorUnit::new(100),
+            currency: Some(enums::Currency::USD),
+        }
+    }
+
+    fn get_report_record(status: ReconRecordStatus) -> ReconReportRecord {
+        ReconReportRecord {
+            status,
+            transaction_type: ReconTransactionType::Payment,
+            connector: Some("stripe".to_string()),
+            connector_transaction_id: Some("pi_1".to_string()),
+            payment_id: None,
+            refund_id: None,
+            transaction_status: Some("charged".to_string()),
+            amount: Some(MinorUnit::new(100)),
+            currency: Some(enums::Currency::USD),
+            settled_amount: Some(MinorUnit::new(100)),
+            settled_currency: Some(enums::Currency::USD),
+        }
+    }
+
+    #[test]
+    fn test_settlement_records_are_counted_by_transaction() {
+        let settlement_records = get_settlement_records(SETTLEMENT_FILE.as_bytes())
+            .expect("Failed to read the settlement records");
+
+        assert_eq!(
+            ["pi_1", "pi_2", "pi_3"].map(|connector_transaction_id| settlement_records
+                .get(&get_settlement_key(
+                    ReconTransactionType::Payment,
+                    "stripe",
+                    connector_transaction_id,
+                ))
+                .map(|(_, occurrences)| *occurrences)),
+            [Some(1), Some(2), None]
+        );
+    }
+
+    #[test]
+    fn test_invalid_settlement_record_is_rejected() {
+        let settlement_file = "\
+connector,transaction_type,connector_transaction_id,amount,currency
+stripe,payment,pi_1,one hundred,USD
+";
+
+        assert!(get_settlement_records(settlement_file.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_transactions_are_matched_against_settlement_records() {
+        let mut settlement_records = get_settlement_records(SETTLEMENT_FILE.as_bytes())
+            .expect("Failed to read the settlement records");
+
+        let statuses = [
+            get_transaction("pi_1", true),
+            get_transaction("pi_2", true),
+            get_transaction("pi_3", true),
+            get_transaction("pi_4", false),
+        ]
+        .map(|transaction| {
+            reconcile_transaction(transaction, &mut settlement_records).map(|record| record.status)
+        });
+
+        assert_eq!(
+            statuses,
+            [
+                Some(ReconRecordStatus::Matched),
+                Some(ReconRecordStatus::DuplicateSettlementRecord),
+                Some(ReconRecordStatus::MissingInConnectorSettlement),
+                None,
+            ]
+        );
+        assert!(settlement_records.is_empty());
+    }
+
+    #[test]
+    fn test_json_report_written_in_chunks_is_a_single_document() {
+        let time_range = TimeRange {
+            start_time: common_utils::date_time::now(),
+            end_time: Some(common_utils::date_time::now()),
+        };
+        let records = [
+            get_report_record(ReconRecordStatus::Matched),
+            get_report_record(ReconRecordStatus::AmountMismatch),
+        ];
+        let mut writer = ReconReportWriter::new(ReconReportFormat::Json, time_range);
+
+        let (first_batch, last_batch) = records.split_at(1);
+
+        let mut report = writer.start().expect("Failed to start the report");
+        for batch in [first_batch, &[], last_batch] {
+            report.extend(
+                writer
+                    .write_records(batch)
+                    .expect("Failed to write the records"),
+            );
+        }
+        report.extend(writer.finish().expect("Failed to finish the report"));
+
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&report).expect("Invalid JSON report"),
+            serde_json::to_value(ReconReportResponse {
+                time_range,
+                records: records.to_vec(),
+                summary: ReconReportSummary {
+                    total_count: 2,
+                    matched: 1,
+                    amount_mismatch: 1,
+                    ..Default::default()
+                },
+            })
+            .expect("Failed to serialize the report")
+        );
+    }
+
+    #[test]
+    fn test_csv_report_header_is_written_once() {
+        let time_range = TimeRange {
+            start_time: common_utils::date_time::now(),
+            end_time: None,
+        };
+        let mut writer = ReconReportWriter::new(ReconReportFormat::Csv, time_range);
+
+        let mut report = writer.start().expect("Failed to start the report");
+        for _ in 0..2 {
+            report.extend(
+                writer
+                    .write_records(&[get_report_record(ReconRecordStatus::Matched)])
+                    .expect("Failed to write the records"),
+            );
+        }
+        report.extend(writer.finish().expect("Failed to finish the report"));
+        let report = String::from_utf8(report).expect("Invalid CSV report");
+
+        assert_eq!(report.lines().count(), 3);
+        assert_eq!(
+            report
+                .lines()
+                .filter(|line| line.starts_with("status,"))
+                .count(),
+            1
+        );
+    }
+}
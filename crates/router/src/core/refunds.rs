@@ -15,13 +15,17 @@ use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     router_data::ErrorResponse, router_request_types::SplitRefundsRequest,
 };
-use hyperswitch_interfaces::integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject};
+use hyperswitch_interfaces::{
+    api::ConnectorSpecifications,
+    integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject},
+};
 use router_env::{instrument, tracing};
 use scheduler::{consumer::types::process_data, utils as process_tracker_utils};
 #[cfg(feature = "olap")]
 use strum::IntoEnumIterator;
 
 use crate::{
+    configs::settings,
     consts,
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
@@ -51,13 +55,27 @@ pub async fn refund_create_core(
     merchant_account: domain::MerchantAccount,
     _profile_id: Option<common_utils::id_type::ProfileId>,
     key_store: domain::MerchantKeyStore,
-    req: refunds::RefundRequest,
+    mut req: refunds::RefundRequest,
+    idempotency_key: Option<String>,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
     let (merchant_id, payment_intent, payment_attempt, amount);
 
     merchant_id = merchant_account.get_id();
 
+    if let Some(idempotency_key) = idempotency_key.as_deref() {
+        if let Some(refund) = find_refund_by_idempotency_key(
+            &state,
+            &merchant_account,
+            idempotency_key,
+            &req.payment_id,
+        )
+        .await?
+        {
+            return Ok(services::ApplicationResponse::Json(refund.foreign_into()));
+        }
+    }
+
     payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
             &(&state).into(),
@@ -120,7 +138,32 @@ pub async fn refund_create_core(
         .await
         .transpose()?;
 
-    Box::pin(validate_and_create_refund(
+    // The idempotency key is stored before the refund is created, so that retrying a request whose
+    // response was lost returns the refund instead of creating another one
+    if let Some(idempotency_key) = idempotency_key.as_deref() {
+        let refund_id = core_utils::get_or_generate_id("refund_id", &req.refund_id, "ref")?;
+        if !reserve_refund_idempotency_key(&state, merchant_id, idempotency_key, &refund_id).await?
+        {
+            let refund = find_refund_by_idempotency_key(
+                &state,
+                &merchant_account,
+                idempotency_key,
+                &req.payment_id,
+            )
+            .await?
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "A refund with the same Idempotency-Key is being processed"
+                        .to_string()
+                })
+            })?;
+            return Ok(services::ApplicationResponse::Json(refund.foreign_into()));
+        }
+        req.refund_id = Some(refund_id);
+    }
+    let refund_id = req.refund_id.clone();
+
+    let refund = Box::pin(validate_and_create_refund(
         &state,
         &merchant_account,
         &key_store,
@@ -130,8 +173,142 @@ pub async fn refund_create_core(
         req,
         creds_identifier,
     ))
-    .await
-    .map(services::ApplicationResponse::Json)
+    .await;
+
+    if let (Err(_), Some(idempotency_key), Some(refund_id)) =
+        (&refund, idempotency_key.as_deref(), refund_id.as_deref())
+    {
+        release_refund_idempotency_key(&state, &merchant_account, idempotency_key, refund_id).await;
+    }
+
+    refund.map(services::ApplicationResponse::Json)
+}
+
+/// Find the refund created by an earlier request with the same idempotency key, if the key has not
+/// expired. An error is returned if the earlier request is still creating the refund.
+#[instrument(skip_all)]
+async fn find_refund_by_idempotency_key(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    idempotency_key: &str,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> RouterResult<Option<storage::Refund>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = merchant_account
+        .get_id()
+        .get_refund_idempotency_key(idempotency_key);
+
+    let Some(refund_id) = redis_conn
+        .get_key::<Option<String>>(&key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the idempotency key of the refund from redis")?
+    else {
+        return Ok(None);
+    };
+
+    let refund = state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            merchant_account.get_id(),
+            &refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A refund with the same Idempotency-Key is being processed".to_string(),
+        })?;
+
+    utils::when(refund.payment_id != *payment_id, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Idempotency-Key has already been used for a refund of a different payment"
+                .to_string()
+        }))
+    })?;
+
+    logger::info!(
+        "Returning refund {} created earlier with the same idempotency key",
+        refund.refund_id
+    );
+    Ok(Some(refund))
+}
+
+/// Store the idempotency key of a refund create request, returns `false` if the key is already
+/// used by another request
+#[instrument(skip_all)]
+async fn reserve_refund_idempotency_key(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    idempotency_key: &str,
+    refund_id: &str,
+) -> RouterResult<bool> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = merchant_id.get_refund_idempotency_key(idempotency_key);
+
+    redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &key.as_str().into(),
+            refund_id,
+            Some(state.conf.refund.idempotency_key_ttl),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the idempotency key of the refund in redis")
+        .map(|reply| reply == redis_interface::SetnxReply::KeySet)
+}
+
+/// Release the idempotency key of a refund create request which failed, so that the request can be
+/// retried. The key is kept if the refund was created before the request failed.
+#[instrument(skip_all)]
+async fn release_refund_idempotency_key(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    idempotency_key: &str,
+    refund_id: &str,
+) {
+    let is_refund_created = state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            merchant_account.get_id(),
+            refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_or_else(|error| !error.current_context().is_db_not_found(), |_| true);
+    if is_refund_created {
+        return;
+    }
+
+    let key = merchant_account
+        .get_id()
+        .get_refund_idempotency_key(idempotency_key);
+    let result = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+        .async_and_then(|redis_conn| async move {
+            redis_conn
+                .delete_key(&key.as_str().into())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to delete the idempotency key of the refund from redis")
+        })
+        .await;
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            "Failed to release the idempotency key of the refund"
+        );
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -792,6 +969,356 @@ pub async fn refund_update_core(
     Ok(services::ApplicationResponse::Json(response.foreign_into()))
 }
 
+// ********************************************** REFUND ELIGIBILITY **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_eligibility_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: refunds::RefundEligibilityRequest,
+) -> RouterResponse<refunds::RefundEligibilityResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    utils::when(
+        !(payment_intent.status == enums::IntentStatus::Succeeded
+            || payment_intent.status == enums::IntentStatus::PartiallyCaptured),
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "refund".into(),
+                field_name: "status".into(),
+                current_value: payment_intent.status.to_string(),
+                states: "succeeded, partially_captured".to_string()
+            })
+            .attach_printable("unable to refund for a unsuccessful payment intent"))
+        },
+    )?;
+
+    let payment_attempt = db
+        .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
+            &req.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
+
+    let connector_transaction_id = payment_attempt.clone().connector_transaction_id.ok_or_else(|| {
+        report!(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Transaction in invalid. Missing field \"connector_transaction_id\" in payment_attempt.")
+    })?;
+
+    let all_refunds = db
+        .find_refund_by_merchant_id_connector_transaction_id(
+            merchant_id,
+            &connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    let currency = payment_attempt.currency.get_required_value("currency")?;
+    let connector = payment_attempt
+        .connector
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("No connector populated in payment attempt")?;
+
+    let total_amount_captured = payment_intent
+        .amount_captured
+        .unwrap_or(payment_attempt.get_total_amount());
+    let refunded_amount = MinorUnit::new(validator::get_total_refunded_amount(&all_refunds));
+    let refundable_amount =
+        std::cmp::max(total_amount_captured - refunded_amount, MinorUnit::new(0));
+
+    let connector_supports_refunds =
+        is_refund_supported_by_connector(&state, &connector, &payment_attempt)?;
+
+    let ineligibility_reason = get_refund_ineligibility_reason(
+        connector_supports_refunds,
+        refundable_amount,
+        &payment_intent.created_at,
+        &all_refunds,
+        &state.conf.refund,
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        refunds::RefundEligibilityResponse {
+            payment_id: payment_intent.payment_id,
+            is_refundable: ineligibility_reason.is_none(),
+            ineligibility_reason,
+            refundable_amount,
+            refunded_amount,
+            currency,
+            connector,
+            constraints: refunds::RefundEligibilityConstraints {
+                connector_supports_refunds,
+                // A refund is allowed as long as there are at most `max_attempts` refunds against the payment
+                remaining_refund_count: (state.conf.refund.max_attempts + 1)
+                    .saturating_sub(all_refunds.len()),
+                refundable_until: payment_intent.created_at
+                    + time::Duration::days(state.conf.refund.max_age + 1),
+            },
+        },
+    ))
+}
+
+/// Performs the same validations as the ones performed while creating a refund, so that an
+/// eligible payment does not fail these validations when the refund is created
+fn get_refund_ineligibility_reason(
+    connector_supports_refunds: Option<bool>,
+    refundable_amount: MinorUnit,
+    payment_created_at: &time::PrimitiveDateTime,
+    all_refunds: &[storage::Refund],
+    refund_config: &settings::Refund,
+) -> Option<String> {
+    if connector_supports_refunds == Some(false) {
+        Some("The connector does not support refunds for this payment method".to_string())
+    } else if refundable_amount <= MinorUnit::new(0) {
+        Some("The payment has already been fully refunded".to_string())
+    } else {
+        validator::validate_payment_order_age(payment_created_at, refund_config.max_age)
+            .and_then(|_| {
+                validator::validate_maximum_refund_against_payment_attempt(
+                    all_refunds,
+                    refund_config.max_attempts,
+                )
+            })
+            .err()
+            .map(|error| error.current_context().to_string())
+    }
+}
+
+fn is_refund_supported_by_connector(
+    state: &SessionState,
+    connector: &str,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<bool>> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector,
+        api::GetToken::Connector,
+        payment_attempt.merchant_connector_id.clone(),
+    )?;
+
+    if validator::validate_for_valid_refunds(payment_attempt, connector_data.connector_name)
+        .is_err()
+    {
+        return Ok(Some(false));
+    }
+
+    Ok(connector_data
+        .connector
+        .get_supported_payment_methods()
+        .zip(payment_attempt.payment_method)
+        .and_then(|(supported_payment_methods, payment_method)| {
+            supported_payment_methods.get(&payment_method)
+        })
+        .zip(payment_attempt.payment_method_type)
+        .and_then(|(supported_payment_method_types, payment_method_type)| {
+            supported_payment_method_types.get(&payment_method_type)
+        })
+        .map(|payment_method_details| {
+            payment_method_details.refunds == common_enums::FeatureStatus::Supported
+        }))
+}
+
+// ********************************************** REFUND BULK **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_bulk_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: refunds::RefundBulkRequest,
+) -> RouterResponse<refunds::RefundBulkResponse> {
+    validator::validate_bulk_refund_request(&req)?;
+
+    // The payments are processed one after the other, and the failure of a payment does not
+    // prevent the rest of the batch from being processed
+    let mut results = Vec::with_capacity(req.items.len());
+    for item in req.items.iter() {
+        let result = if req.dry_run {
+            validate_bulk_refund_item(
+                &state,
+                &merchant_account,
+                profile_id.clone(),
+                &key_store,
+                item,
+            )
+            .await
+        } else {
+            create_bulk_refund_item(
+                &state,
+                &merchant_account,
+                profile_id.clone(),
+                &key_store,
+                &req,
+                item,
+            )
+            .await
+        };
+        results.push(result);
+    }
+
+    let total_count = results.len();
+    let success_count = results
+        .iter()
+        .filter(|result| result.status.is_success())
+        .count();
+
+    Ok(services::ApplicationResponse::Json(
+        refunds::RefundBulkResponse {
+            batch_key: req.batch_key,
+            dry_run: req.dry_run,
+            total_count,
+            success_count,
+            failure_count: total_count - success_count,
+            results,
+        },
+    ))
+}
+
+#[instrument(skip_all, fields(payment_id = ?item.payment_id))]
+async fn create_bulk_refund_item(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: &domain::MerchantKeyStore,
+    req: &refunds::RefundBulkRequest,
+    item: &refunds::RefundBulkItem,
+) -> refunds::RefundBulkItemResponse {
+    let refund_request = refunds::RefundRequest {
+        payment_id: item.payment_id.clone(),
+        amount: item.amount,
+        reason: req.reason.clone(),
+        refund_type: req.refund_type,
+        metadata: req.metadata.clone(),
+        ..Default::default()
+    };
+
+    // Every payment of the batch is refunded with an idempotency key derived from the batch key,
+    // so that retrying the batch returns the refunds created earlier instead of creating new ones
+    let idempotency_key = format!("{}_{}", req.batch_key, item.payment_id.get_string_repr());
+
+    let refund = Box::pin(refund_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        profile_id,
+        key_store.clone(),
+        refund_request,
+        Some(idempotency_key),
+    ))
+    .await
+    .and_then(|response| {
+        response
+            .get_json_body()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from refunds core")
+    });
+
+    match refund {
+        Ok(refund) => refunds::RefundBulkItemResponse {
+            payment_id: item.payment_id.clone(),
+            status: if refund.status == refunds::RefundStatus::Failed {
+                refunds::RefundBulkItemStatus::Failed
+            } else {
+                refunds::RefundBulkItemStatus::Processed
+            },
+            refundable_amount: None,
+            error_code: refund.error_code.clone(),
+            error_message: refund.error_message.clone(),
+            refund: Some(refund),
+        },
+        Err(error) => {
+            logger::error!(bulk_refund_error=?error);
+            refunds::RefundBulkItemResponse {
+                payment_id: item.payment_id.clone(),
+                status: refunds::RefundBulkItemStatus::Failed,
+                refund: None,
+                refundable_amount: None,
+                error_code: Some(error.current_context().error_code()),
+                error_message: Some(error.current_context().error_message()),
+            }
+        }
+    }
+}
+
+#[instrument(skip_all, fields(payment_id = ?item.payment_id))]
+async fn validate_bulk_refund_item(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: &domain::MerchantKeyStore,
+    item: &refunds::RefundBulkItem,
+) -> refunds::RefundBulkItemResponse {
+    let eligibility = Box::pin(refund_eligibility_core(
+        state.clone(),
+        merchant_account.clone(),
+        profile_id,
+        key_store.clone(),
+        refunds::RefundEligibilityRequest {
+            payment_id: item.payment_id.clone(),
+        },
+    ))
+    .await
+    .and_then(|response| {
+        response
+            .get_json_body()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from refund eligibility core")
+    });
+
+    match eligibility {
+        Ok(eligibility) => {
+            let ineligibility_reason = eligibility.ineligibility_reason.or_else(|| {
+                item.amount
+                    .filter(|amount| *amount > eligibility.refundable_amount)
+                    .map(|_| {
+                        "The refund amount exceeds the amount that can be refunded".to_string()
+                    })
+            });
+            let status = if ineligibility_reason.is_none() {
+                refunds::RefundBulkItemStatus::Eligible
+            } else {
+                refunds::RefundBulkItemStatus::Ineligible
+            };
+
+            refunds::RefundBulkItemResponse {
+                payment_id: item.payment_id.clone(),
+                status,
+                refund: None,
+                refundable_amount: Some(eligibility.refundable_amount),
+                error_code: None,
+                error_message: ineligibility_reason,
+            }
+        }
+        Err(error) => refunds::RefundBulkItemResponse {
+            payment_id: item.payment_id.clone(),
+            status: refunds::RefundBulkItemStatus::Ineligible,
+            refund: None,
+            refundable_amount: None,
+            error_code: Some(error.current_context().error_code()),
+            error_message: Some(error.current_context().error_message()),
+        },
+    }
+}
+
 // ********************************************** VALIDATIONS **********************************************
 
 #[instrument(skip_all)]
@@ -981,6 +1508,7 @@ pub async fn refund_list(
 ) -> RouterResponse<api_models::refunds::RefundListResponse> {
     let db = state.store;
     let limit = validator::validate_refund_list(req.limit)?;
+    validator::validate_refund_list_filters(&req)?;
     let offset = req.offset.unwrap_or_default();
 
     let refund_list = db
@@ -1689,3 +2217,50 @@ pub async fn get_refund_sync_process_schedule_time(
 
     Ok(process_tracker_utils::get_time_from_delta(time_delta))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn refund_config() -> settings::Refund {
+        settings::Refund {
+            max_attempts: 10,
+            max_age: 365,
+            idempotency_key_ttl: 86_400,
+        }
+    }
+
+    #[test]
+    fn test_refund_ineligibility_reason() {
+        let config = refund_config();
+        let now = common_utils::date_time::now();
+        let expired_at = now - time::Duration::days(config.max_age + 1);
+
+        assert_eq!(
+            get_refund_ineligibility_reason(Some(true), MinorUnit::new(100), &now, &[], &config),
+            None
+        );
+        assert_eq!(
+            get_refund_ineligibility_reason(None, MinorUnit::new(100), &now, &[], &config),
+            None
+        );
+        assert_eq!(
+            get_refund_ineligibility_reason(Some(false), MinorUnit::new(100), &now, &[], &config),
+            Some("The connector does not support refunds for this payment method".to_string())
+        );
+        assert_eq!(
+            get_refund_ineligibility_reason(Some(true), MinorUnit::new(0), &now, &[], &config),
+            Some("The payment has already been fully refunded".to_string())
+        );
+        assert_eq!(
+            get_refund_ineligibility_reason(
+                Some(true),
+                MinorUnit::new(100),
+                &expired_at,
+                &[],
+                &config
+            ),
+            Some(validator::RefundValidationError::OrderExpired.to_string())
+        );
+    }
+}
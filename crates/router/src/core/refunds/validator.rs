@@ -1,8 +1,11 @@
+use std::collections::HashSet;
+
 use error_stack::report;
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
 use crate::{
+    consts,
     core::errors::{self, CustomResult, RouterResult},
     types::{
         self,
@@ -48,7 +51,21 @@ pub fn validate_refund_amount(
     all_refunds: &[storage::Refund],
     refund_amount: i64,
 ) -> CustomResult<(), RefundValidationError> {
-    let total_refunded_amount: i64 = all_refunds
+    let total_refunded_amount = get_total_refunded_amount(all_refunds);
+
+    utils::when(
+        refund_amount > (amount_captured - total_refunded_amount),
+        || {
+            Err(report!(
+                RefundValidationError::RefundAmountExceedsPaymentAmount
+            ))
+        },
+    )
+}
+
+/// Total amount of the refunds which have either succeeded or are still being processed
+pub fn get_total_refunded_amount(all_refunds: &[storage::Refund]) -> i64 {
+    all_refunds
         .iter()
         .filter_map(|refund| {
             if refund.refund_status != enums::RefundStatus::Failure
@@ -59,16 +76,7 @@ pub fn validate_refund_amount(
                 None
             }
         })
-        .sum();
-
-    utils::when(
-        refund_amount > (amount_captured - total_refunded_amount),
-        || {
-            Err(report!(
-                RefundValidationError::RefundAmountExceedsPaymentAmount
-            ))
-        },
-    )
+        .sum()
 }
 
 #[instrument(skip_all)]
@@ -110,6 +118,82 @@ pub fn validate_refund_list(limit: Option<i64>) -> CustomResult<i64, errors::Api
     }
 }
 
+/// Validates the pagination and range filters of the refunds list request
+pub fn validate_refund_list_filters(
+    req: &api_models::refunds::RefundListRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    utils::when(req.offset.is_some_and(|offset| offset < 0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "offset should not be negative".to_string(),
+        }))
+    })?;
+
+    utils::when(
+        req.time_range.is_some_and(|time_range| {
+            time_range
+                .end_time
+                .is_some_and(|end_time| end_time < time_range.start_time)
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "end_time should not be earlier than start_time".to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        req.amount_filter
+            .as_ref()
+            .and_then(|amount| amount.start_amount.zip(amount.end_amount))
+            .is_some_and(|(start_amount, end_amount)| end_amount < start_amount),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "end_amount should not be less than start_amount".to_string(),
+            }))
+        },
+    )
+}
+
+pub fn validate_bulk_refund_request(
+    req: &types::api::refunds::RefundBulkRequest,
+) -> RouterResult<()> {
+    utils::when(
+        req.batch_key.is_empty() || req.batch_key.len() > consts::MAX_ID_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "batch_key should be between 1 and {} characters long",
+                    consts::MAX_ID_LENGTH
+                ),
+            }))
+        },
+    )?;
+
+    utils::when(
+        req.items.is_empty() || req.items.len() > consts::MAX_BULK_REFUND_COUNT,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "items should contain between 1 and {} payments",
+                    consts::MAX_BULK_REFUND_COUNT
+                ),
+            }))
+        },
+    )?;
+
+    let mut payment_ids = HashSet::with_capacity(req.items.len());
+    utils::when(
+        !req.items
+            .iter()
+            .all(|item| payment_ids.insert(&item.payment_id)),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "A payment can be included only once in a bulk refund".to_string(),
+            }))
+        },
+    )
+}
+
 pub fn validate_for_valid_refunds(
     payment_attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
     connector: api_models::enums::Connector,
@@ -542,7 +542,8 @@ pub async fn link_routing_config(
         diesel_models::enums::RoutingAlgorithmKind::Single
         | diesel_models::enums::RoutingAlgorithmKind::Priority
         | diesel_models::enums::RoutingAlgorithmKind::Advanced
-        | diesel_models::enums::RoutingAlgorithmKind::VolumeSplit => {
+        | diesel_models::enums::RoutingAlgorithmKind::VolumeSplit
+        | diesel_models::enums::RoutingAlgorithmKind::LowestLatency => {
             let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
                 .routing_algorithm
                 .clone()
@@ -400,7 +400,8 @@ impl RoutingAlgorithmHelpers<'_> {
                 self.connector_choice(choice)?;
             }
 
-            routing_types::RoutingAlgorithm::Priority(list) => {
+            routing_types::RoutingAlgorithm::Priority(list)
+            | routing_types::RoutingAlgorithm::LowestLatency(list) => {
                 for choice in list {
                     self.connector_choice(choice)?;
                 }
@@ -508,7 +509,8 @@ pub async fn validate_connectors_in_routing_config(
             connector_choice(choice)?;
         }
 
-        routing_types::RoutingAlgorithm::Priority(list) => {
+        routing_types::RoutingAlgorithm::Priority(list)
+        | routing_types::RoutingAlgorithm::LowestLatency(list) => {
             for choice in list {
                 connector_choice(choice)?;
             }
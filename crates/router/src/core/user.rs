@@ -10,7 +10,7 @@ use api_models::{
 use common_enums::{EntityType, UserAuthType};
 use common_utils::{type_name, types::keymanager::Identifier};
 #[cfg(feature = "email")]
-use diesel_models::user_role::UserRoleUpdate;
+use diesel_models::user_role::{UserRole, UserRoleUpdate};
 use diesel_models::{
     enums::{TotpStatus, UserRoleVersion, UserStatus},
     organization::OrganizationBridge,
@@ -40,6 +40,7 @@ use crate::{
         domain::user_authentication_method::DEFAULT_USER_AUTH_METHOD,
         user_role::ListUserRolesByUserIdPayload,
     },
+    events::audit_events::{AuditEvent, AuditEventType},
     routes::{app::ReqState, SessionState},
     services::{authentication as auth, authorization::roles, openidconnect, ApplicationResponse},
     types::{domain, transformers::ForeignInto},
@@ -80,6 +81,13 @@ pub async fn signup_with_merchant_id(
         )
         .await?;
 
+    let _ = utils::user_role::attach_pending_invitations_to_user(&state, &user_from_db)
+        .await
+        .map_err(|error| logger::error!(?error));
+    let _ = super::user_role::create_invitations_from_role_assignment_rules(&state, &user_from_db)
+        .await
+        .map_err(|error| logger::error!(?error));
+
     let theme = theme_utils::get_theme_using_optional_theme_id(&state, theme_id).await?;
 
     let email_contents = email_types::ResetPassword {
@@ -232,6 +240,13 @@ pub async fn signup_token_only_flow(
         )
         .await?;
 
+    let _ = utils::user_role::attach_pending_invitations_to_user(&state, &user_from_db)
+        .await
+        .map_err(|error| logger::error!(?error));
+    let _ = super::user_role::create_invitations_from_role_assignment_rules(&state, &user_from_db)
+        .await
+        .map_err(|error| logger::error!(?error));
+
     let next_flow =
         domain::NextFlow::from_origin(domain::Origin::SignUp, user_from_db.clone(), &state).await?;
 
@@ -358,6 +373,14 @@ pub async fn connect_account(
             )
             .await?;
 
+        let _ = utils::user_role::attach_pending_invitations_to_user(&state, &user_from_db)
+            .await
+            .map_err(|error| logger::error!(?error));
+        let _ =
+            super::user_role::create_invitations_from_role_assignment_rules(&state, &user_from_db)
+                .await
+                .map_err(|error| logger::error!(?error));
+
         let theme = theme_utils::get_theme_using_optional_theme_id(&state, theme_id).await?;
 
         let magic_link_email = email_types::VerifyEmail {
@@ -1137,32 +1160,34 @@ async fn handle_new_user_invitation(
     })
 }
 
+/// Finds the user role of the invitee in the lineage of the token, which is expected to be a
+/// pending invitation
 #[cfg(feature = "email")]
-pub async fn resend_invite(
-    state: SessionState,
-    user_from_token: auth::UserFromToken,
-    request: user_api::ReInviteUserRequest,
-    auth_id: Option<String>,
-) -> UserResponse<()> {
-    let invitee_email = domain::UserEmail::from_pii_email(request.email)?;
-    let user: domain::UserFromStorage = state
-        .global_store
-        .find_user_by_email(&invitee_email)
-        .await
-        .map_err(|e| {
-            if e.current_context().is_db_not_found() {
-                e.change_context(UserErrors::InvalidRoleOperation)
-                    .attach_printable("User not found in the records")
-            } else {
-                e.change_context(UserErrors::InternalServerError)
-            }
-        })?
-        .into();
+pub async fn find_pending_invitation_user_role(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    user_id: &str,
+) -> UserResult<UserRole> {
+    let user_role = find_user_role_in_lineage(state, user_from_token, user_id).await?;
 
+    if !matches!(user_role.status, UserStatus::InvitationSent) {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("User status is not InvitationSent".to_string());
+    }
+
+    Ok(user_role)
+}
+
+/// Finds the user role of the user in the lineage of the token
+async fn find_user_role_in_lineage(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    user_id: &str,
+) -> UserResult<UserRole> {
     let user_role = match state
         .global_store
         .find_user_role_by_user_id_and_lineage(
-            user.get_user_id(),
+            user_id,
             user_from_token
                 .tenant_id
                 .as_ref()
@@ -1189,7 +1214,7 @@ pub async fn resend_invite(
         None => state
             .global_store
             .find_user_role_by_user_id_and_lineage(
-                user.get_user_id(),
+                user_id,
                 user_from_token
                     .tenant_id
                     .as_ref()
@@ -1205,10 +1230,37 @@ pub async fn resend_invite(
             ))?,
     };
 
-    if !matches!(user_role.status, UserStatus::InvitationSent) {
-        return Err(report!(UserErrors::InvalidRoleOperation))
-            .attach_printable("User status is not InvitationSent".to_string());
-    }
+    Ok(user_role)
+}
+
+#[cfg(feature = "email")]
+pub async fn resend_invite(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_api::ReInviteUserRequest,
+    auth_id: Option<String>,
+) -> UserResponse<()> {
+    let invitee_email = domain::UserEmail::from_pii_email(request.email)?;
+    let user: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_email(&invitee_email)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::InvalidRoleOperation)
+                    .attach_printable("User not found in the records")
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?
+        .into();
+
+    let user_role =
+        find_pending_invitation_user_role(&state, &user_from_token, user.get_user_id()).await?;
+
+    // The cooldown is only started for pending invitations, so that invalid requests do not
+    // block a valid resend
+    utils::user_role::set_invitation_resend_lock_in_redis(&state, user.get_user_id()).await?;
 
     let (entity_id, entity_type) = user_role
         .get_entity_id_and_type()
@@ -1317,6 +1369,7 @@ pub async fn accept_invite_from_email_token_only_flow(
         UserRoleUpdate::UpdateStatus {
             status: UserStatus::Active,
             modified_by: user_from_db.get_user_id().to_owned(),
+            expires_at: None,
         },
     )
     .await;
@@ -1505,6 +1558,77 @@ pub async fn create_tenant_user(
     Ok(ApplicationResponse::StatusOk)
 }
 
+pub async fn impersonate_user(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_api::ImpersonateUserRequest,
+) -> UserResponse<user_api::TokenResponse> {
+    let user_email = domain::UserEmail::from_pii_email(request.email)?;
+    let target_user: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_email(&user_email)
+        .await
+        .to_not_found_response(UserErrors::UserNotFound)?
+        .into();
+
+    if target_user.get_user_id() == user_from_token.user_id {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "User cannot impersonate themselves".to_string()
+        )));
+    }
+
+    // Only the users of the entity of the impersonator can be impersonated, with the role they
+    // hold in it
+    let target_user_role =
+        find_user_role_in_lineage(&state, &user_from_token, target_user.get_user_id()).await?;
+    if target_user_role.status != UserStatus::Active {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "User is not active in the entity".to_string()
+        )));
+    }
+
+    let token = auth::SinglePurposeToken::new_impersonation_token(
+        target_user.get_user_id().to_string(),
+        user_from_token.user_id.clone(),
+        auth::ImpersonatedUserRole {
+            role_id: target_user_role.role_id,
+            org_id: user_from_token.org_id.clone(),
+            merchant_id: user_from_token.merchant_id.clone(),
+            profile_id: user_from_token.profile_id.clone(),
+        },
+        &state.conf,
+        Some(
+            user_from_token
+                .tenant_id
+                .clone()
+                .unwrap_or(state.tenant.tenant_id.clone()),
+        ),
+    )
+    .await?;
+
+    logger::info!(
+        impersonator_user_id = %user_from_token.user_id,
+        impersonated_user_id = %target_user.get_user_id(),
+        "Issued impersonation token"
+    );
+
+    super::user_role::insert_role_audit(
+        &state,
+        &user_from_token,
+        common_enums::RoleAuditAction::ImpersonateUser,
+        target_user.get_user_id().to_string(),
+        Vec::new(),
+        Vec::new(),
+        request.reason,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(user_api::TokenResponse {
+        token: token.into(),
+        token_type: common_enums::TokenPurpose::Impersonation,
+    }))
+}
+
 #[cfg(feature = "v1")]
 pub async fn create_org_merchant_for_user(
     state: SessionState,
@@ -3110,8 +3234,9 @@ pub async fn switch_org_for_user(
         .into());
     }
 
-    let (merchant_id, profile_id, role_id) = match role_info.get_entity_type() {
+    let (merchant_id, profile_id, role_id, role_expires_at) = match role_info.get_entity_type() {
         EntityType::Tenant => {
+            let role_expires_at = user_from_token.get_role_expiry_from_db(&state).await?;
             let merchant_id = state
                 .store
                 .list_merchant_accounts_by_organization_id(&(&state).into(), &request.org_id)
@@ -3144,7 +3269,12 @@ pub async fn switch_org_for_user(
                 .get_id()
                 .to_owned();
 
-            (merchant_id, profile_id, user_from_token.role_id)
+            (
+                merchant_id,
+                profile_id,
+                user_from_token.role_id,
+                role_expires_at,
+            )
         }
         EntityType::Organization | EntityType::Merchant | EntityType::Profile => {
             let user_role = state
@@ -3174,7 +3304,12 @@ pub async fn switch_org_for_user(
             let (merchant_id, profile_id) =
                 utils::user_role::get_single_merchant_id_and_profile_id(&state, &user_role).await?;
 
-            (merchant_id, profile_id, user_role.role_id)
+            (
+                merchant_id,
+                profile_id,
+                user_role.role_id,
+                user_role.expires_at,
+            )
         }
     };
 
@@ -3186,6 +3321,7 @@ pub async fn switch_org_for_user(
         role_id.clone(),
         profile_id.clone(),
         user_from_token.tenant_id.clone(),
+        role_expires_at,
     )
     .await?;
 
@@ -3212,6 +3348,7 @@ pub async fn switch_merchant_for_user_in_org(
     state: SessionState,
     request: user_api::SwitchMerchantRequest,
     user_from_token: auth::UserFromToken,
+    req_state: ReqState,
 ) -> UserResponse<user_api::TokenResponse> {
     if user_from_token.merchant_id == request.merchant_id {
         return Err(UserErrors::InvalidRoleOperationWithMessage(
@@ -3235,7 +3372,8 @@ pub async fn switch_merchant_for_user_in_org(
     .attach_printable("Failed to retrieve role information")?;
 
     // Check if the role is internal and handle separately
-    let (org_id, merchant_id, profile_id, role_id) = if role_info.is_internal() {
+    let (org_id, merchant_id, profile_id, role_id, role_expires_at) = if role_info.is_internal() {
+        let role_expires_at = user_from_token.get_role_expiry_from_db(&state).await?;
         let merchant_key_store = state
             .store
             .get_merchant_key_store_by_merchant_id(
@@ -3277,11 +3415,13 @@ pub async fn switch_merchant_for_user_in_org(
             request.merchant_id,
             profile_id,
             user_from_token.role_id.clone(),
+            role_expires_at,
         )
     } else {
         // Match based on the other entity types
         match role_info.get_entity_type() {
             EntityType::Tenant | EntityType::Organization => {
+                let role_expires_at = user_from_token.get_role_expiry_from_db(&state).await?;
                 let merchant_key_store = state
                     .store
                     .get_merchant_key_store_by_merchant_id(
@@ -3330,6 +3470,7 @@ pub async fn switch_merchant_for_user_in_org(
                     merchant_id,
                     profile_id,
                     user_from_token.role_id.clone(),
+                    role_expires_at,
                 )
             }
 
@@ -3368,11 +3509,15 @@ pub async fn switch_merchant_for_user_in_org(
                     merchant_id,
                     profile_id,
                     user_role.role_id,
+                    user_role.expires_at,
                 )
             }
         }
     };
 
+    let user_id = user_from_token.user_id.clone();
+    let old_merchant_id = user_from_token.merchant_id.clone();
+
     let token = utils::user::generate_jwt_auth_token_with_attributes(
         &state,
         user_from_token.user_id,
@@ -3381,6 +3526,7 @@ pub async fn switch_merchant_for_user_in_org(
         role_id.clone(),
         profile_id,
         user_from_token.tenant_id.clone(),
+        role_expires_at,
     )
     .await?;
 
@@ -3395,6 +3541,16 @@ pub async fn switch_merchant_for_user_in_org(
     )
     .await;
 
+    // Emitting is best-effort, failures are only logged and don't affect the switch
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::MerchantSwitched {
+            user_id,
+            old_merchant_id,
+            new_merchant_id: merchant_id,
+        }))
+        .emit();
+
     let response = user_api::TokenResponse {
         token: token.clone(),
         token_type: common_enums::TokenPurpose::UserInfo,
@@ -3429,8 +3585,9 @@ pub async fn switch_profile_for_user_in_org_and_merchant(
     .change_context(UserErrors::InternalServerError)
     .attach_printable("Failed to retrieve role information")?;
 
-    let (profile_id, role_id) = match role_info.get_entity_type() {
+    let (profile_id, role_id, role_expires_at) = match role_info.get_entity_type() {
         EntityType::Tenant | EntityType::Organization | EntityType::Merchant => {
+            let role_expires_at = user_from_token.get_role_expiry_from_db(&state).await?;
             let merchant_key_store = state
                 .store
                 .get_merchant_key_store_by_merchant_id(
@@ -3456,7 +3613,7 @@ pub async fn switch_profile_for_user_in_org_and_merchant(
                 ))?
                 .get_id()
                 .to_owned();
-            (profile_id, user_from_token.role_id)
+            (profile_id, user_from_token.role_id, role_expires_at)
         }
 
         EntityType::Profile => {
@@ -3485,7 +3642,7 @@ pub async fn switch_profile_for_user_in_org_and_merchant(
                     "No user role associated with the profile".to_string(),
                 ))?;
 
-            (request.profile_id, user_role.role_id)
+            (request.profile_id, user_role.role_id, user_role.expires_at)
         }
     };
 
@@ -3497,6 +3654,7 @@ pub async fn switch_profile_for_user_in_org_and_merchant(
         role_id.clone(),
         profile_id,
         user_from_token.tenant_id.clone(),
+        role_expires_at,
     )
     .await?;
 
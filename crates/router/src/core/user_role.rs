@@ -1,79 +1,215 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 use api_models::{
     user as user_api,
     user_role::{self as user_role_api, role as role_api},
 };
+use common_utils::{id_type, pii};
 use diesel_models::{
     enums::{UserRoleVersion, UserStatus},
     organization::OrganizationBridge,
-    user_role::UserRoleUpdate,
+    role::ListRolesByEntityPayload,
+    role_audit::RoleAuditNew,
+    user_role::{UserRole, UserRoleNew, UserRoleUpdate},
 };
 use error_stack::{report, ResultExt};
 use masking::Secret;
 use once_cell::sync::Lazy;
+use router_env::logger;
 
+#[cfg(feature = "v1")]
+use crate::core::webhooks as webhooks_core;
+#[cfg(feature = "email")]
+use crate::services::email::types as email_types;
 use crate::{
-    core::errors::{StorageErrorExt, UserErrors, UserResponse},
+    consts,
+    core::{
+        api_locking,
+        errors::{StorageErrorExt, UserErrors, UserResponse, UserResult},
+    },
     db::user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload},
-    routes::{app::ReqState, SessionState},
+    routes::{app::ReqState, lock_utils, SessionState},
     services::{
         authentication as auth,
         authorization::{
             info,
             permission_groups::{ParentGroupExt, PermissionGroupExt},
+            permissions::Permission,
             roles,
         },
         ApplicationResponse,
     },
-    types::domain,
+    types::{domain, storage, transformers::ForeignInto},
     utils,
 };
 pub mod role;
-use common_enums::{EntityType, ParentGroup, PermissionGroup};
+use common_enums::{
+    EntityType, ParentGroup, PermissionGroup, RoleAssignmentAttribute, RoleAuditAction, RoleScope,
+};
 use strum::IntoEnumIterator;
 
 // TODO: To be deprecated
 pub async fn get_authorization_info_with_groups(
-    _state: SessionState,
-) -> UserResponse<user_role_api::AuthorizationInfoResponse> {
-    Ok(ApplicationResponse::Json(
-        user_role_api::AuthorizationInfoResponse(
-            info::get_group_authorization_info()
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::GetAuthorizationInfoRequest,
+) -> UserResponse<user_role_api::GetAuthorizationInfoResponse> {
+    let custom_permission_groups = state
+        .global_store
+        .list_custom_permission_groups_for_merchant(
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list custom permission groups")?;
+
+    let authorization_info = info::get_group_authorization_info()
+        .into_iter()
+        .map(user_role_api::AuthorizationInfo::Group)
+        .chain(
+            custom_permission_groups
                 .into_iter()
-                .map(user_role_api::AuthorizationInfo::Group)
-                .collect(),
-        ),
+                .map(|permission_group| {
+                    user_role_api::AuthorizationInfo::CustomGroup(
+                        utils::user_role::get_custom_permission_group_response(permission_group),
+                    )
+                }),
+        )
+        .collect();
+
+    let response = if request.include_feature_flags {
+        let role_info = user_from_token.get_role_info_from_db(&state).await?;
+
+        user_role_api::GetAuthorizationInfoResponse::AuthorizationInfoWithFeatureFlags {
+            authorization_info,
+            feature_flags: get_feature_flags_for_groups(&role_info.get_permission_groups()),
+        }
+    } else {
+        user_role_api::GetAuthorizationInfoResponse::AuthorizationInfo(
+            user_role_api::AuthorizationInfoResponse(authorization_info),
+        )
+    };
+
+    let etag = generate_etag(&response)?;
+
+    Ok(ApplicationResponse::JsonWithHeaders((
+        response,
+        vec![(actix_web::http::header::ETAG.to_string(), etag.into())],
+    )))
+}
+
+/// Generates a strong ETag from the serialized payload, so that it changes whenever
+/// the permission group definitions served in the payload change.
+fn generate_etag<T: serde::Serialize>(payload: &T) -> UserResult<String> {
+    let serialized_payload = serde_json::to_vec(payload)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to serialize payload for etag generation")?;
+
+    Ok(format!(
+        "\"{}\"",
+        blake3::hash(&serialized_payload).to_hex()
     ))
 }
 
+pub fn get_feature_flags_for_groups(
+    groups: &[PermissionGroup],
+) -> HashMap<user_role_api::RoleFeatureFlag, bool> {
+    user_role_api::RoleFeatureFlag::iter()
+        .map(|flag| {
+            (
+                flag,
+                groups.contains(&get_required_group_for_feature_flag(flag)),
+            )
+        })
+        .collect()
+}
+
+fn get_required_group_for_feature_flag(flag: user_role_api::RoleFeatureFlag) -> PermissionGroup {
+    use user_role_api::RoleFeatureFlag;
+
+    match flag {
+        RoleFeatureFlag::CanViewOperations => PermissionGroup::OperationsView,
+        RoleFeatureFlag::CanManageOperations => PermissionGroup::OperationsManage,
+        RoleFeatureFlag::CanViewConnectors => PermissionGroup::ConnectorsView,
+        RoleFeatureFlag::CanManageConnectors => PermissionGroup::ConnectorsManage,
+        RoleFeatureFlag::CanViewWorkflows => PermissionGroup::WorkflowsView,
+        RoleFeatureFlag::CanManageWorkflows => PermissionGroup::WorkflowsManage,
+        RoleFeatureFlag::CanViewAnalytics => PermissionGroup::AnalyticsView,
+        RoleFeatureFlag::CanViewUsers => PermissionGroup::UsersView,
+        RoleFeatureFlag::CanManageUsers => PermissionGroup::UsersManage,
+        RoleFeatureFlag::CanViewAccount => PermissionGroup::AccountView,
+        RoleFeatureFlag::CanManageAccount => PermissionGroup::AccountManage,
+        RoleFeatureFlag::CanViewReconReports => PermissionGroup::ReconReportsView,
+        RoleFeatureFlag::CanManageReconReports => PermissionGroup::ReconReportsManage,
+        RoleFeatureFlag::CanViewReconOps => PermissionGroup::ReconOpsView,
+        RoleFeatureFlag::CanManageReconOps => PermissionGroup::ReconOpsManage,
+    }
+}
+
 pub async fn get_authorization_info_with_group_tag(
+    state: SessionState,
+    request: user_role_api::GetRoleInformationRequest,
 ) -> UserResponse<user_role_api::AuthorizationInfoResponse> {
-    static GROUPS_WITH_PARENT_TAGS: Lazy<Vec<user_role_api::ParentInfo>> = Lazy::new(|| {
-        PermissionGroup::iter()
-            .map(|group| (group.parent(), group))
-            .fold(
-                HashMap::new(),
-                |mut acc: HashMap<ParentGroup, Vec<PermissionGroup>>, (key, value)| {
-                    acc.entry(key).or_default().push(value);
-                    acc
-                },
-            )
-            .into_iter()
-            .map(|(name, value)| user_role_api::ParentInfo {
-                name: name.clone(),
-                description: info::get_parent_group_description(name),
-                groups: value,
-            })
-            .collect()
-    });
+    static GROUPS_WITH_PARENT_TAGS: Lazy<Vec<(ParentGroup, Vec<PermissionGroup>)>> =
+        Lazy::new(|| {
+            PermissionGroup::iter()
+                .map(|group| (group.parent(), group))
+                .fold(
+                    HashMap::new(),
+                    |mut acc: HashMap<ParentGroup, Vec<PermissionGroup>>, (key, value)| {
+                        acc.entry(key).or_default().push(value);
+                        acc
+                    },
+                )
+                .into_iter()
+                .collect()
+        });
+
+    let language =
+        info::DescriptionLanguage::from_locale(request.lang.as_deref().unwrap_or(&state.locale));
+    let requested_tags = request
+        .tags
+        .as_deref()
+        .map(|tags| {
+            tags.split(',')
+                .filter_map(|tag| ParentGroup::from_str(tag.trim()).ok())
+                .collect::<HashSet<_>>()
+        })
+        .unwrap_or_default();
 
     Ok(ApplicationResponse::Json(
         user_role_api::AuthorizationInfoResponse(
             GROUPS_WITH_PARENT_TAGS
                 .iter()
-                .cloned()
-                .map(user_role_api::AuthorizationInfo::GroupWithTag)
+                .filter(|(name, _)| requested_tags.is_empty() || requested_tags.contains(name))
+                .map(|(name, groups)| {
+                    user_role_api::AuthorizationInfo::GroupWithTag(user_role_api::ParentInfo {
+                        name: name.clone(),
+                        description: info::get_parent_group_description(name.clone()),
+                        groups: groups.clone(),
+                        group_descriptions: groups
+                            .iter()
+                            .map(|group| user_role_api::GroupDescription {
+                                group: *group,
+                                description: info::get_localized_group_description(
+                                    *group, language,
+                                ),
+                                category: info::get_localized_parent_group_name(
+                                    name.clone(),
+                                    language,
+                                ),
+                            })
+                            .collect(),
+                    })
+                })
                 .collect(),
         ),
     ))
@@ -116,18 +252,57 @@ pub async fn get_parent_group_info(
     Ok(ApplicationResponse::Json(parent_groups))
 }
 
+pub async fn get_whoami(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<user_role_api::WhoAmIResponse> {
+    // Both lookups are independent, and the role lookup is served from the
+    // predefined roles without a DB call in most cases
+    let (user, role_info) = futures::future::try_join(
+        user_from_token.get_user_from_db(&state),
+        user_from_token.get_role_info_from_db(&state),
+    )
+    .await?;
+
+    let permissions = Permission::iter()
+        .filter(|permission| role_info.check_permission_exists(*permission))
+        .map(|permission| permission.to_string())
+        .collect();
+
+    Ok(ApplicationResponse::Json(user_role_api::WhoAmIResponse {
+        schema_version: consts::user_role::WHOAMI_SCHEMA_VERSION,
+        user_id: user.get_user_id().to_string(),
+        email: user.get_email(),
+        name: user.get_name(),
+        tenant_id: user_from_token
+            .tenant_id
+            .unwrap_or(state.tenant.tenant_id.clone()),
+        org_id: user_from_token.org_id,
+        merchant_id: user_from_token.merchant_id,
+        profile_id: user_from_token.profile_id,
+        role_id: user_from_token.role_id,
+        role_name: role_info.get_role_name().to_string(),
+        entity_type: role_info.get_entity_type(),
+        role_scope: role_info.get_scope(),
+        groups: role_info.get_permission_groups(),
+        permissions,
+    }))
+}
+
 pub async fn update_user_role(
     state: SessionState,
     user_from_token: auth::UserFromToken,
     req: user_role_api::UpdateUserRoleRequest,
     _req_state: ReqState,
-) -> UserResponse<()> {
+) -> UserResponse<role_api::CompareRolesResponse> {
     let role_info = roles::RoleInfo::from_role_id_in_lineage(
         &state,
         &req.role_id,
         &user_from_token.merchant_id,
         &user_from_token.org_id,
-        &user_from_token.profile_id,
+        req.profile_id
+            .as_ref()
+            .unwrap_or(&user_from_token.profile_id),
         user_from_token
             .tenant_id
             .as_ref()
@@ -141,6 +316,15 @@ pub async fn update_user_role(
             .attach_printable(format!("User role cannot be updated to {}", req.role_id));
     }
 
+    if role_info.is_deleted() {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Deleted roles cannot be assigned to users".to_string(),
+        )
+        .into());
+    }
+
+    utils::user_role::validate_user_role_expiry(req.expires_at)?;
+
     let user_to_be_updated =
         utils::user::get_user_from_db_by_email(&state, domain::UserEmail::try_from(req.email)?)
             .await
@@ -164,7 +348,22 @@ pub async fn update_user_role(
     .await
     .change_context(UserErrors::InternalServerError)?;
 
+    if let Some(profile_id) = req.profile_id.clone() {
+        return Box::pin(update_profile_user_role(
+            state,
+            user_from_token,
+            req,
+            profile_id,
+            role_info,
+            updator_role,
+            user_to_be_updated,
+        ))
+        .await;
+    }
+
     let mut is_updated = false;
+    let mut previous_role_groups = Vec::new();
+    let mut previous_role_id = None;
 
     let v2_user_role_to_be_updated = match state
         .global_store
@@ -227,6 +426,15 @@ pub async fn update_user_role(
             ));
         }
 
+        if req.dry_run {
+            return Ok(ApplicationResponse::Json(role::get_role_groups_comparison(
+                user_role.role_id,
+                &role_to_be_updated,
+                req.role_id,
+                &role_info,
+            )));
+        }
+
         state
             .global_store
             .update_user_role_by_user_id_and_lineage(
@@ -241,6 +449,7 @@ pub async fn update_user_role(
                 UserRoleUpdate::UpdateRole {
                     role_id: req.role_id.clone(),
                     modified_by: user_from_token.user_id.clone(),
+                    expires_at: req.expires_at,
                 },
                 UserRoleVersion::V2,
             )
@@ -248,6 +457,8 @@ pub async fn update_user_role(
             .change_context(UserErrors::InternalServerError)?;
 
         is_updated = true;
+        previous_role_groups = role_to_be_updated.get_permission_groups();
+        previous_role_id = Some(user_role.role_id);
     }
 
     let v1_user_role_to_be_updated = match state
@@ -311,6 +522,15 @@ pub async fn update_user_role(
             ));
         }
 
+        if req.dry_run {
+            return Ok(ApplicationResponse::Json(role::get_role_groups_comparison(
+                user_role.role_id,
+                &role_to_be_updated,
+                req.role_id,
+                &role_info,
+            )));
+        }
+
         state
             .global_store
             .update_user_role_by_user_id_and_lineage(
@@ -324,7 +544,8 @@ pub async fn update_user_role(
                 Some(&user_from_token.profile_id),
                 UserRoleUpdate::UpdateRole {
                     role_id: req.role_id.clone(),
-                    modified_by: user_from_token.user_id,
+                    modified_by: user_from_token.user_id.clone(),
+                    expires_at: req.expires_at,
                 },
                 UserRoleVersion::V1,
             )
@@ -332,6 +553,8 @@ pub async fn update_user_role(
             .change_context(UserErrors::InternalServerError)?;
 
         is_updated = true;
+        previous_role_groups = role_to_be_updated.get_permission_groups();
+        previous_role_id = Some(user_role.role_id);
     }
 
     if !is_updated {
@@ -341,15 +564,509 @@ pub async fn update_user_role(
 
     auth::blacklist::insert_user_in_blacklist(&state, user_to_be_updated.get_user_id()).await?;
 
+    insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::UpdateUserRole,
+        user_to_be_updated.get_user_id().to_string(),
+        previous_role_groups,
+        role_info.get_permission_groups(),
+        None,
+    )
+    .await;
+
+    if let Some(old_role_id) = previous_role_id {
+        trigger_user_role_outgoing_webhook(
+            &state,
+            &user_from_token,
+            common_enums::EventType::UserRoleUpdated,
+            user_role_api::UserRoleWebhookContent {
+                user_id: user_to_be_updated.get_user_id().to_string(),
+                merchant_id: user_from_token.merchant_id.clone(),
+                old_role_id,
+                new_role_id: Some(req.role_id),
+            },
+        );
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Assigns a role to the user within a single profile of the merchant. An existing profile level
+/// assignment is updated in place, otherwise a new one is created for a user who already belongs to
+/// the merchant. Merchant and organization level assignments already apply to every profile, so
+/// they are never narrowed down to a profile here.
+async fn update_profile_user_role(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::UpdateUserRoleRequest,
+    profile_id: common_utils::id_type::ProfileId,
+    role_info: roles::RoleInfo,
+    updator_role: roles::RoleInfo,
+    user_to_be_updated: domain::UserFromStorage,
+) -> UserResponse<role_api::CompareRolesResponse> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+
+    if updator_role.get_entity_type() == EntityType::Profile
+        && user_from_token.profile_id != profile_id
+    {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Cannot assign roles in a different profile".to_string(),
+        )))
+        .attach_printable(format!(
+            "Profile level user of {:?} is trying to assign a role in {:?}",
+            user_from_token.profile_id, profile_id
+        ));
+    }
+
+    if role_info.get_entity_type() != EntityType::Profile {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Only profile level roles can be assigned within a profile".to_string(),
+        )
+        .into());
+    }
+
+    let key_manager_state = &(&state).into();
+    let merchant_key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &user_from_token.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to retrieve merchant key store by merchant_id")?;
+
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &merchant_key_store,
+            &user_from_token.merchant_id,
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(UserErrors::InvalidRoleOperationWithMessage(
+            "No such profile found for the merchant".to_string(),
+        ))?;
+
+    let user_role_in_lineage = match state
+        .global_store
+        .find_user_role_by_user_id_and_lineage(
+            user_to_be_updated.get_user_id(),
+            tenant_id,
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+            &profile_id,
+            UserRoleVersion::V2,
+        )
+        .await
+    {
+        Ok(user_role) => Some(user_role),
+        Err(e) => {
+            if e.current_context().is_db_not_found() {
+                None
+            } else {
+                return Err(UserErrors::InternalServerError.into());
+            }
+        }
+    };
+
+    let (previous_role_id, previous_role_groups) = match user_role_in_lineage {
+        Some(user_role) => {
+            if user_role.profile_id.is_none() {
+                return Err(UserErrors::InvalidRoleOperationWithMessage(
+                    "User already has a role that applies to all profiles of the merchant"
+                        .to_string(),
+                )
+                .into());
+            }
+
+            let role_to_be_updated = roles::RoleInfo::from_role_id_org_id_tenant_id(
+                &state,
+                &user_role.role_id,
+                &user_from_token.org_id,
+                tenant_id,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+
+            if !role_to_be_updated.is_updatable() {
+                return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+                    "User role cannot be updated from {}",
+                    role_to_be_updated.get_role_id()
+                ));
+            }
+
+            if req.dry_run {
+                return Ok(ApplicationResponse::Json(role::get_role_groups_comparison(
+                    user_role.role_id,
+                    &role_to_be_updated,
+                    req.role_id,
+                    &role_info,
+                )));
+            }
+
+            state
+                .global_store
+                .update_user_role_by_user_id_and_lineage(
+                    user_to_be_updated.get_user_id(),
+                    tenant_id,
+                    &user_from_token.org_id,
+                    Some(&user_from_token.merchant_id),
+                    Some(&profile_id),
+                    UserRoleUpdate::UpdateRole {
+                        role_id: req.role_id.clone(),
+                        modified_by: user_from_token.user_id.clone(),
+                        expires_at: req.expires_at,
+                    },
+                    UserRoleVersion::V2,
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+
+            (
+                Some(user_role.role_id),
+                role_to_be_updated.get_permission_groups(),
+            )
+        }
+        None => {
+            if req.dry_run {
+                return Err(UserErrors::InvalidRoleOperationWithMessage(
+                    "Dry run is only supported for existing profile level roles".to_string(),
+                )
+                .into());
+            }
+
+            let user_roles_in_merchant = state
+                .global_store
+                .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+                    user_id: user_to_be_updated.get_user_id(),
+                    tenant_id,
+                    org_id: Some(&user_from_token.org_id),
+                    merchant_id: Some(&user_from_token.merchant_id),
+                    profile_id: None,
+                    entity_id: None,
+                    version: None,
+                    status: None,
+                    limit: Some(1),
+                })
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+
+            if user_roles_in_merchant.is_empty() {
+                return Err(report!(UserErrors::InvalidRoleOperation))
+                    .attach_printable("User with given email is not found in the merchant");
+            }
+
+            let now = common_utils::date_time::now();
+            domain::NewUserRole {
+                user_id: user_to_be_updated.get_user_id().to_owned(),
+                role_id: req.role_id.clone(),
+                status: UserStatus::Active,
+                created_by: user_from_token.user_id.clone(),
+                last_modified_by: user_from_token.user_id.clone(),
+                created_at: now,
+                last_modified: now,
+                entity: domain::NoLevel,
+            }
+            .add_entity(domain::ProfileLevel {
+                tenant_id: tenant_id.to_owned(),
+                org_id: user_from_token.org_id.clone(),
+                merchant_id: user_from_token.merchant_id.clone(),
+                profile_id: profile_id.clone(),
+            })
+            .insert_in_v2(&state)
+            .await?;
+
+            if req.expires_at.is_some() {
+                state
+                    .global_store
+                    .update_user_role_by_user_id_and_lineage(
+                        user_to_be_updated.get_user_id(),
+                        tenant_id,
+                        &user_from_token.org_id,
+                        Some(&user_from_token.merchant_id),
+                        Some(&profile_id),
+                        UserRoleUpdate::UpdateRole {
+                            role_id: req.role_id.clone(),
+                            modified_by: user_from_token.user_id.clone(),
+                            expires_at: req.expires_at,
+                        },
+                        UserRoleVersion::V2,
+                    )
+                    .await
+                    .change_context(UserErrors::InternalServerError)?;
+            }
+
+            (None, Vec::new())
+        }
+    };
+
+    auth::blacklist::insert_user_in_blacklist(&state, user_to_be_updated.get_user_id()).await?;
+
+    insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::UpdateUserRole,
+        user_to_be_updated.get_user_id().to_string(),
+        previous_role_groups,
+        role_info.get_permission_groups(),
+        None,
+    )
+    .await;
+
+    if let Some(old_role_id) = previous_role_id {
+        trigger_user_role_outgoing_webhook(
+            &state,
+            &user_from_token,
+            common_enums::EventType::UserRoleUpdated,
+            user_role_api::UserRoleWebhookContent {
+                user_id: user_to_be_updated.get_user_id().to_string(),
+                merchant_id: user_from_token.merchant_id.clone(),
+                old_role_id,
+                new_role_id: Some(req.role_id),
+            },
+        );
+    }
+
     Ok(ApplicationResponse::StatusOk)
 }
 
+/// Records a role mutation in the role audit trail. Failures are only logged, as the audit
+/// trail should never block the mutation it describes.
+pub async fn insert_role_audit(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    action: RoleAuditAction,
+    target_id: String,
+    groups_before: Vec<PermissionGroup>,
+    groups_after: Vec<PermissionGroup>,
+    reason: Option<String>,
+) {
+    let role_audit = RoleAuditNew {
+        action,
+        actor_user_id: user_from_token.user_id.clone(),
+        target_id,
+        groups_before,
+        groups_after,
+        merchant_id: user_from_token.merchant_id.clone(),
+        org_id: user_from_token.org_id.clone(),
+        tenant_id: user_from_token
+            .tenant_id
+            .clone()
+            .unwrap_or(state.tenant.tenant_id.clone()),
+        created_at: common_utils::date_time::now(),
+        reason,
+    };
+
+    if let Err(error) = state.global_store.insert_role_audit(role_audit).await {
+        logger::warn!(role_audit_insert_error=?error, "Failed to insert role audit");
+    }
+}
+
+/// Notifies the merchant about a change in role assignment through outgoing webhooks. The
+/// webhook is sent in the background, so delivery failures never block the role change and are
+/// retried by the webhook delivery subsystem instead.
+#[cfg(feature = "v1")]
+fn trigger_user_role_outgoing_webhook(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    event_type: common_enums::EventType,
+    content: user_role_api::UserRoleWebhookContent,
+) {
+    use router_env::tracing::Instrument;
+
+    let state = state.clone();
+    let merchant_id = user_from_token.merchant_id.clone();
+    let profile_id = user_from_token.profile_id.clone();
+
+    tokio::spawn(
+        async move {
+            if let Err(error) = Box::pin(create_user_role_outgoing_webhook(
+                state,
+                merchant_id,
+                profile_id,
+                event_type,
+                content,
+            ))
+            .await
+            {
+                logger::error!(?error, "Failed to trigger user role outgoing webhook");
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+#[cfg(feature = "v2")]
+fn trigger_user_role_outgoing_webhook(
+    _state: &SessionState,
+    _user_from_token: &auth::UserFromToken,
+    _event_type: common_enums::EventType,
+    _content: user_role_api::UserRoleWebhookContent,
+) {
+    // Outgoing webhooks are not supported in v2 yet
+}
+
+#[cfg(feature = "v1")]
+async fn create_user_role_outgoing_webhook(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    event_type: common_enums::EventType,
+    content: user_role_api::UserRoleWebhookContent,
+) -> UserResult<()> {
+    let key_manager_state = &(&state).into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to fetch merchant key store")?;
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to fetch merchant account")?;
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to fetch business profile")?;
+
+    // Every role change is a separate event, so the primary object id has to be unique per
+    // change for the idempotent event id to not deduplicate them
+    let primary_object_id = common_utils::generate_id_with_default_len("user_role_event");
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        event_type,
+        diesel_models::enums::EventClass::UserRoles,
+        primary_object_id,
+        diesel_models::enums::EventObjectType::UserRoleDetails,
+        api_models::webhooks::OutgoingWebhookContent::UserRoleDetails(Box::new(content)),
+        None,
+    ))
+    .await
+    .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn bulk_update_user_roles(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    requests: user_role_api::BulkUpdateUserRolesRequest,
+    req_state: ReqState,
+) -> UserResponse<Vec<user_role_api::BulkUpdateUserRoleResponse>> {
+    let mut responses = Vec::with_capacity(requests.len());
+
+    // Entries are processed one after the other so that each update is isolated and a failure
+    // in one entry does not affect the others
+    for request in requests {
+        let email = request.email.clone();
+        let is_dry_run = request.dry_run;
+        let response = match update_user_role(
+            state.clone(),
+            user_from_token.clone(),
+            request,
+            req_state.clone(),
+        )
+        .await
+        {
+            Ok(_) => user_role_api::BulkUpdateUserRoleResponse {
+                email,
+                is_updated: !is_dry_run,
+                error: None,
+            },
+            Err(error) => {
+                logger::error!(bulk_update_user_role_error=?error);
+
+                user_role_api::BulkUpdateUserRoleResponse {
+                    email,
+                    is_updated: false,
+                    error: Some(error.current_context().get_error_message()),
+                }
+            }
+        };
+        responses.push(response);
+    }
+
+    Ok(ApplicationResponse::Json(responses))
+}
+
+/// Invitations are accepted under a lock on the user and the entities, so that retried or
+/// concurrent accepts of the same invitations do not race each other
+fn get_accept_invitations_lock_action(
+    user_id: &str,
+    entities: &[user_role_api::Entity],
+) -> api_locking::LockAction {
+    let mut entities = entities
+        .iter()
+        .map(|entity| format!("{}_{}", entity.entity_type, entity.entity_id))
+        .collect::<Vec<_>>();
+    entities.sort();
+
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: format!("{}_{}", user_id, entities.join("_")),
+            api_identifier: lock_utils::ApiIdentifier::UserRole,
+            override_lock_retries: None,
+        },
+    }
+}
+
 pub async fn accept_invitations_v2(
     state: SessionState,
     user_from_token: auth::UserFromToken,
     req: user_role_api::AcceptInvitationsV2Request,
-) -> UserResponse<()> {
-    let lineages = futures::future::try_join_all(req.into_iter().map(|entity| {
+) -> UserResponse<Vec<user_role_api::AcceptInvitationResponse>> {
+    // The lock is not scoped to the merchant of the token, as invitations of other merchants
+    // can be accepted too
+    let merchant_id = id_type::MerchantId::get_merchant_id_not_found();
+    let lock_action = get_accept_invitations_lock_action(&user_from_token.user_id, &req);
+    lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.clone())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let accept_result = accept_invitations_v2_for_user(state.clone(), user_from_token, req).await;
+
+    // The invitations may have been accepted already, and the lock expires by itself, so a
+    // failure to release it does not fail the request
+    lock_action
+        .free_lock_action(&state, merchant_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to release the accept invitations lock"))
+        .ok();
+
+    accept_result
+}
+
+async fn accept_invitations_v2_for_user(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::AcceptInvitationsV2Request,
+) -> UserResponse<Vec<user_role_api::AcceptInvitationResponse>> {
+    req.iter()
+        .try_for_each(|entity| utils::user_role::validate_user_role_expiry(entity.expires_at))?;
+
+    let lineages = futures::future::try_join_all(req.iter().map(|entity| async {
         utils::user_role::get_lineage_for_user_id_and_entity_for_accepting_invite(
             &state,
             &user_from_token.user_id,
@@ -357,9 +1074,11 @@ pub async fn accept_invitations_v2(
                 .tenant_id
                 .as_ref()
                 .unwrap_or(&state.tenant.tenant_id),
-            entity.entity_id,
+            entity.entity_id.clone(),
             entity.entity_type,
         )
+        .await
+        .map(|lineage| lineage.map(|lineage| (lineage, entity.expires_at)))
     }))
     .await?
     .into_iter()
@@ -367,7 +1086,7 @@ pub async fn accept_invitations_v2(
     .collect::<Vec<_>>();
 
     let update_results = futures::future::join_all(lineages.iter().map(
-        |(org_id, merchant_id, profile_id)| async {
+        |((org_id, merchant_id, profile_id), expires_at)| async {
             let (update_v1_result, update_v2_result) =
                 utils::user_role::update_v1_and_v2_user_roles_in_db(
                     &state,
@@ -382,34 +1101,237 @@ pub async fn accept_invitations_v2(
                     UserRoleUpdate::UpdateStatus {
                         status: UserStatus::Active,
                         modified_by: user_from_token.user_id.clone(),
+                        expires_at: *expires_at,
                     },
                 )
                 .await;
 
-            if update_v1_result.is_err_and(|err| !err.current_context().is_db_not_found())
-                || update_v2_result.is_err_and(|err| !err.current_context().is_db_not_found())
+            if update_v1_result
+                .as_ref()
+                .is_err_and(|err| !err.current_context().is_db_not_found())
+                || update_v2_result
+                    .as_ref()
+                    .is_err_and(|err| !err.current_context().is_db_not_found())
             {
                 Err(report!(UserErrors::InternalServerError))
             } else {
-                Ok(())
+                Ok(update_v2_result.or(update_v1_result).ok())
             }
         },
     ))
     .await;
 
-    if update_results.is_empty() || update_results.iter().all(Result::is_err) {
-        return Err(UserErrors::MerchantIdNotFound.into());
-    }
-
-    Ok(ApplicationResponse::StatusOk)
-}
+    if update_results.is_empty() {
+        // Retried or concurrent accepts will not find any pending invitation, such requests are
+        // treated as successful if the invitation has already been accepted
+        let is_already_accepted = utils::user_role::is_invitation_already_accepted(
+            &state,
+            &user_from_token.user_id,
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            &req,
+        )
+        .await?;
+
+        if !is_already_accepted {
+            return Err(UserErrors::MerchantIdNotFound.into());
+        }
+    } else if update_results.iter().all(Result::is_err) {
+        return Err(UserErrors::MerchantIdNotFound.into());
+    }
+
+    let accepted_user_roles = update_results
+        .into_iter()
+        .filter_map(|result| result.ok().flatten())
+        .collect::<Vec<_>>();
+
+    // Invitations are already accepted at this point, so failing to resolve the inviters
+    // must not fail the request
+    let inviters = state
+        .global_store
+        .find_users_by_user_ids(
+            accepted_user_roles
+                .iter()
+                .map(|user_role| user_role.created_by.clone())
+                .collect(),
+        )
+        .await
+        .map_err(|err| logger::error!("Failed to fetch inviters: {err:?}"))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|user| (user.user_id.clone(), user))
+        .collect::<HashMap<_, _>>();
+
+    let response = accepted_user_roles
+        .into_iter()
+        .filter_map(|user_role| {
+            let (entity_id, entity_type) = user_role.get_entity_id_and_type()?;
+            let inviter = inviters.get(&user_role.created_by);
+
+            Some(user_role_api::AcceptInvitationResponse {
+                entity_id,
+                entity_type,
+                inviter_name: inviter.map(|user| user.name.clone()).unwrap_or_else(|| {
+                    Secret::new(consts::user_role::UNKNOWN_INVITER_NAME.to_string())
+                }),
+                inviter_email: inviter.map(|user| user.email.clone()),
+            })
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn accept_all_invitations(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<Vec<user_role_api::AcceptAllInvitationsResponse>> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+
+    let pending_user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id: &user_from_token.user_id,
+            tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: Some(UserStatus::InvitationSent),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list user roles by user id and invitation sent")?;
+
+    // V1 and V2 rows of the same invitation share the lineage, they are accepted together
+    let mut seen_lineages = HashSet::new();
+    let invitations = pending_user_roles
+        .into_iter()
+        .filter(|user_role| !user_role.is_expired())
+        .filter(|user_role| {
+            seen_lineages.insert((
+                user_role.org_id.clone(),
+                user_role.merchant_id.clone(),
+                user_role.profile_id.clone(),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    // Failure to accept one invitation must not prevent accepting the others
+    let state = &state;
+    let user_id = user_from_token.user_id.as_str();
+    let response = futures::future::join_all(invitations.into_iter().map(|user_role| async move {
+        let (entity_id, entity_type) = user_role.get_entity_id_and_type()?;
+
+        let is_accepted = match user_role.org_id.as_ref() {
+            Some(org_id) => {
+                let (update_v1_result, update_v2_result) =
+                    utils::user_role::update_v1_and_v2_user_roles_in_db(
+                        state,
+                        user_id,
+                        tenant_id,
+                        org_id,
+                        user_role.merchant_id.as_ref(),
+                        user_role.profile_id.as_ref(),
+                        UserRoleUpdate::UpdateStatus {
+                            status: UserStatus::Active,
+                            modified_by: user_id.to_string(),
+                            expires_at: user_role.expires_at,
+                        },
+                    )
+                    .await;
+
+                let has_failed = [&update_v1_result, &update_v2_result]
+                    .into_iter()
+                    .any(|result| {
+                        result
+                            .as_ref()
+                            .is_err_and(|err| !err.current_context().is_db_not_found())
+                    });
+                let is_accepted =
+                    !has_failed && (update_v1_result.is_ok() || update_v2_result.is_ok());
+
+                if !is_accepted {
+                    logger::error!(
+                        ?update_v1_result,
+                        ?update_v2_result,
+                        "Failed to accept invitation for {entity_type} {entity_id}"
+                    );
+                }
+
+                is_accepted
+            }
+            None => false,
+        };
+
+        Some(user_role_api::AcceptAllInvitationsResponse {
+            entity_id,
+            entity_type,
+            merchant_id: user_role.merchant_id,
+            is_accepted,
+        })
+    }))
+    .await
+    .into_iter()
+    .flatten()
+    .collect();
+
+    Ok(ApplicationResponse::Json(response))
+}
 
 pub async fn accept_invitations_pre_auth(
     state: SessionState,
     user_token: auth::UserFromSinglePurposeToken,
     req: user_role_api::AcceptInvitationsPreAuthRequest,
 ) -> UserResponse<user_api::TokenResponse> {
-    let lineages = futures::future::try_join_all(req.into_iter().map(|entity| {
+    let merchant_id = id_type::MerchantId::get_merchant_id_not_found();
+    let lock_action = get_accept_invitations_lock_action(&user_token.user_id, &req);
+    lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.clone())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let accept_result = accept_invitations_pre_auth_for_user(state.clone(), user_token, req).await;
+
+    // The invitations may have been accepted already, and the lock expires by itself, so a
+    // failure to release it does not fail the request
+    lock_action
+        .free_lock_action(&state, merchant_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to release the accept invitations lock"))
+        .ok();
+
+    accept_result
+}
+
+async fn accept_invitations_pre_auth_for_user(
+    state: SessionState,
+    user_token: auth::UserFromSinglePurposeToken,
+    req: user_role_api::AcceptInvitationsPreAuthRequest,
+) -> UserResponse<user_api::TokenResponse> {
+    req.iter()
+        .try_for_each(|entity| utils::user_role::validate_user_role_expiry(entity.expires_at))?;
+
+    utils::user_role::validate_entities_allowed_for_user(
+        &state,
+        &user_token.user_id,
+        user_token
+            .tenant_id
+            .as_ref()
+            .unwrap_or(&state.tenant.tenant_id),
+        &req,
+    )
+    .await?;
+
+    let lineages = futures::future::try_join_all(req.iter().map(|entity| async {
         utils::user_role::get_lineage_for_user_id_and_entity_for_accepting_invite(
             &state,
             &user_token.user_id,
@@ -417,68 +1339,763 @@ pub async fn accept_invitations_pre_auth(
                 .tenant_id
                 .as_ref()
                 .unwrap_or(&state.tenant.tenant_id),
-            entity.entity_id,
+            entity.entity_id.clone(),
             entity.entity_type,
         )
+        .await
+        .map(|lineage| lineage.map(|lineage| (lineage, entity.expires_at)))
     }))
     .await?
     .into_iter()
     .flatten()
     .collect::<Vec<_>>();
 
-    let update_results = futures::future::join_all(lineages.iter().map(
-        |(org_id, merchant_id, profile_id)| async {
-            let (update_v1_result, update_v2_result) =
-                utils::user_role::update_v1_and_v2_user_roles_in_db(
-                    &state,
-                    user_token.user_id.as_str(),
-                    user_token
-                        .tenant_id
-                        .as_ref()
-                        .unwrap_or(&state.tenant.tenant_id),
-                    org_id,
-                    merchant_id.as_ref(),
-                    profile_id.as_ref(),
-                    UserRoleUpdate::UpdateStatus {
-                        status: UserStatus::Active,
-                        modified_by: user_token.user_id.clone(),
-                    },
-                )
-                .await;
+    let update_results = futures::future::join_all(lineages.iter().map(
+        |((org_id, merchant_id, profile_id), expires_at)| async {
+            let (update_v1_result, update_v2_result) =
+                utils::user_role::update_v1_and_v2_user_roles_in_db(
+                    &state,
+                    user_token.user_id.as_str(),
+                    user_token
+                        .tenant_id
+                        .as_ref()
+                        .unwrap_or(&state.tenant.tenant_id),
+                    org_id,
+                    merchant_id.as_ref(),
+                    profile_id.as_ref(),
+                    UserRoleUpdate::UpdateStatus {
+                        status: UserStatus::Active,
+                        modified_by: user_token.user_id.clone(),
+                        expires_at: *expires_at,
+                    },
+                )
+                .await;
+
+            if update_v1_result.is_err_and(|err| !err.current_context().is_db_not_found())
+                || update_v2_result.is_err_and(|err| !err.current_context().is_db_not_found())
+            {
+                Err(report!(UserErrors::InternalServerError))
+            } else {
+                Ok(())
+            }
+        },
+    ))
+    .await;
+
+    if update_results.is_empty() {
+        // Retried or concurrent accepts will not find any pending invitation, such requests are
+        // treated as successful if the invitation has already been accepted
+        let is_already_accepted = utils::user_role::is_invitation_already_accepted(
+            &state,
+            &user_token.user_id,
+            user_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            &req,
+        )
+        .await?;
+
+        if !is_already_accepted {
+            return Err(UserErrors::MerchantIdNotFound.into());
+        }
+    } else if update_results.iter().all(Result::is_err) {
+        return Err(UserErrors::MerchantIdNotFound.into());
+    }
+
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(user_token.user_id.as_str())
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let current_flow =
+        domain::CurrentFlow::new(user_token, domain::SPTFlow::MerchantSelect.into())?;
+    let next_flow = current_flow.next(user_from_db.clone(), &state).await?;
+
+    let token = next_flow.get_token(&state).await?;
+
+    let response = user_api::TokenResponse {
+        token: token.clone(),
+        token_type: next_flow.get_flow().into(),
+    };
+    auth::cookies::set_cookie_response(response, token)
+}
+
+pub async fn create_role_assignment_rule(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::CreateRoleAssignmentRuleRequest,
+) -> UserResponse<user_role_api::RoleAssignmentRuleResponse> {
+    let value = get_normalized_role_assignment_rule_value(request.attribute, &request.value)?;
+
+    let tenant_id = user_from_token
+        .tenant_id
+        .clone()
+        .unwrap_or(state.tenant.tenant_id.clone());
+
+    let role_info = roles::RoleInfo::from_role_id_in_lineage(
+        &state,
+        &request.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+        &user_from_token.profile_id,
+        &tenant_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    // Rules only apply to merchant level invitations
+    if !role_info.is_invitable() || role_info.get_entity_type() != EntityType::Merchant {
+        return Err(report!(UserErrors::InvalidRoleId)).attach_printable(format!(
+            "role_id = {} cannot be assigned through rules",
+            request.role_id
+        ));
+    }
+
+    let requestor_role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .attach_printable("Invalid role_id in JWT")?;
+    if requestor_role_info.get_entity_type() < role_info.get_entity_type() {
+        return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+            "Invalid operation, rule requestor = {} cannot assign role of entity = {}",
+            requestor_role_info.get_entity_type(),
+            role_info.get_entity_type()
+        ));
+    }
+    role::validate_groups_held_by_user(&requestor_role_info, &role_info.get_permission_groups())
+        .change_context(UserErrors::InvalidRoleOperationWithMessage(
+            "Cannot assign a role with permission groups not held by the user".to_string(),
+        ))?;
+
+    let existing_rules = state
+        .global_store
+        .list_role_assignment_rules_for_merchant(
+            &tenant_id,
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list role assignment rules")?;
+
+    if existing_rules.len() >= consts::user_role::MAX_ROLE_ASSIGNMENT_RULES_PER_MERCHANT {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(format!(
+            "Merchant cannot have more than {} role assignment rules",
+            consts::user_role::MAX_ROLE_ASSIGNMENT_RULES_PER_MERCHANT
+        ))
+        .into());
+    }
+
+    let priority = request.priority.unwrap_or_else(|| {
+        existing_rules
+            .last()
+            .map(|rule| rule.priority.saturating_add(1))
+            .unwrap_or_default()
+    });
+
+    let role_assignment_rule = state
+        .global_store
+        .insert_role_assignment_rule(diesel_models::role_assignment_rule::RoleAssignmentRuleNew {
+            priority,
+            attribute: request.attribute,
+            value,
+            role_id: request.role_id,
+            merchant_id: user_from_token.merchant_id.clone(),
+            org_id: user_from_token.org_id.clone(),
+            tenant_id,
+            created_by: user_from_token.user_id.clone(),
+            created_at: common_utils::date_time::now(),
+        })
+        .await
+        .to_duplicate_response(UserErrors::InvalidRoleOperationWithMessage(format!(
+            "Role assignment rule with priority {priority} already exists"
+        )))?;
+
+    Ok(ApplicationResponse::Json(
+        utils::user_role::get_role_assignment_rule_response(role_assignment_rule),
+    ))
+}
+
+pub async fn list_role_assignment_rules(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<Vec<user_role_api::RoleAssignmentRuleResponse>> {
+    let role_assignment_rules = state
+        .global_store
+        .list_role_assignment_rules_for_merchant(
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list role assignment rules")?;
+
+    Ok(ApplicationResponse::Json(
+        role_assignment_rules
+            .into_iter()
+            .map(utils::user_role::get_role_assignment_rule_response)
+            .collect(),
+    ))
+}
+
+fn get_normalized_role_assignment_rule_value(
+    attribute: RoleAssignmentAttribute,
+    value: &str,
+) -> UserResult<String> {
+    match attribute {
+        RoleAssignmentAttribute::EmailDomain => {
+            let domain = value.trim().trim_start_matches('@').to_lowercase();
+            if domain.is_empty()
+                || domain.len() > consts::user_role::MAX_ROLE_ASSIGNMENT_RULE_VALUE_LENGTH
+                || domain.contains(|c: char| c == '@' || c.is_whitespace())
+            {
+                return Err(UserErrors::InvalidRoleOperationWithMessage(
+                    "Invalid email domain for role assignment rule".to_string(),
+                )
+                .into());
+            }
+
+            Ok(domain)
+        }
+    }
+}
+
+/// Invites a new user to the merchants whose role assignment rules match the user, with the role
+/// of the first matching rule of each merchant. Merchants the user has already been invited to
+/// are left out, as the role was then chosen explicitly.
+pub async fn create_invitations_from_role_assignment_rules(
+    state: &SessionState,
+    user: &domain::UserFromStorage,
+) -> UserResult<()> {
+    let user_email = domain::UserEmail::from_pii_email(user.get_email())?;
+    let Ok(email_domain) = user_email.extract_domain() else {
+        return Ok(());
+    };
+
+    let matching_rules = state
+        .global_store
+        .list_role_assignment_rules_by_attribute_value(
+            &state.tenant.tenant_id,
+            RoleAssignmentAttribute::EmailDomain,
+            &email_domain.to_lowercase(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list role assignment rules")?;
+    let merchants = matching_rules
+        .into_iter()
+        .map(|rule| (rule.org_id, rule.merchant_id))
+        .collect::<HashSet<_>>();
+
+    let existing_merchant_ids = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id: user.get_user_id(),
+            tenant_id: &state.tenant.tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .filter_map(|user_role| user_role.merchant_id)
+        .collect::<HashSet<_>>();
+
+    for (org_id, merchant_id) in merchants {
+        if existing_merchant_ids.contains(&merchant_id) {
+            continue;
+        }
+
+        let Some(rule) = resolve_role_assignment_rule(
+            state,
+            &user_email,
+            &state.tenant.tenant_id,
+            &org_id,
+            &merchant_id,
+        )
+        .await
+        else {
+            continue;
+        };
+
+        let now = common_utils::date_time::now();
+        let _ = domain::NewUserRole {
+            user_id: user.get_user_id().to_string(),
+            role_id: rule.role_id,
+            status: UserStatus::InvitationSent,
+            created_by: rule.created_by.clone(),
+            last_modified_by: rule.created_by,
+            created_at: now,
+            last_modified: now,
+            entity: domain::NoLevel,
+        }
+        .add_entity(domain::MerchantLevel {
+            tenant_id: state.tenant.tenant_id.clone(),
+            org_id,
+            merchant_id,
+        })
+        .insert_in_v2(state)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                role_assignment_rule_id = rule.id,
+                "Failed to invite user through role assignment rule"
+            )
+        });
+    }
+
+    Ok(())
+}
+
+/// Picks the first role assignment rule of the merchant matching the user. Rules whose attribute
+/// is missing for the user never match, and rules whose role is no longer assignable are skipped.
+async fn resolve_role_assignment_rule(
+    state: &SessionState,
+    user_email: &domain::UserEmail,
+    tenant_id: &id_type::TenantId,
+    org_id: &id_type::OrganizationId,
+    merchant_id: &id_type::MerchantId,
+) -> Option<storage::RoleAssignmentRule> {
+    let role_assignment_rules = state
+        .global_store
+        .list_role_assignment_rules_for_merchant(tenant_id, org_id, merchant_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to list role assignment rules"))
+        .ok()?;
+
+    for rule in role_assignment_rules {
+        let is_match = match rule.attribute {
+            RoleAssignmentAttribute::EmailDomain => user_email
+                .extract_domain()
+                .is_ok_and(|domain| domain.eq_ignore_ascii_case(&rule.value)),
+        };
+
+        if !is_match {
+            continue;
+        }
+
+        match roles::RoleInfo::from_role_id_org_id_tenant_id(
+            state,
+            &rule.role_id,
+            org_id,
+            tenant_id,
+        )
+        .await
+        {
+            Ok(role_info)
+                if role_info.is_invitable()
+                    && role_info.get_entity_type() == EntityType::Merchant =>
+            {
+                return Some(rule);
+            }
+            Ok(_) => logger::warn!(
+                role_assignment_rule_id = rule.id,
+                "Role of role assignment rule is no longer assignable"
+            ),
+            Err(error) => logger::warn!(
+                ?error,
+                role_assignment_rule_id = rule.id,
+                "Failed to fetch role of role assignment rule"
+            ),
+        }
+    }
+
+    None
+}
+
+/// Lists the merchants the user can pick from while accepting invitations before login. An
+/// empty list is returned when there is at most one merchant, as there is nothing to choose.
+pub async fn list_switchable_merchants(
+    state: SessionState,
+    user_token: auth::UserFromSinglePurposeToken,
+) -> UserResponse<Vec<user_role_api::SwitchableMerchantResponse>> {
+    let tenant_id = user_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id: &user_token.user_id,
+            tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: Some(UserStatus::InvitationSent),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list user roles by user id and invitation sent")?;
+
+    // A user can be invited at both merchant and profile level of the same merchant, the
+    // first invitation found decides the role shown for the merchant
+    let merchant_role_map = user_roles
+        .iter()
+        .filter_map(|user_role| {
+            user_role
+                .merchant_id
+                .as_ref()
+                .zip(user_role.org_id.as_ref())
+                .map(|(merchant_id, org_id)| (merchant_id, (org_id, user_role.role_id.as_str())))
+        })
+        .fold(HashMap::new(), |mut map, (merchant_id, role)| {
+            map.entry(merchant_id.clone()).or_insert(role);
+            map
+        });
+
+    if merchant_role_map.len() <= 1 {
+        return Ok(ApplicationResponse::Json(Vec::new()));
+    }
+
+    let key_manager_state = &(&state).into();
+
+    let merchant_name_map = state
+        .store
+        .list_multiple_merchant_accounts(
+            key_manager_state,
+            merchant_role_map.keys().cloned().collect(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|merchant| {
+            (
+                merchant.get_id().clone(),
+                merchant
+                    .merchant_name
+                    .map(|encryptable_name| encryptable_name.into_inner()),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    let switchable_merchants = futures::future::try_join_all(merchant_role_map.into_iter().map(
+        |(merchant_id, (org_id, role_id))| {
+            let merchant_name = merchant_name_map.get(&merchant_id).cloned().flatten();
+            let state = &state;
+
+            async move {
+                let role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+                    state, role_id, org_id, tenant_id,
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+
+                Ok::<_, error_stack::Report<UserErrors>>(
+                    user_role_api::SwitchableMerchantResponse {
+                        merchant_id,
+                        merchant_name,
+                        role_id: role_id.to_string(),
+                        role_name: role_info.get_role_name().to_string(),
+                    },
+                )
+            }
+        },
+    ))
+    .await?;
+
+    Ok(ApplicationResponse::Json(switchable_merchants))
+}
+
+#[cfg(feature = "email")]
+pub async fn resend_invitation(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::ResendInvitationRequest,
+    auth_id: Option<String>,
+) -> UserResponse<()> {
+    let invitee: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&request.user_id)
+        .await
+        .to_not_found_response(UserErrors::InvalidRoleOperationWithMessage(
+            "User not found in records".to_string(),
+        ))?
+        .into();
+
+    // Regenerates the accept invite token and sends the email, at most once per cooldown
+    super::user::resend_invite(
+        state,
+        user_from_token,
+        user_api::ReInviteUserRequest {
+            email: invitee.get_email(),
+        },
+        auth_id,
+    )
+    .await
+}
+
+#[cfg(feature = "email")]
+pub async fn create_pending_invitation(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::InviteUserByEmailRequest,
+    auth_id: Option<String>,
+) -> UserResponse<user_role_api::InviteUserByEmailResponse> {
+    let invitee_email = domain::UserEmail::from_pii_email(request.email.clone())?;
+
+    match state.global_store.find_user_by_email(&invitee_email).await {
+        Ok(_) => {
+            return Err(report!(UserErrors::UserExists))
+                .attach_printable("Pending invitations can only be created for new users");
+        }
+        Err(e) if e.current_context().is_db_not_found() => {}
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    }
+
+    let tenant_id = user_from_token
+        .tenant_id
+        .clone()
+        .unwrap_or(state.tenant.tenant_id.clone());
+
+    let role_info = roles::RoleInfo::from_role_id_in_lineage(
+        &state,
+        &request.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+        &user_from_token.profile_id,
+        &tenant_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    if !role_info.is_invitable() {
+        return Err(report!(UserErrors::InvalidRoleId))
+            .attach_printable(format!("role_id = {} is not invitable", request.role_id));
+    }
+
+    let (entity_id, profile_id) = match role_info.get_entity_type() {
+        EntityType::Tenant => {
+            return Err(UserErrors::InvalidRoleOperationWithMessage(
+                "Tenant roles are not allowed for this operation".to_string(),
+            )
+            .into());
+        }
+        EntityType::Organization => (user_from_token.org_id.get_string_repr().to_owned(), None),
+        EntityType::Merchant => (
+            user_from_token.merchant_id.get_string_repr().to_owned(),
+            None,
+        ),
+        EntityType::Profile => (
+            user_from_token.profile_id.get_string_repr().to_owned(),
+            Some(user_from_token.profile_id.clone()),
+        ),
+    };
+
+    let now = common_utils::date_time::now();
+    let pending_invitation = diesel_models::pending_invitation::PendingInvitationNew {
+        email: request.email.clone(),
+        tenant_id: tenant_id.clone(),
+        org_id: user_from_token.org_id.clone(),
+        merchant_id: user_from_token.merchant_id.clone(),
+        profile_id,
+        entity_id: entity_id.clone(),
+        entity_type: role_info.get_entity_type(),
+        role_id: request.role_id.clone(),
+        created_by: user_from_token.user_id.clone(),
+        created_at: now,
+        last_modified_at: now,
+    };
+
+    // Inviting the same email to the same merchant again replaces the earlier invitation
+    match state
+        .global_store
+        .find_pending_invitation_by_email_tenant_id_merchant_id(
+            &request.email,
+            &tenant_id,
+            &user_from_token.merchant_id,
+        )
+        .await
+    {
+        Ok(existing_invitation) => state
+            .global_store
+            .update_pending_invitation_by_id(existing_invitation.id, pending_invitation.into())
+            .await
+            .change_context(UserErrors::InternalServerError)?,
+        Err(e) if e.current_context().is_db_not_found() => state
+            .global_store
+            .insert_pending_invitation(pending_invitation)
+            .await
+            .to_duplicate_response(UserErrors::InvalidRoleOperationWithMessage(
+                "Invitation for the email is already being created".to_string(),
+            ))?,
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    };
+
+    let theme = utils::user::theme::get_most_specific_theme_using_token_and_min_entity(
+        &state,
+        &user_from_token,
+        role_info.get_entity_type(),
+    )
+    .await?;
+
+    let email_contents = email_types::InviteUser {
+        recipient_email: invitee_email,
+        user_name: domain::UserName::new(request.name)?,
+        settings: state.conf.clone(),
+        subject: consts::user::EMAIL_SUBJECT_INVITATION,
+        entity: email_types::Entity {
+            entity_id,
+            entity_type: role_info.get_entity_type(),
+        },
+        auth_id,
+        theme_id: theme.as_ref().map(|theme| theme.theme_id.clone()),
+        theme_config: theme
+            .map(|theme| theme.email_config())
+            .unwrap_or(state.conf.theme.email_config.clone()),
+    };
 
-            if update_v1_result.is_err_and(|err| !err.current_context().is_db_not_found())
-                || update_v2_result.is_err_and(|err| !err.current_context().is_db_not_found())
-            {
-                Err(report!(UserErrors::InternalServerError))
-            } else {
-                Ok(())
-            }
+    let send_email_result = state
+        .email_client
+        .compose_and_send_email(
+            email_types::get_base_url(&state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await;
+    logger::info!(?send_email_result);
+
+    Ok(ApplicationResponse::Json(
+        user_role_api::InviteUserByEmailResponse {
+            email: request.email,
+            is_email_sent: send_email_result.is_ok(),
         },
     ))
-    .await;
+}
 
-    if update_results.is_empty() || update_results.iter().all(Result::is_err) {
-        return Err(UserErrors::MerchantIdNotFound.into());
-    }
+fn is_active_org_admin(user_role: &UserRole) -> bool {
+    user_role.role_id == common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN
+        && user_role.status == UserStatus::Active
+}
 
-    let user_from_db: domain::UserFromStorage = state
+/// Ensures that deleting a user does not leave the organization without an active admin. If the
+/// user is the last active organization admin, the admin role is given to `transfer_to` before
+/// the deletion goes ahead.
+async fn validate_or_transfer_last_org_admin(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    user_id: &str,
+    transfer_to: Option<String>,
+) -> UserResult<()> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+
+    let org_user_roles = state
         .global_store
-        .find_user_by_id(user_token.user_id.as_str())
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: None,
+            tenant_id,
+            org_id: &user_from_token.org_id,
+            merchant_id: None,
+            profile_id: None,
+            version: None,
+            limit: None,
+        })
         .await
-        .change_context(UserErrors::InternalServerError)?
-        .into();
+        .change_context(UserErrors::InternalServerError)?;
 
-    let current_flow =
-        domain::CurrentFlow::new(user_token, domain::SPTFlow::MerchantSelect.into())?;
-    let next_flow = current_flow.next(user_from_db.clone(), &state).await?;
+    let active_org_admin_ids = org_user_roles
+        .iter()
+        .filter(|user_role| is_active_org_admin(user_role))
+        .map(|user_role| user_role.user_id.as_str())
+        .collect::<HashSet<_>>();
 
-    let token = next_flow.get_token(&state).await?;
+    if !active_org_admin_ids.contains(user_id) || active_org_admin_ids.len() > 1 {
+        return Ok(());
+    }
 
-    let response = user_api::TokenResponse {
-        token: token.clone(),
-        token_type: next_flow.get_flow().into(),
-    };
-    auth::cookies::set_cookie_response(response, token)
+    let transfer_to = transfer_to.ok_or(UserErrors::InvalidRoleOperationWithMessage(
+        "Cannot delete the last organization admin, provide transfer_to to reassign the admin role"
+            .to_string(),
+    ))?;
+
+    if transfer_to == user_id {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Organization admin role cannot be transferred to the user being deleted".to_string(),
+        )
+        .into());
+    }
+
+    let transfer_to_user_roles = org_user_roles
+        .iter()
+        .filter(|user_role| {
+            user_role.user_id == transfer_to && user_role.status == UserStatus::Active
+        })
+        .collect::<Vec<_>>();
+
+    if transfer_to_user_roles.is_empty() {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Organization admin role can only be transferred to an active organization member"
+                .to_string(),
+        )
+        .into());
+    }
+
+    let org_level_user_role = transfer_to_user_roles.into_iter().find(|user_role| {
+        user_role
+            .get_entity_id_and_type()
+            .is_some_and(|(_, entity_type)| entity_type == EntityType::Organization)
+    });
+
+    if let Some(org_level_user_role) = org_level_user_role {
+        state
+            .global_store
+            .update_user_role_by_user_id_and_lineage(
+                &transfer_to,
+                tenant_id,
+                &user_from_token.org_id,
+                None,
+                None,
+                UserRoleUpdate::UpdateRole {
+                    role_id: common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
+                    modified_by: user_from_token.user_id.clone(),
+                    expires_at: None,
+                },
+                org_level_user_role.version,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to transfer organization admin role")?;
+    } else {
+        let now = common_utils::date_time::now();
+        domain::NewUserRole {
+            user_id: transfer_to.clone(),
+            role_id: common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
+            status: UserStatus::Active,
+            created_by: user_from_token.user_id.clone(),
+            last_modified_by: user_from_token.user_id.clone(),
+            created_at: now,
+            last_modified: now,
+            entity: domain::NoLevel,
+        }
+        .add_entity(domain::OrganizationLevel {
+            tenant_id: tenant_id.clone(),
+            org_id: user_from_token.org_id.clone(),
+        })
+        .insert_in_v2(state)
+        .await
+        .attach_printable("Failed to transfer organization admin role")?;
+    }
+
+    auth::blacklist::insert_user_in_blacklist(state, &transfer_to).await
 }
 
 pub async fn delete_user_role(
@@ -506,6 +2123,23 @@ pub async fn delete_user_role(
             .attach_printable("User deleting himself");
     }
 
+    // Blank reasons carry no information for reviewers, so they are stored as null
+    let reason = request
+        .reason
+        .map(|reason| reason.trim().to_string())
+        .filter(|reason| !reason.is_empty());
+
+    if reason
+        .as_ref()
+        .is_some_and(|reason| reason.len() > consts::user_role::MAX_ROLE_AUDIT_REASON_LENGTH)
+    {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(format!(
+            "Reason cannot be longer than {} characters",
+            consts::user_role::MAX_ROLE_AUDIT_REASON_LENGTH
+        ))
+        .into());
+    }
+
     let deletion_requestor_role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
         &state,
         &user_from_token.role_id,
@@ -518,7 +2152,19 @@ pub async fn delete_user_role(
     .await
     .change_context(UserErrors::InternalServerError)?;
 
+    validate_or_transfer_last_org_admin(
+        &state,
+        &user_from_token,
+        user_from_db.get_user_id(),
+        request.transfer_to,
+    )
+    .await?;
+
     let mut user_role_deleted_flag = false;
+    let mut deleted_role_id = None;
+    let mut deleted_role_groups = Vec::new();
+    let mut deleted_user_role_ids = Vec::new();
+    let mut deleted_user_roles = Vec::new();
 
     // Find in V2
     let user_role_v2 = match state
@@ -577,6 +2223,12 @@ pub async fn delete_user_role(
         }
 
         user_role_deleted_flag = true;
+        deleted_role_id = Some(role_to_be_deleted.role_id.clone());
+        deleted_role_groups = target_role_info.get_permission_groups();
+        deleted_user_roles.push(role_to_be_deleted.clone());
+        deleted_user_role_ids.extend(
+            archive_deleted_user_role(&state, role_to_be_deleted, &user_from_token.user_id).await?,
+        );
         state
             .global_store
             .delete_user_role_by_user_id_and_lineage(
@@ -652,6 +2304,12 @@ pub async fn delete_user_role(
         }
 
         user_role_deleted_flag = true;
+        deleted_role_id = Some(role_to_be_deleted.role_id.clone());
+        deleted_role_groups = target_role_info.get_permission_groups();
+        deleted_user_roles.push(role_to_be_deleted.clone());
+        deleted_user_role_ids.extend(
+            archive_deleted_user_role(&state, role_to_be_deleted, &user_from_token.user_id).await?,
+        );
         state
             .global_store
             .delete_user_role_by_user_id_and_lineage(
@@ -670,51 +2328,482 @@ pub async fn delete_user_role(
             .attach_printable("Error while deleting user role")?;
     }
 
-    if !user_role_deleted_flag {
-        return Err(report!(UserErrors::InvalidDeleteOperation))
-            .attach_printable("User is not associated with the merchant");
-    }
+    if !user_role_deleted_flag {
+        return Err(report!(UserErrors::InvalidDeleteOperation))
+            .attach_printable("User is not associated with the merchant");
+    }
+
+    // Concurrent deletions of organization admins can each see another admin remaining, so the
+    // admins are counted again after the deletion and the deletion is reverted if it left the
+    // organization without an active admin
+    if deleted_user_roles.iter().any(is_active_org_admin) {
+        revert_deletion_of_last_org_admin(
+            &state,
+            &user_from_token,
+            deleted_user_roles,
+            &deleted_user_role_ids,
+        )
+        .await?;
+    }
+
+    // Deleting the user is deferred to the purge of the deleted user roles, so that they can still
+    // be restored till then
+    let is_user_deletion_deferred = !deleted_user_role_ids.is_empty();
+    if is_user_deletion_deferred {
+        add_deleted_user_role_purge_task(
+            &state,
+            user_from_db.get_user_id(),
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            deleted_user_role_ids,
+        )
+        .await?;
+    }
+
+    // Check if user has any more role associations
+    let remaining_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id: user_from_db.get_user_id(),
+            tenant_id: user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    // If user has no more role associated with him then deleting user
+    if remaining_roles.is_empty() && !is_user_deletion_deferred {
+        state
+            .global_store
+            .delete_user_by_user_id(user_from_db.get_user_id())
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Error while deleting user entry")?;
+    }
+
+    auth::blacklist::insert_user_in_blacklist(&state, user_from_db.get_user_id()).await?;
+
+    insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::DeleteUserRole,
+        user_from_db.get_user_id().to_string(),
+        deleted_role_groups,
+        Vec::new(),
+        reason,
+    )
+    .await;
+
+    if let Some(old_role_id) = deleted_role_id {
+        trigger_user_role_outgoing_webhook(
+            &state,
+            &user_from_token,
+            common_enums::EventType::UserRoleDeleted,
+            user_role_api::UserRoleWebhookContent {
+                user_id: user_from_db.get_user_id().to_string(),
+                merchant_id: user_from_token.merchant_id.clone(),
+                old_role_id,
+                new_role_id: None,
+            },
+        );
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn undo_delete_user_role(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::UndoDeleteUserRoleRequest,
+) -> UserResponse<()> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_email(&domain::UserEmail::from_pii_email(request.email)?)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::InvalidRoleOperation)
+                    .attach_printable("User not found in our records")
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?
+        .into();
+
+    let deleted_user_roles = state
+        .global_store
+        .list_deleted_user_roles_by_user_id_and_lineage(
+            user_from_db.get_user_id(),
+            tenant_id,
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+            &user_from_token.profile_id,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list deleted user roles")?;
+
+    // Deleted user roles are listed latest first, only the latest deletion of a lineage is
+    // restored in case the user was deleted multiple times within the grace period
+    let grace_period =
+        time::Duration::seconds(state.conf.user.user_role_deletion_grace_period_in_secs);
+    let now = common_utils::date_time::now();
+    let mut seen_lineages = HashSet::new();
+    let restorable_user_roles = deleted_user_roles
+        .into_iter()
+        .filter(|deleted_user_role| deleted_user_role.deleted_at.saturating_add(grace_period) > now)
+        .filter(|deleted_user_role| {
+            seen_lineages.insert((
+                deleted_user_role.version,
+                deleted_user_role.org_id.clone(),
+                deleted_user_role.merchant_id.clone(),
+                deleted_user_role.profile_id.clone(),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    if restorable_user_roles.is_empty() {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "No deleted user role found which can still be restored".to_string(),
+        )
+        .into());
+    }
+
+    let requestor_role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+        &state,
+        &user_from_token.role_id,
+        &user_from_token.org_id,
+        tenant_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    let mut restored_role_groups = Vec::new();
+    for deleted_user_role in &restorable_user_roles {
+        restored_role_groups = validate_deleted_user_role_for_restore(
+            &state,
+            &user_from_token,
+            &requestor_role_info,
+            deleted_user_role,
+        )
+        .await?;
+    }
+
+    // All the user roles are restored before any of the deleted user roles is removed, so that
+    // the restored user roles can be rolled back if restoring one of them fails
+    let mut restored_user_roles = Vec::with_capacity(restorable_user_roles.len());
+    for deleted_user_role in &restorable_user_roles {
+        match state
+            .global_store
+            .insert_user_role(
+                deleted_user_role
+                    .clone()
+                    .into_user_role_new(user_from_token.user_id.clone()),
+            )
+            .await
+            .to_duplicate_response(UserErrors::InvalidRoleOperationWithMessage(
+                "User already has a role in the lineage".to_string(),
+            )) {
+            Ok(user_role) => restored_user_roles.push(user_role),
+            Err(error) => {
+                rollback_restored_user_roles(
+                    &state,
+                    &user_from_token,
+                    tenant_id,
+                    restored_user_roles,
+                )
+                .await;
+                return Err(error);
+            }
+        }
+    }
+
+    // Deleted user roles left behind are removed by their purge, which keeps the user as long as
+    // the user has any user role
+    for deleted_user_role in restorable_user_roles {
+        let _ = state
+            .global_store
+            .delete_deleted_user_role_by_id(deleted_user_role.id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to delete restored user role"));
+    }
+
+    insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::RestoreUserRole,
+        user_from_db.get_user_id().to_string(),
+        Vec::new(),
+        restored_role_groups,
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+async fn rollback_restored_user_roles(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    tenant_id: &id_type::TenantId,
+    restored_user_roles: Vec<UserRole>,
+) {
+    for user_role in restored_user_roles {
+        let _ = state
+            .global_store
+            .delete_user_role_by_user_id_and_lineage(
+                &user_role.user_id,
+                tenant_id,
+                &user_from_token.org_id,
+                &user_from_token.merchant_id,
+                &user_from_token.profile_id,
+                user_role.version,
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to roll back restored user role"));
+    }
+}
+
+/// Checks that the role, merchant and profile of the deleted user role still exist and that the
+/// requestor is allowed to restore it, returns the permission groups of the role
+async fn validate_deleted_user_role_for_restore(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    requestor_role_info: &roles::RoleInfo,
+    deleted_user_role: &storage::DeletedUserRole,
+) -> UserResult<Vec<PermissionGroup>> {
+    let role_info = roles::RoleInfo::from_role_id_in_lineage(
+        state,
+        &deleted_user_role.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+        &user_from_token.profile_id,
+        &deleted_user_role.tenant_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleOperationWithMessage(
+        "Role of the deleted user no longer exists".to_string(),
+    ))?;
+
+    if role_info.is_deleted() {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Role of the deleted user no longer exists".to_string(),
+        )
+        .into());
+    }
+
+    if requestor_role_info.get_entity_type() < role_info.get_entity_type() {
+        return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+            "Invalid operation, restore requestor = {} cannot restore target = {}",
+            requestor_role_info.get_entity_type(),
+            role_info.get_entity_type()
+        ));
+    }
+
+    if let Some(merchant_id) = deleted_user_role.merchant_id.as_ref() {
+        let key_manager_state = &state.into();
+        let key_store = state
+            .store
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                merchant_id,
+                &state.store.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(UserErrors::InvalidRoleOperationWithMessage(
+                "Merchant of the deleted user no longer exists".to_string(),
+            ))?;
+
+        state
+            .store
+            .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+            .await
+            .to_not_found_response(UserErrors::InvalidRoleOperationWithMessage(
+                "Merchant of the deleted user no longer exists".to_string(),
+            ))?;
+
+        if let Some(profile_id) = deleted_user_role.profile_id.as_ref() {
+            state
+                .store
+                .find_business_profile_by_merchant_id_profile_id(
+                    key_manager_state,
+                    &key_store,
+                    merchant_id,
+                    profile_id,
+                )
+                .await
+                .to_not_found_response(UserErrors::InvalidRoleOperationWithMessage(
+                    "Profile of the deleted user no longer exists".to_string(),
+                ))?;
+        }
+    }
+
+    Ok(role_info.get_permission_groups())
+}
 
-    // Check if user has any more role associations
-    let remaining_roles = state
+async fn revert_deletion_of_last_org_admin(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    deleted_user_roles: Vec<UserRole>,
+    deleted_user_role_ids: &[i32],
+) -> UserResult<()> {
+    let has_active_org_admin = state
         .global_store
-        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
-            user_id: user_from_db.get_user_id(),
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: None,
             tenant_id: user_from_token
                 .tenant_id
                 .as_ref()
                 .unwrap_or(&state.tenant.tenant_id),
-
-            org_id: None,
+            org_id: &user_from_token.org_id,
             merchant_id: None,
             profile_id: None,
-            entity_id: None,
             version: None,
-            status: None,
             limit: None,
         })
         .await
-        .change_context(UserErrors::InternalServerError)?;
+        .change_context(UserErrors::InternalServerError)?
+        .iter()
+        .any(is_active_org_admin);
 
-    // If user has no more role associated with him then deleting user
-    if remaining_roles.is_empty() {
+    if has_active_org_admin {
+        return Ok(());
+    }
+
+    for user_role in deleted_user_roles {
         state
             .global_store
-            .delete_user_by_user_id(user_from_db.get_user_id())
+            .insert_user_role(UserRoleNew {
+                user_id: user_role.user_id,
+                merchant_id: user_role.merchant_id,
+                role_id: user_role.role_id,
+                org_id: user_role.org_id,
+                status: user_role.status,
+                created_by: user_role.created_by,
+                last_modified_by: user_role.last_modified_by,
+                created_at: user_role.created_at,
+                last_modified: user_role.last_modified,
+                profile_id: user_role.profile_id,
+                entity_id: user_role.entity_id,
+                entity_type: user_role.entity_type,
+                version: user_role.version,
+                tenant_id: user_role.tenant_id,
+                expires_at: user_role.expires_at,
+            })
             .await
             .change_context(UserErrors::InternalServerError)
-            .attach_printable("Error while deleting user entry")?;
+            .attach_printable("Failed to revert deletion of the last organization admin")?;
     }
 
-    auth::blacklist::insert_user_in_blacklist(&state, user_from_db.get_user_id()).await?;
-    Ok(ApplicationResponse::StatusOk)
+    for deleted_user_role_id in deleted_user_role_ids {
+        state
+            .global_store
+            .delete_deleted_user_role_by_id(*deleted_user_role_id)
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to delete archived user role")?;
+    }
+
+    Err(UserErrors::InvalidRoleOperationWithMessage(
+        "Cannot delete the last organization admin, provide transfer_to to reassign the admin role"
+            .to_string(),
+    )
+    .into())
+}
+
+/// Keeps a copy of the user role being deleted so that it can be restored within the grace
+/// period, returns `None` if restoring deleted user roles is disabled
+async fn archive_deleted_user_role(
+    state: &SessionState,
+    user_role: UserRole,
+    deleted_by: &str,
+) -> UserResult<Option<i32>> {
+    if state.conf.user.user_role_deletion_grace_period_in_secs <= 0 {
+        return Ok(None);
+    }
+
+    state
+        .global_store
+        .insert_deleted_user_role(storage::DeletedUserRoleNew::new(
+            user_role,
+            deleted_by.to_string(),
+        ))
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to archive deleted user role")
+        .map(|deleted_user_role| Some(deleted_user_role.id))
+}
+
+async fn add_deleted_user_role_purge_task(
+    state: &SessionState,
+    user_id: &str,
+    tenant_id: &id_type::TenantId,
+    deleted_user_role_ids: Vec<i32>,
+) -> UserResult<()> {
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        state.conf.user.user_role_deletion_grace_period_in_secs,
+    ));
+
+    let runner = storage::ProcessTrackerRunner::DeletedUserRolePurgeWorkflow;
+    let task = consts::user_role::DELETED_USER_ROLE_PURGE_TASK;
+    let process_tracker_id = format!(
+        "{runner}_{task}_{user_id}_{}",
+        deleted_user_role_ids
+            .iter()
+            .min()
+            .ok_or(UserErrors::InternalServerError)?
+    );
+
+    let tracking_data = storage::DeletedUserRolePurgeTrackingData {
+        user_id: user_id.to_string(),
+        tenant_id: tenant_id.clone(),
+        deleted_user_role_ids,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        [consts::user_role::DELETED_USER_ROLE_PURGE_TAG],
+        tracking_data,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to construct deleted user role purge process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to insert deleted user role purge task to process_tracker")?;
+
+    Ok(())
 }
 
 pub async fn list_users_in_lineage(
     state: SessionState,
     user_from_token: auth::UserFromToken,
     request: user_role_api::ListUsersInEntityRequest,
-) -> UserResponse<Vec<user_role_api::ListUsersInEntityResponse>> {
+) -> UserResponse<user_role_api::ListUsersInLineageResponse> {
     let requestor_role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
         &state,
         &user_from_token.role_id,
@@ -842,77 +2931,583 @@ pub async fn list_users_in_lineage(
         })
         .collect::<HashSet<_>>();
 
-    let mut email_map = state
+    let invitation_expiry = time::Duration::seconds(state.conf.user.invitation_expiry_in_secs);
+    let now = common_utils::date_time::now();
+
+    let mut user_role_map =
+        user_roles_set
+            .into_iter()
+            .fold(HashMap::new(), |mut map, user_role| {
+                map.entry(user_role.user_id.clone())
+                    .or_insert(Vec::with_capacity(1))
+                    .push(user_role);
+                map
+            });
+    let user_ids = user_role_map.keys().cloned().collect::<Vec<_>>();
+
+    let total_count = state
         .global_store
-        .find_users_by_user_ids(
-            user_roles_set
-                .iter()
-                .map(|user_role| user_role.user_id.clone())
-                .collect(),
+        .get_users_count_by_user_ids(user_ids.clone())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    let total_count =
+        usize::try_from(total_count).change_context(UserErrors::InternalServerError)?;
+
+    let limit = request
+        .limit
+        .unwrap_or(consts::user::DEFAULT_LIST_USERS_IN_LINEAGE_LIMIT);
+    let offset = request.offset.unwrap_or(0);
+
+    // The users are ordered by (created_at, user_id) for the pagination to be deterministic
+    // across pages
+    let users = state
+        .global_store
+        .find_users_by_user_ids_paginated(user_ids, Some(i64::from(limit)), Some(i64::from(offset)))
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let users = users
+        .into_iter()
+        .map(|user| {
+            let user_roles = user_role_map
+                .remove(&user.user_id)
+                .ok_or(UserErrors::InternalServerError)?;
+            Ok::<_, error_stack::Report<UserErrors>>((user.email, user_roles))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Role info is only resolved for the roles held by the users of the page
+    let role_ids = users
+        .iter()
+        .flat_map(|(_, user_roles)| user_roles.iter().map(|user_role| &user_role.role_id))
+        .collect::<HashSet<_>>();
+    let role_info_map = futures::future::try_join_all(role_ids.into_iter().map(|role_id| async {
+        roles::RoleInfo::from_role_id_org_id_tenant_id(
+            &state,
+            role_id,
+            &user_from_token.org_id,
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
         )
         .await
-        .change_context(UserErrors::InternalServerError)?
+        .map(|role_info| {
+            (
+                role_id.clone(),
+                user_role_api::role::MinimalRoleInfo {
+                    role_id: role_id.clone(),
+                    role_name: role_info.get_role_name().to_string(),
+                },
+            )
+        })
+    }))
+    .await
+    .change_context(UserErrors::InternalServerError)?
+    .into_iter()
+    .collect::<HashMap<_, _>>();
+
+    let users = users
         .into_iter()
-        .map(|user| (user.user_id.clone(), user.email))
-        .collect::<HashMap<_, _>>();
+        .map(|(email, user_roles)| {
+            let assignments = user_roles
+                .into_iter()
+                .map(|user_role| {
+                    let role = role_info_map
+                        .get(&user_role.role_id)
+                        .cloned()
+                        .ok_or(UserErrors::InternalServerError)?;
+                    let assignment_info =
+                        get_role_assignment_info(role, &user_role, invitation_expiry, now);
+
+                    Ok((user_role, assignment_info))
+                })
+                .collect::<Result<Vec<_>, error_stack::Report<UserErrors>>>()?;
 
-    let role_info_map =
-        futures::future::try_join_all(user_roles_set.iter().map(|user_role| async {
-            roles::RoleInfo::from_role_id_org_id_tenant_id(
-                &state,
-                &user_role.role_id,
-                &user_from_token.org_id,
-                user_from_token
-                    .tenant_id
-                    .as_ref()
-                    .unwrap_or(&state.tenant.tenant_id),
+            Ok::<_, error_stack::Report<UserErrors>>((email, assignments))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let response = if request.as_tree {
+        user_role_api::ListUsersInLineageResponse::Tree(get_lineage_tree(
+            user_from_token.org_id,
+            total_count,
+            users,
+        ))
+    } else {
+        user_role_api::ListUsersInLineageResponse::Flat(
+            user_role_api::ListUsersInEntityPaginatedResponse {
+                total_count,
+                users: users
+                    .into_iter()
+                    .map(
+                        |(email, assignments)| user_role_api::ListUsersInEntityResponse {
+                            email,
+                            roles: assignments
+                                .into_iter()
+                                .map(|(_, assignment_info)| assignment_info)
+                                .collect(),
+                        },
+                    )
+                    .collect(),
+            },
+        )
+    };
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Groups the role assignments of the users under the organization, merchant and profile nodes
+/// they are held at. Users keep their listing order within a node, nodes are ordered by id.
+fn get_lineage_tree(
+    org_id: id_type::OrganizationId,
+    total_count: usize,
+    users: Vec<(
+        pii::Email,
+        Vec<(UserRole, user_role_api::UserRoleAssignmentInfo)>,
+    )>,
+) -> user_role_api::ListUsersInLineageTreeResponse {
+    let mut tenant_users = Vec::new();
+    let mut org_users = Vec::new();
+    let mut merchant_users: HashMap<id_type::MerchantId, Vec<_>> = HashMap::new();
+    let mut profile_users: HashMap<id_type::MerchantId, HashMap<id_type::ProfileId, Vec<_>>> =
+        HashMap::new();
+
+    for (email, assignments) in users {
+        for (user_role, assignment_info) in assignments {
+            let Some((_, entity_type)) = user_role.get_entity_id_and_type() else {
+                continue;
+            };
+
+            let node_users = match (entity_type, user_role.merchant_id, user_role.profile_id) {
+                (EntityType::Tenant, _, _) => &mut tenant_users,
+                (EntityType::Organization, _, _) => &mut org_users,
+                (EntityType::Merchant, Some(merchant_id), _) => {
+                    merchant_users.entry(merchant_id).or_default()
+                }
+                (EntityType::Profile, Some(merchant_id), Some(profile_id)) => profile_users
+                    .entry(merchant_id)
+                    .or_default()
+                    .entry(profile_id)
+                    .or_default(),
+                (EntityType::Merchant | EntityType::Profile, _, _) => continue,
+            };
+
+            match node_users.last_mut().filter(|user| user.email == email) {
+                Some(user) => user.roles.push(assignment_info),
+                None => node_users.push(user_role_api::ListUsersInEntityResponse {
+                    email: email.clone(),
+                    roles: vec![assignment_info],
+                }),
+            }
+        }
+    }
+
+    let mut merchant_ids = merchant_users
+        .keys()
+        .chain(profile_users.keys())
+        .cloned()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    merchant_ids.sort_by(|merchant_a, merchant_b| {
+        merchant_a
+            .get_string_repr()
+            .cmp(merchant_b.get_string_repr())
+    });
+
+    let merchants = merchant_ids
+        .into_iter()
+        .map(|merchant_id| {
+            let mut profiles = profile_users
+                .remove(&merchant_id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(profile_id, users)| user_role_api::ProfileLineageNode { profile_id, users })
+                .collect::<Vec<_>>();
+            profiles.sort_by(|profile_a, profile_b| {
+                profile_a
+                    .profile_id
+                    .get_string_repr()
+                    .cmp(profile_b.profile_id.get_string_repr())
+            });
+
+            user_role_api::MerchantLineageNode {
+                users: merchant_users.remove(&merchant_id).unwrap_or_default(),
+                merchant_id,
+                profiles,
+            }
+        })
+        .collect();
+
+    user_role_api::ListUsersInLineageTreeResponse {
+        total_count,
+        tenant_users,
+        organization: user_role_api::OrganizationLineageNode {
+            org_id,
+            users: org_users,
+            merchants,
+        },
+    }
+}
+
+pub async fn list_roles_for_user(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::ListRolesForUserRequest,
+) -> UserResponse<Vec<user_role_api::ListRolesForUserResponse>> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+
+    let requestor_role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+        &state,
+        &user_from_token.role_id,
+        &user_from_token.org_id,
+        tenant_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+    let requestor_entity_type = requestor_role_info.get_entity_type();
+
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id: &request.user_id,
+            tenant_id,
+            org_id: Some(&user_from_token.org_id),
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: Some(UserStatus::Active),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list user roles by user_id")?;
+
+    // Only roles within the requestor's lineage and at or below the requestor's entity are visible
+    let mut seen_assignments = HashSet::new();
+    let user_roles = user_roles
+        .into_iter()
+        .filter(|user_role| {
+            let is_in_lineage = match requestor_entity_type {
+                EntityType::Tenant | EntityType::Organization => true,
+                EntityType::Merchant => {
+                    user_role.merchant_id.as_ref() == Some(&user_from_token.merchant_id)
+                }
+                EntityType::Profile => {
+                    user_role.merchant_id.as_ref() == Some(&user_from_token.merchant_id)
+                        && user_role.profile_id.as_ref() == Some(&user_from_token.profile_id)
+                }
+            };
+
+            is_in_lineage
+                && user_role
+                    .get_entity_id_and_type()
+                    .is_some_and(|(_, entity_type)| entity_type <= requestor_entity_type)
+        })
+        .filter(|user_role| {
+            seen_assignments.insert((user_role.merchant_id.clone(), user_role.role_id.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    if user_roles.is_empty() {
+        return Err(UserErrors::UserNotInLineage.into());
+    }
+
+    let roles = futures::future::try_join_all(user_roles.into_iter().map(|user_role| async {
+        roles::RoleInfo::from_role_id_org_id_tenant_id(
+            &state,
+            &user_role.role_id,
+            &user_from_token.org_id,
+            tenant_id,
+        )
+        .await
+        .map(|role_info| user_role_api::ListRolesForUserResponse {
+            merchant_id: user_role.merchant_id,
+            role_id: user_role.role_id,
+            role_name: role_info.get_role_name().to_string(),
+            role_scope: role_info.get_scope(),
+            entity_type: role_info.get_entity_type(),
+        })
+    }))
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::Json(roles))
+}
+
+fn get_role_assignment_info(
+    role: role_api::MinimalRoleInfo,
+    user_role: &diesel_models::user_role::UserRole,
+    invitation_expiry: time::Duration,
+    now: time::PrimitiveDateTime,
+) -> user_role_api::UserRoleAssignmentInfo {
+    match user_role.status {
+        UserStatus::Active => user_role_api::UserRoleAssignmentInfo {
+            role,
+            invitation_status: user_role_api::InvitationStatus::Active,
+            invited_at: None,
+            expires_at: None,
+        },
+        UserStatus::InvitationSent => {
+            let expires_at = user_role.created_at + invitation_expiry;
+            let invitation_status = if expires_at <= now {
+                user_role_api::InvitationStatus::Expired
+            } else {
+                user_role_api::InvitationStatus::Pending
+            };
+
+            user_role_api::UserRoleAssignmentInfo {
+                role,
+                invitation_status,
+                invited_at: Some(user_role.created_at),
+                expires_at: Some(expires_at),
+            }
+        }
+    }
+}
+
+pub async fn check_role_integrity(
+    state: SessionState,
+    request: user_role_api::CheckRoleIntegrityRequest,
+) -> UserResponse<user_role_api::CheckRoleIntegrityResponse> {
+    let scan_limit = request
+        .limit
+        .unwrap_or(consts::user_role::ROLE_INTEGRITY_CHECK_DEFAULT_LIMIT)
+        .min(consts::user_role::ROLE_INTEGRITY_CHECK_MAX_LIMIT);
+
+    let mut response = user_role_api::CheckRoleIntegrityResponse::default();
+    let mut role_existence = HashMap::new();
+    let mut merchant_existence = HashMap::new();
+    let mut start_after_id = request.start_after_id;
+    let mut remaining = scan_limit;
+
+    // Rows are scanned in id order batch by batch, only the counts and a bounded number of
+    // samples are retained across batches
+    while remaining > 0 {
+        let batch_size = remaining.min(consts::user_role::ROLE_INTEGRITY_CHECK_BATCH_SIZE);
+        let user_roles = state
+            .global_store
+            .list_user_roles_by_tenant_id_after_id(
+                &state.tenant.tenant_id,
+                start_after_id,
+                batch_size,
             )
             .await
-            .map(|role_info| {
-                (
-                    user_role.role_id.clone(),
-                    user_role_api::role::MinimalRoleInfo {
-                        role_id: user_role.role_id.clone(),
-                        role_name: role_info.get_role_name().to_string(),
-                    },
-                )
+            .change_context(UserErrors::InternalServerError)?;
+
+        let fetched_count =
+            u32::try_from(user_roles.len()).change_context(UserErrors::InternalServerError)?;
+        remaining = remaining.saturating_sub(fetched_count);
+        response.scanned_count += user_roles.len();
+
+        for user_role in user_roles {
+            start_after_id = Some(user_role.id);
+
+            let role_exists = match role_existence.get(&user_role.role_id) {
+                Some(role_exists) => *role_exists,
+                None => {
+                    let role_exists = check_role_exists(&state, &user_role.role_id).await?;
+                    role_existence.insert(user_role.role_id.clone(), role_exists);
+                    role_exists
+                }
+            };
+
+            let merchant_exists = match user_role.merchant_id.as_ref() {
+                Some(merchant_id) => match merchant_existence.get(merchant_id) {
+                    Some(merchant_exists) => *merchant_exists,
+                    None => {
+                        let merchant_exists = check_merchant_exists(&state, merchant_id).await?;
+                        merchant_existence.insert(merchant_id.clone(), merchant_exists);
+                        merchant_exists
+                    }
+                },
+                None => true,
+            };
+
+            if !role_exists {
+                response.orphaned_role_count += 1;
+                if response.orphaned_role_samples.len()
+                    < consts::user_role::ROLE_INTEGRITY_CHECK_MAX_SAMPLES
+                {
+                    response
+                        .orphaned_role_samples
+                        .push(get_orphaned_user_role(&user_role));
+                }
+            }
+
+            if !merchant_exists {
+                response.orphaned_merchant_count += 1;
+                if response.orphaned_merchant_samples.len()
+                    < consts::user_role::ROLE_INTEGRITY_CHECK_MAX_SAMPLES
+                {
+                    response
+                        .orphaned_merchant_samples
+                        .push(get_orphaned_user_role(&user_role));
+                }
+            }
+        }
+
+        // A short batch means there are no more rows left to scan
+        if fetched_count < batch_size {
+            return Ok(ApplicationResponse::Json(response));
+        }
+    }
+
+    response.next_start_after_id = start_after_id;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+async fn check_role_exists(state: &SessionState, role_id: &str) -> UserResult<bool> {
+    if roles::predefined_roles::PREDEFINED_ROLES.contains_key(role_id) {
+        return Ok(true);
+    }
+
+    match state.global_store.find_role_by_role_id(role_id).await {
+        Ok(role) => Ok(role.deleted_at.is_none()),
+        Err(e) if e.current_context().is_db_not_found() => Ok(false),
+        Err(e) => Err(e.change_context(UserErrors::InternalServerError)),
+    }
+}
+
+async fn check_merchant_exists(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> UserResult<bool> {
+    match state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(e) if e.current_context().is_db_not_found() => Ok(false),
+        Err(e) => Err(e.change_context(UserErrors::InternalServerError)),
+    }
+}
+
+pub async fn export_rbac(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::ExportRbacRequest,
+) -> UserResponse<user_role_api::RbacExport> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .clone()
+        .unwrap_or(state.tenant.tenant_id.clone());
+    let limit = request
+        .limit
+        .unwrap_or(consts::user_role::RBAC_EXPORT_DEFAULT_LIMIT)
+        .min(consts::user_role::RBAC_EXPORT_MAX_LIMIT);
+
+    // Custom roles of a merchant are capped, so all of them are exported with the first page
+    let roles = if request.start_after_id.is_none() {
+        state
+            .global_store
+            .generic_list_roles_by_entity_type(
+                ListRolesByEntityPayload::Merchant(user_from_token.merchant_id.clone()),
+                true,
+                tenant_id.clone(),
+                user_from_token.org_id.clone(),
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to list custom roles of merchant")?
+            .into_iter()
+            .filter(|role| {
+                role.scope == RoleScope::Merchant
+                    && role.merchant_id == user_from_token.merchant_id
+                    && role.deleted_at.is_none()
+            })
+            .map(|role| user_role_api::RbacExportRole {
+                role_id: role.role_id,
+                role_name: role.role_name,
+                groups: role.groups,
+                custom_permissions: role.custom_permissions.unwrap_or_default(),
+                entity_type: role.entity_type,
+                scope: role.scope,
             })
-        }))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_merchant_id_after_id(
+            &tenant_id,
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+            request.start_after_id,
+            limit,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list user roles of merchant")?;
+
+    let next_start_after_id = user_roles
+        .last()
+        .map(|user_role| user_role.id)
+        .filter(|_| usize::try_from(limit).is_ok_and(|limit| user_roles.len() >= limit));
+
+    let user_ids = user_roles
+        .iter()
+        .map(|user_role| user_role.user_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let emails = state
+        .global_store
+        .find_users_by_user_ids(user_ids)
         .await
         .change_context(UserErrors::InternalServerError)?
         .into_iter()
+        .map(|user| (user.user_id, user.email))
         .collect::<HashMap<_, _>>();
 
-    let user_role_map = user_roles_set
+    let assignments = user_roles
         .into_iter()
-        .fold(HashMap::new(), |mut map, user_role| {
-            map.entry(user_role.user_id)
-                .or_insert(Vec::with_capacity(1))
-                .push(user_role.role_id);
-            map
-        });
+        .filter_map(|user_role| {
+            let (_, entity_type) = user_role.get_entity_id_and_type()?;
+            let email = emails.get(&user_role.user_id)?.clone();
 
-    Ok(ApplicationResponse::Json(
-        user_role_map
-            .into_iter()
-            .map(|(user_id, role_id_vec)| {
-                Ok::<_, error_stack::Report<UserErrors>>(user_role_api::ListUsersInEntityResponse {
-                    email: email_map
-                        .remove(&user_id)
-                        .ok_or(UserErrors::InternalServerError)?,
-                    roles: role_id_vec
-                        .into_iter()
-                        .map(|role_id| {
-                            role_info_map
-                                .get(&role_id)
-                                .cloned()
-                                .ok_or(UserErrors::InternalServerError)
-                        })
-                        .collect::<Result<Vec<_>, _>>()?,
-                })
+            Some(user_role_api::RbacExportAssignment {
+                email,
+                role_id: user_role.role_id,
+                entity_type,
+                profile_id: user_role.profile_id,
+                status: user_role.status.foreign_into(),
             })
-            .collect::<Result<Vec<_>, _>>()?,
-    ))
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(user_role_api::RbacExport {
+        version: consts::user_role::RBAC_EXPORT_VERSION,
+        merchant_id: user_from_token.merchant_id,
+        exported_at: common_utils::date_time::now(),
+        roles,
+        assignments,
+        next_start_after_id,
+    }))
+}
+
+fn get_orphaned_user_role(
+    user_role: &diesel_models::user_role::UserRole,
+) -> user_role_api::OrphanedUserRole {
+    user_role_api::OrphanedUserRole {
+        user_role_id: user_role.id,
+        user_id: user_role.user_id.clone(),
+        role_id: user_role.role_id.clone(),
+        merchant_id: user_role.merchant_id.clone(),
+    }
 }
 
 pub async fn list_invitations_for_user(
@@ -1089,3 +3684,34 @@ pub async fn list_invitations_for_user(
         .collect::<Result<Vec<_>, _>>()
         .map(ApplicationResponse::Json)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_flags_for_groups() {
+        let feature_flags = get_feature_flags_for_groups(&[
+            PermissionGroup::ConnectorsView,
+            PermissionGroup::ConnectorsManage,
+            PermissionGroup::AnalyticsView,
+        ]);
+
+        assert_eq!(
+            feature_flags.len(),
+            user_role_api::RoleFeatureFlag::iter().count()
+        );
+        assert_eq!(
+            feature_flags.get(&user_role_api::RoleFeatureFlag::CanManageConnectors),
+            Some(&true)
+        );
+        assert_eq!(
+            feature_flags.get(&user_role_api::RoleFeatureFlag::CanViewAnalytics),
+            Some(&true)
+        );
+        assert_eq!(
+            feature_flags.get(&user_role_api::RoleFeatureFlag::CanManageUsers),
+            Some(&false)
+        );
+    }
+}
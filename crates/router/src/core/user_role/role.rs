@@ -1,35 +1,61 @@
-use std::{cmp, collections::HashSet};
+use std::{
+    cmp,
+    collections::{BTreeSet, HashMap, HashSet},
+};
 
-use api_models::user_role::role as role_api;
-use common_enums::{EntityType, ParentGroup, PermissionGroup};
-use common_utils::generate_id_with_default_len;
-use diesel_models::role::{ListRolesByEntityPayload, RoleNew, RoleUpdate};
+use api_models::user_role::{self as user_role_api, role as role_api};
+use common_enums::{
+    EntityType, ParentGroup, PermissionGroup, Resource, RoleAuditAction, RoleScope,
+};
+use common_utils::{errors::ReportSwitchExt, generate_id_with_default_len, id_type};
+use diesel_models::{
+    custom_permission_group::{CustomPermissionGroup, CustomPermissionGroupNew},
+    enums::{UserRoleVersion, UserStatus},
+    role::{ListRolesByEntityPayload, Role, RoleNew, RoleUpdate},
+    role_template::{RoleTemplate, RoleTemplateNew, RoleTemplateUpdate},
+};
 use error_stack::{report, ResultExt};
+use router_env::logger;
+use strum::IntoEnumIterator;
 
 use crate::{
-    core::errors::{StorageErrorExt, UserErrors, UserResponse},
+    consts,
+    core::errors::{
+        role::{RoleError, RoleResponse, RoleResult},
+        StorageErrorExt, UserErrors,
+    },
+    db::user_role::ListUserRolesByUserIdPayload,
     routes::{app::ReqState, SessionState},
     services::{
         authentication::{blacklist, UserFromToken},
         authorization::{
+            info,
             permission_groups::{ParentGroupExt, PermissionGroupExt},
+            permissions::{get_permission_from_name, Permission},
             roles::{self, predefined_roles::PREDEFINED_ROLES},
         },
         ApplicationResponse,
     },
-    types::domain::user::RoleName,
+    types::domain::{self, user::RoleName},
     utils,
 };
 
 pub async fn get_role_from_token_with_groups(
     state: SessionState,
     user_from_token: UserFromToken,
-) -> UserResponse<Vec<PermissionGroup>> {
+) -> RoleResponse<Vec<PermissionGroup>> {
     let role_info = user_from_token
         .get_role_info_from_db(&state)
         .await
+        .switch()
         .attach_printable("Invalid role_id in JWT")?;
 
+    // Usage tracking is best effort and must not fail the request
+    utils::user_role::record_role_usage(&state, &user_from_token.role_id, &user_from_token.org_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to record role usage"))
+        .ok();
+
     let permissions = role_info.get_permission_groups().to_vec();
 
     Ok(ApplicationResponse::Json(permissions))
@@ -38,8 +64,11 @@ pub async fn get_role_from_token_with_groups(
 pub async fn get_groups_and_resources_for_role_from_token(
     state: SessionState,
     user_from_token: UserFromToken,
-) -> UserResponse<role_api::GroupsAndResources> {
-    let role_info = user_from_token.get_role_info_from_db(&state).await?;
+) -> RoleResponse<role_api::GroupsAndResources> {
+    let role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()?;
 
     let groups = role_info
         .get_permission_groups()
@@ -58,53 +87,104 @@ pub async fn get_groups_and_resources_for_role_from_token(
     }))
 }
 
+pub async fn get_my_permissions(
+    state: SessionState,
+    user_from_token: UserFromToken,
+) -> RoleResponse<role_api::EffectivePermissionsResponse> {
+    let role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()?;
+
+    let permissions = Permission::iter()
+        .filter(|permission| role_info.check_permission_exists(*permission))
+        .map(|permission| permission.to_string())
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        role_api::EffectivePermissionsResponse {
+            groups: role_info.get_permission_groups(),
+            permissions,
+        },
+    ))
+}
+
 pub async fn create_role(
     state: SessionState,
     user_from_token: UserFromToken,
     req: role_api::CreateRoleRequest,
     _req_state: ReqState,
-) -> UserResponse<role_api::RoleInfoWithGroupsResponse> {
+) -> RoleResponse<role_api::RoleInfoWithGroupsResponse> {
+    utils::user_role::check_role_write_rate_limit(&state, &user_from_token.merchant_id)
+        .await
+        .switch()?;
+
     let now = common_utils::date_time::now();
 
-    let user_entity_type = user_from_token
+    let user_role_info = user_from_token
         .get_role_info_from_db(&state)
         .await
-        .attach_printable("Invalid role_id in JWT")?
-        .get_entity_type();
+        .switch()
+        .attach_printable("Invalid role_id in JWT")?;
+    let user_entity_type = user_role_info.get_entity_type();
 
     let role_entity_type = req.entity_type.unwrap_or(EntityType::Merchant);
 
     if matches!(role_entity_type, EntityType::Organization) {
-        return Err(report!(UserErrors::InvalidRoleOperation))
-            .attach_printable("User trying to create org level custom role");
+        return Err(report!(RoleError::InvalidRoleOperation(
+            "Organization level custom roles cannot be created".to_string()
+        )));
     }
 
     // TODO: Remove in PR custom-role-write-pr
     if matches!(role_entity_type, EntityType::Profile) {
-        return Err(report!(UserErrors::InvalidRoleOperation))
-            .attach_printable("User trying to create profile level custom role");
+        return Err(report!(RoleError::InvalidRoleOperation(
+            "Profile level custom roles cannot be created".to_string()
+        )));
     }
 
     let requestor_entity_from_role_scope = EntityType::from(req.role_scope);
 
     if requestor_entity_from_role_scope < role_entity_type {
-        return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
-            "User is trying to create role of type {} and scope {}",
+        return Err(report!(RoleError::InvalidRoleOperation(format!(
+            "Role of type {} cannot have scope {}",
             role_entity_type, requestor_entity_from_role_scope
-        ));
+        ))));
     }
     let max_from_scope_and_entity = cmp::max(requestor_entity_from_role_scope, role_entity_type);
 
     if user_entity_type < max_from_scope_and_entity {
-        return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
-            "{} is trying to create of scope {} and of type {}",
+        return Err(report!(RoleError::PermissionEscalation(format!(
+            "{} level user cannot create role of scope {} and of type {}",
             user_entity_type, requestor_entity_from_role_scope, role_entity_type
-        ));
+        ))));
     }
 
-    let role_name = RoleName::new(req.role_name)?;
+    let role_name = RoleName::new(req.role_name).switch()?;
+
+    let custom_groups = state
+        .global_store
+        .list_custom_permission_groups_for_merchant(
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+        )
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to list custom permission groups")?;
 
-    utils::user_role::validate_role_groups(&req.groups)?;
+    let groups = req.groups;
+    let custom_permissions =
+        get_permissions_from_custom_group_names(req.custom_groups, &custom_groups)?;
+    // Roles made up only of custom permission groups have no predefined groups to validate
+    if !groups.is_empty() || custom_permissions.is_empty() {
+        utils::user_role::validate_role_groups(&groups).switch()?;
+    }
+    validate_groups_held_by_user(&user_role_info, &groups)?;
+    validate_permissions_held_by_user(&user_role_info, &custom_permissions)?;
     utils::user_role::validate_role_name(
         &state,
         &role_name,
@@ -117,8 +197,21 @@ pub async fn create_role(
         &user_from_token.profile_id,
         &role_entity_type,
     )
-    .await?;
+    .await
+    .switch()?;
+    utils::user_role::validate_custom_roles_limit(
+        &state,
+        &user_from_token.merchant_id,
+        user_from_token
+            .tenant_id
+            .as_ref()
+            .unwrap_or(&state.tenant.tenant_id),
+    )
+    .await
+    .switch()?;
 
+    // The user is recorded as the actor in the role audit trail, after the role is created
+    let actor = user_from_token.clone();
     let (org_id, merchant_id, profile_id) = match role_entity_type {
         EntityType::Organization | EntityType::Tenant => {
             (user_from_token.org_id, user_from_token.merchant_id, None)
@@ -138,7 +231,7 @@ pub async fn create_role(
             role_name: role_name.get_role_name(),
             merchant_id,
             org_id,
-            groups: req.groups,
+            groups,
             scope: req.role_scope,
             entity_type: role_entity_type,
             created_by: user_from_token.user_id.clone(),
@@ -146,27 +239,181 @@ pub async fn create_role(
             created_at: now,
             last_modified_at: now,
             profile_id,
-            tenant_id: user_from_token.tenant_id.unwrap_or(state.tenant.tenant_id),
+            tenant_id: user_from_token
+                .tenant_id
+                .unwrap_or(state.tenant.tenant_id.clone()),
+            custom_permissions: (!custom_permissions.is_empty())
+                .then(|| custom_permissions.iter().map(ToString::to_string).collect()),
+            template_id: None,
         })
         .await
-        .to_duplicate_response(UserErrors::RoleNameAlreadyExists)?;
+        .to_duplicate_response(RoleError::DuplicateRoleName)?;
+
+    super::insert_role_audit(
+        &state,
+        &actor,
+        RoleAuditAction::CreateRole,
+        role.role_id.clone(),
+        Vec::new(),
+        role.groups.clone(),
+        None,
+    )
+    .await;
 
     Ok(ApplicationResponse::Json(
         role_api::RoleInfoWithGroupsResponse {
             groups: role.groups,
+            inherited_groups: Vec::new(),
             role_id: role.role_id,
             role_name: role.role_name,
             role_scope: role.scope,
             entity_type: role.entity_type,
+            assigned_user_count: None,
+            version: Some(role.version),
+            last_used_at: role.last_used_at,
         },
     ))
 }
 
+/// Resolves the custom permission groups of a role request to the permissions they grant
+fn get_permissions_from_custom_group_names(
+    custom_group_names: Vec<String>,
+    custom_groups: &[CustomPermissionGroup],
+) -> RoleResult<Vec<Permission>> {
+    let mut custom_permissions = BTreeSet::new();
+    let mut unknown_groups = Vec::new();
+
+    for group_name in custom_group_names {
+        if let Some(custom_group) = custom_groups
+            .iter()
+            .find(|custom_group| custom_group.group_name == group_name)
+        {
+            custom_permissions.extend(
+                custom_group
+                    .permissions
+                    .iter()
+                    .filter_map(|permission| get_permission_from_name(permission)),
+            );
+        } else {
+            unknown_groups.push(group_name);
+        }
+    }
+
+    if !unknown_groups.is_empty() {
+        return Err(RoleError::InvalidRoleOperation(format!(
+            "Unknown custom permission groups: {}",
+            unknown_groups.join(", ")
+        ))
+        .into());
+    }
+
+    Ok(custom_permissions.into_iter().collect())
+}
+
+/// Users cannot grant permission groups they do not hold themselves. Org level users and above
+/// are exempted, as they sit above the lineage of the roles they manage.
+pub(crate) fn validate_groups_held_by_user(
+    user_role_info: &roles::RoleInfo,
+    groups: &[PermissionGroup],
+) -> RoleResult<()> {
+    if user_role_info.get_entity_type() >= EntityType::Organization {
+        return Ok(());
+    }
+
+    let user_groups = user_role_info
+        .get_permission_groups()
+        .into_iter()
+        .collect::<HashSet<_>>();
+    let groups_not_held = groups
+        .iter()
+        .filter(|group| !user_groups.contains(group))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    if !groups_not_held.is_empty() {
+        return Err(RoleError::PermissionEscalation(format!(
+            "Cannot grant permission groups not held by the user: {}",
+            groups_not_held.join(", ")
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+fn validate_permissions_held_by_user(
+    user_role_info: &roles::RoleInfo,
+    permissions: &[Permission],
+) -> RoleResult<()> {
+    if user_role_info.get_entity_type() >= EntityType::Organization {
+        return Ok(());
+    }
+
+    let permissions_not_held = permissions
+        .iter()
+        .filter(|permission| !user_role_info.check_permission_exists(**permission))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    if !permissions_not_held.is_empty() {
+        return Err(RoleError::PermissionEscalation(format!(
+            "Cannot grant permissions not held by the user: {}",
+            permissions_not_held.join(", ")
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+pub async fn clone_role(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: role_api::CloneRoleRequest,
+    source_role_id: &str,
+    req_state: ReqState,
+) -> RoleResponse<role_api::RoleInfoWithGroupsResponse> {
+    let source_role_info = roles::RoleInfo::from_role_id_in_lineage(
+        &state,
+        source_role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+        &user_from_token.profile_id,
+        user_from_token
+            .tenant_id
+            .as_ref()
+            .unwrap_or(&state.tenant.tenant_id),
+    )
+    .await
+    .to_not_found_response(RoleError::RoleNotFound)?;
+
+    if source_role_info.is_internal() {
+        return Err(report!(RoleError::RoleNotFound))
+            .attach_printable("User trying to clone an internal role");
+    }
+
+    // Clones are always created as merchant level custom roles, irrespective of whether the
+    // source is a predefined role or a custom role
+    create_role(
+        state,
+        user_from_token,
+        role_api::CreateRoleRequest {
+            role_name: req.role_name,
+            groups: source_role_info.get_permission_groups(),
+            custom_groups: Vec::new(),
+            role_scope: RoleScope::Merchant,
+            entity_type: Some(EntityType::Merchant),
+        },
+        req_state,
+    )
+    .await
+}
+
 pub async fn get_role_with_groups(
     state: SessionState,
     user_from_token: UserFromToken,
     role: role_api::GetRoleRequest,
-) -> UserResponse<role_api::RoleInfoWithGroupsResponse> {
+) -> RoleResponse<role_api::RoleInfoWithGroupsResponse> {
     let role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
         &state,
         &role.role_id,
@@ -177,28 +424,222 @@ pub async fn get_role_with_groups(
             .unwrap_or(&state.tenant.tenant_id),
     )
     .await
-    .to_not_found_response(UserErrors::InvalidRoleId)?;
+    .to_not_found_response(RoleError::RoleNotFound)?;
 
     if role_info.is_internal() {
-        return Err(UserErrors::InvalidRoleId.into());
+        return Err(RoleError::RoleNotFound.into());
     }
 
+    let assigned_user_count = state
+        .global_store
+        .get_active_users_count_for_role(
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            &user_from_token.org_id,
+            &role.role_id,
+        )
+        .await
+        .change_context(RoleError::InternalServerError)?;
+
+    let last_used_at = match role_info.get_last_used_at() {
+        Some(last_used_at) => Some(last_used_at),
+        None if PREDEFINED_ROLES.contains_key(role.role_id.as_str()) => {
+            utils::user_role::get_predefined_roles_last_used_at(&state, &user_from_token.org_id)
+                .await
+                .switch()?
+                .remove(&role.role_id)
+        }
+        None => None,
+    };
+
+    // Only the role the user is operating under inherits from the roles held above it
+    let inherited_groups = if role.role_id == user_from_token.role_id {
+        get_inherited_groups(&state, &user_from_token, &role_info).await?
+    } else {
+        Vec::new()
+    };
+
     Ok(ApplicationResponse::Json(
         role_api::RoleInfoWithGroupsResponse {
-            groups: role_info.get_permission_groups().to_vec(),
+            groups: role_info
+                .get_permission_groups()
+                .into_iter()
+                .chain(inherited_groups.iter().map(|inherited| inherited.group))
+                .collect(),
+            inherited_groups,
             role_id: role.role_id,
             role_name: role_info.get_role_name().to_string(),
             role_scope: role_info.get_scope(),
             entity_type: role_info.get_entity_type(),
+            assigned_user_count: Some(assigned_user_count),
+            version: role_info.get_version(),
+            last_used_at,
         },
     ))
 }
 
+/// Collects the groups inherited from the active roles the user holds at entities above the
+/// entity of the given role, in the lineage of the token. Groups of the given role take
+/// precedence, so inherited groups of a parent group already granted by it are left out.
+async fn get_inherited_groups(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+    role_info: &roles::RoleInfo,
+) -> RoleResult<Vec<role_api::InheritedPermissionGroup>> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id: &user_from_token.user_id,
+            tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: Some(UserStatus::Active),
+            limit: None,
+        })
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to list user roles of user")?;
+
+    let direct_parents = role_info
+        .get_permission_groups()
+        .iter()
+        .map(|group| group.parent())
+        .collect::<HashSet<_>>();
+    let mut inherited_groups: Vec<role_api::InheritedPermissionGroup> = Vec::new();
+
+    for user_role in user_roles {
+        if user_role.is_expired() {
+            continue;
+        }
+        let Some((entity_id, entity_type)) = user_role.get_entity_id_and_type() else {
+            continue;
+        };
+        let is_in_lineage = match entity_type {
+            EntityType::Tenant => true,
+            EntityType::Organization => entity_id == user_from_token.org_id.get_string_repr(),
+            EntityType::Merchant => entity_id == user_from_token.merchant_id.get_string_repr(),
+            EntityType::Profile => false,
+        };
+        if entity_type <= role_info.get_entity_type() || !is_in_lineage {
+            continue;
+        }
+
+        let inherited_role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+            state,
+            &user_role.role_id,
+            &user_from_token.org_id,
+            tenant_id,
+        )
+        .await
+        .change_context(RoleError::InternalServerError)?;
+
+        for group in inherited_role_info.get_permission_groups() {
+            if direct_parents.contains(&group.parent())
+                || inherited_groups
+                    .iter()
+                    .any(|inherited| inherited.group == group)
+            {
+                continue;
+            }
+            inherited_groups.push(role_api::InheritedPermissionGroup {
+                group,
+                role_id: user_role.role_id.clone(),
+                entity_type,
+            });
+        }
+    }
+
+    Ok(inherited_groups)
+}
+
+pub async fn compare_roles(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    request: role_api::CompareRolesRequest,
+) -> RoleResponse<role_api::CompareRolesResponse> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+
+    let (base_role_info, target_role_info) = tokio::try_join!(
+        roles::RoleInfo::from_role_id_org_id_tenant_id(
+            &state,
+            &request.base,
+            &user_from_token.org_id,
+            tenant_id,
+        ),
+        roles::RoleInfo::from_role_id_org_id_tenant_id(
+            &state,
+            &request.target,
+            &user_from_token.org_id,
+            tenant_id,
+        ),
+    )
+    .to_not_found_response(RoleError::RoleNotFound)?;
+
+    if base_role_info.is_internal() || target_role_info.is_internal() {
+        return Err(RoleError::RoleNotFound.into());
+    }
+
+    Ok(ApplicationResponse::Json(get_role_groups_comparison(
+        request.base,
+        &base_role_info,
+        request.target,
+        &target_role_info,
+    )))
+}
+
+pub fn get_role_groups_comparison(
+    base_role_id: String,
+    base_role_info: &roles::RoleInfo,
+    target_role_id: String,
+    target_role_info: &roles::RoleInfo,
+) -> role_api::CompareRolesResponse {
+    let base_groups = base_role_info
+        .get_permission_groups()
+        .into_iter()
+        .collect::<HashSet<_>>();
+    let target_groups = target_role_info
+        .get_permission_groups()
+        .into_iter()
+        .collect::<HashSet<_>>();
+
+    let mut response = role_api::CompareRolesResponse {
+        base_role_id,
+        target_role_id,
+        only_in_base: Vec::new(),
+        only_in_target: Vec::new(),
+        in_both: Vec::new(),
+    };
+
+    for group in PermissionGroup::iter() {
+        let group_info = info::get_group_info_from_permission_group(group);
+        match (base_groups.contains(&group), target_groups.contains(&group)) {
+            (true, true) => response.in_both.push(group_info),
+            (true, false) => response.only_in_base.push(group_info),
+            (false, true) => response.only_in_target.push(group_info),
+            (false, false) => (),
+        }
+    }
+
+    response
+}
+
 pub async fn get_parent_info_for_role(
     state: SessionState,
     user_from_token: UserFromToken,
     role: role_api::GetRoleRequest,
-) -> UserResponse<role_api::RoleInfoWithParents> {
+) -> RoleResponse<role_api::RoleInfoWithParents> {
     let role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
         &state,
         &role.role_id,
@@ -209,10 +650,10 @@ pub async fn get_parent_info_for_role(
             .unwrap_or(&state.tenant.tenant_id),
     )
     .await
-    .to_not_found_response(UserErrors::InvalidRoleId)?;
+    .to_not_found_response(RoleError::RoleNotFound)?;
 
     if role_info.is_internal() {
-        return Err(UserErrors::InvalidRoleId.into());
+        return Err(RoleError::RoleNotFound.into());
     }
 
     let parent_groups = ParentGroup::get_descriptions_for_groups(
@@ -248,8 +689,16 @@ pub async fn update_role(
     user_from_token: UserFromToken,
     req: role_api::UpdateRoleRequest,
     role_id: &str,
-) -> UserResponse<role_api::RoleInfoWithGroupsResponse> {
-    let role_name = req.role_name.map(RoleName::new).transpose()?;
+) -> RoleResponse<role_api::RoleInfoWithGroupsResponse> {
+    utils::user_role::check_role_write_rate_limit(&state, &user_from_token.merchant_id)
+        .await
+        .switch()?;
+
+    if PREDEFINED_ROLES.contains_key(role_id) {
+        return Err(RoleError::PredefinedRoleImmutable.into());
+    }
+
+    let role_name = req.role_name.map(RoleName::new).transpose().switch()?;
 
     let role_info = roles::RoleInfo::from_role_id_in_lineage(
         &state,
@@ -263,9 +712,12 @@ pub async fn update_role(
             .unwrap_or(&state.tenant.tenant_id),
     )
     .await
-    .to_not_found_response(UserErrors::InvalidRoleOperation)?;
+    .to_not_found_response(RoleError::RoleNotFound)?;
 
-    let user_role_info = user_from_token.get_role_info_from_db(&state).await?;
+    let user_role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()?;
 
     let requested_entity_from_role_scope = EntityType::from(role_info.get_scope());
     let requested_role_entity_type = role_info.get_entity_type();
@@ -273,12 +725,12 @@ pub async fn update_role(
         cmp::max(requested_entity_from_role_scope, requested_role_entity_type);
 
     if user_role_info.get_entity_type() < max_from_scope_and_entity {
-        return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
-            "{} is trying to update of scope {} and of type {}",
+        return Err(report!(RoleError::PermissionEscalation(format!(
+            "{} level user cannot update role of scope {} and of type {}",
             user_role_info.get_entity_type(),
             requested_entity_from_role_scope,
             requested_role_entity_type
-        ));
+        ))));
     }
 
     if let Some(ref role_name) = role_name {
@@ -294,127 +746,470 @@ pub async fn update_role(
             &user_from_token.profile_id,
             &role_info.get_entity_type(),
         )
-        .await?;
+        .await
+        .switch()?;
     }
 
     if let Some(ref groups) = req.groups {
-        utils::user_role::validate_role_groups(groups)?;
+        utils::user_role::validate_role_groups(groups).switch()?;
+
+        let existing_groups = role_info
+            .get_permission_groups()
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let groups_to_be_added = groups
+            .iter()
+            .filter(|group| !existing_groups.contains(group))
+            .copied()
+            .collect::<Vec<_>>();
+        validate_groups_held_by_user(&user_role_info, &groups_to_be_added)?;
     }
 
     let updated_role = state
         .global_store
-        .update_role_by_role_id(
+        .update_role_by_role_id_and_version(
             role_id,
+            req.version,
             RoleUpdate::UpdateDetails {
                 groups: req.groups,
                 role_name: role_name.map(RoleName::get_role_name),
                 last_modified_at: common_utils::date_time::now(),
-                last_modified_by: user_from_token.user_id,
+                last_modified_by: user_from_token.user_id.clone(),
             },
         )
         .await
-        .to_duplicate_response(UserErrors::RoleNameAlreadyExists)?;
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error
+                    .change_context(RoleError::RoleVersionMismatch)
+                    .attach_printable(format!(
+                        "Role {role_id} is no longer at version {}",
+                        req.version
+                    ))
+            } else if error.current_context().is_db_unique_violation() {
+                error.change_context(RoleError::DuplicateRoleName)
+            } else {
+                error.change_context(RoleError::InternalServerError)
+            }
+        })?;
+
+    blacklist::insert_role_in_blacklist(&state, role_id)
+        .await
+        .switch()?;
 
-    blacklist::insert_role_in_blacklist(&state, role_id).await?;
+    super::insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::UpdateRole,
+        role_id.to_string(),
+        role_info.get_permission_groups(),
+        updated_role.groups.clone(),
+        None,
+    )
+    .await;
 
     Ok(ApplicationResponse::Json(
         role_api::RoleInfoWithGroupsResponse {
             groups: updated_role.groups,
+            inherited_groups: Vec::new(),
             role_id: updated_role.role_id,
             role_name: updated_role.role_name,
             role_scope: updated_role.scope,
             entity_type: updated_role.entity_type,
+            assigned_user_count: None,
+            version: Some(updated_role.version),
+            last_used_at: updated_role.last_used_at,
         },
     ))
 }
 
-pub async fn list_roles_with_info(
+pub async fn preview_role_update(
     state: SessionState,
     user_from_token: UserFromToken,
-    request: role_api::ListRolesRequest,
-) -> UserResponse<Vec<role_api::RoleInfoResponseNew>> {
-    let user_role_info = user_from_token
-        .get_role_info_from_db(&state)
-        .await
-        .attach_printable("Invalid role_id in JWT")?;
-
-    if user_role_info.is_internal() {
-        return Err(UserErrors::InvalidRoleOperationWithMessage(
-            "Internal roles are not allowed for this operation".to_string(),
-        )
-        .into());
+    req: role_api::PreviewRoleUpdateRequest,
+    role_id: &str,
+) -> RoleResponse<role_api::PreviewRoleUpdateResponse> {
+    if PREDEFINED_ROLES.contains_key(role_id) {
+        return Err(RoleError::PredefinedRoleImmutable.into());
     }
 
-    let mut role_info_vec = PREDEFINED_ROLES
-        .iter()
-        .map(|(_, role_info)| role_info.clone())
-        .collect::<Vec<_>>();
-
-    let user_role_entity = user_role_info.get_entity_type();
-    let is_lineage_data_required = request.entity_type.is_none();
     let tenant_id = user_from_token
         .tenant_id
         .as_ref()
-        .unwrap_or(&state.tenant.tenant_id)
-        .to_owned();
-    let custom_roles =
-        match utils::user_role::get_min_entity(user_role_entity, request.entity_type)? {
-            EntityType::Tenant | EntityType::Organization => state
-                .global_store
-                .generic_list_roles_by_entity_type(
-                    ListRolesByEntityPayload::Organization,
-                    is_lineage_data_required,
-                    tenant_id,
-                    user_from_token.org_id,
-                )
-                .await
-                .change_context(UserErrors::InternalServerError)
-                .attach_printable("Failed to get roles")?,
-            EntityType::Merchant => state
-                .global_store
-                .generic_list_roles_by_entity_type(
-                    ListRolesByEntityPayload::Merchant(user_from_token.merchant_id),
-                    is_lineage_data_required,
-                    tenant_id,
-                    user_from_token.org_id,
-                )
-                .await
-                .change_context(UserErrors::InternalServerError)
-                .attach_printable("Failed to get roles")?,
-
-            EntityType::Profile => state
-                .global_store
-                .generic_list_roles_by_entity_type(
-                    ListRolesByEntityPayload::Profile(
-                        user_from_token.merchant_id,
-                        user_from_token.profile_id,
-                    ),
-                    is_lineage_data_required,
-                    tenant_id,
-                    user_from_token.org_id,
-                )
-                .await
-                .change_context(UserErrors::InternalServerError)
-                .attach_printable("Failed to get roles")?,
-        };
-
-    role_info_vec.extend(custom_roles.into_iter().map(roles::RoleInfo::from));
+        .unwrap_or(&state.tenant.tenant_id);
 
-    let list_role_info_response = role_info_vec
+    let role_info = roles::RoleInfo::from_role_id_in_lineage(
+        &state,
+        role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+        &user_from_token.profile_id,
+        tenant_id,
+    )
+    .await
+    .to_not_found_response(RoleError::RoleNotFound)?;
+
+    utils::user_role::validate_role_groups(&req.groups).switch()?;
+
+    let current_groups = role_info
+        .get_permission_groups()
+        .into_iter()
+        .collect::<HashSet<_>>();
+    let proposed_groups = req.groups.into_iter().collect::<HashSet<_>>();
+
+    let current_permissions =
+        get_permissions_for_groups(role_info.get_entity_type(), &current_groups);
+    let proposed_permissions =
+        get_permissions_for_groups(role_info.get_entity_type(), &proposed_groups);
+    let permission_delta = role_api::PermissionDelta {
+        permissions_gained: proposed_permissions
+            .difference(&current_permissions)
+            .map(ToString::to_string)
+            .collect(),
+        permissions_lost: current_permissions
+            .difference(&proposed_permissions)
+            .map(ToString::to_string)
+            .collect(),
+    };
+
+    let (total_affected_users, user_ids) = tokio::try_join!(
+        state.global_store.get_active_users_count_for_role(
+            tenant_id,
+            &user_from_token.org_id,
+            role_id,
+        ),
+        state.global_store.list_active_user_ids_for_role(
+            tenant_id,
+            &user_from_token.org_id,
+            role_id,
+            consts::user_role::MAX_ROLE_UPDATE_PREVIEW_USERS,
+        ),
+    )
+    .change_context(RoleError::InternalServerError)?;
+
+    let mut users = state
+        .global_store
+        .find_users_by_user_ids(user_ids)
+        .await
+        .change_context(RoleError::InternalServerError)?;
+    users.sort_by(|user_a, user_b| user_a.user_id.cmp(&user_b.user_id));
+
+    let affected_users = users
+        .into_iter()
+        .map(|user| role_api::AffectedUserInfo {
+            user_id: user.user_id,
+            email: user.email,
+            permission_delta: permission_delta.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        role_api::PreviewRoleUpdateResponse {
+            role_id: role_id.to_string(),
+            groups_added: PermissionGroup::iter()
+                .filter(|group| proposed_groups.contains(group) && !current_groups.contains(group))
+                .collect(),
+            groups_removed: PermissionGroup::iter()
+                .filter(|group| current_groups.contains(group) && !proposed_groups.contains(group))
+                .collect(),
+            total_affected_users,
+            is_truncated: i64::try_from(affected_users.len()).unwrap_or(i64::MAX)
+                < total_affected_users,
+            affected_users,
+        },
+    ))
+}
+
+/// Permissions a role of the given entity type would be granted by holding the given groups
+fn get_permissions_for_groups(
+    entity_type: EntityType,
+    groups: &HashSet<PermissionGroup>,
+) -> BTreeSet<Permission> {
+    Permission::iter()
+        .filter(|permission| {
+            permission.entity_type() <= entity_type
+                && groups.iter().any(|group| {
+                    permission.scope() <= group.scope()
+                        && group.resources().contains(&permission.resource())
+                })
+        })
+        .collect()
+}
+
+pub async fn soft_delete_role(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    role_id: &str,
+) -> RoleResponse<()> {
+    let role_info = get_custom_role_for_deletion_update(&state, &user_from_token, role_id).await?;
+
+    if role_info.is_deleted() {
+        return Err(RoleError::InvalidRoleOperation("Role is already deleted".to_string()).into());
+    }
+
+    state
+        .global_store
+        .update_role_by_role_id(
+            role_id,
+            RoleUpdate::SoftDelete {
+                last_modified_at: common_utils::date_time::now(),
+                last_modified_by: user_from_token.user_id.clone(),
+            },
+        )
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to soft delete role")?;
+
+    blacklist::insert_role_in_blacklist(&state, role_id)
+        .await
+        .switch()?;
+
+    super::insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::DeleteRole,
+        role_id.to_string(),
+        role_info.get_permission_groups(),
+        role_info.get_permission_groups(),
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn restore_role(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    role_id: &str,
+) -> RoleResponse<role_api::RoleInfoWithGroupsResponse> {
+    let role_info = get_custom_role_for_deletion_update(&state, &user_from_token, role_id).await?;
+
+    if !role_info.is_deleted() {
+        return Err(RoleError::InvalidRoleOperation("Role is not deleted".to_string()).into());
+    }
+
+    let restored_role = state
+        .global_store
+        .update_role_by_role_id(
+            role_id,
+            RoleUpdate::Restore {
+                last_modified_at: common_utils::date_time::now(),
+                last_modified_by: user_from_token.user_id.clone(),
+            },
+        )
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to restore role")?;
+
+    blacklist::insert_role_in_blacklist(&state, role_id)
+        .await
+        .switch()?;
+
+    super::insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::RestoreRole,
+        role_id.to_string(),
+        role_info.get_permission_groups(),
+        restored_role.groups.clone(),
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(
+        role_api::RoleInfoWithGroupsResponse {
+            groups: restored_role.groups,
+            inherited_groups: Vec::new(),
+            role_id: restored_role.role_id,
+            role_name: restored_role.role_name,
+            role_scope: restored_role.scope,
+            entity_type: restored_role.entity_type,
+            assigned_user_count: None,
+            version: Some(restored_role.version),
+            last_used_at: restored_role.last_used_at,
+        },
+    ))
+}
+
+async fn get_custom_role_for_deletion_update(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+    role_id: &str,
+) -> RoleResult<roles::RoleInfo> {
+    if PREDEFINED_ROLES.contains_key(role_id) {
+        return Err(RoleError::PredefinedRoleImmutable.into());
+    }
+
+    let role_info = roles::RoleInfo::from_role_id_in_lineage(
+        state,
+        role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+        &user_from_token.profile_id,
+        user_from_token
+            .tenant_id
+            .as_ref()
+            .unwrap_or(&state.tenant.tenant_id),
+    )
+    .await
+    .to_not_found_response(RoleError::RoleNotFound)?;
+
+    let user_role_info = user_from_token
+        .get_role_info_from_db(state)
+        .await
+        .switch()?;
+
+    let requested_entity_from_role_scope = EntityType::from(role_info.get_scope());
+    let requested_role_entity_type = role_info.get_entity_type();
+    let max_from_scope_and_entity =
+        cmp::max(requested_entity_from_role_scope, requested_role_entity_type);
+
+    if user_role_info.get_entity_type() < max_from_scope_and_entity {
+        return Err(report!(RoleError::PermissionEscalation(format!(
+            "{} level user cannot delete or restore role of scope {} and of type {}",
+            user_role_info.get_entity_type(),
+            requested_entity_from_role_scope,
+            requested_role_entity_type
+        ))));
+    }
+
+    Ok(role_info)
+}
+
+pub async fn list_roles_with_info(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    request: role_api::ListRolesRequest,
+) -> RoleResponse<Vec<role_api::RoleInfoResponseNew>> {
+    let user_role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()
+        .attach_printable("Invalid role_id in JWT")?;
+
+    if user_role_info.is_internal() {
+        return Err(RoleError::InvalidRoleOperation(
+            "Internal roles are not allowed for this operation".to_string(),
+        )
+        .into());
+    }
+
+    let mut role_info_vec = PREDEFINED_ROLES
+        .iter()
+        .map(|(_, role_info)| role_info.clone())
+        .collect::<Vec<_>>();
+
+    let predefined_roles_last_used_at =
+        utils::user_role::get_predefined_roles_last_used_at(&state, &user_from_token.org_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to get predefined roles usage"))
+            .unwrap_or_default();
+
+    let user_role_entity = user_role_info.get_entity_type();
+
+    if let Some(entity_type) = request.entity_type {
+        if matches!(entity_type, EntityType::Tenant) {
+            return Err(RoleError::InvalidRoleOperation(
+                "entity_type must be one of organization, merchant or profile".to_string(),
+            )
+            .into());
+        }
+
+        if entity_type > user_role_entity {
+            return Err(RoleError::InvalidRoleOperation(format!(
+                "{} level user cannot list roles at {} level",
+                user_role_entity, entity_type
+            ))
+            .into());
+        }
+    }
+
+    let is_lineage_data_required = request.entity_type.is_none();
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id)
+        .to_owned();
+    let custom_roles =
+        match utils::user_role::get_min_entity(user_role_entity, request.entity_type).switch()? {
+            EntityType::Tenant | EntityType::Organization => state
+                .global_store
+                .generic_list_roles_by_entity_type(
+                    ListRolesByEntityPayload::Organization,
+                    is_lineage_data_required,
+                    tenant_id,
+                    user_from_token.org_id,
+                )
+                .await
+                .change_context(RoleError::InternalServerError)
+                .attach_printable("Failed to get roles")?,
+            EntityType::Merchant => state
+                .global_store
+                .generic_list_roles_by_entity_type(
+                    ListRolesByEntityPayload::Merchant(user_from_token.merchant_id),
+                    is_lineage_data_required,
+                    tenant_id,
+                    user_from_token.org_id,
+                )
+                .await
+                .change_context(RoleError::InternalServerError)
+                .attach_printable("Failed to get roles")?,
+
+            EntityType::Profile => state
+                .global_store
+                .generic_list_roles_by_entity_type(
+                    ListRolesByEntityPayload::Profile(
+                        user_from_token.merchant_id,
+                        user_from_token.profile_id,
+                    ),
+                    is_lineage_data_required,
+                    tenant_id,
+                    user_from_token.org_id,
+                )
+                .await
+                .change_context(RoleError::InternalServerError)
+                .attach_printable("Failed to get roles")?,
+        };
+
+    role_info_vec.extend(custom_roles.into_iter().map(roles::RoleInfo::from));
+
+    let name_search = request
+        .name_search
+        .as_deref()
+        .map(str::trim)
+        .filter(|name_search| !name_search.is_empty())
+        .map(str::to_lowercase);
+
+    let list_role_info_response = role_info_vec
         .into_iter()
         .filter_map(|role_info| {
             let is_lower_entity = user_role_entity >= role_info.get_entity_type();
             let request_filter = request.entity_type.map_or(true, |entity_type| {
                 entity_type == role_info.get_entity_type()
             });
+            let name_filter = name_search.as_ref().map_or(true, |name_search| {
+                role_info
+                    .get_role_name()
+                    .to_lowercase()
+                    .contains(name_search.as_str())
+            });
 
-            (is_lower_entity && request_filter).then_some(role_api::RoleInfoResponseNew {
-                role_id: role_info.get_role_id().to_string(),
-                role_name: role_info.get_role_name().to_string(),
-                groups: role_info.get_permission_groups().to_vec(),
-                entity_type: role_info.get_entity_type(),
-                scope: role_info.get_scope(),
-            })
+            (is_lower_entity && request_filter && name_filter && !role_info.is_deleted()).then_some(
+                role_api::RoleInfoResponseNew {
+                    role_id: role_info.get_role_id().to_string(),
+                    role_name: role_info.get_role_name().to_string(),
+                    groups: (request.fields == role_api::ListRolesFields::Full)
+                        .then(|| role_info.get_permission_groups().to_vec()),
+                    entity_type: role_info.get_entity_type(),
+                    scope: role_info.get_scope(),
+                    last_used_at: role_info.get_last_used_at().or_else(|| {
+                        predefined_roles_last_used_at
+                            .get(role_info.get_role_id())
+                            .copied()
+                    }),
+                },
+            )
         })
         .collect::<Vec<_>>();
 
@@ -426,15 +1221,16 @@ pub async fn list_roles_at_entity_level(
     user_from_token: UserFromToken,
     req: role_api::ListRolesAtEntityLevelRequest,
     check_type: role_api::RoleCheckType,
-) -> UserResponse<Vec<role_api::MinimalRoleInfo>> {
+) -> RoleResponse<Vec<role_api::MinimalRoleInfo>> {
     let user_entity_type = user_from_token
         .get_role_info_from_db(&state)
         .await
+        .switch()
         .attach_printable("Invalid role_id in JWT")?
         .get_entity_type();
 
     if req.entity_type > user_entity_type {
-        return Err(UserErrors::InvalidRoleOperationWithMessage(
+        return Err(RoleError::InvalidRoleOperation(
             "User is attempting to request list roles above the current entity level".to_string(),
         )
         .into());
@@ -461,7 +1257,7 @@ pub async fn list_roles_at_entity_level(
                 user_from_token.org_id,
             )
             .await
-            .change_context(UserErrors::InternalServerError)
+            .change_context(RoleError::InternalServerError)
             .attach_printable("Failed to get roles")?,
 
         EntityType::Merchant => state
@@ -473,7 +1269,7 @@ pub async fn list_roles_at_entity_level(
                 user_from_token.org_id,
             )
             .await
-            .change_context(UserErrors::InternalServerError)
+            .change_context(RoleError::InternalServerError)
             .attach_printable("Failed to get roles")?,
 
         EntityType::Profile => state
@@ -488,7 +1284,7 @@ pub async fn list_roles_at_entity_level(
                 user_from_token.org_id,
             )
             .await
-            .change_context(UserErrors::InternalServerError)
+            .change_context(RoleError::InternalServerError)
             .attach_printable("Failed to get roles")?,
     };
 
@@ -501,7 +1297,10 @@ pub async fn list_roles_at_entity_level(
                 role_api::RoleCheckType::Invite => role_info.is_invitable(),
                 role_api::RoleCheckType::Update => role_info.is_updatable(),
             };
-            if check_type && role_info.get_entity_type() == req.entity_type {
+            if check_type
+                && !role_info.is_deleted()
+                && role_info.get_entity_type() == req.entity_type
+            {
                 Some(role_api::MinimalRoleInfo {
                     role_id: role_info.get_role_id().to_string(),
                     role_name: role_info.get_role_name().to_string(),
@@ -514,3 +1313,856 @@ pub async fn list_roles_at_entity_level(
 
     Ok(ApplicationResponse::Json(list_minimal_role_info))
 }
+
+pub async fn list_role_audit(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    request: role_api::ListRoleAuditRequest,
+) -> RoleResponse<Vec<role_api::RoleAuditResponse>> {
+    let limit = request
+        .limit
+        .unwrap_or(consts::user::DEFAULT_LIST_ROLE_AUDIT_LIMIT);
+
+    let role_audits = state
+        .global_store
+        .list_role_audits_for_merchant(
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+            Some(limit.into()),
+            request.offset.map(Into::into),
+        )
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to list role audits")?;
+
+    Ok(ApplicationResponse::Json(
+        role_audits
+            .into_iter()
+            .map(|role_audit| role_api::RoleAuditResponse {
+                action: role_audit.action,
+                actor_user_id: role_audit.actor_user_id,
+                target_id: role_audit.target_id,
+                groups_before: role_audit.groups_before,
+                groups_after: role_audit.groups_after,
+                created_at: role_audit.created_at,
+                reason: role_audit.reason,
+            })
+            .collect(),
+    ))
+}
+
+pub async fn create_permission_group(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: role_api::CreatePermissionGroupRequest,
+) -> RoleResponse<role_api::CustomPermissionGroupResponse> {
+    let group_name = req.group_name.trim().to_string();
+    if group_name.is_empty()
+        || group_name.len() > consts::user_role::MAX_PERMISSION_GROUP_NAME_LENGTH
+    {
+        return Err(report!(RoleError::InvalidRoleOperation(format!(
+            "Permission group name must be between 1 and {} characters",
+            consts::user_role::MAX_PERMISSION_GROUP_NAME_LENGTH
+        ))));
+    }
+
+    // Custom groups share the identifier space of role requests with the predefined groups
+    if PermissionGroup::iter().any(|group| group.to_string().eq_ignore_ascii_case(&group_name)) {
+        return Err(report!(RoleError::InvalidRoleOperation(format!(
+            "Permission group name {group_name} is reserved"
+        ))));
+    }
+
+    if req.permissions.is_empty() {
+        return Err(report!(RoleError::InvalidRoleOperation(
+            "Permission group must contain at least one permission".to_string()
+        )));
+    }
+
+    let (permissions, unknown_permissions): (BTreeSet<_>, Vec<_>) = req
+        .permissions
+        .into_iter()
+        .map(|permission| get_permission_from_name(&permission).ok_or(permission))
+        .partition(Result::is_ok);
+
+    if !unknown_permissions.is_empty() {
+        return Err(report!(RoleError::InvalidRoleOperation(format!(
+            "Unknown permissions: {}",
+            unknown_permissions
+                .into_iter()
+                .filter_map(Result::err)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))));
+    }
+
+    let permissions = permissions
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect::<Vec<_>>();
+
+    if permissions
+        .iter()
+        .any(|permission| permission.resource() == Resource::SupportAdmin)
+    {
+        return Err(report!(RoleError::InvalidRoleOperation(
+            "Support administration permissions cannot be added to permission groups".to_string()
+        )));
+    }
+
+    let user_role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()
+        .attach_printable("Invalid role_id in JWT")?;
+    validate_permissions_held_by_user(&user_role_info, &permissions)?;
+
+    let permission_group = state
+        .global_store
+        .insert_custom_permission_group(CustomPermissionGroupNew {
+            group_name,
+            description: req.description,
+            permissions: permissions.iter().map(ToString::to_string).collect(),
+            merchant_id: user_from_token.merchant_id.clone(),
+            org_id: user_from_token.org_id.clone(),
+            tenant_id: user_from_token
+                .tenant_id
+                .clone()
+                .unwrap_or(state.tenant.tenant_id.clone()),
+            created_by: user_from_token.user_id.clone(),
+            created_at: common_utils::date_time::now(),
+        })
+        .await
+        .to_duplicate_response(RoleError::DuplicatePermissionGroupName)?;
+
+    Ok(ApplicationResponse::Json(
+        utils::user_role::get_custom_permission_group_response(permission_group),
+    ))
+}
+
+pub async fn list_permission_groups(
+    state: SessionState,
+    user_from_token: UserFromToken,
+) -> RoleResponse<Vec<role_api::CustomPermissionGroupResponse>> {
+    let permission_groups = state
+        .global_store
+        .list_custom_permission_groups_for_merchant(
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+            &user_from_token.org_id,
+            &user_from_token.merchant_id,
+        )
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to list custom permission groups")?;
+
+    Ok(ApplicationResponse::Json(
+        permission_groups
+            .into_iter()
+            .map(utils::user_role::get_custom_permission_group_response)
+            .collect(),
+    ))
+}
+
+pub async fn import_rbac(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    on_conflict: user_role_api::RbacImportConflictStrategy,
+    document: user_role_api::RbacExport,
+) -> RoleResponse<user_role_api::ImportRbacResponse> {
+    if document.version != consts::user_role::RBAC_EXPORT_VERSION {
+        return Err(report!(RoleError::InvalidRoleOperation(format!(
+            "Unsupported RBAC export version {}, expected {}",
+            document.version,
+            consts::user_role::RBAC_EXPORT_VERSION
+        ))));
+    }
+
+    let tenant_id = user_from_token
+        .tenant_id
+        .clone()
+        .unwrap_or(state.tenant.tenant_id.clone());
+    let user_role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()
+        .attach_printable("Invalid role_id in JWT")?;
+
+    let mut taken_role_names = state
+        .global_store
+        .generic_list_roles_by_entity_type(
+            ListRolesByEntityPayload::Merchant(user_from_token.merchant_id.clone()),
+            false,
+            tenant_id.clone(),
+            user_from_token.org_id.clone(),
+        )
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to list roles of merchant")?
+        .into_iter()
+        .map(|role| role.role_name)
+        .chain(
+            PREDEFINED_ROLES
+                .values()
+                .map(|role_info| role_info.get_role_name().to_string()),
+        )
+        .collect::<HashSet<_>>();
+
+    let mut response = user_role_api::ImportRbacResponse::default();
+    let mut role_id_mapping = HashMap::new();
+
+    for role in document.roles {
+        match import_role(
+            &state,
+            &user_from_token,
+            &user_role_info,
+            &mut taken_role_names,
+            on_conflict,
+            &role,
+        )
+        .await
+        {
+            Ok(imported_role) => {
+                role_id_mapping.insert(role.role_id, imported_role.role_id.clone());
+                response.created_roles.push(imported_role);
+            }
+            Err(error) if matches!(error.current_context(), RoleError::InternalServerError) => {
+                return Err(error);
+            }
+            Err(error) => response.skipped_roles.push(user_role_api::SkippedRbacRole {
+                role_id: role.role_id,
+                role_name: role.role_name,
+                reason: error.current_context().get_error_message(),
+            }),
+        }
+    }
+
+    for assignment in document.assignments {
+        match import_assignment(
+            &state,
+            &user_from_token,
+            &user_role_info,
+            &role_id_mapping,
+            &assignment,
+        )
+        .await
+        {
+            Ok(()) => response.created_assignments_count += 1,
+            Err(error) if matches!(error.current_context(), RoleError::InternalServerError) => {
+                return Err(error);
+            }
+            Err(error) => response
+                .skipped_assignments
+                .push(user_role_api::SkippedRbacAssignment {
+                    email: assignment.email,
+                    role_id: assignment.role_id,
+                    reason: error.current_context().get_error_message(),
+                }),
+        }
+    }
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+async fn import_role(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+    user_role_info: &roles::RoleInfo,
+    taken_role_names: &mut HashSet<String>,
+    on_conflict: user_role_api::RbacImportConflictStrategy,
+    role: &user_role_api::RbacExportRole,
+) -> RoleResult<user_role_api::ImportedRbacRole> {
+    if role.entity_type != EntityType::Merchant || role.scope != RoleScope::Merchant {
+        return Err(report!(RoleError::InvalidRoleOperation(
+            "Only merchant level custom roles can be imported".to_string()
+        )));
+    }
+
+    let mut role_name = RoleName::new(role.role_name.clone())
+        .switch()?
+        .get_role_name();
+    if taken_role_names.contains(&role_name) {
+        role_name = match on_conflict {
+            user_role_api::RbacImportConflictStrategy::Skip => {
+                return Err(report!(RoleError::DuplicateRoleName));
+            }
+            user_role_api::RbacImportConflictStrategy::Rename => (1..)
+                .map(|suffix| format!("{role_name}_{suffix}"))
+                .find(|candidate| !taken_role_names.contains(candidate))
+                .map(|candidate| RoleName::new(candidate).switch())
+                .transpose()?
+                .ok_or(RoleError::InternalServerError)?
+                .get_role_name(),
+        };
+    }
+
+    let custom_permissions = role
+        .custom_permissions
+        .iter()
+        .map(|permission| {
+            get_permission_from_name(permission).ok_or_else(|| {
+                report!(RoleError::InvalidRoleOperation(format!(
+                    "Unknown permission {permission}"
+                )))
+            })
+        })
+        .collect::<RoleResult<Vec<_>>>()?;
+    if custom_permissions
+        .iter()
+        .any(|permission| permission.resource() == Resource::SupportAdmin)
+    {
+        return Err(report!(RoleError::InvalidRoleOperation(
+            "Support administration permissions cannot be imported".to_string()
+        )));
+    }
+
+    if !role.groups.is_empty() || custom_permissions.is_empty() {
+        utils::user_role::validate_role_groups(&role.groups).switch()?;
+    }
+    validate_groups_held_by_user(user_role_info, &role.groups)?;
+    validate_permissions_held_by_user(user_role_info, &custom_permissions)?;
+
+    let tenant_id = user_from_token
+        .tenant_id
+        .clone()
+        .unwrap_or(state.tenant.tenant_id.clone());
+    utils::user_role::validate_custom_roles_limit(state, &user_from_token.merchant_id, &tenant_id)
+        .await
+        .switch()?;
+
+    let now = common_utils::date_time::now();
+    let created_role = state
+        .global_store
+        .insert_role(RoleNew {
+            role_id: generate_id_with_default_len("role"),
+            role_name,
+            merchant_id: user_from_token.merchant_id.clone(),
+            org_id: user_from_token.org_id.clone(),
+            groups: role.groups.clone(),
+            scope: RoleScope::Merchant,
+            entity_type: EntityType::Merchant,
+            created_by: user_from_token.user_id.clone(),
+            last_modified_by: user_from_token.user_id.clone(),
+            created_at: now,
+            last_modified_at: now,
+            profile_id: None,
+            tenant_id,
+            custom_permissions: (!custom_permissions.is_empty())
+                .then(|| custom_permissions.iter().map(ToString::to_string).collect()),
+            template_id: None,
+        })
+        .await
+        .to_duplicate_response(RoleError::DuplicateRoleName)?;
+    taken_role_names.insert(created_role.role_name.clone());
+
+    super::insert_role_audit(
+        state,
+        user_from_token,
+        RoleAuditAction::CreateRole,
+        created_role.role_id.clone(),
+        Vec::new(),
+        created_role.groups.clone(),
+        Some(format!("Imported from role {}", role.role_id)),
+    )
+    .await;
+
+    Ok(user_role_api::ImportedRbacRole {
+        source_role_id: role.role_id.clone(),
+        role_id: created_role.role_id,
+        role_name: created_role.role_name,
+    })
+}
+
+async fn import_assignment(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+    user_role_info: &roles::RoleInfo,
+    role_id_mapping: &HashMap<String, String>,
+    assignment: &user_role_api::RbacExportAssignment,
+) -> RoleResult<()> {
+    // Profiles are not carried over by the export, so profile level assignments cannot be mapped
+    if assignment.entity_type != EntityType::Merchant {
+        return Err(report!(RoleError::InvalidRoleOperation(
+            "Only merchant level assignments can be imported".to_string()
+        )));
+    }
+
+    let role_id = match role_id_mapping.get(&assignment.role_id) {
+        Some(role_id) => role_id.clone(),
+        None if PREDEFINED_ROLES.contains_key(assignment.role_id.as_str()) => {
+            assignment.role_id.clone()
+        }
+        None => {
+            return Err(report!(RoleError::InvalidRoleOperation(
+                "Role of the assignment was not imported".to_string()
+            )));
+        }
+    };
+
+    let tenant_id = user_from_token
+        .tenant_id
+        .clone()
+        .unwrap_or(state.tenant.tenant_id.clone());
+    let role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+        state,
+        &role_id,
+        &user_from_token.org_id,
+        &tenant_id,
+    )
+    .await
+    .change_context(RoleError::InternalServerError)?;
+
+    if !role_info.is_invitable() || role_info.get_entity_type() != EntityType::Merchant {
+        return Err(report!(RoleError::InvalidRoleOperation(format!(
+            "Role {role_id} cannot be assigned at merchant level"
+        ))));
+    }
+    validate_groups_held_by_user(user_role_info, &role_info.get_permission_groups())?;
+
+    let user_email = domain::UserEmail::from_pii_email(assignment.email.clone()).switch()?;
+    let user: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_email(&user_email)
+        .await
+        .to_not_found_response(RoleError::InvalidRoleOperation(
+            "User does not exist".to_string(),
+        ))?
+        .into();
+
+    for version in [UserRoleVersion::V2, UserRoleVersion::V1] {
+        match state
+            .global_store
+            .find_user_role_by_user_id_and_lineage(
+                user.get_user_id(),
+                &tenant_id,
+                &user_from_token.org_id,
+                &user_from_token.merchant_id,
+                &user_from_token.profile_id,
+                version,
+            )
+            .await
+        {
+            Ok(_) => {
+                return Err(report!(RoleError::InvalidRoleOperation(
+                    "User already has a role in the merchant".to_string()
+                )));
+            }
+            Err(error) if error.current_context().is_db_not_found() => (),
+            Err(error) => {
+                return Err(error.change_context(RoleError::InternalServerError))
+                    .attach_printable("Failed to fetch user role");
+            }
+        }
+    }
+
+    // Imported assignments are only invitations, which the users have to accept in the target
+    // merchant, irrespective of their status in the export
+    let now = common_utils::date_time::now();
+    domain::NewUserRole {
+        user_id: user.get_user_id().to_string(),
+        role_id,
+        status: UserStatus::InvitationSent,
+        created_by: user_from_token.user_id.clone(),
+        last_modified_by: user_from_token.user_id.clone(),
+        created_at: now,
+        last_modified: now,
+        entity: domain::NoLevel,
+    }
+    .add_entity(domain::MerchantLevel {
+        tenant_id,
+        org_id: user_from_token.org_id.clone(),
+        merchant_id: user_from_token.merchant_id.clone(),
+    })
+    .insert_in_v2(state)
+    .await
+    .switch()?;
+
+    Ok(())
+}
+
+fn validate_role_template_author(user_role_info: &roles::RoleInfo) -> RoleResult<()> {
+    if user_role_info.get_entity_type() < EntityType::Organization {
+        return Err(report!(RoleError::PermissionEscalation(format!(
+            "{} level user cannot manage role templates",
+            user_role_info.get_entity_type()
+        ))));
+    }
+
+    Ok(())
+}
+
+pub async fn create_role_template(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: role_api::CreateRoleTemplateRequest,
+) -> RoleResponse<role_api::RoleTemplateResponse> {
+    let user_role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()
+        .attach_printable("Invalid role_id in JWT")?;
+    validate_role_template_author(&user_role_info)?;
+
+    let role_name = RoleName::new(req.role_name).switch()?;
+    utils::user_role::validate_role_groups(&req.groups).switch()?;
+    validate_groups_held_by_user(&user_role_info, &req.groups)?;
+
+    let now = common_utils::date_time::now();
+    let role_template = state
+        .global_store
+        .insert_role_template(RoleTemplateNew {
+            template_id: generate_id_with_default_len("role_template"),
+            role_name: role_name.get_role_name(),
+            groups: req.groups,
+            org_id: user_from_token.org_id.clone(),
+            tenant_id: user_from_token
+                .tenant_id
+                .clone()
+                .unwrap_or(state.tenant.tenant_id.clone()),
+            created_by: user_from_token.user_id.clone(),
+            created_at: now,
+            last_modified_by: user_from_token.user_id.clone(),
+            last_modified_at: now,
+        })
+        .await
+        .to_duplicate_response(RoleError::DuplicateRoleTemplateName)?;
+
+    super::insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::CreateRoleTemplate,
+        role_template.template_id.clone(),
+        Vec::new(),
+        role_template.groups.clone(),
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(
+        utils::user_role::get_role_template_response(role_template, None),
+    ))
+}
+
+pub async fn update_role_template(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: role_api::UpdateRoleTemplateRequest,
+    template_id: &str,
+) -> RoleResponse<role_api::RoleTemplateResponse> {
+    let user_role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()
+        .attach_printable("Invalid role_id in JWT")?;
+    validate_role_template_author(&user_role_info)?;
+
+    let role_name = req.role_name.map(RoleName::new).transpose().switch()?;
+
+    let role_template = state
+        .global_store
+        .find_role_template_by_template_id(
+            template_id,
+            &user_from_token.org_id,
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+        )
+        .await
+        .to_not_found_response(RoleError::RoleTemplateNotFound)?;
+
+    if let Some(ref groups) = req.groups {
+        utils::user_role::validate_role_groups(groups).switch()?;
+
+        let existing_groups = role_template.groups.iter().collect::<HashSet<_>>();
+        let groups_to_be_added = groups
+            .iter()
+            .filter(|group| !existing_groups.contains(group))
+            .copied()
+            .collect::<Vec<_>>();
+        validate_groups_held_by_user(&user_role_info, &groups_to_be_added)?;
+    }
+
+    let updated_role_template = state
+        .global_store
+        .update_role_template_by_template_id(
+            template_id,
+            RoleTemplateUpdate::UpdateDetails {
+                groups: req.groups,
+                role_name: role_name.map(RoleName::get_role_name),
+                last_modified_at: common_utils::date_time::now(),
+                last_modified_by: user_from_token.user_id.clone(),
+            },
+        )
+        .await
+        .to_duplicate_response(RoleError::DuplicateRoleTemplateName)?;
+
+    super::insert_role_audit(
+        &state,
+        &user_from_token,
+        RoleAuditAction::UpdateRoleTemplate,
+        template_id.to_string(),
+        role_template.groups,
+        updated_role_template.groups.clone(),
+        None,
+    )
+    .await;
+
+    let synced_roles = if req.sync_derived_roles {
+        Some(
+            sync_roles_derived_from_template(&state, &user_from_token, &updated_role_template)
+                .await?,
+        )
+    } else {
+        None
+    };
+
+    Ok(ApplicationResponse::Json(
+        utils::user_role::get_role_template_response(updated_role_template, synced_roles),
+    ))
+}
+
+/// Overwrites the groups of every live role created from the template with the groups of the
+/// template. Roles are synced one after the other so that a failure in one does not affect the
+/// others.
+async fn sync_roles_derived_from_template(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+    role_template: &RoleTemplate,
+) -> RoleResult<Vec<role_api::DerivedRoleSyncResponse>> {
+    let derived_roles = state
+        .global_store
+        .list_roles_by_template_id(
+            &role_template.template_id,
+            &role_template.org_id,
+            &role_template.tenant_id,
+        )
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to list roles derived from role template")?;
+
+    let mut responses = Vec::with_capacity(derived_roles.len());
+
+    for role in derived_roles {
+        let sync_result = async {
+            let updated_role = state
+                .global_store
+                .update_role_by_role_id(
+                    &role.role_id,
+                    RoleUpdate::UpdateDetails {
+                        groups: Some(role_template.groups.clone()),
+                        role_name: None,
+                        last_modified_at: common_utils::date_time::now(),
+                        last_modified_by: user_from_token.user_id.clone(),
+                    },
+                )
+                .await
+                .change_context(RoleError::InternalServerError)?;
+
+            blacklist::insert_role_in_blacklist(state, &role.role_id)
+                .await
+                .switch()?;
+
+            super::insert_role_audit(
+                state,
+                user_from_token,
+                RoleAuditAction::UpdateRole,
+                role.role_id.clone(),
+                role.groups.clone(),
+                updated_role.groups,
+                Some(format!(
+                    "Synced from role template {}",
+                    role_template.template_id
+                )),
+            )
+            .await;
+
+            Ok::<_, error_stack::Report<RoleError>>(())
+        }
+        .await;
+
+        let response = match sync_result {
+            Ok(()) => role_api::DerivedRoleSyncResponse {
+                role_id: role.role_id,
+                merchant_id: role.merchant_id,
+                is_synced: true,
+                error: None,
+            },
+            Err(error) => {
+                logger::error!(role_template_sync_error=?error);
+
+                role_api::DerivedRoleSyncResponse {
+                    role_id: role.role_id,
+                    merchant_id: role.merchant_id,
+                    is_synced: false,
+                    error: Some(error.current_context().get_error_message()),
+                }
+            }
+        };
+        responses.push(response);
+    }
+
+    Ok(responses)
+}
+
+pub async fn apply_role_template(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: role_api::ApplyRoleTemplateRequest,
+    template_id: &str,
+) -> RoleResponse<Vec<role_api::ApplyRoleTemplateResponse>> {
+    if req.merchant_ids.is_empty()
+        || req.merchant_ids.len() > consts::user_role::MAX_ROLE_TEMPLATE_APPLY_MERCHANTS
+    {
+        return Err(report!(RoleError::InvalidRoleOperation(format!(
+            "Role template can be applied to between 1 and {} merchants at once",
+            consts::user_role::MAX_ROLE_TEMPLATE_APPLY_MERCHANTS
+        ))));
+    }
+
+    let user_role_info = user_from_token
+        .get_role_info_from_db(&state)
+        .await
+        .switch()
+        .attach_printable("Invalid role_id in JWT")?;
+    validate_role_template_author(&user_role_info)?;
+
+    let role_template = state
+        .global_store
+        .find_role_template_by_template_id(
+            template_id,
+            &user_from_token.org_id,
+            user_from_token
+                .tenant_id
+                .as_ref()
+                .unwrap_or(&state.tenant.tenant_id),
+        )
+        .await
+        .to_not_found_response(RoleError::RoleTemplateNotFound)?;
+
+    // The template may have been authored by a user holding more groups than the current user
+    validate_groups_held_by_user(&user_role_info, &role_template.groups)?;
+
+    let mut seen_merchant_ids = HashSet::new();
+    let merchant_ids = req
+        .merchant_ids
+        .into_iter()
+        .filter(|merchant_id| seen_merchant_ids.insert(merchant_id.clone()))
+        .collect::<Vec<_>>();
+
+    let merchant_ids_in_org = state
+        .store
+        .list_multiple_merchant_accounts(&(&state).into(), merchant_ids.clone())
+        .await
+        .change_context(RoleError::InternalServerError)
+        .attach_printable("Failed to list merchant accounts")?
+        .into_iter()
+        .filter(|merchant_account| merchant_account.organization_id == user_from_token.org_id)
+        .map(|merchant_account| merchant_account.get_id().to_owned())
+        .collect::<HashSet<_>>();
+
+    let mut responses = Vec::with_capacity(merchant_ids.len());
+
+    // Merchants are processed one after the other so that a failure in one does not affect the
+    // others
+    for merchant_id in merchant_ids {
+        let apply_result = if merchant_ids_in_org.contains(&merchant_id) {
+            create_role_from_template(&state, &user_from_token, &role_template, &merchant_id).await
+        } else {
+            Err(report!(RoleError::InvalidRoleOperation(
+                "Merchant does not belong to the organization".to_string()
+            )))
+        };
+
+        let response = match apply_result {
+            Ok(role) => role_api::ApplyRoleTemplateResponse {
+                merchant_id,
+                is_applied: true,
+                role_id: Some(role.role_id),
+                error: None,
+            },
+            Err(error) => {
+                logger::error!(apply_role_template_error=?error);
+
+                role_api::ApplyRoleTemplateResponse {
+                    merchant_id,
+                    is_applied: false,
+                    role_id: None,
+                    error: Some(error.current_context().get_error_message()),
+                }
+            }
+        };
+        responses.push(response);
+    }
+
+    Ok(ApplicationResponse::Json(responses))
+}
+
+async fn create_role_from_template(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+    role_template: &RoleTemplate,
+    merchant_id: &id_type::MerchantId,
+) -> RoleResult<Role> {
+    let role_name = RoleName::new(role_template.role_name.clone()).switch()?;
+    utils::user_role::validate_role_name(
+        state,
+        &role_name,
+        merchant_id,
+        &role_template.org_id,
+        &role_template.tenant_id,
+        &user_from_token.profile_id,
+        &EntityType::Merchant,
+    )
+    .await
+    .switch()?;
+    utils::user_role::validate_custom_roles_limit(state, merchant_id, &role_template.tenant_id)
+        .await
+        .switch()?;
+
+    let now = common_utils::date_time::now();
+    let role = state
+        .global_store
+        .insert_role(RoleNew {
+            role_id: generate_id_with_default_len("role"),
+            role_name: role_name.get_role_name(),
+            merchant_id: merchant_id.clone(),
+            org_id: role_template.org_id.clone(),
+            groups: role_template.groups.clone(),
+            scope: RoleScope::Merchant,
+            entity_type: EntityType::Merchant,
+            created_by: user_from_token.user_id.clone(),
+            last_modified_by: user_from_token.user_id.clone(),
+            created_at: now,
+            last_modified_at: now,
+            profile_id: None,
+            tenant_id: role_template.tenant_id.clone(),
+            custom_permissions: None,
+            template_id: Some(role_template.template_id.clone()),
+        })
+        .await
+        .to_duplicate_response(RoleError::DuplicateRoleName)?;
+
+    super::insert_role_audit(
+        state,
+        user_from_token,
+        RoleAuditAction::CreateRole,
+        role.role_id.clone(),
+        Vec::new(),
+        role.groups.clone(),
+        Some(format!(
+            "Created from role template {} for merchant {}",
+            role_template.template_id,
+            merchant_id.get_string_repr()
+        )),
+    )
+    .await;
+
+    Ok(role)
+}
@@ -532,86 +532,161 @@ pub fn get_split_refunds(
             }
         }
         Some(common_types::payments::SplitPaymentsRequest::AdyenSplitPayment(_)) => {
+            get_adyen_split_refunds(&split_refund_input)
+        }
+        Some(common_types::payments::SplitPaymentsRequest::XenditSplitPayment(_)) => {
+            get_xendit_split_refunds(&split_refund_input)
+        }
+        // Generic split instructions are refunded as per the split payment of the connector which
+        // they were sent to, as recorded in the charges of the payment
+        Some(common_types::payments::SplitPaymentsRequest::GenericSplitPayment(_)) => {
             match &split_refund_input.payment_charges {
-                Some(common_types::payments::ConnectorChargeResponseData::AdyenSplitPayment(
+                Some(common_types::payments::ConnectorChargeResponseData::XenditSplitPayment(
+                    _,
+                )) => get_xendit_split_refunds(&split_refund_input),
+                _ => get_adyen_split_refunds(&split_refund_input),
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+#[cfg(feature = "v1")]
+fn get_adyen_split_refunds(
+    split_refund_input: &super::refunds::transformers::SplitRefundInput,
+) -> RouterResult<Option<router_request_types::SplitRefundsRequest>> {
+    match &split_refund_input.payment_charges {
+        Some(common_types::payments::ConnectorChargeResponseData::AdyenSplitPayment(
+            adyen_split_payment_response,
+        )) => {
+            if let Some(common_types::refunds::SplitRefund::AdyenSplitRefund(
+                split_refund_request,
+            )) = split_refund_input.refund_request.clone()
+            {
+                super::refunds::validator::validate_adyen_charge_refund(
                     adyen_split_payment_response,
-                )) => {
-                    if let Some(common_types::refunds::SplitRefund::AdyenSplitRefund(
+                    &split_refund_request,
+                )?;
+
+                Ok(Some(
+                    router_request_types::SplitRefundsRequest::AdyenSplitRefund(
                         split_refund_request,
-                    )) = split_refund_input.refund_request.clone()
-                    {
-                        super::refunds::validator::validate_adyen_charge_refund(
-                            adyen_split_payment_response,
-                            &split_refund_request,
-                        )?;
-
-                        Ok(Some(
-                            router_request_types::SplitRefundsRequest::AdyenSplitRefund(
-                                split_refund_request,
-                            ),
-                        ))
-                    } else {
-                        Ok(None)
-                    }
-                }
-                _ => Ok(None),
+                    ),
+                ))
+            } else {
+                Ok(None)
             }
         }
-        Some(common_types::payments::SplitPaymentsRequest::XenditSplitPayment(_)) => {
-            match (
-                &split_refund_input.payment_charges,
-                &split_refund_input.refund_request,
-            ) {
-                (
-                    Some(common_types::payments::ConnectorChargeResponseData::XenditSplitPayment(
-                        xendit_split_payment_response,
-                    )),
-                    Some(common_types::refunds::SplitRefund::XenditSplitRefund(
-                        split_refund_request,
-                    )),
-                ) => {
-                    let user_id = super::refunds::validator::validate_xendit_charge_refund(
-                        xendit_split_payment_response,
-                        split_refund_request,
-                    )?;
+        _ => Ok(None),
+    }
+}
 
-                    Ok(user_id.map(|for_user_id| {
-                        router_request_types::SplitRefundsRequest::XenditSplitRefund(
-                            common_types::domain::XenditSplitSubMerchantData { for_user_id },
-                        )
-                    }))
-                }
-                (
-                    Some(common_types::payments::ConnectorChargeResponseData::XenditSplitPayment(
-                        xendit_split_payment_response,
-                    )),
-                    None,
-                ) => {
-                    let option_for_user_id = match xendit_split_payment_response {
-                        common_types::payments::XenditChargeResponseData::MultipleSplits(
-                            common_types::payments::XenditMultipleSplitResponse {
-                                for_user_id, ..
-                            },
-                        ) => for_user_id.clone(),
-                        common_types::payments::XenditChargeResponseData::SingleSplit(
-                            common_types::domain::XenditSplitSubMerchantData { for_user_id },
-                        ) => Some(for_user_id.clone()),
-                    };
-
-                    if option_for_user_id.is_some() {
-                        Err(errors::ApiErrorResponse::MissingRequiredField {
-                            field_name: "split_refunds.xendit_split_refund.for_user_id",
-                        })?
-                    } else {
-                        Ok(None)
-                    }
-                }
-                _ => Ok(None),
+#[cfg(feature = "v1")]
+fn get_xendit_split_refunds(
+    split_refund_input: &super::refunds::transformers::SplitRefundInput,
+) -> RouterResult<Option<router_request_types::SplitRefundsRequest>> {
+    match (
+        &split_refund_input.payment_charges,
+        &split_refund_input.refund_request,
+    ) {
+        (
+            Some(common_types::payments::ConnectorChargeResponseData::XenditSplitPayment(
+                xendit_split_payment_response,
+            )),
+            Some(common_types::refunds::SplitRefund::XenditSplitRefund(split_refund_request)),
+        ) => {
+            let user_id = super::refunds::validator::validate_xendit_charge_refund(
+                xendit_split_payment_response,
+                split_refund_request,
+            )?;
+
+            Ok(user_id.map(|for_user_id| {
+                router_request_types::SplitRefundsRequest::XenditSplitRefund(
+                    common_types::domain::XenditSplitSubMerchantData { for_user_id },
+                )
+            }))
+        }
+        (
+            Some(common_types::payments::ConnectorChargeResponseData::XenditSplitPayment(
+                xendit_split_payment_response,
+            )),
+            None,
+        ) => {
+            let option_for_user_id = match xendit_split_payment_response {
+                common_types::payments::XenditChargeResponseData::MultipleSplits(
+                    common_types::payments::XenditMultipleSplitResponse { for_user_id, .. },
+                ) => for_user_id.clone(),
+                common_types::payments::XenditChargeResponseData::SingleSplit(
+                    common_types::domain::XenditSplitSubMerchantData { for_user_id },
+                ) => Some(for_user_id.clone()),
+            };
+
+            if option_for_user_id.is_some() {
+                Err(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "split_refunds.xendit_split_refund.for_user_id",
+                })?
+            } else {
+                Ok(None)
             }
         }
         _ => Ok(None),
     }
 }
+
+/// Converts the generic split instructions of a payment into the split payment request of the
+/// connector, for the connectors which support distributing a payment across multiple recipients
+pub fn get_connector_split_payments(
+    connector_name: &str,
+    split_payments: Option<common_types::payments::SplitPaymentsRequest>,
+    currency: enums::Currency,
+) -> Option<common_types::payments::SplitPaymentsRequest> {
+    match (
+        api_models::enums::Connector::from_str(connector_name),
+        split_payments,
+    ) {
+        (
+            Ok(api_models::enums::Connector::Adyen),
+            Some(common_types::payments::SplitPaymentsRequest::GenericSplitPayment(
+                generic_split_payment,
+            )),
+        ) => Some(
+            common_types::payments::SplitPaymentsRequest::AdyenSplitPayment(
+                generic_split_payment.into(),
+            ),
+        ),
+        (
+            Ok(api_models::enums::Connector::Xendit),
+            Some(common_types::payments::SplitPaymentsRequest::GenericSplitPayment(
+                generic_split_payment,
+            )),
+        ) => Some(
+            common_types::payments::SplitPaymentsRequest::XenditSplitPayment(
+                common_types::payments::XenditSplitRequest::MultipleSplits(
+                    (generic_split_payment, currency).into(),
+                ),
+            ),
+        ),
+        (_, split_payments) => split_payments,
+    }
+}
+
+/// Generic split instructions which the connector did not act upon are recorded on the payment
+/// attempt, so that the split can be settled during reconciliation
+pub fn get_recorded_split_payments(
+    split_payments: Option<&common_types::payments::SplitPaymentsRequest>,
+) -> Option<common_types::payments::ConnectorChargeResponseData> {
+    match split_payments {
+        Some(common_types::payments::SplitPaymentsRequest::GenericSplitPayment(
+            generic_split_payment,
+        )) => Some(
+            common_types::payments::ConnectorChargeResponseData::GenericSplitPayment(
+                generic_split_payment.clone(),
+            ),
+        ),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
@@ -713,6 +788,82 @@ mod tests {
         ];
         assert_eq!(filtered_list, expected_result);
     }
+
+    fn get_generic_split_payment() -> common_types::payments::SplitPaymentsRequest {
+        common_types::payments::SplitPaymentsRequest::GenericSplitPayment(
+            common_types::payments::GenericSplitPaymentRequest {
+                split_items: vec![common_types::payments::GenericSplitItem {
+                    recipient_id: "recipient_1".to_string(),
+                    amount: common_utils::types::MinorUnit::new(1000),
+                    reference: "split_1".to_string(),
+                    description: None,
+                }],
+            },
+        )
+    }
+
+    #[test]
+    fn test_generic_split_payment_is_converted_for_adyen() {
+        let split_payments = get_connector_split_payments(
+            "adyen",
+            Some(get_generic_split_payment()),
+            enums::Currency::USD,
+        );
+
+        let expected_split_payments =
+            common_types::payments::SplitPaymentsRequest::AdyenSplitPayment(
+                common_types::domain::AdyenSplitData {
+                    store: None,
+                    split_items: vec![common_types::domain::AdyenSplitItem {
+                        amount: Some(common_utils::types::MinorUnit::new(1000)),
+                        split_type: enums::AdyenSplitType::BalanceAccount,
+                        account: Some("recipient_1".to_string()),
+                        reference: "split_1".to_string(),
+                        description: None,
+                    }],
+                },
+            );
+        assert_eq!(split_payments, Some(expected_split_payments));
+    }
+
+    #[test]
+    fn test_generic_split_payment_is_converted_for_xendit() {
+        let split_payments = get_connector_split_payments(
+            "xendit",
+            Some(get_generic_split_payment()),
+            enums::Currency::IDR,
+        );
+
+        let expected_split_payments =
+            common_types::payments::SplitPaymentsRequest::XenditSplitPayment(
+                common_types::payments::XenditSplitRequest::MultipleSplits(
+                    common_types::payments::XenditMultipleSplitRequest {
+                        name: "generic_split_payment".to_string(),
+                        description: "Split of the payment across multiple recipients".to_string(),
+                        for_user_id: None,
+                        routes: vec![common_types::payments::XenditSplitRoute {
+                            flat_amount: Some(common_utils::types::MinorUnit::new(1000)),
+                            percent_amount: None,
+                            currency: enums::Currency::IDR,
+                            destination_account_id: "recipient_1".to_string(),
+                            reference_id: "split_1".to_string(),
+                        }],
+                    },
+                ),
+            );
+        assert_eq!(split_payments, Some(expected_split_payments));
+    }
+
+    #[test]
+    fn test_generic_split_payment_is_retained_for_other_connectors() {
+        let split_payments = get_connector_split_payments(
+            "stripe",
+            Some(get_generic_split_payment()),
+            enums::Currency::USD,
+        );
+
+        assert_eq!(split_payments, Some(get_generic_split_payment()));
+    }
 }
 
 // Dispute Stage can move linearly from PreDispute -> Dispute -> PreArbitration
@@ -13,6 +13,7 @@ pub mod webhook_events;
 
 #[cfg(feature = "v2")]
 pub(crate) use self::incoming_v2::incoming_webhooks_wrapper;
+pub use self::types::{verify_outgoing_webhook_signature, OutgoingWebhookSignatureScheme};
 #[cfg(feature = "v1")]
 pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
@@ -51,7 +51,10 @@ use crate::{
     utils::{self as helper_utils, ext_traits::OptionExt, generate_id},
 };
 #[cfg(feature = "payouts")]
-use crate::{core::payouts, types::storage::PayoutAttemptUpdate};
+use crate::{
+    core::payouts,
+    types::storage::{PayoutAttemptUpdate, PayoutsUpdate},
+};
 
 #[allow(clippy::too_many_arguments)]
 pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
@@ -754,7 +757,7 @@ async fn payments_incoming_webhook_flow(
 async fn payouts_incoming_webhook_flow(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
-    business_profile: domain::Profile,
+    _business_profile: domain::Profile,
     key_store: domain::MerchantKeyStore,
     webhook_details: api::IncomingWebhookDetails,
     event_type: webhooks::IncomingWebhookEvent,
@@ -789,91 +792,102 @@ async fn payouts_incoming_webhook_flow(
                 .attach_printable("received a non-payout id when processing payout webhooks")?,
         };
 
-        let payouts = db
-            .find_payout_by_merchant_id_payout_id(
-                merchant_account.get_id(),
-                &payout_attempt.payout_id,
-                merchant_account.storage_scheme,
+        let status = common_enums::PayoutStatus::foreign_try_from(event_type)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("failed payout status mapping from event type")?;
+
+        // Another webhook of the payout could be updating the payout at the same time
+        let lock_action = payouts::get_payout_status_lock_action(&payout_attempt.payout_id);
+        lock_action
+            .clone()
+            .perform_locking_action(&state, merchant_account.get_id().clone())
+            .await?;
+
+        let update_result = async {
+            let action_req = payout_models::PayoutRequest::PayoutActionRequest(
+                payout_models::PayoutActionRequest {
+                    payout_id: payout_attempt.payout_id.clone(),
+                },
+            );
+
+            let mut payout_data = payouts::make_payout_data(
+                &state,
+                &merchant_account,
+                None,
+                &key_store,
+                &action_req,
+                common_utils::consts::DEFAULT_LOCALE,
             )
-            .await
-            .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
-            .attach_printable("Failed to fetch the payout")?;
+            .await?;
 
-        let payout_attempt_update = PayoutAttemptUpdate::StatusUpdate {
-            connector_payout_id: payout_attempt.connector_payout_id.clone(),
-            status: common_enums::PayoutStatus::foreign_try_from(event_type)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("failed payout status mapping from event type")?,
-            error_message: None,
-            error_code: None,
-            is_eligible: payout_attempt.is_eligible,
-            unified_code: None,
-            unified_message: None,
-        };
+            let current_status = payout_data.payout_attempt.status;
+            if !payouts::helpers::is_valid_payout_status_transition(current_status, status) {
+                logger::info!(
+                    "Ignoring payout status update from {current_status} to {status} received through webhook"
+                );
+                return Ok((payout_data, false));
+            }
 
-        let action_req =
-            payout_models::PayoutRequest::PayoutActionRequest(payout_models::PayoutActionRequest {
-                payout_id: payouts.payout_id.clone(),
-            });
+            let payout_attempt_update = PayoutAttemptUpdate::StatusUpdate {
+                connector_payout_id: payout_data.payout_attempt.connector_payout_id.clone(),
+                status,
+                error_message: None,
+                error_code: None,
+                is_eligible: payout_data.payout_attempt.is_eligible,
+                unified_code: None,
+                unified_message: None,
+            };
 
-        let payout_data = payouts::make_payout_data(
-            &state,
-            &merchant_account,
-            None,
-            &key_store,
-            &action_req,
-            common_utils::consts::DEFAULT_LOCALE,
-        )
-        .await?;
+            payout_data.payout_attempt = db
+                .update_payout_attempt(
+                    &payout_data.payout_attempt,
+                    payout_attempt_update,
+                    &payout_data.payouts,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while updating payout attempt: payout_attempt_id: {}",
+                        payout_attempt.payout_attempt_id
+                    )
+                })?;
 
-        let updated_payout_attempt = db
-            .update_payout_attempt(
-                &payout_attempt,
-                payout_attempt_update,
-                &payout_data.payouts,
-                merchant_account.storage_scheme,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
-            .attach_printable_lazy(|| {
-                format!(
-                    "Failed while updating payout attempt: payout_attempt_id: {}",
-                    payout_attempt.payout_attempt_id
+            payout_data.payouts = db
+                .update_payout(
+                    &payout_data.payouts,
+                    PayoutsUpdate::StatusUpdate { status },
+                    &payout_data.payout_attempt,
+                    merchant_account.storage_scheme,
                 )
-            })?;
+                .await
+                .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .attach_printable("Failed while updating payout")?;
 
-        let event_type: Option<enums::EventType> = updated_payout_attempt.status.foreign_into();
+            Ok::<_, error_stack::Report<errors::ApiErrorResponse>>((payout_data, true))
+        }
+        .await;
 
-        // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
-        if let Some(outgoing_event_type) = event_type {
-            let router_response =
-                payouts::response_handler(&state, &merchant_account, &payout_data).await?;
+        lock_action
+            .free_lock_action(&state, merchant_account.get_id().clone())
+            .await?;
 
-            let payout_create_response: payout_models::PayoutCreateResponse = match router_response
-            {
-                services::ApplicationResponse::Json(response) => response,
-                _ => Err(errors::ApiErrorResponse::WebhookResourceNotFound)
-                    .attach_printable("Failed to fetch the payout create response")?,
-            };
+        let (payout_data, is_status_updated) = update_result?;
 
-            Box::pin(super::create_event_and_trigger_outgoing_webhook(
-                state,
-                merchant_account,
-                business_profile,
+        if is_status_updated {
+            payouts::trigger_payout_status_webhook(
+                &state,
+                &merchant_account,
                 &key_store,
-                outgoing_event_type,
-                enums::EventClass::Payouts,
-                updated_payout_attempt.payout_id.clone(),
-                enums::EventObjectType::PayoutDetails,
-                api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_create_response)),
-                Some(updated_payout_attempt.created_at),
-            ))
+                &payout_data,
+            )
             .await?;
         }
 
         Ok(WebhookResponseTracker::Payout {
-            payout_id: updated_payout_attempt.payout_id,
-            status: updated_payout_attempt.status,
+            payout_id: payout_data.payout_attempt.payout_id,
+            status: payout_data.payout_attempt.status,
         })
     } else {
         metrics::INCOMING_PAYOUT_WEBHOOK_SIGNATURE_FAILURE_METRIC.add(1, &[]);
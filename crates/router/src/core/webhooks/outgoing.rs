@@ -79,6 +79,21 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         return Ok(());
     }
 
+    if !utils::is_merchant_subscribed_to_event(
+        state.store.as_ref(),
+        &business_profile.merchant_id,
+        event_type,
+    )
+    .await
+    {
+        logger::debug!(
+            merchant_id=?business_profile.merchant_id,
+            %idempotent_event_id,
+            "Merchant is not subscribed to the event type; skipping outgoing webhooks for event"
+        );
+        return Ok(());
+    }
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -917,6 +932,12 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::UserRoleDetails(user_role_response) => {
+                Self::UserRole {
+                    user_id: user_role_response.user_id.clone(),
+                    content: serde_json::to_value(user_role_response).unwrap_or_default(),
+                }
+            }
         }
     }
 }
@@ -961,5 +982,8 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::UserRole { user_id, content } => {
+            OutgoingWebhookEventContent::UserRole { user_id, content }
+        }
     })
 }
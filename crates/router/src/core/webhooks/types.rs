@@ -1,6 +1,9 @@
 use api_models::webhooks;
-use common_utils::{crypto::SignMessage, ext_traits::Encode};
-use error_stack::ResultExt;
+use common_utils::{
+    crypto::{SignMessage, VerifySignature},
+    ext_traits::Encode,
+};
+use error_stack::{report, ResultExt};
 use masking::Secret;
 use serde::Serialize;
 
@@ -56,6 +59,109 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     }
 }
 
+/// Schemes used for signing outgoing webhooks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutgoingWebhookSignatureScheme {
+    /// Hex encoded HMAC-SHA512 of the body, sent in the `X-Webhook-Signature-512` header
+    HmacSha512,
+    /// Hex encoded HMAC-SHA256 of `<timestamp>.<body>`, sent as `t=<timestamp>,v1=<signature>` in
+    /// the `Stripe-Signature` header to merchants using the Stripe compatibility layer
+    StripeCompatible,
+}
+
+/// Verifies the signature of an outgoing webhook as received by the merchant, using the
+/// `payment_response_hash_key` of the profile the webhook was sent for. Signatures are compared
+/// in constant time, and the scheme the webhook was signed with is returned on success.
+pub fn verify_outgoing_webhook_signature(
+    body: &[u8],
+    request_headers: &http::HeaderMap,
+    payment_response_hash_key: impl AsRef<[u8]>,
+) -> errors::CustomResult<OutgoingWebhookSignatureScheme, errors::OutgoingWebhookSignatureError> {
+    let get_header = move |name: &str| {
+        request_headers
+            .get(name)
+            .map(|value| {
+                value
+                    .to_str()
+                    .change_context(errors::OutgoingWebhookSignatureError::MalformedSignature)
+            })
+            .transpose()
+    };
+
+    let (scheme, is_verified) = if let Some(signature) = get_header(headers::X_WEBHOOK_SIGNATURE)? {
+        let is_verified =
+            verify_hmac_sha512_signature(signature, body, payment_response_hash_key.as_ref())?;
+        (OutgoingWebhookSignatureScheme::HmacSha512, is_verified)
+    } else if let Some(signature) = get_header(headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE)? {
+        let is_verified = verify_stripe_compatible_signature(
+            signature,
+            body,
+            payment_response_hash_key.as_ref(),
+        )?;
+        (
+            OutgoingWebhookSignatureScheme::StripeCompatible,
+            is_verified,
+        )
+    } else {
+        return Err(report!(
+            errors::OutgoingWebhookSignatureError::MissingSignature
+        ));
+    };
+
+    if is_verified {
+        Ok(scheme)
+    } else {
+        Err(report!(
+            errors::OutgoingWebhookSignatureError::SignatureMismatch
+        ))
+    }
+}
+
+fn verify_hmac_sha512_signature(
+    signature: &str,
+    body: &[u8],
+    key: &[u8],
+) -> errors::CustomResult<bool, errors::OutgoingWebhookSignatureError> {
+    let signature = hex::decode(signature)
+        .change_context(errors::OutgoingWebhookSignatureError::MalformedSignature)?;
+
+    common_utils::crypto::HmacSha512
+        .verify_signature(key, &signature, body)
+        .change_context(errors::OutgoingWebhookSignatureError::SignatureMismatch)
+}
+
+fn verify_stripe_compatible_signature(
+    signature: &str,
+    body: &[u8],
+    key: &[u8],
+) -> errors::CustomResult<bool, errors::OutgoingWebhookSignatureError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for (name, value) in signature.split(',').filter_map(|part| part.split_once('=')) {
+        match name.trim() {
+            "t" => timestamp = Some(value.trim()),
+            "v1" => signatures.push(
+                hex::decode(value.trim())
+                    .change_context(errors::OutgoingWebhookSignatureError::MalformedSignature)?,
+            ),
+            _ => (),
+        }
+    }
+
+    let timestamp = timestamp.ok_or(errors::OutgoingWebhookSignatureError::MalformedSignature)?;
+    let signed_payload = [timestamp.as_bytes(), b".", body].concat();
+
+    // Every signature is checked, so that the time taken does not depend on which one matched
+    let mut is_verified = false;
+    for signature in signatures {
+        is_verified |= common_utils::crypto::HmacSha256
+            .verify_signature(key, &signature, &signed_payload)
+            .change_context(errors::OutgoingWebhookSignatureError::SignatureMismatch)?;
+    }
+
+    Ok(is_verified)
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) merchant_id: common_utils::id_type::MerchantId,
@@ -66,3 +172,84 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::SignMessage;
+
+    use super::*;
+
+    const KEY: &[u8] = b"payment_response_hash_key";
+    const BODY: &[u8] = br#"{"merchant_id":"merchant_1","event_id":"evt_1"}"#;
+
+    fn headers_with(name: &'static str, value: String) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(name, value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_verify_hmac_sha512_signature() {
+        let signature = hex::encode(
+            common_utils::crypto::HmacSha512
+                .sign_message(KEY, BODY)
+                .unwrap(),
+        );
+        let headers = headers_with(headers::X_WEBHOOK_SIGNATURE, signature);
+
+        assert_eq!(
+            verify_outgoing_webhook_signature(BODY, &headers, KEY).unwrap(),
+            OutgoingWebhookSignatureScheme::HmacSha512
+        );
+        assert_eq!(
+            verify_outgoing_webhook_signature(b"{}", &headers, KEY)
+                .unwrap_err()
+                .current_context(),
+            &errors::OutgoingWebhookSignatureError::SignatureMismatch
+        );
+    }
+
+    #[test]
+    fn test_verify_stripe_compatible_signature() {
+        let signed_payload = [b"1700000000.".as_slice(), BODY].concat();
+        let signature = hex::encode(
+            common_utils::crypto::HmacSha256
+                .sign_message(KEY, &signed_payload)
+                .unwrap(),
+        );
+        let headers = headers_with(
+            headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE,
+            format!("t=1700000000,v1={signature}"),
+        );
+
+        assert_eq!(
+            verify_outgoing_webhook_signature(BODY, &headers, KEY).unwrap(),
+            OutgoingWebhookSignatureScheme::StripeCompatible
+        );
+        assert_eq!(
+            verify_outgoing_webhook_signature(BODY, &headers, b"other_key")
+                .unwrap_err()
+                .current_context(),
+            &errors::OutgoingWebhookSignatureError::SignatureMismatch
+        );
+    }
+
+    #[test]
+    fn test_verify_missing_or_malformed_signature() {
+        assert_eq!(
+            verify_outgoing_webhook_signature(BODY, &http::HeaderMap::new(), KEY)
+                .unwrap_err()
+                .current_context(),
+            &errors::OutgoingWebhookSignatureError::MissingSignature
+        );
+
+        let headers = headers_with(headers::X_WEBHOOK_SIGNATURE, "not_hex".to_string());
+        assert_eq!(
+            verify_outgoing_webhook_signature(BODY, &headers, KEY)
+                .unwrap_err()
+                .current_context(),
+            &errors::OutgoingWebhookSignatureError::MalformedSignature
+        );
+    }
+}
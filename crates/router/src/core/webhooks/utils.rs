@@ -1,6 +1,9 @@
 use std::marker::PhantomData;
 
-use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{StringExt, ValueExt},
+};
 use error_stack::ResultExt;
 
 use crate::{
@@ -57,6 +60,46 @@ pub async fn is_webhook_event_disabled(
     }
 }
 
+/// Fetch the outgoing webhook event types the merchant is subscribed to from the configs table.
+/// Merchants who have never updated their subscription are subscribed to all event types.
+pub async fn get_webhook_event_subscription(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> CustomResult<api::webhook_events::WebhookEventSubscription, errors::StorageError> {
+    match db
+        .find_config_by_key(&merchant_id.get_webhook_event_subscription_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("WebhookEventSubscription")
+            .change_context(errors::StorageError::DeserializationFailed),
+        Err(err) if err.current_context().is_db_not_found() => {
+            Ok(api::webhook_events::WebhookEventSubscription::default())
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Check whether outgoing webhooks for the `event_type` should be delivered to the merchant.
+/// Webhooks are delivered if the subscription of the merchant could not be fetched.
+pub async fn is_merchant_subscribed_to_event(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    event_type: types::storage::enums::EventType,
+) -> bool {
+    get_webhook_event_subscription(db, merchant_id)
+        .await
+        .map(|subscription| subscription.is_subscribed_to(event_type))
+        .unwrap_or_else(|err| {
+            logger::warn!(
+                ?err,
+                "error while fetching merchant webhook event subscription"
+            );
+            true
+        })
+}
+
 pub async fn construct_webhook_router_data(
     state: &SessionState,
     connector_name: &str,
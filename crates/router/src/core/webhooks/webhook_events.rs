@@ -1,13 +1,16 @@
+use std::collections::HashMap;
+
+use common_utils::ext_traits::Encode;
 use error_stack::ResultExt;
 use masking::PeekInterface;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     routes::SessionState,
     services::ApplicationResponse,
     types::{api, domain, storage, transformers::ForeignTryFrom},
-    utils::{OptionExt, StringExt},
+    utils::{self, OptionExt, StringExt},
 };
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
@@ -182,6 +185,7 @@ pub async fn retry_delivery_attempt(
 
     let business_profile_id = event_to_retry
         .business_profile_id
+        .clone()
         .get_required_value("business_profile_id")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to read business profile ID from event to retry")?;
@@ -191,6 +195,373 @@ pub async fn retry_delivery_attempt(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to find business profile")?;
 
+    let new_event_id =
+        redeliver_event(&state, business_profile, &key_store, event_to_retry).await?;
+
+    let updated_event = store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            &new_event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+    ))
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn replay_webhooks(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: api::webhook_events::WebhookReplayRequest,
+) -> RouterResponse<api::webhook_events::WebhookReplayResponse> {
+    let api::webhook_events::WebhookReplayRequest {
+        created_after,
+        created_before,
+        profile_id,
+        force,
+    } = request;
+
+    // Events created later are delivered as usual, limiting the time range to the current time
+    // keeps the events being replayed the same across the batches of the replay
+    let now = common_utils::date_time::now();
+    let created_before = created_before.min(now);
+
+    utils::when(created_after >= created_before, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`created_after` must be earlier than `created_before` and the current time"
+                .to_string(),
+        })
+    })?;
+
+    // Ensure that the merchant account and the business profile, if specified, exist
+    get_account_and_key_store(state.clone(), merchant_id.clone(), profile_id.clone()).await?;
+
+    let store = state.store.as_ref();
+    let runner = storage::ProcessTrackerRunner::WebhookReplayWorkflow;
+    let task = "WEBHOOK_REPLAY";
+    let tag = ["WEBHOOK", "REPLAY"];
+    let process_tracker_id = format!("{runner}_{task}_{}", merchant_id.get_string_repr());
+    let tracking_data = storage::WebhookReplayTrackingData {
+        merchant_id: merchant_id.clone(),
+        profile_id: profile_id.clone(),
+        created_after,
+        created_before,
+        force,
+        offset: 0,
+        replayed_events: 0,
+        skipped_events: 0,
+        failed_events: 0,
+    };
+
+    // A single replay is run at a time for a merchant, for which the task of the previous replay
+    // is reused once it is finished. Concurrent requests are serialized by the API lock.
+    let existing_process = store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find webhook replay task")?;
+
+    match existing_process {
+        Some(process) if process.status != storage::enums::ProcessTrackerStatus::Finish => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A webhook replay is already in progress for the merchant".to_string(),
+            })?
+        }
+        Some(process) => {
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize webhook replay tracking data")?;
+            store
+                .as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(now),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(storage::business_status::PENDING)),
+                        status: Some(storage::enums::ProcessTrackerStatus::New),
+                        updated_at: Some(now),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule webhook replay task")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                task,
+                runner,
+                tag,
+                tracking_data,
+                now,
+                hyperswitch_domain_models::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct webhook replay task")?;
+            store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to schedule webhook replay task")?;
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookReplayResponse {
+            merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+            force,
+        },
+    ))
+}
+
+/// Replays the next batch of the events of a scheduled webhook replay, and advances the tracking
+/// data past the batch. Returns the number of events found in the batch.
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn replay_webhooks_batch(
+    state: &SessionState,
+    tracking_data: &mut storage::WebhookReplayTrackingData,
+    batch_size: i64,
+) -> errors::RouterResult<usize> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let (account, key_store) = get_account_and_key_store(
+        state.clone(),
+        tracking_data.merchant_id.clone(),
+        tracking_data.profile_id.clone(),
+    )
+    .await?;
+
+    // The events within the time range do not change across batches, as the time range is in the
+    // past and the replayed deliveries are not initial events themselves
+    let mut business_profiles = HashMap::new();
+    let events = match account {
+        MerchantAccountOrProfile::MerchantAccount(merchant_account) => {
+            store
+                .list_initial_events_by_merchant_id_constraints(
+                    key_manager_state,
+                    merchant_account.get_id(),
+                    Some(tracking_data.created_after),
+                    Some(tracking_data.created_before),
+                    Some(batch_size),
+                    Some(tracking_data.offset),
+                    &key_store,
+                )
+                .await
+        }
+        MerchantAccountOrProfile::Profile(business_profile) => {
+            let events = store
+                .list_initial_events_by_profile_id_constraints(
+                    key_manager_state,
+                    business_profile.get_id(),
+                    Some(tracking_data.created_after),
+                    Some(tracking_data.created_before),
+                    Some(batch_size),
+                    Some(tracking_data.offset),
+                    &key_store,
+                )
+                .await;
+            business_profiles.insert(business_profile.get_id().to_owned(), *business_profile);
+            events
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to list events to replay")?;
+
+    let listed_events = events.len();
+
+    for event in events {
+        let initial_attempt_id = event
+            .initial_attempt_id
+            .clone()
+            .unwrap_or_else(|| event.event_id.clone());
+
+        if !tracking_data.force {
+            let delivery_attempts = store
+                .list_events_by_merchant_id_initial_attempt_id(
+                    key_manager_state,
+                    &key_store.merchant_id,
+                    &initial_attempt_id,
+                    &key_store,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list delivery attempts for initial event")?;
+
+            let is_already_delivered = event.is_webhook_notified
+                || delivery_attempts
+                    .iter()
+                    .any(|delivery_attempt| delivery_attempt.is_webhook_notified);
+
+            if is_already_delivered {
+                tracking_data.skipped_events += 1;
+                continue;
+            }
+        }
+
+        let business_profile = match event.business_profile_id.clone() {
+            Some(business_profile_id) => match business_profiles.get(&business_profile_id) {
+                Some(business_profile) => Ok(business_profile.clone()),
+                None => store
+                    .find_business_profile_by_profile_id(
+                        key_manager_state,
+                        &key_store,
+                        &business_profile_id,
+                    )
+                    .await
+                    .map(|business_profile| {
+                        business_profiles.insert(business_profile_id, business_profile.clone());
+                        business_profile
+                    })
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find business profile"),
+            },
+            None => Err(error_stack::report!(
+                errors::ApiErrorResponse::InternalServerError
+            ))
+            .attach_printable("Failed to read business profile ID from event to replay"),
+        };
+
+        let result = match business_profile {
+            Ok(business_profile) => {
+                redeliver_event(state, business_profile, &key_store, event).await
+            }
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(_) => tracking_data.replayed_events += 1,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to replay webhook for initial attempt: {initial_attempt_id}"
+                );
+                tracking_data.failed_events += 1;
+            }
+        }
+    }
+
+    tracking_data.offset = tracking_data
+        .offset
+        .saturating_add(i64::try_from(listed_events).unwrap_or(i64::MAX));
+
+    Ok(listed_events)
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_webhook_event_subscription(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+) -> RouterResponse<api::webhook_events::WebhookEventSubscriptionResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let subscription = super::utils::get_webhook_event_subscription(store, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook event subscription")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookEventSubscriptionResponse {
+            merchant_id,
+            subscription,
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn update_webhook_event_subscription(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    subscription: api::webhook_events::WebhookEventSubscription,
+) -> RouterResponse<api::webhook_events::WebhookEventSubscriptionResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mut event_types = Vec::with_capacity(subscription.event_types.len());
+    for event_type in subscription.event_types {
+        if !event_types.contains(&event_type) {
+            event_types.push(event_type);
+        }
+    }
+    let subscription = api::webhook_events::WebhookEventSubscription { event_types };
+
+    let key = merchant_id.get_webhook_event_subscription_key();
+    let config = subscription
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize webhook event subscription")?;
+
+    match store
+        .update_config_by_key(
+            &key,
+            storage::ConfigUpdate::Update {
+                config: Some(config.clone()),
+            },
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => store
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update webhook event subscription")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookEventSubscriptionResponse {
+            merchant_id,
+            subscription,
+        },
+    ))
+}
+
+/// Creates a new manual retry delivery attempt for the specified event, and delivers it to the
+/// webhook endpoint configured in the business profile. Returns the ID of the new delivery attempt.
+#[cfg(feature = "v1")]
+async fn redeliver_event(
+    state: &SessionState,
+    business_profile: domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    event_to_retry: domain::Event,
+) -> errors::RouterResult<String> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
     let new_event_id = super::utils::generate_event_id();
     let idempotent_event_id = super::utils::get_idempotent_event_id(
@@ -220,7 +591,7 @@ pub async fn retry_delivery_attempt(
     };
 
     let event = store
-        .insert_event(key_manager_state, new_event, &key_store)
+        .insert_event(key_manager_state, new_event, key_store)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert event")?;
@@ -239,7 +610,7 @@ pub async fn retry_delivery_attempt(
     Box::pin(super::outgoing::trigger_webhook_and_raise_event(
         state.clone(),
         business_profile,
-        &key_store,
+        key_store,
         event,
         request_content,
         delivery_attempt,
@@ -248,19 +619,7 @@ pub async fn retry_delivery_attempt(
     ))
     .await;
 
-    let updated_event = store
-        .find_event_by_merchant_id_event_id(
-            key_manager_state,
-            &key_store.merchant_id,
-            &new_event_id,
-            &key_store,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
-
-    Ok(ApplicationResponse::Json(
-        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
-    ))
+    Ok(new_event_id)
 }
 
 async fn get_account_and_key_store(
@@ -10,8 +10,10 @@ pub mod callback_mapper;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod custom_permission_group;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod deleted_user_role;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod ephemeral_key;
@@ -28,12 +30,17 @@ pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
+pub mod payment_fx_details;
 pub mod payment_link;
 pub mod payment_method_session;
+pub mod pending_invitation;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
+pub mod role_assignment_rule;
+pub mod role_audit;
+pub mod role_template;
 pub mod routing_algorithm;
 pub mod unified_translations;
 pub mod user;
@@ -124,6 +131,7 @@ pub trait StorageInterface:
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
     + payment_link::PaymentLinkInterface
+    + payment_fx_details::PaymentFxDetailsInterface
     + RedisConnInterface
     + RequestIdStore
     + business_profile::ProfileInterface
@@ -155,6 +163,12 @@ pub trait GlobalStorageInterface:
     + user_role::UserRoleInterface
     + user_key_store::UserKeyStoreInterface
     + role::RoleInterface
+    + role_audit::RoleAuditInterface
+    + custom_permission_group::CustomPermissionGroupInterface
+    + role_template::RoleTemplateInterface
+    + role_assignment_rule::RoleAssignmentRuleInterface
+    + pending_invitation::PendingInvitationInterface
+    + deleted_user_role::DeletedUserRoleInterface
     + 'static
 {
 }
@@ -0,0 +1,106 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomPermissionGroupInterface {
+    async fn insert_custom_permission_group(
+        &self,
+        permission_group: storage::CustomPermissionGroupNew,
+    ) -> CustomResult<storage::CustomPermissionGroup, errors::StorageError>;
+
+    async fn list_custom_permission_groups_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::CustomPermissionGroup>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomPermissionGroupInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_custom_permission_group(
+        &self,
+        permission_group: storage::CustomPermissionGroupNew,
+    ) -> CustomResult<storage::CustomPermissionGroup, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        permission_group
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_custom_permission_groups_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::CustomPermissionGroup>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomPermissionGroup::list_by_tenant_id_org_id_merchant_id(
+            &conn,
+            tenant_id,
+            org_id,
+            merchant_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomPermissionGroupInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_custom_permission_group(
+        &self,
+        _permission_group: storage::CustomPermissionGroupNew,
+    ) -> CustomResult<storage::CustomPermissionGroup, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn list_custom_permission_groups_for_merchant(
+        &self,
+        _tenant_id: &id_type::TenantId,
+        _org_id: &id_type::OrganizationId,
+        _merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::CustomPermissionGroup>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomPermissionGroupInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_custom_permission_group(
+        &self,
+        permission_group: storage::CustomPermissionGroupNew,
+    ) -> CustomResult<storage::CustomPermissionGroup, errors::StorageError> {
+        self.diesel_store
+            .insert_custom_permission_group(permission_group)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_custom_permission_groups_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::CustomPermissionGroup>, errors::StorageError> {
+        self.diesel_store
+            .list_custom_permission_groups_for_merchant(tenant_id, org_id, merchant_id)
+            .await
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+use async_bb8_diesel::AsyncConnection;
 use common_utils::{ext_traits::AsyncExt, id_type, types::keymanager::KeyManagerState};
 use diesel_models::query::customers::CustomerListConstraints as DieselCustomerListConstraints;
 use error_stack::ResultExt;
@@ -32,6 +34,135 @@ impl From<CustomerListConstraints> for DieselCustomerListConstraints {
     }
 }
 
+#[cfg(feature = "v1")]
+pub struct CustomerMergeResult {
+    pub target_customer: customer::Customer,
+    pub payment_methods_merged: usize,
+    pub mandates_merged: usize,
+    pub payments_merged: usize,
+}
+
+/// The records are updated directly in the database, callers must ensure that the merchant does
+/// not use the KV storage scheme. `None` is returned when either of the customers has been merged
+/// into another customer, which is checked after both the customers are locked.
+#[cfg(feature = "v1")]
+async fn merge_customers_in_db(
+    store: &crate::services::Store,
+    state: &KeyManagerState,
+    merchant_id: &id_type::MerchantId,
+    source_customer_id: &id_type::CustomerId,
+    target_customer_id: &id_type::CustomerId,
+    target_customer_update: Option<storage_types::CustomerUpdate>,
+    key_store: &domain::MerchantKeyStore,
+) -> CustomResult<Option<CustomerMergeResult>, errors::StorageError> {
+    let conn = crate::connection::pg_connection_write(store).await?;
+
+    let merged_customers = conn
+        .transaction_async(|conn| async move {
+            let unmerged_customers =
+                storage_types::Customer::lock_unmerged_by_customer_ids_merchant_id(
+                    &conn,
+                    vec![source_customer_id.to_owned(), target_customer_id.to_owned()],
+                    merchant_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+            // Either of the customers has been merged by a concurrent merge
+            if unmerged_customers.len() != 2 {
+                return Ok(None);
+            }
+
+            let payment_methods_merged =
+                diesel_models::PaymentMethod::update_customer_id_by_merchant_id_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                    target_customer_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+            let mandates_merged =
+                diesel_models::Mandate::update_customer_id_by_merchant_id_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                    target_customer_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+            let payments_merged =
+                diesel_models::PaymentIntent::update_customer_id_by_merchant_id_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                    target_customer_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+            storage_types::Customer::update_by_customer_id_merchant_id(
+                &conn,
+                source_customer_id.to_owned(),
+                merchant_id.to_owned(),
+                storage_types::CustomerUpdate::Merge {
+                    merged_into_customer_id: target_customer_id.to_owned(),
+                }
+                .into(),
+            )
+            .await
+            .map_err(errors::StorageError::from)?;
+
+            let target_customer = match target_customer_update {
+                Some(customer_update) => {
+                    storage_types::Customer::update_by_customer_id_merchant_id(
+                        &conn,
+                        target_customer_id.to_owned(),
+                        merchant_id.to_owned(),
+                        customer_update.into(),
+                    )
+                    .await
+                    .map_err(errors::StorageError::from)?
+                }
+                None => storage_types::Customer::find_by_customer_id_merchant_id(
+                    &conn,
+                    target_customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?,
+            };
+
+            Ok::<_, errors::StorageError>(Some((
+                target_customer,
+                payment_methods_merged,
+                mandates_merged,
+                payments_merged,
+            )))
+        })
+        .await?;
+
+    let Some((target_customer, payment_methods_merged, mandates_merged, payments_merged)) =
+        merged_customers
+    else {
+        return Ok(None);
+    };
+
+    let target_customer = target_customer
+        .convert(state, key_store.key.get_inner(), merchant_id.clone().into())
+        .await
+        .change_context(errors::StorageError::DecryptionError)?;
+
+    Ok(Some(CustomerMergeResult {
+        target_customer,
+        payment_methods_merged,
+        mandates_merged,
+        payments_merged,
+    }))
+}
+
 #[async_trait::async_trait]
 pub trait CustomerInterface
 where
@@ -146,6 +277,20 @@ where
         key_store: &domain::MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<customer::Customer, errors::StorageError>;
+
+    /// Moves the payment methods, mandates and payments of the source customer to the target
+    /// customer and marks the source customer as merged into the target, in a single database
+    /// transaction. `None` is returned when either of the customers has already been merged.
+    #[cfg(feature = "v1")]
+    async fn merge_customers(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        source_customer_id: &id_type::CustomerId,
+        target_customer_id: &id_type::CustomerId,
+        target_customer_update: Option<storage_types::CustomerUpdate>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Option<CustomerMergeResult>, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -181,6 +326,29 @@ mod storage {
 
     #[async_trait::async_trait]
     impl CustomerInterface for Store {
+        #[cfg(feature = "v1")]
+        #[instrument(skip_all)]
+        async fn merge_customers(
+            &self,
+            state: &KeyManagerState,
+            merchant_id: &id_type::MerchantId,
+            source_customer_id: &id_type::CustomerId,
+            target_customer_id: &id_type::CustomerId,
+            target_customer_update: Option<storage_types::CustomerUpdate>,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Option<super::CustomerMergeResult>, errors::StorageError> {
+            super::merge_customers_in_db(
+                self,
+                state,
+                merchant_id,
+                source_customer_id,
+                target_customer_id,
+                target_customer_update,
+                key_store,
+            )
+            .await
+        }
+
         #[instrument(skip_all)]
         // check customer not found in kv and fallback to db
         #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -1001,6 +1169,29 @@ mod storage {
 
     #[async_trait::async_trait]
     impl CustomerInterface for Store {
+        #[cfg(feature = "v1")]
+        #[instrument(skip_all)]
+        async fn merge_customers(
+            &self,
+            state: &KeyManagerState,
+            merchant_id: &id_type::MerchantId,
+            source_customer_id: &id_type::CustomerId,
+            target_customer_id: &id_type::CustomerId,
+            target_customer_update: Option<storage_types::CustomerUpdate>,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Option<super::CustomerMergeResult>, errors::StorageError> {
+            super::merge_customers_in_db(
+                self,
+                state,
+                merchant_id,
+                source_customer_id,
+                target_customer_id,
+                target_customer_update,
+                key_store,
+            )
+            .await
+        }
+
         #[instrument(skip_all)]
         #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
         async fn find_customer_optional_by_customer_id_merchant_id(
@@ -1338,6 +1529,20 @@ mod storage {
 
 #[async_trait::async_trait]
 impl CustomerInterface for MockDb {
+    #[cfg(feature = "v1")]
+    async fn merge_customers(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_id: &id_type::MerchantId,
+        _source_customer_id: &id_type::CustomerId,
+        _target_customer_id: &id_type::CustomerId,
+        _target_customer_update: Option<storage_types::CustomerUpdate>,
+        _key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Option<CustomerMergeResult>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     #[allow(clippy::panic)]
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
     async fn find_customer_optional_by_customer_id_merchant_id(
@@ -0,0 +1,196 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait DeletedUserRoleInterface {
+    async fn insert_deleted_user_role(
+        &self,
+        deleted_user_role: storage::DeletedUserRoleNew,
+    ) -> CustomResult<storage::DeletedUserRole, errors::StorageError>;
+
+    async fn list_deleted_user_roles_by_user_id_and_lineage(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::DeletedUserRole>, errors::StorageError>;
+
+    async fn list_deleted_user_roles_by_user_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::DeletedUserRole>, errors::StorageError>;
+
+    async fn delete_deleted_user_role_by_id(
+        &self,
+        id: i32,
+    ) -> CustomResult<storage::DeletedUserRole, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DeletedUserRoleInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_deleted_user_role(
+        &self,
+        deleted_user_role: storage::DeletedUserRoleNew,
+    ) -> CustomResult<storage::DeletedUserRole, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        deleted_user_role
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_deleted_user_roles_by_user_id_and_lineage(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::DeletedUserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DeletedUserRole::list_by_user_id_tenant_id_org_id_merchant_id_profile_id(
+            &conn,
+            user_id.to_owned(),
+            tenant_id.to_owned(),
+            org_id.to_owned(),
+            merchant_id.to_owned(),
+            profile_id.to_owned(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_deleted_user_roles_by_user_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::DeletedUserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DeletedUserRole::list_by_user_id_tenant_id(
+            &conn,
+            user_id.to_owned(),
+            tenant_id.to_owned(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_deleted_user_role_by_id(
+        &self,
+        id: i32,
+    ) -> CustomResult<storage::DeletedUserRole, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DeletedUserRole::delete_by_id(&conn, id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DeletedUserRoleInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_deleted_user_role(
+        &self,
+        _deleted_user_role: storage::DeletedUserRoleNew,
+    ) -> CustomResult<storage::DeletedUserRole, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn list_deleted_user_roles_by_user_id_and_lineage(
+        &self,
+        _user_id: &str,
+        _tenant_id: &id_type::TenantId,
+        _org_id: &id_type::OrganizationId,
+        _merchant_id: &id_type::MerchantId,
+        _profile_id: &id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::DeletedUserRole>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn list_deleted_user_roles_by_user_id(
+        &self,
+        _user_id: &str,
+        _tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::DeletedUserRole>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_deleted_user_role_by_id(
+        &self,
+        _id: i32,
+    ) -> CustomResult<storage::DeletedUserRole, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl DeletedUserRoleInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_deleted_user_role(
+        &self,
+        deleted_user_role: storage::DeletedUserRoleNew,
+    ) -> CustomResult<storage::DeletedUserRole, errors::StorageError> {
+        self.diesel_store
+            .insert_deleted_user_role(deleted_user_role)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_deleted_user_roles_by_user_id_and_lineage(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::DeletedUserRole>, errors::StorageError> {
+        self.diesel_store
+            .list_deleted_user_roles_by_user_id_and_lineage(
+                user_id,
+                tenant_id,
+                org_id,
+                merchant_id,
+                profile_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_deleted_user_roles_by_user_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::DeletedUserRole>, errors::StorageError> {
+        self.diesel_store
+            .list_deleted_user_roles_by_user_id(user_id, tenant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_deleted_user_role_by_id(
+        &self,
+        id: i32,
+    ) -> CustomResult<storage::DeletedUserRole, errors::StorageError> {
+        self.diesel_store.delete_deleted_user_role_by_id(id).await
+    }
+}
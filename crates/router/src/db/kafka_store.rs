@@ -372,6 +372,28 @@ impl ConfigInterface for KafkaStore {
 
 #[async_trait::async_trait]
 impl CustomerInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn merge_customers(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        source_customer_id: &id_type::CustomerId,
+        target_customer_id: &id_type::CustomerId,
+        target_customer_update: Option<storage::CustomerUpdate>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Option<super::customers::CustomerMergeResult>, errors::StorageError> {
+        self.diesel_store
+            .merge_customers(
+                state,
+                merchant_id,
+                source_customer_id,
+                target_customer_id,
+                target_customer_update,
+                key_store,
+            )
+            .await
+    }
+
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
@@ -1790,6 +1812,24 @@ impl PaymentAttemptInterface for KafkaStore {
             .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_by_statuses_modified_within(
+        &self,
+        statuses: Vec<common_enums::AttemptStatus>,
+        time_range: &common_utils::types::TimeRange,
+        excluded_connectors: Vec<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payment_attempts_by_statuses_modified_within(
+                statuses,
+                time_range,
+                excluded_connectors,
+                limit,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3259,6 +3299,26 @@ impl UserInterface for KafkaStore {
     ) -> CustomResult<Vec<storage::User>, errors::StorageError> {
         self.diesel_store.find_users_by_user_ids(user_ids).await
     }
+
+    async fn find_users_by_user_ids_paginated(
+        &self,
+        user_ids: Vec<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::User>, errors::StorageError> {
+        self.diesel_store
+            .find_users_by_user_ids_paginated(user_ids, limit, offset)
+            .await
+    }
+
+    async fn get_users_count_by_user_ids(
+        &self,
+        user_ids: Vec<String>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_users_count_by_user_ids(user_ids)
+            .await
+    }
 }
 
 impl RedisConnInterface for KafkaStore {
@@ -3364,6 +3424,59 @@ impl UserRoleInterface for KafkaStore {
     ) -> CustomResult<Vec<user_storage::UserRole>, errors::StorageError> {
         self.diesel_store.list_user_roles_by_org_id(payload).await
     }
+
+    async fn list_user_roles_by_tenant_id_after_id(
+        &self,
+        tenant_id: &id_type::TenantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> CustomResult<Vec<user_storage::UserRole>, errors::StorageError> {
+        self.diesel_store
+            .list_user_roles_by_tenant_id_after_id(tenant_id, start_after_id, limit)
+            .await
+    }
+
+    async fn list_user_roles_by_merchant_id_after_id(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> CustomResult<Vec<user_storage::UserRole>, errors::StorageError> {
+        self.diesel_store
+            .list_user_roles_by_merchant_id_after_id(
+                tenant_id,
+                org_id,
+                merchant_id,
+                start_after_id,
+                limit,
+            )
+            .await
+    }
+
+    async fn get_active_users_count_for_role(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        role_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_active_users_count_for_role(tenant_id, org_id, role_id)
+            .await
+    }
+
+    async fn list_active_user_ids_for_role(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        role_id: &str,
+        limit: u32,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        self.diesel_store
+            .list_active_user_ids_for_role(tenant_id, org_id, role_id, limit)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3783,6 +3896,27 @@ impl RoleInterface for KafkaStore {
             .await
     }
 
+    async fn update_role_by_role_id_and_version(
+        &self,
+        role_id: &str,
+        version: i32,
+        role_update: storage::RoleUpdate,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        self.diesel_store
+            .update_role_by_role_id_and_version(role_id, version, role_update)
+            .await
+    }
+
+    async fn update_role_last_used_at(
+        &self,
+        role_id: &str,
+        last_used_at: PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .update_role_last_used_at(role_id, last_used_at)
+            .await
+    }
+
     async fn delete_role_by_role_id(
         &self,
         role_id: &str,
@@ -3815,6 +3949,26 @@ impl RoleInterface for KafkaStore {
             .generic_list_roles_by_entity_type(payload, is_lineage_data_required, tenant_id, org_id)
             .await
     }
+
+    async fn get_roles_count_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_roles_count_by_merchant_id(merchant_id, tenant_id)
+            .await
+    }
+    async fn list_roles_by_template_id(
+        &self,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::Role>, errors::StorageError> {
+        self.diesel_store
+            .list_roles_by_template_id(template_id, org_id, tenant_id)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,96 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentFxDetailsInterface {
+    async fn insert_payment_fx_details(
+        &self,
+        payment_fx_details: storage::PaymentFxDetailsNew,
+    ) -> CustomResult<storage::PaymentFxDetails, errors::StorageError>;
+
+    async fn find_payment_fx_details_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentFxDetails>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentFxDetailsInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_fx_details(
+        &self,
+        payment_fx_details: storage::PaymentFxDetailsNew,
+    ) -> CustomResult<storage::PaymentFxDetails, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_fx_details
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_fx_details_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentFxDetails>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentFxDetails::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentFxDetailsInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_payment_fx_details(
+        &self,
+        _payment_fx_details: storage::PaymentFxDetailsNew,
+    ) -> CustomResult<storage::PaymentFxDetails, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_fx_details_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentFxDetails>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentFxDetailsInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_fx_details(
+        &self,
+        payment_fx_details: storage::PaymentFxDetailsNew,
+    ) -> CustomResult<storage::PaymentFxDetails, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_fx_details(payment_fx_details)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_fx_details_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentFxDetails>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_fx_details_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+}
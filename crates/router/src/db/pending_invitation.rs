@@ -0,0 +1,220 @@
+use common_utils::{id_type, pii};
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PendingInvitationInterface {
+    async fn insert_pending_invitation(
+        &self,
+        pending_invitation: storage::PendingInvitationNew,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError>;
+
+    async fn find_pending_invitation_by_email_tenant_id_merchant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError>;
+
+    async fn update_pending_invitation_by_id(
+        &self,
+        id: i32,
+        pending_invitation_update: storage::PendingInvitationUpdate,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError>;
+
+    async fn list_pending_invitations_by_email_tenant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::PendingInvitation>, errors::StorageError>;
+
+    async fn delete_pending_invitations_by_email_tenant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PendingInvitationInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_pending_invitation(
+        &self,
+        pending_invitation: storage::PendingInvitationNew,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        pending_invitation
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_pending_invitation_by_email_tenant_id_merchant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PendingInvitation::find_by_email_tenant_id_merchant_id(
+            &conn,
+            email,
+            tenant_id,
+            merchant_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_pending_invitation_by_id(
+        &self,
+        id: i32,
+        pending_invitation_update: storage::PendingInvitationUpdate,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PendingInvitation::update_by_id(&conn, id, pending_invitation_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_pending_invitations_by_email_tenant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::PendingInvitation>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PendingInvitation::list_by_email_tenant_id(&conn, email, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_pending_invitations_by_email_tenant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PendingInvitation::delete_by_email_tenant_id(&conn, email, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PendingInvitationInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_pending_invitation(
+        &self,
+        _pending_invitation: storage::PendingInvitationNew,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn find_pending_invitation_by_email_tenant_id_merchant_id(
+        &self,
+        _email: &pii::Email,
+        _tenant_id: &id_type::TenantId,
+        _merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn update_pending_invitation_by_id(
+        &self,
+        _id: i32,
+        _pending_invitation_update: storage::PendingInvitationUpdate,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn list_pending_invitations_by_email_tenant_id(
+        &self,
+        _email: &pii::Email,
+        _tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::PendingInvitation>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_pending_invitations_by_email_tenant_id(
+        &self,
+        _email: &pii::Email,
+        _tenant_id: &id_type::TenantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PendingInvitationInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_pending_invitation(
+        &self,
+        pending_invitation: storage::PendingInvitationNew,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        self.diesel_store
+            .insert_pending_invitation(pending_invitation)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_pending_invitation_by_email_tenant_id_merchant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        self.diesel_store
+            .find_pending_invitation_by_email_tenant_id_merchant_id(email, tenant_id, merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_pending_invitation_by_id(
+        &self,
+        id: i32,
+        pending_invitation_update: storage::PendingInvitationUpdate,
+    ) -> CustomResult<storage::PendingInvitation, errors::StorageError> {
+        self.diesel_store
+            .update_pending_invitation_by_id(id, pending_invitation_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_pending_invitations_by_email_tenant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::PendingInvitation>, errors::StorageError> {
+        self.diesel_store
+            .list_pending_invitations_by_email_tenant_id(email, tenant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_pending_invitations_by_email_tenant_id(
+        &self,
+        email: &pii::Email,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_pending_invitations_by_email_tenant_id(email, tenant_id)
+            .await
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "olap")]
 use std::collections::{HashMap, HashSet};
 
+#[cfg(feature = "olap")]
+use api_models::payments::{SortBy, SortOn};
 #[cfg(feature = "olap")]
 use common_utils::types::{ConnectorTransactionIdTrait, MinorUnit};
 use diesel_models::{errors::DatabaseError, refund::RefundUpdateInternal};
@@ -1090,7 +1092,7 @@ impl RefundInterface for MockDb {
         }
 
         let refunds = self.refunds.lock().await;
-        let filtered_refunds = refunds
+        let mut filtered_refunds = refunds
             .iter()
             .filter(|refund| refund.merchant_id == *merchant_id)
             .filter(|refund| {
@@ -1150,12 +1152,53 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
-            .skip(usize::try_from(offset).unwrap_or_default())
-            .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
+            .filter(|refund| {
+                refund_details
+                    .page_after
+                    .as_ref()
+                    .map_or(true, |(created_at, refund_id)| {
+                        let ordering =
+                            (refund.created_at, &refund.refund_id).cmp(&(*created_at, refund_id));
+                        match refund_details
+                            .order
+                            .as_ref()
+                            .map(|order| (&order.on, &order.by))
+                        {
+                            Some((SortOn::Created, SortBy::Asc)) => ordering.is_gt(),
+                            Some((SortOn::Created, SortBy::Desc)) => ordering.is_lt(),
+                            Some((SortOn::Amount, _)) | None => true,
+                        }
+                    })
+            })
             .cloned()
             .collect::<Vec<_>>();
 
-        Ok(filtered_refunds)
+        filtered_refunds.sort_by(|refund, other_refund| {
+            let Some(order) = &refund_details.order else {
+                return (other_refund.modified_at, &other_refund.refund_id)
+                    .cmp(&(refund.modified_at, &refund.refund_id));
+            };
+
+            let ordering = match order.on {
+                SortOn::Amount => refund
+                    .refund_amount
+                    .get_amount_as_i64()
+                    .cmp(&other_refund.refund_amount.get_amount_as_i64()),
+                SortOn::Created => refund.created_at.cmp(&other_refund.created_at),
+            }
+            .then_with(|| refund.refund_id.cmp(&other_refund.refund_id));
+
+            match order.by {
+                SortBy::Asc => ordering,
+                SortBy::Desc => ordering.reverse(),
+            }
+        });
+
+        Ok(filtered_refunds
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
+            .collect())
     }
 
     #[cfg(feature = "olap")]
@@ -3,8 +3,9 @@ use diesel_models::{
     enums::{EntityType, RoleScope},
     role as storage,
 };
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::MockDb;
 use crate::{
@@ -47,6 +48,19 @@ pub trait RoleInterface {
         role_update: storage::RoleUpdate,
     ) -> CustomResult<storage::Role, errors::StorageError>;
 
+    async fn update_role_by_role_id_and_version(
+        &self,
+        role_id: &str,
+        version: i32,
+        role_update: storage::RoleUpdate,
+    ) -> CustomResult<storage::Role, errors::StorageError>;
+
+    async fn update_role_last_used_at(
+        &self,
+        role_id: &str,
+        last_used_at: PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError>;
+
     async fn delete_role_by_role_id(
         &self,
         role_id: &str,
@@ -69,6 +83,18 @@ pub trait RoleInterface {
         tenant_id: id_type::TenantId,
         org_id: id_type::OrganizationId,
     ) -> CustomResult<Vec<storage::Role>, errors::StorageError>;
+
+    async fn get_roles_count_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<i64, errors::StorageError>;
+    async fn list_roles_by_template_id(
+        &self,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::Role>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -142,6 +168,32 @@ impl RoleInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn update_role_by_role_id_and_version(
+        &self,
+        role_id: &str,
+        version: i32,
+        role_update: storage::RoleUpdate,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Role::update_by_role_id_and_version(&conn, role_id, version, role_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_role_last_used_at(
+        &self,
+        role_id: &str,
+        last_used_at: PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Role::update_last_used_at_by_role_id(&conn, role_id, last_used_at)
+            .await
+            .map(|_| ())
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn delete_role_by_role_id(
         &self,
@@ -195,6 +247,30 @@ impl RoleInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn get_roles_count_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Role::get_count_by_merchant_id(&conn, merchant_id, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn list_roles_by_template_id(
+        &self,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::Role>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Role::list_by_template_id(&conn, template_id, org_id, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -227,6 +303,11 @@ impl RoleInterface for MockDb {
             last_modified_by: role.last_modified_by,
             profile_id: role.profile_id,
             tenant_id: role.tenant_id,
+            deleted_at: None,
+            version: 1,
+            custom_permissions: role.custom_permissions,
+            last_used_at: None,
+            template_id: role.template_id,
         };
         roles.push(role.clone());
         Ok(role)
@@ -326,6 +407,27 @@ impl RoleInterface for MockDb {
                         role_name: role_name.unwrap_or(role.role_name.to_owned()),
                         last_modified_by,
                         last_modified_at,
+                        version: role.version + 1,
+                        ..role.to_owned()
+                    },
+                    storage::RoleUpdate::SoftDelete {
+                        last_modified_at,
+                        last_modified_by,
+                    } => storage::Role {
+                        last_modified_by,
+                        last_modified_at,
+                        deleted_at: Some(last_modified_at),
+                        version: role.version + 1,
+                        ..role.to_owned()
+                    },
+                    storage::RoleUpdate::Restore {
+                        last_modified_at,
+                        last_modified_by,
+                    } => storage::Role {
+                        last_modified_by,
+                        last_modified_at,
+                        deleted_at: None,
+                        version: role.version + 1,
                         ..role.to_owned()
                     },
                 };
@@ -339,6 +441,47 @@ impl RoleInterface for MockDb {
             )
     }
 
+    async fn update_role_by_role_id_and_version(
+        &self,
+        role_id: &str,
+        version: i32,
+        role_update: storage::RoleUpdate,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let is_version_matching = self
+            .roles
+            .lock()
+            .await
+            .iter()
+            .any(|role| role.role_id == role_id && role.version == version);
+
+        if !is_version_matching {
+            return Err(errors::StorageError::ValueNotFound(format!(
+                "No role available for role_id = {role_id} and version = {version}"
+            ))
+            .into());
+        }
+
+        self.update_role_by_role_id(role_id, role_update).await
+    }
+
+    async fn update_role_last_used_at(
+        &self,
+        role_id: &str,
+        last_used_at: PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut roles = self.roles.lock().await;
+        roles
+            .iter_mut()
+            .find(|role| role.role_id == role_id)
+            .map(|role| role.last_used_at = Some(last_used_at))
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No role available for role_id = {role_id}"
+                ))
+                .into(),
+            )
+    }
+
     async fn delete_role_by_role_id(
         &self,
         role_id: &str,
@@ -453,4 +596,44 @@ impl RoleInterface for MockDb {
 
         Ok(roles_list)
     }
+
+    #[instrument(skip_all)]
+    async fn get_roles_count_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let roles = self.roles.lock().await;
+        let count = roles
+            .iter()
+            .filter(|role| {
+                role.merchant_id == *merchant_id
+                    && role.tenant_id == *tenant_id
+                    && role.deleted_at.is_none()
+            })
+            .count();
+
+        i64::try_from(count).change_context(errors::StorageError::MockDbError)
+    }
+    #[instrument(skip_all)]
+    async fn list_roles_by_template_id(
+        &self,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<Vec<storage::Role>, errors::StorageError> {
+        let roles = self.roles.lock().await;
+        let roles_list = roles
+            .iter()
+            .filter(|role| {
+                role.template_id.as_deref() == Some(template_id)
+                    && role.org_id == *org_id
+                    && role.tenant_id == *tenant_id
+                    && role.deleted_at.is_none()
+            })
+            .cloned()
+            .collect();
+
+        Ok(roles_list)
+    }
 }
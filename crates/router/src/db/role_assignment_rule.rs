@@ -0,0 +1,150 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait RoleAssignmentRuleInterface {
+    async fn insert_role_assignment_rule(
+        &self,
+        role_assignment_rule: storage::RoleAssignmentRuleNew,
+    ) -> CustomResult<storage::RoleAssignmentRule, errors::StorageError>;
+
+    async fn list_role_assignment_rules_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::RoleAssignmentRule>, errors::StorageError>;
+
+    async fn list_role_assignment_rules_by_attribute_value(
+        &self,
+        tenant_id: &id_type::TenantId,
+        attribute: enums::RoleAssignmentAttribute,
+        value: &str,
+    ) -> CustomResult<Vec<storage::RoleAssignmentRule>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoleAssignmentRuleInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_role_assignment_rule(
+        &self,
+        role_assignment_rule: storage::RoleAssignmentRuleNew,
+    ) -> CustomResult<storage::RoleAssignmentRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        role_assignment_rule
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_assignment_rules_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::RoleAssignmentRule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoleAssignmentRule::list_by_tenant_id_org_id_merchant_id(
+            &conn,
+            tenant_id,
+            org_id,
+            merchant_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_assignment_rules_by_attribute_value(
+        &self,
+        tenant_id: &id_type::TenantId,
+        attribute: enums::RoleAssignmentAttribute,
+        value: &str,
+    ) -> CustomResult<Vec<storage::RoleAssignmentRule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoleAssignmentRule::list_by_tenant_id_attribute_value(
+            &conn, tenant_id, attribute, value,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleAssignmentRuleInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_role_assignment_rule(
+        &self,
+        _role_assignment_rule: storage::RoleAssignmentRuleNew,
+    ) -> CustomResult<storage::RoleAssignmentRule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_assignment_rules_for_merchant(
+        &self,
+        _tenant_id: &id_type::TenantId,
+        _org_id: &id_type::OrganizationId,
+        _merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::RoleAssignmentRule>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_assignment_rules_by_attribute_value(
+        &self,
+        _tenant_id: &id_type::TenantId,
+        _attribute: enums::RoleAssignmentAttribute,
+        _value: &str,
+    ) -> CustomResult<Vec<storage::RoleAssignmentRule>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleAssignmentRuleInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_role_assignment_rule(
+        &self,
+        role_assignment_rule: storage::RoleAssignmentRuleNew,
+    ) -> CustomResult<storage::RoleAssignmentRule, errors::StorageError> {
+        self.diesel_store
+            .insert_role_assignment_rule(role_assignment_rule)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_assignment_rules_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::RoleAssignmentRule>, errors::StorageError> {
+        self.diesel_store
+            .list_role_assignment_rules_for_merchant(tenant_id, org_id, merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_assignment_rules_by_attribute_value(
+        &self,
+        tenant_id: &id_type::TenantId,
+        attribute: enums::RoleAssignmentAttribute,
+        value: &str,
+    ) -> CustomResult<Vec<storage::RoleAssignmentRule>, errors::StorageError> {
+        self.diesel_store
+            .list_role_assignment_rules_by_attribute_value(tenant_id, attribute, value)
+            .await
+    }
+}
@@ -0,0 +1,114 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RoleAuditInterface {
+    async fn insert_role_audit(
+        &self,
+        role_audit: storage::RoleAuditNew,
+    ) -> CustomResult<storage::RoleAudit, errors::StorageError>;
+
+    async fn list_role_audits_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RoleAudit>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoleAuditInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_role_audit(
+        &self,
+        role_audit: storage::RoleAuditNew,
+    ) -> CustomResult<storage::RoleAudit, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        role_audit
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_audits_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RoleAudit>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoleAudit::list_by_tenant_id_org_id_merchant_id(
+            &conn,
+            tenant_id,
+            org_id,
+            merchant_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleAuditInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_role_audit(
+        &self,
+        _role_audit: storage::RoleAuditNew,
+    ) -> CustomResult<storage::RoleAudit, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_audits_for_merchant(
+        &self,
+        _tenant_id: &id_type::TenantId,
+        _org_id: &id_type::OrganizationId,
+        _merchant_id: &id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RoleAudit>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleAuditInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_role_audit(
+        &self,
+        role_audit: storage::RoleAuditNew,
+    ) -> CustomResult<storage::RoleAudit, errors::StorageError> {
+        self.diesel_store.insert_role_audit(role_audit).await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_role_audits_for_merchant(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RoleAudit>, errors::StorageError> {
+        self.diesel_store
+            .list_role_audits_for_merchant(tenant_id, org_id, merchant_id, limit, offset)
+            .await
+    }
+}
@@ -0,0 +1,142 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RoleTemplateInterface {
+    async fn insert_role_template(
+        &self,
+        role_template: storage::RoleTemplateNew,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError>;
+
+    async fn find_role_template_by_template_id(
+        &self,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError>;
+
+    async fn update_role_template_by_template_id(
+        &self,
+        template_id: &str,
+        template_update: storage::RoleTemplateUpdate,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoleTemplateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_role_template(
+        &self,
+        role_template: storage::RoleTemplateNew,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        role_template
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_role_template_by_template_id(
+        &self,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoleTemplate::find_by_template_id_org_id_tenant_id(
+            &conn,
+            template_id,
+            org_id,
+            tenant_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_role_template_by_template_id(
+        &self,
+        template_id: &str,
+        template_update: storage::RoleTemplateUpdate,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RoleTemplate::update_by_template_id(&conn, template_id, template_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleTemplateInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_role_template(
+        &self,
+        _role_template: storage::RoleTemplateNew,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn find_role_template_by_template_id(
+        &self,
+        _template_id: &str,
+        _org_id: &id_type::OrganizationId,
+        _tenant_id: &id_type::TenantId,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[instrument(skip_all)]
+    async fn update_role_template_by_template_id(
+        &self,
+        _template_id: &str,
+        _template_update: storage::RoleTemplateUpdate,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleTemplateInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_role_template(
+        &self,
+        role_template: storage::RoleTemplateNew,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        self.diesel_store.insert_role_template(role_template).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_role_template_by_template_id(
+        &self,
+        template_id: &str,
+        org_id: &id_type::OrganizationId,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        self.diesel_store
+            .find_role_template_by_template_id(template_id, org_id, tenant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_role_template_by_template_id(
+        &self,
+        template_id: &str,
+        template_update: storage::RoleTemplateUpdate,
+    ) -> CustomResult<storage::RoleTemplate, errors::StorageError> {
+        self.diesel_store
+            .update_role_template_by_template_id(template_id, template_update)
+            .await
+    }
+}
@@ -50,6 +50,19 @@ pub trait UserInterface {
         &self,
         user_ids: Vec<String>,
     ) -> CustomResult<Vec<storage::User>, errors::StorageError>;
+
+    /// Lists the users ordered by their creation time, with the user id breaking ties
+    async fn find_users_by_user_ids_paginated(
+        &self,
+        user_ids: Vec<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::User>, errors::StorageError>;
+
+    async fn get_users_count_by_user_ids(
+        &self,
+        user_ids: Vec<String>,
+    ) -> CustomResult<i64, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -132,6 +145,28 @@ impl UserInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    async fn find_users_by_user_ids_paginated(
+        &self,
+        user_ids: Vec<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::User>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::User::find_users_by_user_ids_paginated(&conn, user_ids, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn get_users_count_by_user_ids(
+        &self,
+        user_ids: Vec<String>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::User::get_count_by_user_ids(&conn, user_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -319,4 +354,20 @@ impl UserInterface for MockDb {
     ) -> CustomResult<Vec<storage::User>, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_users_by_user_ids_paginated(
+        &self,
+        _user_ids: Vec<String>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::User>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_users_count_by_user_ids(
+        &self,
+        _user_ids: Vec<String>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
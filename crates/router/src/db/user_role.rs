@@ -1,3 +1,5 @@
+use std::collections::{BTreeSet, HashSet};
+
 use common_utils::id_type;
 use diesel_models::{
     enums::{self, UserStatus},
@@ -89,6 +91,37 @@ pub trait UserRoleInterface {
         &self,
         payload: ListUserRolesByOrgIdPayload<'a>,
     ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
+
+    async fn list_user_roles_by_tenant_id_after_id(
+        &self,
+        tenant_id: &id_type::TenantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
+
+    async fn list_user_roles_by_merchant_id_after_id(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
+
+    async fn get_active_users_count_for_role(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        role_id: &str,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn list_active_user_ids_for_role(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        role_id: &str,
+        limit: u32,
+    ) -> CustomResult<Vec<String>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -234,6 +267,80 @@ impl UserRoleInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    async fn list_user_roles_by_tenant_id_after_id(
+        &self,
+        tenant_id: &id_type::TenantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserRole::list_user_roles_by_tenant_id_after_id(
+            &conn,
+            tenant_id.to_owned(),
+            start_after_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn list_user_roles_by_merchant_id_after_id(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserRole::list_user_roles_by_merchant_id_after_id(
+            &conn,
+            tenant_id.to_owned(),
+            org_id.to_owned(),
+            merchant_id.to_owned(),
+            start_after_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn get_active_users_count_for_role(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        role_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserRole::get_active_users_count_for_role(
+            &conn,
+            tenant_id.to_owned(),
+            org_id.to_owned(),
+            role_id.to_owned(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn list_active_user_ids_for_role(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        role_id: &str,
+        limit: u32,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserRole::list_active_user_ids_for_role(
+            &conn,
+            tenant_id.to_owned(),
+            org_id.to_owned(),
+            role_id.to_owned(),
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -270,6 +377,7 @@ impl UserRoleInterface for MockDb {
             entity_type: None,
             version: enums::UserRoleVersion::V1,
             tenant_id: user_role.tenant_id,
+            expires_at: user_role.expires_at,
         };
         db_user_roles.push(user_role.clone());
         Ok(user_role)
@@ -371,16 +479,20 @@ impl UserRoleInterface for MockDb {
                     storage::UserRoleUpdate::UpdateRole {
                         role_id,
                         modified_by,
+                        expires_at,
                     } => {
                         user_role.role_id = role_id.to_string();
                         user_role.last_modified_by = modified_by.to_string();
+                        user_role.expires_at = *expires_at;
                     }
                     storage::UserRoleUpdate::UpdateStatus {
                         status,
                         modified_by,
+                        expires_at,
                     } => {
                         user_role.status = *status;
                         user_role.last_modified_by = modified_by.to_string();
+                        user_role.expires_at = *expires_at;
                     }
                 }
                 return Ok(user_role.clone());
@@ -480,7 +592,9 @@ impl UserRoleInterface for MockDb {
                     .version
                     .inspect(|ver| filter_condition = filter_condition && ver == &role.version);
                 payload.status.inspect(|status| {
-                    filter_condition = filter_condition && status == &role.status
+                    filter_condition = filter_condition
+                        && status == &role.status
+                        && (*status != UserStatus::Active || !role.is_expired())
                 });
 
                 filter_condition.then(|| role.to_owned())
@@ -556,4 +670,106 @@ impl UserRoleInterface for MockDb {
 
         Ok(filtered_roles)
     }
+
+    async fn list_user_roles_by_tenant_id_after_id(
+        &self,
+        tenant_id: &id_type::TenantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let user_roles = self.user_roles.lock().await;
+
+        let mut filtered_roles: Vec<_> = user_roles
+            .iter()
+            .filter(|role| {
+                role.tenant_id == *tenant_id
+                    && start_after_id.map_or(true, |start_after_id| role.id > start_after_id)
+            })
+            .cloned()
+            .collect();
+        filtered_roles.sort_by_key(|role| role.id);
+
+        Ok(filtered_roles
+            .into_iter()
+            .take(limit.try_into().unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    async fn list_user_roles_by_merchant_id_after_id(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: &id_type::MerchantId,
+        start_after_id: Option<i32>,
+        limit: u32,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let user_roles = self.user_roles.lock().await;
+
+        let mut filtered_roles: Vec<_> = user_roles
+            .iter()
+            .filter(|role| {
+                role.tenant_id == *tenant_id
+                    && role.org_id.as_ref() == Some(org_id)
+                    && role.merchant_id.as_ref() == Some(merchant_id)
+                    && start_after_id.map_or(true, |start_after_id| role.id > start_after_id)
+            })
+            .cloned()
+            .collect();
+        filtered_roles.sort_by_key(|role| role.id);
+
+        Ok(filtered_roles
+            .into_iter()
+            .take(limit.try_into().unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    async fn get_active_users_count_for_role(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        role_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let user_roles = self.user_roles.lock().await;
+
+        let user_ids: HashSet<_> = user_roles
+            .iter()
+            .filter(|user_role| {
+                user_role.tenant_id == *tenant_id
+                    && user_role.org_id.as_ref() == Some(org_id)
+                    && user_role.role_id == role_id
+                    && user_role.status == UserStatus::Active
+                    && !user_role.is_expired()
+            })
+            .map(|user_role| &user_role.user_id)
+            .collect();
+
+        i64::try_from(user_ids.len()).change_context(errors::StorageError::MockDbError)
+    }
+
+    async fn list_active_user_ids_for_role(
+        &self,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        role_id: &str,
+        limit: u32,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        let user_roles = self.user_roles.lock().await;
+
+        let user_ids: BTreeSet<_> = user_roles
+            .iter()
+            .filter(|user_role| {
+                user_role.tenant_id == *tenant_id
+                    && user_role.org_id.as_ref() == Some(org_id)
+                    && user_role.role_id == role_id
+                    && user_role.status == UserStatus::Active
+                    && !user_role.is_expired()
+            })
+            .map(|user_role| user_role.user_id.clone())
+            .collect();
+
+        Ok(user_ids
+            .into_iter()
+            .take(limit.try_into().unwrap_or(usize::MAX))
+            .collect())
+    }
 }
@@ -1,5 +1,5 @@
 use api_models::payments::Amount;
-use common_utils::types::MinorUnit;
+use common_utils::{id_type, types::MinorUnit};
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
 use serde::Serialize;
@@ -39,6 +39,11 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    MerchantSwitched {
+        user_id: String,
+        old_merchant_id: id_type::MerchantId,
+        new_merchant_id: id_type::MerchantId,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +88,7 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus { .. } => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::MerchantSwitched { .. } => "merchant_switched",
         };
         format!(
             "{event_type}-{}",
@@ -228,6 +228,8 @@ pub fn mk_app(
     }
 
     server_app = server_app.service(routes::Cache::server(state.clone()));
+    server_app = server_app.service(routes::CircuitBreaker::server(state.clone()));
+    server_app = server_app.service(routes::ConnectorLatency::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state.clone()));
 
     server_app
@@ -7,7 +7,9 @@ pub mod apple_pay_certificates_migration;
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
+pub mod circuit_breaker;
 pub mod configs;
+pub mod connector_latency;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -72,10 +74,11 @@ pub use self::app::PaymentMethodSession;
 #[cfg(all(feature = "olap", feature = "recon", feature = "v1"))]
 pub use self::app::Recon;
 pub use self::app::{
-    ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorOnboarding,
-    Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm, Health, Hypersense,
-    Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments,
-    Poll, Profile, ProfileNew, Refunds, Relay, RelayWebhooks, SessionState, User, Webhooks,
+    ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, CircuitBreaker, Configs,
+    ConnectorLatency, ConnectorOnboarding, Customers, Disputes, EphemeralKey, FeatureMatrix, Files,
+    Forex, Gsm, Health, Hypersense, Mandates, MerchantAccount, MerchantConnectorAccount,
+    PaymentLink, PaymentMethods, Payments, Poll, Profile, ProfileNew, Refunds, Relay,
+    RelayWebhooks, SessionState, User, Webhooks,
 };
 #[cfg(feature = "olap")]
 pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
@@ -55,8 +55,8 @@ use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_ve
 #[cfg(feature = "oltp")]
 use super::webhooks::*;
 use super::{
-    admin, api_keys, cache::*, connector_onboarding, disputes, files, gsm, health::*, profiles,
-    relay, user, user_role,
+    admin, api_keys, cache::*, circuit_breaker, connector_latency, connector_onboarding, disputes,
+    files, gsm, health::*, profiles, relay, user, user_role,
 };
 #[cfg(feature = "v1")]
 use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
@@ -538,6 +538,14 @@ impl DummyConnector {
                 web::resource("/payments/{payment_id}")
                     .route(web::get().to(dummy_connector_payment_data)),
             )
+            .service(
+                web::resource("/payments/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_capture_payment)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}/void")
+                    .route(web::post().to(dummy_connector_void_payment)),
+            )
             .service(
                 web::resource("/{payment_id}/refund").route(web::post().to(dummy_connector_refund)),
             )
@@ -672,6 +680,10 @@ impl Payments {
                         .route(web::get().to(payments::profile_payments_list))
                         .route(web::post().to(payments::profile_payments_list_by_filter)),
                 )
+                .service(
+                    web::resource("/metadata/search")
+                        .route(web::post().to(payments::payments_search_by_metadata)),
+                )
                 .service(
                     web::resource("/filter")
                         .route(web::post().to(payments::get_filters_for_payments)),
@@ -695,6 +707,24 @@ impl Payments {
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments::payments_manual_update)),
                 )
+                .service(
+                    web::resource("/{payment_id}/raw_connector_payloads")
+                        .route(web::get().to(payments::payments_raw_connector_payloads_retrieve)),
+                )
+                .service(
+                    web::resource("/{payment_id}/timeline")
+                        .route(web::get().to(payments::payments_timeline_retrieve)),
+                )
+                .service(
+                    web::resource("/{payment_id}/signed_payment_link")
+                        .route(web::post().to(payment_link::create_signed_payment_link)),
+                )
+        }
+        #[cfg(all(feature = "oltp", not(feature = "payment_methods_v2")))]
+        {
+            route = route.service(
+                web::resource("/verify").route(web::post().to(payments::payments_verify_card)),
+            );
         }
         #[cfg(feature = "oltp")]
         {
@@ -1079,6 +1109,12 @@ impl Customers {
                 .service(web::resource("/list").route(web::get().to(customers::customers_list)))
         }
 
+        #[cfg(all(feature = "oltp", feature = "v1"))]
+        {
+            route = route
+                .service(web::resource("/merge").route(web::post().to(customers::customers_merge)))
+        }
+
         #[cfg(feature = "oltp")]
         {
             route = route
@@ -1140,6 +1176,11 @@ impl Refunds {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
                 .service(web::resource("/sync").route(web::post().to(refunds_retrieve_with_body)))
+                .service(web::resource("/bulk").route(web::post().to(refunds_bulk_create)))
+                .service(
+                    web::resource("/eligibility/{payment_id}")
+                        .route(web::get().to(refunds_eligibility)),
+                )
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
@@ -1286,6 +1327,10 @@ impl PaymentMethods {
                     web::resource("/{payment_method_id}/tokenize-card")
                         .route(web::post().to(payment_methods::tokenize_card_using_pm_api)),
                 )
+                .service(
+                    web::resource("/{payment_method_id}/contexts")
+                        .route(web::get().to(payment_methods::payment_method_contexts_list_api)),
+                )
                 .service(
                     web::resource("/{payment_method_id}/update")
                         .route(web::post().to(payment_methods::payment_method_update_api)),
@@ -1363,6 +1408,9 @@ impl Recon {
                 web::resource("/verify_token")
                     .route(web::get().to(recon_routes::verify_recon_token)),
             )
+            .service(
+                web::resource("/report").route(web::post().to(recon_routes::generate_recon_report)),
+            )
     }
 }
 
@@ -1801,6 +1849,10 @@ impl Disputes {
                     .route(web::put().to(disputes::attach_dispute_evidence))
                     .route(web::delete().to(disputes::delete_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/bundle")
+                    .route(web::post().to(disputes::submit_dispute_evidence_bundle)),
+            )
             .service(
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
@@ -1851,6 +1903,32 @@ impl Cache {
     }
 }
 
+pub struct CircuitBreaker;
+
+impl CircuitBreaker {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/circuit_breaker")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(circuit_breaker::retrieve_connector_circuit_breaker)),
+            )
+    }
+}
+
+pub struct ConnectorLatency;
+
+impl ConnectorLatency {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_latency")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(connector_latency::retrieve_connector_latency)),
+            )
+    }
+}
+
 pub struct PaymentLink;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -1863,6 +1941,10 @@ impl PaymentLink {
                 web::resource("/{payment_link_id}")
                     .route(web::get().to(payment_link::payment_link_retrieve)),
             )
+            .service(
+                web::resource("signed/{link_token}")
+                    .route(web::get().to(payment_link::initiate_signed_payment_link)),
+            )
             .service(
                 web::resource("{merchant_id}/{payment_id}")
                     .route(web::get().to(payment_link::initiate_payment_link)),
@@ -2135,6 +2217,7 @@ impl User {
 
         route = route
             .service(web::resource("").route(web::get().to(user::get_user_details)))
+            .service(web::resource("/whoami").route(web::get().to(user_role::get_whoami)))
             .service(web::resource("/signin").route(web::post().to(user::user_signin)))
             .service(web::resource("/v2/signin").route(web::post().to(user::user_signin)))
             // signin/signup with sso using openidconnect
@@ -2148,6 +2231,11 @@ impl User {
             .service(
                 web::resource("/tenant_signup").route(web::post().to(user::create_tenant_user)),
             )
+            .service(web::resource("/impersonate").route(web::post().to(user::impersonate_user)))
+            .service(
+                web::resource("/role_integrity")
+                    .route(web::get().to(user_role::check_role_integrity)),
+            )
             .service(web::resource("/create_org").route(web::post().to(user::user_org_create)))
             .service(
                 web::resource("/create_merchant")
@@ -2292,6 +2380,14 @@ impl User {
                 .service(
                     web::resource("/user/resend_invite").route(web::post().to(user::resend_invite)),
                 )
+                .service(
+                    web::resource("/user/resend_invitation")
+                        .route(web::post().to(user_role::resend_invitation)),
+                )
+                .service(
+                    web::resource("/user/invite_by_email")
+                        .route(web::post().to(user_role::invite_user_by_email)),
+                )
                 .service(
                     web::resource("/accept_invite_from_email")
                         .route(web::post().to(user::accept_invite_from_email)),
@@ -2326,10 +2422,18 @@ impl User {
                             web::resource("")
                                 .route(web::post().to(user_role::accept_invitations_v2)),
                         )
+                        .service(
+                            web::resource("/all")
+                                .route(web::post().to(user_role::accept_all_invitations)),
+                        )
                         .service(
                             web::resource("/pre_auth")
                                 .route(web::post().to(user_role::accept_invitations_pre_auth)),
                         )
+                        .service(
+                            web::resource("/pre_auth/merchants")
+                                .route(web::get().to(user_role::list_switchable_merchants)),
+                        )
                         .service(
                             web::scope("/v2")
                                 .service(
@@ -2347,51 +2451,116 @@ impl User {
                     web::resource("/update_role")
                         .route(web::post().to(user_role::update_user_role)),
                 )
+                .service(
+                    web::resource("/update_role/bulk")
+                        .route(web::post().to(user_role::bulk_update_user_roles)),
+                )
                 .service(
                     web::resource("/delete").route(web::delete().to(user_role::delete_user_role)),
+                )
+                .service(
+                    web::resource("/delete/undo")
+                        .route(web::post().to(user_role::undo_delete_user_role)),
+                )
+                .service(
+                    web::resource("/{user_id}/roles")
+                        .route(web::get().to(user_role::list_roles_for_user)),
                 ),
         );
 
+        // RBAC export and import
+        route = route.service(
+            web::scope("/rbac")
+                .service(web::resource("/export").route(web::get().to(user_role::export_rbac)))
+                .service(web::resource("/import").route(web::post().to(user_role::import_rbac))),
+        );
+
         // Role information
-        route =
-            route.service(
-                web::scope("/role")
-                    .service(
-                        web::resource("")
-                            .route(web::get().to(user_role::get_role_from_token))
-                            .route(web::post().to(user_role::create_role)),
-                    )
-                    .service(web::resource("/v2").route(
+        route = route.service(
+            web::scope("/role")
+                .service(
+                    web::resource("")
+                        .route(web::get().to(user_role::get_role_from_token))
+                        .route(web::post().to(user_role::create_role)),
+                )
+                .service(
+                    web::resource("/v2").route(
                         web::get().to(user_role::get_groups_and_resources_for_role_from_token),
-                    ))
-                    // TODO: To be deprecated
-                    .service(
-                        web::resource("/v2/list")
-                            .route(web::get().to(user_role::list_roles_with_info)),
-                    )
-                    .service(
-                        web::scope("/list")
-                            .service(
-                                web::resource("")
-                                    .route(web::get().to(user_role::list_roles_with_info)),
-                            )
-                            .service(web::resource("/invite").route(
+                    ),
+                )
+                .service(
+                    web::resource("/permissions")
+                        .route(web::get().to(user_role::get_my_permissions)),
+                )
+                // TODO: To be deprecated
+                .service(
+                    web::resource("/v2/list").route(web::get().to(user_role::list_roles_with_info)),
+                )
+                .service(
+                    web::scope("/list")
+                        .service(
+                            web::resource("").route(web::get().to(user_role::list_roles_with_info)),
+                        )
+                        .service(
+                            web::resource("/invite").route(
                                 web::get().to(user_role::list_invitable_roles_at_entity_level),
-                            ))
-                            .service(web::resource("/update").route(
+                            ),
+                        )
+                        .service(
+                            web::resource("/update").route(
                                 web::get().to(user_role::list_updatable_roles_at_entity_level),
-                            )),
-                    )
-                    .service(
-                        web::resource("/{role_id}")
-                            .route(web::get().to(user_role::get_role))
-                            .route(web::put().to(user_role::update_role)),
-                    )
-                    .service(
-                        web::resource("/{role_id}/v2")
-                            .route(web::get().to(user_role::get_parent_info_for_role)),
-                    ),
-            );
+                            ),
+                        ),
+                )
+                .service(web::resource("/compare").route(web::get().to(user_role::compare_roles)))
+                .service(web::resource("/audit").route(web::get().to(user_role::list_role_audit)))
+                .service(
+                    web::resource("/permission_group")
+                        .route(web::get().to(user_role::list_permission_groups))
+                        .route(web::post().to(user_role::create_permission_group)),
+                )
+                .service(
+                    web::scope("/template")
+                        .service(
+                            web::resource("")
+                                .route(web::post().to(user_role::create_role_template)),
+                        )
+                        .service(
+                            web::resource("/{template_id}")
+                                .route(web::put().to(user_role::update_role_template)),
+                        )
+                        .service(
+                            web::resource("/{template_id}/apply")
+                                .route(web::post().to(user_role::apply_role_template)),
+                        ),
+                )
+                .service(
+                    web::resource("/assignment_rule")
+                        .route(web::get().to(user_role::list_role_assignment_rules))
+                        .route(web::post().to(user_role::create_role_assignment_rule)),
+                )
+                .service(
+                    web::resource("/{role_id}")
+                        .route(web::get().to(user_role::get_role))
+                        .route(web::put().to(user_role::update_role))
+                        .route(web::delete().to(user_role::delete_role)),
+                )
+                .service(
+                    web::resource("/{role_id}/preview_update")
+                        .route(web::post().to(user_role::preview_role_update)),
+                )
+                .service(
+                    web::resource("/{role_id}/restore")
+                        .route(web::post().to(user_role::restore_role)),
+                )
+                .service(
+                    web::resource("/{role_id}/v2")
+                        .route(web::get().to(user_role::get_parent_info_for_role)),
+                )
+                .service(
+                    web::resource("/{role_id}/clone").route(web::post().to(user_role::clone_role)),
+                ),
+        );
 
         #[cfg(feature = "dummy_connector")]
         {
@@ -2456,6 +2625,14 @@ impl WebhookEvents {
                 web::resource("")
                     .route(web::get().to(webhook_events::list_initial_webhook_delivery_attempts)),
             )
+            .service(
+                web::resource("/replay").route(web::post().to(webhook_events::replay_webhooks)),
+            )
+            .service(
+                web::resource("/subscription")
+                    .route(web::get().to(webhook_events::retrieve_webhook_event_subscription))
+                    .route(web::post().to(webhook_events::update_webhook_event_subscription)),
+            )
             .service(
                 web::scope("/{event_id}")
                     .service(
@@ -2479,5 +2656,9 @@ impl FeatureMatrix {
         web::scope("/feature_matrix")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::get().to(feature_matrix::fetch_feature_matrix)))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(feature_matrix::fetch_connector_feature_matrix)),
+            )
     }
 }
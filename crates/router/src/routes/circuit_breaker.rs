@@ -0,0 +1,32 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::{circuit_breaker as circuit_breaker_types, enums as api_enums};
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, circuit_breaker},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCircuitBreakerRetrieve))]
+pub async fn retrieve_connector_circuit_breaker(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::Connector>,
+) -> impl Responder {
+    let flow = Flow::ConnectorCircuitBreakerRetrieve;
+    let payload = circuit_breaker_types::ConnectorCircuitBreakerRequest {
+        connector: path.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| circuit_breaker::retrieve_connector_circuit_breaker(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
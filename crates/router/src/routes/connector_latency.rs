@@ -0,0 +1,32 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::{connector_latency as connector_latency_types, enums as api_enums};
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, connector_latency},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorLatencyRetrieve))]
+pub async fn retrieve_connector_latency(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::Connector>,
+) -> impl Responder {
+    let flow = Flow::ConnectorLatencyRetrieve;
+    let payload = connector_latency_types::ConnectorLatencyRequest {
+        connector: path.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| connector_latency::retrieve_connector_latency(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -324,3 +324,31 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersMerge))]
+pub async fn customers_merge(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<customers::CustomerMergeRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomersMerge;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            merge_customers(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
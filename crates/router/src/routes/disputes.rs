@@ -335,7 +335,7 @@ pub async fn submit_dispute_evidence(
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth),
             &auth::JWTAuth {
-                permission: Permission::ProfileDisputeWrite,
+                permission: Permission::ProfileDisputeEvidenceWrite,
             },
             req.headers(),
         ),
@@ -389,7 +389,64 @@ pub async fn attach_dispute_evidence(
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth),
             &auth::JWTAuth {
-                permission: Permission::ProfileDisputeWrite,
+                permission: Permission::ProfileDisputeEvidenceWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Disputes - Submit Dispute Evidence Bundle
+///
+/// To upload multiple evidence documents along with the text fields of the evidence and submit
+/// them to the connector in a single call
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/bundle",
+    request_body=MultipartRequestWithFile,
+    responses(
+        (status = 200, description = "The dispute evidence bundle submitted successfully", body = SubmitEvidenceBundleResponse),
+        (status = 400, description = "Bad Request"),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Submit Dispute Evidence Bundle",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceBundleSubmit))]
+pub async fn submit_dispute_evidence_bundle(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: Multipart,
+) -> HttpResponse {
+    let flow = Flow::DisputesEvidenceBundleSubmit;
+    //Get submit_evidence_bundle_request from the multipart request
+    let submit_evidence_bundle_request =
+        match utils::get_submit_evidence_bundle_request(payload).await {
+            Ok(valid_request) => valid_request,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        submit_evidence_bundle_request,
+        |state, auth: auth::AuthenticationData, req, _| {
+            disputes::submit_evidence_bundle(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeEvidenceWrite,
             },
             req.headers(),
         ),
@@ -477,7 +534,7 @@ pub async fn delete_dispute_evidence(
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth),
             &auth::JWTAuth {
-                permission: Permission::ProfileDisputeWrite,
+                permission: Permission::ProfileDisputeEvidenceWrite,
             },
             req.headers(),
         ),
@@ -94,3 +94,98 @@ pub async fn get_attach_evidence_request(
         create_file_request,
     })
 }
+
+pub async fn get_submit_evidence_bundle_request(
+    mut payload: Multipart,
+) -> CustomResult<disputes::SubmitEvidenceBundleRequest, errors::ApiErrorResponse> {
+    let mut dispute_id: Option<String> = None;
+    let mut text_fields = serde_json::Map::new();
+    let mut evidence_documents = Vec::new();
+
+    while let Ok(Some(mut field)) = payload.try_next().await {
+        let content_disposition = field.content_disposition();
+        let field_name = content_disposition.get_name().map(String::from);
+        let file_name = content_disposition.get_filename().map(String::from);
+        // Documents are expected under the name of their evidence type, while the rest of the
+        // fields are treated as the text fields of the evidence
+        match (field_name, file_name) {
+            (Some(field_name), Some(file_name)) => {
+                let evidence_type: disputes::EvidenceType = field_name
+                    .clone()
+                    .parse_enum("Evidence Type")
+                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!("`{field_name}` is not a valid evidence type"),
+                    })?;
+                //Collect the file content and throw error if something fails
+                let mut file_data = Vec::new();
+                let mut stream = field.into_stream();
+                while let Some(chunk) = stream.next().await {
+                    match chunk {
+                        Ok(bytes) => file_data.push(bytes),
+                        Err(err) => Err(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable_lazy(|| format!("File parsing error: {err}"))?,
+                    }
+                }
+                evidence_documents.push((evidence_type, file_name, file_data));
+            }
+            (Some(field_name), None) if field_name == "dispute_id" => {
+                dispute_id = helpers::read_string(&mut field).await;
+            }
+            (Some(field_name), None) => {
+                if let Some(value) = helpers::read_string(&mut field).await {
+                    text_fields.insert(field_name, serde_json::Value::String(value));
+                }
+            }
+            // Can ignore unnamed params
+            (None, _) => (),
+        }
+    }
+    let dispute_id = dispute_id.get_required_value("dispute_id")?;
+    text_fields.insert(
+        "dispute_id".to_string(),
+        serde_json::Value::String(dispute_id.clone()),
+    );
+    let submit_evidence_request: api_models::disputes::SubmitEvidenceRequest =
+        serde_json::from_value(serde_json::Value::Object(text_fields)).change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "Invalid text fields in the evidence bundle".to_string(),
+            },
+        )?;
+
+    let mut evidence_files = Vec::with_capacity(evidence_documents.len());
+    for (evidence_type, file_name, file_data) in evidence_documents {
+        let file = file_data.concat().to_vec();
+        //Get and validate file size
+        let file_size = i32::try_from(file.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("File size error")?;
+        // Check if empty file and throw error
+        fp_utils::when(file_size <= 0, || {
+            Err(errors::ApiErrorResponse::MissingFile)
+                .attach_printable_lazy(|| format!("Missing / Invalid file for {evidence_type}"))
+        })?;
+        // Get file mime type using 'infer'
+        let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
+        let file_type = kind
+            .mime_type()
+            .parse::<mime::Mime>()
+            .change_context(errors::ApiErrorResponse::MissingFileContentType)
+            .attach_printable("File content type error")?;
+        evidence_files.push(disputes::AttachEvidenceRequest {
+            evidence_type,
+            create_file_request: files::CreateFileRequest {
+                file,
+                file_name: Some(file_name),
+                file_size,
+                file_type,
+                purpose: files::FilePurpose::DisputeEvidence,
+                dispute_id: Some(dispute_id.clone()),
+            },
+        });
+    }
+
+    Ok(disputes::SubmitEvidenceBundleRequest {
+        submit_evidence_request,
+        evidence_files,
+    })
+}
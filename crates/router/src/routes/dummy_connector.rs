@@ -104,6 +104,51 @@ pub async fn dummy_connector_payment_data(
     .await
 }
 
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentCapture))]
+pub async fn dummy_connector_capture_payment(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorPaymentCaptureRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentCapture;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.into_inner());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payment_capture(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentVoid))]
+pub async fn dummy_connector_void_payment(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentVoid;
+    let payment_id = path.into_inner();
+    let payload = types::DummyConnectorPaymentVoidRequest { payment_id };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payment_void(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "dummy_connector", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?types::Flow::DummyRefundCreate))]
 pub async fn dummy_connector_refund(
@@ -1,3 +1,12 @@
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
+
+// Magic amounts (in minor units) which deterministically decide the outcome of a payment,
+// irrespective of the payment method used
+pub const DECLINED_PAYMENT_AMOUNT: i64 = 1001;
+pub const INSUFFICIENT_FUNDS_PAYMENT_AMOUNT: i64 = 1002;
+pub const THREE_DS_PAYMENT_AMOUNT: i64 = 1003;
+pub const DECLINED_CAPTURE_PAYMENT_AMOUNT: i64 = 1004;
+pub const DECLINED_VOID_PAYMENT_AMOUNT: i64 = 1005;
+pub const DECLINED_REFUND_AMOUNT: i64 = 1006;
@@ -97,10 +97,10 @@ pub async fn payment_complete(
 
     let payment_data = utils::get_payment_data_by_attempt_id(&state, req.attempt_id.clone()).await;
 
-    let payment_status = if req.confirm {
-        types::DummyConnectorStatus::Succeeded
-    } else {
-        types::DummyConnectorStatus::Failed
+    let payment_status = match (req.confirm, &payment_data) {
+        (true, Ok(payment_data)) => payment_data.get_success_status(),
+        (true, Err(_)) => types::DummyConnectorStatus::Succeeded,
+        (false, _) => types::DummyConnectorStatus::Failed,
     };
 
     let redis_conn = state
@@ -148,13 +148,13 @@ pub async fn payment_complete(
 }
 
 #[cfg(all(feature = "dummy_connector", feature = "v1"))]
-pub async fn refund_payment(
+pub async fn payment_capture(
     state: SessionState,
-    req: types::DummyConnectorRefundRequest,
-) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
+    req: types::DummyConnectorPaymentCaptureRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
     utils::tokio_mock_sleep(
-        state.conf.dummy_connector.refund_duration,
-        state.conf.dummy_connector.refund_tolerance,
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
     )
     .await;
 
@@ -169,10 +169,10 @@ pub async fn refund_payment(
         utils::get_payment_data_from_payment_id(&state, payment_id.get_string_repr().to_owned())
             .await?;
 
-    payment_data.is_eligible_for_refund(req.amount)?;
+    payment_data.is_eligible_for_capture(req.amount)?;
 
-    let refund_id = generate_id_with_default_len(consts::REFUND_ID_PREFIX);
-    payment_data.eligible_amount -= req.amount;
+    payment_data.status = types::DummyConnectorStatus::Succeeded;
+    payment_data.eligible_amount = req.amount;
 
     utils::store_data_in_redis(
         &state,
@@ -181,9 +181,83 @@ pub async fn refund_payment(
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn payment_void(
+    state: SessionState,
+    req: types::DummyConnectorPaymentVoidRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let mut payment_data = utils::get_payment_data_from_payment_id(
+        &state,
+        req.payment_id.get_string_repr().to_owned(),
+    )
+    .await?;
+
+    payment_data.is_eligible_for_void()?;
+
+    payment_data.status = types::DummyConnectorStatus::Cancelled;
+    payment_data.eligible_amount = 0;
+
+    utils::store_data_in_redis(
+        &state,
+        req.payment_id.get_string_repr().to_owned(),
+        payment_data.to_owned(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn refund_payment(
+    state: SessionState,
+    req: types::DummyConnectorRefundRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.refund_duration,
+        state.conf.dummy_connector.refund_tolerance,
+    )
+    .await;
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+
+    let mut payment_data =
+        utils::get_payment_data_from_payment_id(&state, payment_id.get_string_repr().to_owned())
+            .await?;
+
+    payment_data.is_eligible_for_refund(req.amount)?;
+
+    let refund_id = generate_id_with_default_len(consts::REFUND_ID_PREFIX);
+    let refund_status = if req.amount == consts::DECLINED_REFUND_AMOUNT {
+        types::DummyConnectorStatus::Failed
+    } else {
+        payment_data.eligible_amount -= req.amount;
+
+        utils::store_data_in_redis(
+            &state,
+            payment_id.get_string_repr().to_owned(),
+            payment_data.to_owned(),
+            state.conf.dummy_connector.payment_ttl,
+        )
+        .await?;
+        types::DummyConnectorStatus::Succeeded
+    };
 
     let refund_data = types::DummyConnectorRefundResponse::new(
-        types::DummyConnectorStatus::Succeeded,
+        refund_status,
         refund_id.to_owned(),
         payment_data.currency,
         common_utils::date_time::now(),
@@ -37,6 +37,12 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_09", message = "Payment is not in an authorized state")]
+    PaymentNotAuthorized,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_10", message = "The capture amount exceeds the amount authorized")]
+    CaptureAmountExceedsPaymentAmount,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +89,12 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::PaymentNotAuthorized => {
+                AER::BadRequest(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::CaptureAmountExceedsPaymentAmount => {
+                AER::BadRequest(ApiError::new("DC", 10, self.error_message(), None))
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use api_models::enums::Currency;
+use api_models::enums::{CaptureMethod, Currency};
 use common_utils::{errors::CustomResult, generate_id_with_default_len};
 use error_stack::report;
 use masking::Secret;
@@ -16,6 +16,8 @@ pub enum Flow {
     DummyPaymentRetrieve,
     DummyPaymentAuthorize,
     DummyPaymentComplete,
+    DummyPaymentCapture,
+    DummyPaymentVoid,
     DummyRefundCreate,
     DummyRefundRetrieve,
 }
@@ -61,9 +63,11 @@ impl DummyConnectors {
 #[serde(rename_all = "lowercase")]
 pub enum DummyConnectorStatus {
     Succeeded,
+    Authorized,
     #[default]
     Processing,
     Failed,
+    Cancelled,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -95,6 +99,13 @@ impl DummyConnectorPaymentAttempt {
         next_action: Option<DummyConnectorNextAction>,
         return_url: Option<String>,
     ) -> DummyConnectorPaymentData {
+        // A successful payment with manual capture stays authorized until it is captured
+        let status = match status {
+            DummyConnectorStatus::Succeeded if self.payment_request.is_manual_capture() => {
+                DummyConnectorStatus::Authorized
+            }
+            status => status,
+        };
         DummyConnectorPaymentData {
             attempt_id: self.attempt_id,
             payment_id: self.payment_id,
@@ -105,6 +116,7 @@ impl DummyConnectorPaymentAttempt {
             created: self.timestamp,
             currency: self.payment_request.currency,
             payment_method_type: self.payment_request.payment_method_data.into(),
+            capture_method: self.payment_request.capture_method,
             next_action,
             return_url,
         }
@@ -118,6 +130,14 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    #[serde(default)]
+    pub capture_method: Option<CaptureMethod>,
+}
+
+impl DummyConnectorPaymentRequest {
+    pub fn is_manual_capture(&self) -> bool {
+        matches!(self.capture_method, Some(CaptureMethod::Manual))
+    }
 }
 
 pub trait GetPaymentMethodDetails {
@@ -257,6 +277,8 @@ pub struct DummyConnectorPaymentData {
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub connector: DummyConnectors,
+    #[serde(default)]
+    pub capture_method: Option<CaptureMethod>,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
 }
@@ -275,6 +297,45 @@ impl DummyConnectorPaymentData {
         }
         Ok(())
     }
+
+    pub fn is_eligible_for_capture(&self, capture_amount: i64) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the capture"));
+        }
+        if self.amount < capture_amount {
+            return Err(
+                report!(DummyConnectorErrors::CaptureAmountExceedsPaymentAmount)
+                    .attach_printable("Authorized amount is lesser than capture amount"),
+            );
+        }
+        if self.amount == consts::DECLINED_CAPTURE_PAYMENT_AMOUNT {
+            return Err(report!(DummyConnectorErrors::PaymentDeclined {
+                message: "Capture declined",
+            }));
+        }
+        Ok(())
+    }
+
+    pub fn is_eligible_for_void(&self) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the void"));
+        }
+        if self.amount == consts::DECLINED_VOID_PAYMENT_AMOUNT {
+            return Err(report!(DummyConnectorErrors::PaymentDeclined {
+                message: "Void declined",
+            }));
+        }
+        Ok(())
+    }
+
+    pub fn get_success_status(&self) -> DummyConnectorStatus {
+        match self.capture_method {
+            Some(CaptureMethod::Manual) => DummyConnectorStatus::Authorized,
+            _ => DummyConnectorStatus::Succeeded,
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
@@ -330,6 +391,17 @@ pub struct DummyConnectorPaymentCompleteBody {
     pub confirm: bool,
 }
 
+#[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPaymentCaptureRequest {
+    pub amount: i64,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPaymentVoidRequest {
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
 #[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
@@ -376,3 +448,82 @@ pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
 pub type DummyConnectorResult<T> = CustomResult<T, DummyConnectorErrors>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_payment_data(status: DummyConnectorStatus, amount: i64) -> DummyConnectorPaymentData {
+        DummyConnectorPaymentData {
+            attempt_id: "dummy_attempt_test".to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            status,
+            amount,
+            eligible_amount: amount,
+            currency: Currency::USD,
+            created: common_utils::date_time::now(),
+            payment_method_type: DummyConnectorPaymentMethodType::Card,
+            connector: DummyConnectors::PhonyPay,
+            capture_method: Some(CaptureMethod::Manual),
+            next_action: None,
+            return_url: None,
+        }
+    }
+
+    #[test]
+    fn test_capture_eligibility() {
+        let payment_data = get_payment_data(DummyConnectorStatus::Authorized, 2000);
+        assert!(payment_data.is_eligible_for_capture(2000).is_ok());
+        assert!(payment_data.is_eligible_for_capture(1000).is_ok());
+        assert!(matches!(
+            payment_data
+                .is_eligible_for_capture(2001)
+                .map_err(|error| error.current_context().clone()),
+            Err(DummyConnectorErrors::CaptureAmountExceedsPaymentAmount)
+        ));
+
+        let payment_data = get_payment_data(DummyConnectorStatus::Succeeded, 2000);
+        assert!(matches!(
+            payment_data
+                .is_eligible_for_capture(2000)
+                .map_err(|error| error.current_context().clone()),
+            Err(DummyConnectorErrors::PaymentNotAuthorized)
+        ));
+
+        let payment_data = get_payment_data(
+            DummyConnectorStatus::Authorized,
+            consts::DECLINED_CAPTURE_PAYMENT_AMOUNT,
+        );
+        assert!(matches!(
+            payment_data
+                .is_eligible_for_capture(consts::DECLINED_CAPTURE_PAYMENT_AMOUNT)
+                .map_err(|error| error.current_context().clone()),
+            Err(DummyConnectorErrors::PaymentDeclined { .. })
+        ));
+    }
+
+    #[test]
+    fn test_void_eligibility() {
+        let payment_data = get_payment_data(DummyConnectorStatus::Authorized, 2000);
+        assert!(payment_data.is_eligible_for_void().is_ok());
+
+        let payment_data = get_payment_data(DummyConnectorStatus::Cancelled, 2000);
+        assert!(matches!(
+            payment_data
+                .is_eligible_for_void()
+                .map_err(|error| error.current_context().clone()),
+            Err(DummyConnectorErrors::PaymentNotAuthorized)
+        ));
+
+        let payment_data = get_payment_data(
+            DummyConnectorStatus::Authorized,
+            consts::DECLINED_VOID_PAYMENT_AMOUNT,
+        );
+        assert!(matches!(
+            payment_data
+                .is_eligible_for_void()
+                .map_err(|error| error.current_context().clone()),
+            Err(DummyConnectorErrors::PaymentDeclined { .. })
+        ));
+    }
+}
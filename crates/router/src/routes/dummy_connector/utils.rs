@@ -219,20 +219,29 @@ impl ProcessPaymentAttempt for types::DummyConnectorCard {
         payment_attempt: types::DummyConnectorPaymentAttempt,
         redirect_url: String,
     ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
-        match self.get_flow_from_card_number()? {
-            types::DummyConnectorCardFlow::NoThreeDS(status, error) => {
+        self.get_flow_from_card_number()?
+            .build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
+    }
+}
+
+impl ProcessPaymentAttempt for types::DummyConnectorCardFlow {
+    fn build_payment_data_from_payment_attempt(
+        self,
+        payment_attempt: types::DummyConnectorPaymentAttempt,
+        redirect_url: String,
+    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
+        match self {
+            Self::NoThreeDS(status, error) => {
                 if let Some(error) = error {
                     Err(error)?;
                 }
                 Ok(payment_attempt.build_payment_data(status, None, None))
             }
-            types::DummyConnectorCardFlow::ThreeDS(_, _) => {
-                Ok(payment_attempt.clone().build_payment_data(
-                    types::DummyConnectorStatus::Processing,
-                    Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
-                    payment_attempt.payment_request.return_url,
-                ))
-            }
+            Self::ThreeDS(_, _) => Ok(payment_attempt.clone().build_payment_data(
+                types::DummyConnectorStatus::Processing,
+                Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
+                payment_attempt.payment_request.return_url,
+            )),
         }
     }
 }
@@ -286,6 +295,34 @@ impl types::DummyConnectorCard {
     }
 }
 
+impl types::DummyConnectorPaymentAttempt {
+    /// Magic amounts decide the flow of the payment irrespective of the payment method used,
+    /// all the other amounts fall back to the payment method specific flow
+    pub fn get_flow_from_amount(&self) -> Option<types::DummyConnectorCardFlow> {
+        match self.payment_request.amount {
+            consts::DECLINED_PAYMENT_AMOUNT => Some(types::DummyConnectorCardFlow::NoThreeDS(
+                types::DummyConnectorStatus::Failed,
+                Some(errors::DummyConnectorErrors::PaymentDeclined {
+                    message: "Card declined",
+                }),
+            )),
+            consts::INSUFFICIENT_FUNDS_PAYMENT_AMOUNT => {
+                Some(types::DummyConnectorCardFlow::NoThreeDS(
+                    types::DummyConnectorStatus::Failed,
+                    Some(errors::DummyConnectorErrors::PaymentDeclined {
+                        message: "Insufficient funds",
+                    }),
+                ))
+            }
+            consts::THREE_DS_PAYMENT_AMOUNT => Some(types::DummyConnectorCardFlow::ThreeDS(
+                types::DummyConnectorStatus::Succeeded,
+                None,
+            )),
+            _ => None,
+        }
+    }
+}
+
 impl ProcessPaymentAttempt for types::DummyConnectorWallet {
     fn build_payment_data_from_payment_attempt(
         self,
@@ -320,6 +357,9 @@ impl ProcessPaymentAttempt for types::DummyConnectorPaymentMethodData {
         payment_attempt: types::DummyConnectorPaymentAttempt,
         redirect_url: String,
     ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
+        if let Some(flow) = payment_attempt.get_flow_from_amount() {
+            return flow.build_payment_data_from_payment_attempt(payment_attempt, redirect_url);
+        }
         match self {
             Self::Card(card) => {
                 card.build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
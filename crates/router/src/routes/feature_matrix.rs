@@ -10,10 +10,16 @@ use strum::IntoEnumIterator;
 
 use crate::{
     self as app,
-    core::{api_locking::LockAction, errors::RouterResponse},
+    core::{
+        api_locking::LockAction,
+        errors::{self, RouterResponse},
+    },
     services::{api, authentication as auth, connector_integration_interface::ConnectorEnum},
     settings,
-    types::api::{self as api_types, payments as payment_types},
+    types::{
+        self,
+        api::{self as api_types, payments as payment_types},
+    },
 };
 
 #[instrument(skip_all)]
@@ -39,6 +45,43 @@ pub async fn fetch_feature_matrix(
     .await
 }
 
+#[instrument(skip_all)]
+pub async fn fetch_connector_feature_matrix(
+    state: web::Data<app::AppState>,
+    req: HttpRequest,
+    path: web::Path<Connector>,
+) -> impl Responder {
+    let flow = Flow::ConnectorFeatureMatrix;
+    let connector_name = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, (), (), _| generate_connector_feature_matrix(state, connector_name),
+        &auth::NoAuth,
+        LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn generate_connector_feature_matrix(
+    state: app::SessionState,
+    connector_name: Connector,
+) -> RouterResponse<feature_matrix::ConnectorFeatureMatrixResponse> {
+    let connector = api_types::ConnectorData::convert_connector(&connector_name.to_string())?;
+
+    build_connector_feature_details(&state, connector, connector_name.to_string())
+        .map(ApplicationResponse::Json)
+        .ok_or_else(|| {
+            errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Feature matrix is not available for {connector_name}"),
+            }
+            .into()
+        })
+}
+
 pub async fn generate_feature_matrix(
     state: app::SessionState,
     req: payment_types::FeatureMatrixRequest,
@@ -93,6 +136,7 @@ fn build_connector_feature_details(
             })
             .collect::<Vec<feature_matrix::SupportedPaymentMethod>>();
 
+        let capabilities = build_connector_capabilities(&connector, &supported_payment_methods);
         let connector_about = connector.get_connector_about();
         let supported_webhook_flows = connector
             .get_supported_webhook_flows()
@@ -102,12 +146,69 @@ fn build_connector_feature_details(
             display_name: connector_about.map(|about| about.display_name.to_string()),
             description: connector_about.map(|about| about.description.to_string()),
             category: connector_about.map(|about| about.connector_type),
+            capabilities,
             supported_webhook_flows,
             supported_payment_methods,
         }
     })
 }
 
+fn get_feature_status(is_supported: bool) -> enums::FeatureStatus {
+    if is_supported {
+        enums::FeatureStatus::Supported
+    } else {
+        enums::FeatureStatus::NotSupported
+    }
+}
+
+/// Summarizes the features that the connector supports for at least one payment method type.
+/// Incremental authorization is not specific to a payment method type, its support is derived
+/// from the connector integration of the flow.
+fn build_connector_capabilities(
+    connector: &ConnectorEnum,
+    supported_payment_methods: &[feature_matrix::SupportedPaymentMethod],
+) -> feature_matrix::ConnectorCapabilities {
+    let is_supported_by_any = |predicate: fn(&feature_matrix::SupportedPaymentMethod) -> bool| {
+        get_feature_status(supported_payment_methods.iter().any(predicate))
+    };
+
+    let incremental_authorization_integration = connector.get_connector_integration::<
+        api_types::IncrementalAuthorization,
+        types::PaymentFlowData,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    >();
+
+    feature_matrix::ConnectorCapabilities {
+        three_ds: is_supported_by_any(|payment_method| {
+            matches!(
+                &payment_method.payment_method_specific_features,
+                Some(feature_matrix::PaymentMethodSpecificFeatures::Card(card_features))
+                    if card_features.three_ds == enums::FeatureStatus::Supported
+            )
+        }),
+        mandates: is_supported_by_any(|payment_method| {
+            payment_method.mandates == enums::FeatureStatus::Supported
+        }),
+        refunds: is_supported_by_any(|payment_method| {
+            payment_method.refunds == enums::FeatureStatus::Supported
+        }),
+        manual_capture: is_supported_by_any(|payment_method| {
+            payment_method
+                .supported_capture_methods
+                .contains(&enums::CaptureMethod::Manual)
+        }),
+        partial_capture: is_supported_by_any(|payment_method| {
+            payment_method
+                .supported_capture_methods
+                .contains(&enums::CaptureMethod::ManualMultiple)
+        }),
+        incremental_authorization: get_feature_status(
+            incremental_authorization_integration.is_flow_implemented(),
+        ),
+    }
+}
+
 fn build_payment_method_wise_feature_details(
     state: &app::SessionState,
     connector_name: &str,
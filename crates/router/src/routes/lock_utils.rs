@@ -21,6 +21,8 @@ pub enum ApiIdentifier {
     CardsInfo,
     Files,
     Cache,
+    CircuitBreaker,
+    ConnectorLatency,
     Profile,
     Verification,
     ApiKeys,
@@ -100,6 +102,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersMerge
             | Flow::CustomersList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
@@ -112,6 +115,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodsList
             | Flow::CustomerPaymentMethodsList
             | Flow::PaymentMethodsRetrieve
+            | Flow::PaymentMethodContextsList
             | Flow::PaymentMethodsUpdate
             | Flow::PaymentMethodsDelete
             | Flow::PaymentMethodCollectLink
@@ -134,6 +138,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
+            | Flow::PaymentsMetadataSearch
             | Flow::PaymentsFilters
             | Flow::PaymentsAggregate
             | Flow::PaymentsRedirect
@@ -143,6 +148,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GetExtendedCardInfo
             | Flow::PaymentsCompleteAuthorize
             | Flow::PaymentsManualUpdate
+            | Flow::PaymentsRawConnectorPayloadsRetrieve
+            | Flow::PaymentsTimelineRetrieve
+            | Flow::PaymentsVerifyCard
             | Flow::SessionUpdateTaxCalculation
             | Flow::PaymentsConfirmIntent
             | Flow::PaymentsCreateIntent
@@ -172,6 +180,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsList
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
+            | Flow::RefundsEligibility
+            | Flow::RefundsBulkCreate
             | Flow::RefundsManualUpdate => Self::Refunds,
             Flow::Relay | Flow::RelayRetrieve => Self::Relay,
 
@@ -181,6 +191,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventReplay
+            | Flow::WebhookEventSubscriptionRetrieve
+            | Flow::WebhookEventSubscriptionUpdate
             | Flow::RecoveryIncomingWebhookReceive => Self::Webhooks,
 
             Flow::ApiKeyCreate
@@ -193,6 +206,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DisputesList
             | Flow::DisputesFilters
             | Flow::DisputesEvidenceSubmit
+            | Flow::DisputesEvidenceBundleSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
@@ -207,6 +221,10 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CacheInvalidate => Self::Cache,
 
+            Flow::ConnectorCircuitBreakerRetrieve => Self::CircuitBreaker,
+
+            Flow::ConnectorLatencyRetrieve => Self::ConnectorLatency,
+
             Flow::ProfileCreate
             | Flow::ProfileUpdate
             | Flow::ProfileRetrieve
@@ -219,7 +237,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::SignedPaymentLinkCreate
+            | Flow::SignedPaymentLinkInitiate => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -240,6 +260,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GetMultipleDashboardMetadata
             | Flow::VerifyPaymentConnector
             | Flow::InternalUserSignup
+            | Flow::ImpersonateUser
             | Flow::TenantUserCreate
             | Flow::SwitchOrg
             | Flow::SwitchMerchantV2
@@ -293,17 +314,42 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GetRoleV2
             | Flow::GetRoleFromToken
             | Flow::GetRoleFromTokenV2
+            | Flow::GetMyPermissions
+            | Flow::WhoAmI
             | Flow::UpdateUserRole
+            | Flow::BulkUpdateUserRoles
             | Flow::GetAuthorizationInfo
             | Flow::GetRolesInfo
             | Flow::GetParentGroupInfo
             | Flow::AcceptInvitationsV2
+            | Flow::AcceptAllInvitations
             | Flow::AcceptInvitationsPreAuth
+            | Flow::ListSwitchableMerchants
+            | Flow::ResendInvitation
+            | Flow::InviteUserByEmail
             | Flow::DeleteUserRole
+            | Flow::UndoDeleteUserRole
             | Flow::CreateRole
+            | Flow::CloneRole
+            | Flow::CompareRoles
+            | Flow::ListRoleAudit
+            | Flow::DeleteRole
+            | Flow::RestoreRole
             | Flow::UpdateRole
+            | Flow::PreviewRoleUpdate
+            | Flow::CreatePermissionGroup
+            | Flow::ListPermissionGroups
+            | Flow::CreateRoleTemplate
+            | Flow::UpdateRoleTemplate
+            | Flow::ApplyRoleTemplate
+            | Flow::CreateRoleAssignmentRule
+            | Flow::ListRoleAssignmentRules
             | Flow::UserFromEmail
-            | Flow::ListUsersInLineage => Self::UserRole,
+            | Flow::ListUsersInLineage
+            | Flow::ListRolesForUser
+            | Flow::CheckRoleIntegrity
+            | Flow::ExportRbac
+            | Flow::ImportRbac => Self::UserRole,
 
             Flow::GetActionUrl | Flow::SyncOnboardingStatus | Flow::ResetTrackingId => {
                 Self::ConnectorOnboarding
@@ -312,11 +358,12 @@ impl From<Flow> for ApiIdentifier {
             Flow::ReconMerchantUpdate
             | Flow::ReconTokenRequest
             | Flow::ReconServiceRequest
-            | Flow::ReconVerifyToken => Self::Recon,
+            | Flow::ReconVerifyToken
+            | Flow::ReconReportGenerate => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
 
-            Flow::FeatureMatrix => Self::Documentation,
+            Flow::FeatureMatrix | Flow::ConnectorFeatureMatrix => Self::Documentation,
 
             Flow::TokenizeCard
             | Flow::TokenizeCardUsingPaymentMethodId
@@ -20,6 +20,9 @@ counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
 
 counter_metric!(PAYMENT_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_PAYMENT, GLOBAL_METER);
+counter_metric!(ILLEGAL_PAYMENT_STATUS_TRANSITION, GLOBAL_METER);
+counter_metric!(FRAUD_CHECK_HOOK_DECISION_COUNT, GLOBAL_METER);
+counter_metric!(FRAUD_CHECK_HOOK_TIMEOUT_COUNT, GLOBAL_METER);
 //TODO: This can be removed, added for payment list debugging
 histogram_metric_f64!(PAYMENT_LIST_LATENCY, GLOBAL_METER);
 
@@ -55,6 +58,10 @@ counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
 
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
+// Role operation metrics
+counter_metric!(ROLE_OPERATION_COUNT, GLOBAL_METER);
+histogram_metric_f64!(ROLE_OPERATION_TIME, GLOBAL_METER);
+
 // Flow Specific Metrics
 
 histogram_metric_f64!(CONNECTOR_REQUEST_TIME, GLOBAL_METER);
@@ -102,12 +109,15 @@ counter_metric!(APPLE_PAY_SIMPLIFIED_FLOW_FAILED_PAYMENT, GLOBAL_METER);
 
 // Metrics for Payment Auto Retries
 counter_metric!(AUTO_RETRY_CONNECTION_CLOSED, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_TRANSIENT_CONNECTOR_ERROR_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MISS_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_CONNECTOR_FAILOVER_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_CONNECTOR_FAILOVER_SKIPPED_COUNT, GLOBAL_METER);
 
 // Metrics for Payout Auto Retries
 counter_metric!(AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
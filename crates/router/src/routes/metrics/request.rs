@@ -18,6 +18,34 @@ where
     result
 }
 
+/// Records the latency and outcome of a role mutation, tagged with its flow and the merchant it
+/// was performed for
+pub async fn record_role_operation_metrics<F, T, E>(
+    future: F,
+    flow: &impl router_env::types::FlowMetric,
+    merchant_id: Option<common_utils::id_type::MerchantId>,
+) -> error_stack::Result<T, E>
+where
+    F: futures::Future<Output = error_stack::Result<T, E>>,
+{
+    let (result, time) = metric_utils::time_future(future).await;
+    let outcome = if result.is_ok() { "success" } else { "failure" };
+    let attributes = router_env::metric_attributes!(
+        ("flow", flow.to_string()),
+        (
+            "merchant_id",
+            merchant_id
+                .map(|merchant_id| merchant_id.get_string_repr().to_owned())
+                .unwrap_or_default()
+        ),
+        ("outcome", outcome),
+    );
+
+    super::ROLE_OPERATION_COUNT.add(1, attributes);
+    super::ROLE_OPERATION_TIME.record(time.as_secs_f64(), attributes);
+    result
+}
+
 pub fn status_code_metrics(
     status_code: String,
     flow: String,
@@ -42,6 +70,7 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::GenericLinkForm(_)
         | ApplicationResponse::PaymentLinkForm(_)
         | ApplicationResponse::FileData(_)
+        | ApplicationResponse::FileStream(_)
         | ApplicationResponse::JsonWithHeaders(_) => 200,
         ApplicationResponse::JsonForRedirection(_) => 302,
     }
@@ -200,3 +200,70 @@ pub async fn payment_link_status(
     ))
     .await
 }
+
+/// Payment Link - Create Signed
+///
+/// To create a signed link to the hosted payment page of a payment, which can be opened only once before it expires. The validity of the link is configurable per merchant, and the link is signed using the payment response hash key of the profile
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/signed_payment_link",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Signed payment link created", body = SignedPaymentLinkResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Create a Signed Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SignedPaymentLinkCreate))]
+pub async fn create_signed_payment_link(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::SignedPaymentLinkCreate;
+    let payment_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            create_signed_payment_link_for_payment(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SignedPaymentLinkInitiate))]
+pub async fn initiate_signed_payment_link(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<api_models::payments::SignedPaymentLinkQuery>,
+) -> impl Responder {
+    let flow = Flow::SignedPaymentLinkInitiate;
+    let link_token = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, (), query, _| initiate_signed_payment_link_flow(state, link_token.clone(), query),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,3 +1,5 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::recon as recon_api;
 use router_env::Flow;
@@ -77,3 +79,38 @@ pub async fn verify_recon_token(state: web::Data<AppState>, http_req: HttpReques
     ))
     .await
 }
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn generate_recon_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_api::ReconReportQuery>,
+    MultipartForm(form): MultipartForm<recon::report::ReconReportForm>,
+) -> HttpResponse {
+    let flow = Flow::ReconReportGenerate;
+    let recon_api::ReconReportQuery { time_range, format } = query.into_inner();
+    let payload = recon::report::ReconReportRequest {
+        time_range,
+        format,
+        settlement_file: form.file,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: authentication::AuthenticationData, req, _| {
+            recon::report::generate_recon_report(state, auth.merchant_account, auth.key_store, req)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
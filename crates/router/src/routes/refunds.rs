@@ -1,9 +1,10 @@
 use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
-use super::app::AppState;
+use super::{app::AppState, lock_utils};
 use crate::{
     core::{api_locking, refunds::*},
+    headers,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -31,6 +32,23 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let idempotency_key =
+        match auth::get_header_value_by_key(headers::IDEMPOTENCY_KEY.into(), req.headers()) {
+            Ok(idempotency_key) => idempotency_key.map(ToOwned::to_owned),
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+    // Concurrent requests with the same idempotency key are processed one after the other,
+    // so that only one of them creates the refund
+    let locking_action = idempotency_key
+        .clone()
+        .map(|idempotency_key| api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: idempotency_key,
+                api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+                override_lock_retries: None,
+            },
+        })
+        .unwrap_or(api_locking::LockAction::NotApplicable);
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -43,6 +61,7 @@ pub async fn refunds_create(
                 auth.profile_id,
                 auth.key_store,
                 req,
+                idempotency_key.clone(),
             )
         },
         auth::auth_type(
@@ -52,7 +71,7 @@ pub async fn refunds_create(
             },
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        locking_action,
     ))
     .await
 }
@@ -167,6 +186,120 @@ pub async fn refunds_retrieve_with_body(
     ))
     .await
 }
+/// Refunds - Eligibility
+///
+/// To check whether a refund can be initiated against a payment, and for how much, without creating a refund
+#[utoipa::path(
+    get,
+    path = "/refunds/eligibility/{payment_id}",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Refund eligibility retrieved", body = RefundEligibilityResponse),
+        (status = 404, description = "Payment does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve Refund Eligibility",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsEligibility))]
+// #[get("/eligibility/{payment_id}")]
+pub async fn refunds_eligibility(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let flow = Flow::RefundsEligibility;
+    let payload = refunds::RefundEligibilityRequest {
+        payment_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            refund_eligibility_core(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Refunds - Bulk Create
+///
+/// To create refunds against multiple payments at once, or to validate whether they can be refunded
+#[utoipa::path(
+    post,
+    path = "/refunds/bulk",
+    request_body=RefundBulkRequest,
+    responses(
+        (status = 200, description = "Bulk refund processed", body = RefundBulkResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Refunds",
+    operation_id = "Create Refunds in Bulk",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBulkCreate))]
+// #[post("/bulk")]
+pub async fn refunds_bulk_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundBulkRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsBulkCreate;
+    let payload = json_payload.into_inner();
+    // Concurrent requests for the same batch are processed one after the other, so that a payment
+    // is not refunded twice
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payload.batch_key.clone(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            refund_bulk_core(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
 /// Refunds - Update
 ///
 /// To update the properties of a Refund object. This may include attaching a reason for the refund or metadata fields
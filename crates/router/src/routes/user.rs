@@ -305,6 +305,26 @@ pub async fn user_merchant_account_create(
     .await
 }
 
+pub async fn impersonate_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::ImpersonateUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ImpersonateUser;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| user_core::impersonate_user(state, user, req_body),
+        &auth::JWTAuth {
+            permission: Permission::MerchantSupportAdminWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "dummy_connector", feature = "v1"))]
 pub async fn generate_sample_data(
     state: web::Data<AppState>,
@@ -966,7 +986,9 @@ pub async fn switch_merchant_for_user_in_org(
         state.clone(),
         &http_req,
         json_payload.into_inner(),
-        |state, user, req, _| user_core::switch_merchant_for_user_in_org(state, req, user),
+        |state, user, req, req_state| {
+            user_core::switch_merchant_for_user_in_org(state, req, user, req_state)
+        },
         &auth::DashboardNoPermissionAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -1,9 +1,10 @@
-use actix_web::{web, HttpRequest, HttpResponse};
+use actix_web::{http::header, web, HttpRequest, HttpResponse};
+#[cfg(feature = "email")]
+use api_models::user as user_api;
 use api_models::user_role::{self as user_role_api, role as role_api};
-use common_enums::TokenPurpose;
 use router_env::Flow;
 
-use super::AppState;
+use super::{metrics, AppState};
 use crate::{
     core::{
         api_locking,
@@ -20,22 +21,53 @@ use crate::{
 pub async fn get_authorization_info(
     state: web::Data<AppState>,
     http_req: HttpRequest,
+    query: web::Query<user_role_api::GetAuthorizationInfoRequest>,
 ) -> HttpResponse {
     let flow = Flow::GetAuthorizationInfo;
-    Box::pin(api::server_wrap(
+    let response = Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &http_req,
-        (),
-        |state, _: (), _, _| async move {
-            user_role_core::get_authorization_info_with_groups(state).await
+        query.into_inner(),
+        |state, user, request, _| async move {
+            user_role_core::get_authorization_info_with_groups(state, user, request).await
         },
         &auth::JWTAuth {
             permission: Permission::MerchantUserRead,
         },
         api_locking::LockAction::NotApplicable,
     ))
-    .await
+    .await;
+
+    if_none_match_response(&http_req, response)
+}
+
+/// Replaces a successful response with `304 Not Modified` when its `ETag` matches the
+/// `If-None-Match` header sent by the client.
+fn if_none_match_response(req: &HttpRequest, response: HttpResponse) -> HttpResponse {
+    let etag = match response.headers().get(header::ETAG) {
+        Some(etag) if response.status().is_success() => etag.clone(),
+        _ => return response,
+    };
+
+    let is_not_modified = req
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .any(|tag| tag == "*" || etag.to_str().is_ok_and(|etag| etag == tag))
+        });
+
+    if is_not_modified {
+        HttpResponse::NotModified()
+            .insert_header((header::ETAG, etag))
+            .finish()
+    } else {
+        response
+    }
 }
 
 pub async fn get_role_from_token(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
@@ -55,6 +87,36 @@ pub async fn get_role_from_token(state: web::Data<AppState>, req: HttpRequest) -
     .await
 }
 
+pub async fn get_my_permissions(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::GetMyPermissions;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| async move { role_core::get_my_permissions(state, user).await },
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_whoami(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::WhoAmI;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| async move { user_role_core::get_whoami(state, user).await },
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn get_groups_and_resources_for_role_from_token(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -81,12 +143,189 @@ pub async fn create_role(
     json_payload: web::Json<role_api::CreateRoleRequest>,
 ) -> HttpResponse {
     let flow = Flow::CreateRole;
+    Box::pin(api::server_wrap(
+        flow.clone(),
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, req, req_state| {
+            let merchant_id = user.merchant_id.clone();
+            metrics::request::record_role_operation_metrics(
+                role_core::create_role(state, user, req, req_state),
+                &flow,
+                Some(merchant_id),
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_permission_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<role_api::CreatePermissionGroupRequest>,
+) -> HttpResponse {
+    let flow = Flow::CreatePermissionGroup;
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &req,
         json_payload.into_inner(),
-        role_core::create_role,
+        |state, user, payload, _| role_core::create_permission_group(state, user, payload),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_role_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<role_api::CreateRoleTemplateRequest>,
+) -> HttpResponse {
+    let flow = Flow::CreateRoleTemplate;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, _| role_core::create_role_template(state, user, payload),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn update_role_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<role_api::UpdateRoleTemplateRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::UpdateRoleTemplate;
+    let template_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, _| {
+            role_core::update_role_template(state, user, payload, &template_id)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn apply_role_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<role_api::ApplyRoleTemplateRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ApplyRoleTemplate;
+    let template_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, _| {
+            role_core::apply_role_template(state, user, payload, &template_id)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_role_assignment_rule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::CreateRoleAssignmentRuleRequest>,
+) -> HttpResponse {
+    let flow = Flow::CreateRoleAssignmentRule;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, _| user_role_core::create_role_assignment_rule(state, user, payload),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_role_assignment_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::ListRoleAssignmentRules;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_role_core::list_role_assignment_rules(state, user),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_permission_groups(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ListPermissionGroups;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| role_core::list_permission_groups(state, user),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn clone_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<role_api::CloneRoleRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CloneRole;
+    let source_role_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, req, req_state| {
+            role_core::clone_role(state, user, req, &source_role_id, req_state)
+        },
         &auth::JWTAuth {
             permission: Permission::MerchantUserWrite,
         },
@@ -120,6 +359,48 @@ pub async fn get_role(
     .await
 }
 
+pub async fn compare_roles(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<role_api::CompareRolesRequest>,
+) -> HttpResponse {
+    let flow = Flow::CompareRoles;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        query.into_inner(),
+        |state, user, payload, _| role_core::compare_roles(state, user, payload),
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_role_audit(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<role_api::ListRoleAuditRequest>,
+) -> HttpResponse {
+    let flow = Flow::ListRoleAudit;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        query.into_inner(),
+        |state, user, payload, _| role_core::list_role_audit(state, user, payload),
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn get_parent_info_for_role(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -154,12 +435,86 @@ pub async fn update_role(
     let flow = Flow::UpdateRole;
     let role_id = path.into_inner();
 
+    Box::pin(api::server_wrap(
+        flow.clone(),
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, req, _| {
+            let merchant_id = user.merchant_id.clone();
+            metrics::request::record_role_operation_metrics(
+                role_core::update_role(state, user, req, &role_id),
+                &flow,
+                Some(merchant_id),
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn preview_role_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<role_api::PreviewRoleUpdateRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PreviewRoleUpdate;
+    let role_id = path.into_inner();
+
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &req,
         json_payload.into_inner(),
-        |state, user, req, _| role_core::update_role(state, user, req, &role_id),
+        |state, user, req, _| role_core::preview_role_update(state, user, req, &role_id),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DeleteRole;
+    let role_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| role_core::soft_delete_role(state, user, &role_id),
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn restore_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RestoreRole;
+    let role_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| role_core::restore_role(state, user, &role_id),
         &auth::JWTAuth {
             permission: Permission::MerchantUserWrite,
         },
@@ -175,12 +530,40 @@ pub async fn update_user_role(
 ) -> HttpResponse {
     let flow = Flow::UpdateUserRole;
     let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow.clone(),
+        state.clone(),
+        &req,
+        payload,
+        |state, user, req, req_state| {
+            let merchant_id = user.merchant_id.clone();
+            metrics::request::record_role_operation_metrics(
+                user_role_core::update_user_role(state, user, req, req_state),
+                &flow,
+                Some(merchant_id),
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn bulk_update_user_roles(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::BulkUpdateUserRolesRequest>,
+) -> HttpResponse {
+    let flow = Flow::BulkUpdateUserRoles;
+    let payload = json_payload.into_inner();
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &req,
         payload,
-        user_role_core::update_user_role,
+        user_role_core::bulk_update_user_roles,
         &auth::JWTAuth {
             permission: Permission::ProfileUserWrite,
         },
@@ -197,11 +580,33 @@ pub async fn accept_invitations_v2(
     let flow = Flow::AcceptInvitationsV2;
     let payload = json_payload.into_inner();
     Box::pin(api::server_wrap(
-        flow,
+        flow.clone(),
         state.clone(),
         &req,
         payload,
-        |state, user, req_body, _| user_role_core::accept_invitations_v2(state, user, req_body),
+        |state, user, req_body, _| {
+            let merchant_id = user.merchant_id.clone();
+            metrics::request::record_role_operation_metrics(
+                user_role_core::accept_invitations_v2(state, user, req_body),
+                &flow,
+                Some(merchant_id),
+            )
+        },
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn accept_all_invitations(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::AcceptAllInvitations;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_role_core::accept_all_invitations(state, user),
         &auth::DashboardNoPermissionAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -214,16 +619,91 @@ pub async fn accept_invitations_pre_auth(
     json_payload: web::Json<user_role_api::AcceptInvitationsPreAuthRequest>,
 ) -> HttpResponse {
     let flow = Flow::AcceptInvitationsPreAuth;
+    let token_purpose = state.conf.user.get_accept_invitation_token_purpose();
     let payload = json_payload.into_inner();
     Box::pin(api::server_wrap(
-        flow,
+        flow.clone(),
         state.clone(),
         &req,
         payload,
-        |state, user, req_body, _| async move {
-            user_role_core::accept_invitations_pre_auth(state, user, req_body).await
+        // Invitations accepted before authentication are not tied to a single merchant
+        |state, user, req_body, _| {
+            metrics::request::record_role_operation_metrics(
+                user_role_core::accept_invitations_pre_auth(state, user, req_body),
+                &flow,
+                None,
+            )
+        },
+        &auth::SinglePurposeJWTAuth(token_purpose),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_switchable_merchants(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::ListSwitchableMerchants;
+    let token_purpose = state.conf.user.get_accept_invitation_token_purpose();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_role_core::list_switchable_merchants(state, user),
+        &auth::SinglePurposeJWTAuth(token_purpose),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "email")]
+pub async fn resend_invitation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<user_role_api::ResendInvitationRequest>,
+    query: web::Query<user_api::AuthIdAndThemeIdQueryParam>,
+) -> HttpResponse {
+    let flow = Flow::ResendInvitation;
+    let auth_id = query.into_inner().auth_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload.into_inner(),
+        |state, user, req_payload, _| {
+            user_role_core::resend_invitation(state, user, req_payload, auth_id.clone())
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "email")]
+pub async fn invite_user_by_email(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<user_role_api::InviteUserByEmailRequest>,
+    query: web::Query<user_api::AuthIdAndThemeIdQueryParam>,
+) -> HttpResponse {
+    let flow = Flow::InviteUserByEmail;
+    let auth_id = query.into_inner().auth_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload.into_inner(),
+        |state, user, req_payload, _| {
+            user_role_core::create_pending_invitation(state, user, req_payload, auth_id.clone())
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserWrite,
         },
-        &auth::SinglePurposeJWTAuth(TokenPurpose::AcceptInvite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -235,12 +715,39 @@ pub async fn delete_user_role(
     payload: web::Json<user_role_api::DeleteUserRoleRequest>,
 ) -> HttpResponse {
     let flow = Flow::DeleteUserRole;
+    Box::pin(api::server_wrap(
+        flow.clone(),
+        state.clone(),
+        &req,
+        payload.into_inner(),
+        |state, user, req, req_state| {
+            let merchant_id = user.merchant_id.clone();
+            metrics::request::record_role_operation_metrics(
+                user_role_core::delete_user_role(state, user, req, req_state),
+                &flow,
+                Some(merchant_id),
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn undo_delete_user_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<user_role_api::UndoDeleteUserRoleRequest>,
+) -> HttpResponse {
+    let flow = Flow::UndoDeleteUserRole;
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &req,
         payload.into_inner(),
-        user_role_core::delete_user_role,
+        |state, user, req, _| user_role_core::undo_delete_user_role(state, user, req),
         &auth::JWTAuth {
             permission: Permission::ProfileUserWrite,
         },
@@ -252,6 +759,7 @@ pub async fn delete_user_role(
 pub async fn get_role_information(
     state: web::Data<AppState>,
     http_req: HttpRequest,
+    query: web::Query<user_role_api::GetRoleInformationRequest>,
 ) -> HttpResponse {
     let flow = Flow::GetRolesInfo;
 
@@ -259,9 +767,9 @@ pub async fn get_role_information(
         flow,
         state.clone(),
         &http_req,
-        (),
-        |_, _: (), _, _| async move {
-            user_role_core::get_authorization_info_with_group_tag().await
+        query.into_inner(),
+        |state, _: (), request, _| async move {
+            user_role_core::get_authorization_info_with_group_tag(state, request).await
         },
         &auth::JWTAuth {
             permission: Permission::ProfileUserRead,
@@ -314,6 +822,80 @@ pub async fn list_users_in_lineage(
     .await
 }
 
+pub async fn export_rbac(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<user_role_api::ExportRbacRequest>,
+) -> HttpResponse {
+    let flow = Flow::ExportRbac;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        query.into_inner(),
+        |state, user_from_token, request, _| {
+            user_role_core::export_rbac(state, user_from_token, request)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn import_rbac(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<user_role_api::ImportRbacRequest>,
+    json_payload: web::Json<user_role_api::RbacExport>,
+) -> HttpResponse {
+    let flow = Flow::ImportRbac;
+    let on_conflict = query.into_inner().on_conflict;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user_from_token, document, _| {
+            role_core::import_rbac(state, user_from_token, on_conflict, document)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_roles_for_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ListRolesForUser;
+    let request_payload = user_role_api::ListRolesForUserRequest {
+        user_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        request_payload,
+        |state, user_from_token, request, _| {
+            user_role_core::list_roles_for_user(state, user_from_token, request)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn list_roles_with_info(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -398,6 +980,7 @@ pub async fn list_invitations_for_user(
     req: HttpRequest,
 ) -> HttpResponse {
     let flow = Flow::ListInvitationsForUser;
+    let token_purpose = state.conf.user.get_accept_invitation_token_purpose();
 
     Box::pin(api::server_wrap(
         flow,
@@ -407,7 +990,25 @@ pub async fn list_invitations_for_user(
         |state, user_id_from_token, _, _| {
             user_role_core::list_invitations_for_user(state, user_id_from_token)
         },
-        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::AcceptInvite),
+        &auth::SinglePurposeOrLoginTokenAuth(token_purpose),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn check_role_integrity(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<user_role_api::CheckRoleIntegrityRequest>,
+) -> HttpResponse {
+    let flow = Flow::CheckRoleIntegrity;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        query.into_inner(),
+        |state, _, request, _| user_role_core::check_role_integrity(state, request),
+        &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
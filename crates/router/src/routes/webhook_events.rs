@@ -3,11 +3,14 @@ use router_env::{instrument, tracing, Flow};
 
 use crate::{
     core::{api_locking, webhooks::webhook_events},
-    routes::AppState,
+    routes::{lock_utils, AppState},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookEventSubscription,
+        WebhookEventSubscriptionRetrieveRequestInternal,
+        WebhookEventSubscriptionUpdateRequestInternal, WebhookReplayRequest,
+        WebhookReplayRequestInternal,
     },
 };
 
@@ -130,3 +133,127 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplay))]
+#[cfg(feature = "v1")]
+pub async fn replay_webhooks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<WebhookReplayRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplay;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookReplayRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: json_payload.into_inner(),
+    };
+    // Concurrent replay requests of a merchant are processed one after the other, so that a
+    // single replay is scheduled for the merchant at a time
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: merchant_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::replay_webhooks(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventSubscriptionRetrieve))]
+pub async fn retrieve_webhook_event_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventSubscriptionRetrieve;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookEventSubscriptionRetrieveRequestInternal {
+        merchant_id: merchant_id.clone(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::retrieve_webhook_event_subscription(state, request_internal.merchant_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventSubscriptionUpdate))]
+pub async fn update_webhook_event_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<WebhookEventSubscription>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventSubscriptionUpdate;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookEventSubscriptionUpdateRequestInternal {
+        merchant_id: merchant_id.clone(),
+        subscription: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::update_webhook_event_subscription(
+                state,
+                request_internal.merchant_id,
+                request_internal.subscription,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -26,12 +26,13 @@ use common_utils::{
     request::RequestContent,
 };
 use error_stack::{report, Report, ResultExt};
+use futures::TryStreamExt;
 use hyperswitch_domain_models::router_data_v2::flow_common_types as common_types;
 pub use hyperswitch_domain_models::{
     api::{
-        ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
-        GenericLinks, PaymentLinkAction, PaymentLinkFormData, PaymentLinkStatusData,
-        RedirectionFormData,
+        ApplicationResponse, FileStream, GenericExpiredLinkData, GenericLinkFormData,
+        GenericLinkStatusData, GenericLinks, PaymentLinkAction, PaymentLinkFormData,
+        PaymentLinkStatusData, RedirectionFormData,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
@@ -192,193 +193,295 @@ where
                 ),
             );
 
-            let connector_request = match connector_request {
-                Some(connector_request) => Some(connector_request),
-                None => connector_integration
-                    .build_request(req, &state.conf.connectors)
-                    .inspect_err(|error| {
-                        if matches!(
-                            error.current_context(),
-                            &errors::ConnectorError::RequestEncodingFailed
-                                | &errors::ConnectorError::RequestEncodingFailedWithReason(_)
-                        ) {
-                            metrics::REQUEST_BUILD_FAILURE.add(
-                                1,
-                                router_env::metric_attributes!((
-                                    "connector",
-                                    req.connector.clone()
-                                )),
-                            )
-                        }
-                    })?,
+            let retry_policy = state
+                .conf
+                .connector_retry_policy
+                .get_retry_policy(&req.connector);
+            // Only idempotent flows are retried, so that a retry can never charge the customer twice
+            let max_attempts = if is_idempotent_flow::<T>() {
+                retry_policy.max_attempts.max(1)
+            } else {
+                1
             };
 
-            match connector_request {
-                Some(request) => {
-                    let masked_request_body = match &request.body {
-                        Some(request) => match request {
-                            RequestContent::Json(i)
-                            | RequestContent::FormUrlEncoded(i)
-                            | RequestContent::Xml(i) => i
-                                .masked_serialize()
-                                .unwrap_or(json!({ "error": "failed to mask serialize"})),
-                            RequestContent::FormData(_) => json!({"request_type": "FORM_DATA"}),
-                            RequestContent::RawBytes(_) => json!({"request_type": "RAW_BYTES"}),
-                        },
-                        None => serde_json::Value::Null,
-                    };
-                    let request_url = request.url.clone();
-                    let request_method = request.method;
-                    let current_time = Instant::now();
-                    let response =
-                        call_connector_api(state, request, "execute_connector_processing_step")
-                            .await;
-                    let external_latency = current_time.elapsed().as_millis();
-                    logger::info!(raw_connector_request=?masked_request_body);
-                    let status_code = response
-                        .as_ref()
-                        .map(|i| {
-                            i.as_ref()
-                                .map_or_else(|value| value.status_code, |value| value.status_code)
-                        })
-                        .unwrap_or_default();
-                    let mut connector_event = ConnectorEvent::new(
-                        state.tenant.tenant_id.clone(),
-                        req.connector.clone(),
-                        std::any::type_name::<T>(),
-                        masked_request_body,
-                        request_url,
-                        request_method,
-                        req.payment_id.clone(),
-                        req.merchant_id.clone(),
-                        state.request_id.as_ref(),
-                        external_latency,
-                        req.refund_id.clone(),
-                        req.dispute_id.clone(),
-                        status_code,
-                    );
+            let mut connector_request = connector_request;
+            let mut attempt = 1;
+            loop {
+                let response = call_connector_and_handle_response(
+                    state,
+                    &connector_integration,
+                    req,
+                    connector_request.take(),
+                    attempt - 1,
+                )
+                .await;
 
-                    match response {
-                        Ok(body) => {
-                            let response = match body {
-                                Ok(body) => {
-                                    let connector_http_status_code = Some(body.status_code);
-                                    let handle_response_result = connector_integration
-                                        .handle_response(req, Some(&mut connector_event), body)
-                                        .inspect_err(|error| {
-                                            if error.current_context()
-                                            == &errors::ConnectorError::ResponseDeserializationFailed
-                                        {
-                                            metrics::RESPONSE_DESERIALIZATION_FAILURE.add(
-
-                                                1,
-                                                router_env::metric_attributes!((
-                                                    "connector",
-                                                    req.connector.clone(),
-                                                )),
-                                            )
-                                        }
-                                        });
-                                    match handle_response_result {
-                                        Ok(mut data) => {
-                                            state.event_handler().log_event(&connector_event);
-                                            data.connector_http_status_code =
-                                                connector_http_status_code;
-                                            // Add up multiple external latencies in case of multiple external calls within the same request.
-                                            data.external_latency = Some(
-                                                data.external_latency
-                                                    .map_or(external_latency, |val| {
-                                                        val + external_latency
-                                                    }),
-                                            );
-                                            Ok(data)
-                                        }
-                                        Err(err) => {
-                                            connector_event
-                                                .set_error(json!({"error": err.to_string()}));
+                if attempt >= max_attempts || !is_transient_connector_failure(&response) {
+                    break response;
+                }
 
-                                            state.event_handler().log_event(&connector_event);
-                                            Err(err)
-                                        }
-                                    }?
-                                }
-                                Err(body) => {
-                                    router_data.connector_http_status_code = Some(body.status_code);
-                                    router_data.external_latency = Some(
-                                        router_data
-                                            .external_latency
+                metrics::AUTO_RETRY_TRANSIENT_CONNECTOR_ERROR_COUNT.add(
+                    1,
+                    router_env::metric_attributes!(("connector", req.connector.clone())),
+                );
+                logger::warn!(
+                    connector = %req.connector,
+                    attempt,
+                    "Retrying connector call due to a transient error"
+                );
+                tokio::time::sleep(retry_policy.get_backoff_duration(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Logs the connector event, and stores the masked connector request and response against the
+/// payment if the merchant has opted in to store them
+async fn log_connector_event<T, Req, Resp>(
+    state: &SessionState,
+    req: &types::RouterData<T, Req, Resp>,
+    connector_event: &ConnectorEvent,
+) {
+    state.event_handler().log_event(connector_event);
+
+    if payments::helpers::should_store_raw_connector_payloads(state, &req.merchant_id).await {
+        payments::helpers::store_raw_connector_payload(
+            state,
+            &req.merchant_id,
+            &req.payment_id,
+            connector_event,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to store the raw connector payload"))
+        .ok();
+    }
+}
+
+/// Builds the connector request (if not already built), sends it to the connector and handles the response
+#[instrument(skip_all)]
+async fn call_connector_and_handle_response<
+    T,
+    ResourceCommonData: Clone + RouterDataConversion<T, Req, Resp> + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+>(
+    state: &SessionState,
+    connector_integration: &BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
+    req: &types::RouterData<T, Req, Resp>,
+    connector_request: Option<Request>,
+    retry_count: u8,
+) -> CustomResult<types::RouterData<T, Req, Resp>, errors::ConnectorError>
+where
+    T: Clone + Debug + 'static,
+{
+    let mut router_data = req.clone();
+    let connector_request = match connector_request {
+        Some(connector_request) => Some(connector_request),
+        None => connector_integration
+            .build_request(req, &state.conf.connectors)
+            .inspect_err(|error| {
+                if matches!(
+                    error.current_context(),
+                    &errors::ConnectorError::RequestEncodingFailed
+                        | &errors::ConnectorError::RequestEncodingFailedWithReason(_)
+                ) {
+                    metrics::REQUEST_BUILD_FAILURE.add(
+                        1,
+                        router_env::metric_attributes!(("connector", req.connector.clone())),
+                    )
+                }
+            })?,
+    };
+
+    match connector_request {
+        Some(request) => {
+            let masked_request_body = match &request.body {
+                Some(request) => match request {
+                    RequestContent::Json(i)
+                    | RequestContent::FormUrlEncoded(i)
+                    | RequestContent::Xml(i) => i
+                        .masked_serialize()
+                        .unwrap_or(json!({ "error": "failed to mask serialize"})),
+                    RequestContent::FormData(_) => json!({"request_type": "FORM_DATA"}),
+                    RequestContent::RawBytes(_) => json!({"request_type": "RAW_BYTES"}),
+                },
+                None => serde_json::Value::Null,
+            };
+            let request_url = request.url.clone();
+            let request_method = request.method;
+            let current_time = Instant::now();
+            let response =
+                call_connector_api(state, request, "execute_connector_processing_step").await;
+            let external_latency = current_time.elapsed().as_millis();
+            logger::info!(raw_connector_request=?masked_request_body);
+            let status_code = response
+                .as_ref()
+                .map(|i| {
+                    i.as_ref()
+                        .map_or_else(|value| value.status_code, |value| value.status_code)
+                })
+                .unwrap_or_default();
+            let mut connector_event = ConnectorEvent::new(
+                state.tenant.tenant_id.clone(),
+                req.connector.clone(),
+                std::any::type_name::<T>(),
+                masked_request_body,
+                request_url,
+                request_method,
+                req.payment_id.clone(),
+                req.merchant_id.clone(),
+                state.request_id.as_ref(),
+                external_latency,
+                req.refund_id.clone(),
+                req.dispute_id.clone(),
+                status_code,
+            );
+            // Retried calls are recorded as separate connector events, told apart by the retry count
+            connector_event.set_retry_count(retry_count);
+
+            match response {
+                Ok(body) => {
+                    let response = match body {
+                        Ok(body) => {
+                            let connector_http_status_code = Some(body.status_code);
+                            let handle_response_result = connector_integration
+                                .handle_response(req, Some(&mut connector_event), body)
+                                .inspect_err(|error| {
+                                    if error.current_context()
+                                        == &errors::ConnectorError::ResponseDeserializationFailed
+                                    {
+                                        metrics::RESPONSE_DESERIALIZATION_FAILURE.add(
+                                            1,
+                                            router_env::metric_attributes!((
+                                                "connector",
+                                                req.connector.clone(),
+                                            )),
+                                        )
+                                    }
+                                });
+                            match handle_response_result {
+                                Ok(mut data) => {
+                                    log_connector_event(state, req, &connector_event).await;
+                                    data.connector_http_status_code = connector_http_status_code;
+                                    // Add up multiple external latencies in case of multiple external calls within the same request.
+                                    data.external_latency = Some(
+                                        data.external_latency
                                             .map_or(external_latency, |val| val + external_latency),
                                     );
-                                    metrics::CONNECTOR_ERROR_RESPONSE_COUNT.add(
-                                        1,
-                                        router_env::metric_attributes!((
-                                            "connector",
-                                            req.connector.clone(),
-                                        )),
-                                    );
+                                    Ok(data)
+                                }
+                                Err(err) => {
+                                    connector_event.set_error(json!({"error": err.to_string()}));
 
-                                    let error = match body.status_code {
-                                        500..=511 => {
-                                            let error_res = connector_integration
-                                                .get_5xx_error_response(
-                                                    body,
-                                                    Some(&mut connector_event),
-                                                )?;
-                                            state.event_handler().log_event(&connector_event);
-                                            error_res
-                                        }
-                                        _ => {
-                                            let error_res = connector_integration
-                                                .get_error_response(
-                                                    body,
-                                                    Some(&mut connector_event),
-                                                )?;
-                                            if let Some(status) = error_res.attempt_status {
-                                                router_data.status = status;
-                                            };
-                                            state.event_handler().log_event(&connector_event);
-                                            error_res
-                                        }
+                                    log_connector_event(state, req, &connector_event).await;
+                                    Err(err)
+                                }
+                            }?
+                        }
+                        Err(body) => {
+                            router_data.connector_http_status_code = Some(body.status_code);
+                            router_data.external_latency = Some(
+                                router_data
+                                    .external_latency
+                                    .map_or(external_latency, |val| val + external_latency),
+                            );
+                            metrics::CONNECTOR_ERROR_RESPONSE_COUNT.add(
+                                1,
+                                router_env::metric_attributes!((
+                                    "connector",
+                                    req.connector.clone(),
+                                )),
+                            );
+
+                            let error = match body.status_code {
+                                500..=511 => {
+                                    let error_res = connector_integration
+                                        .get_5xx_error_response(body, Some(&mut connector_event))?;
+                                    log_connector_event(state, req, &connector_event).await;
+                                    error_res
+                                }
+                                _ => {
+                                    let error_res = connector_integration
+                                        .get_error_response(body, Some(&mut connector_event))?;
+                                    if let Some(status) = error_res.attempt_status {
+                                        router_data.status = status;
                                     };
-
-                                    router_data.response = Err(error);
-
-                                    router_data
+                                    log_connector_event(state, req, &connector_event).await;
+                                    error_res
                                 }
                             };
-                            Ok(response)
-                        }
-                        Err(error) => {
-                            connector_event.set_error(json!({"error": error.to_string()}));
-                            state.event_handler().log_event(&connector_event);
-                            if error.current_context().is_upstream_timeout() {
-                                let error_response = ErrorResponse {
-                                    code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
-                                    message: consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string(),
-                                    reason: Some(consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string()),
-                                    status_code: 504,
-                                    attempt_status: None,
-                                    connector_transaction_id: None,
-                                };
-                                router_data.response = Err(error_response);
-                                router_data.connector_http_status_code = Some(504);
-                                router_data.external_latency = Some(
-                                    router_data
-                                        .external_latency
-                                        .map_or(external_latency, |val| val + external_latency),
-                                );
-                                Ok(router_data)
-                            } else {
-                                Err(error.change_context(
-                                    errors::ConnectorError::ProcessingStepFailed(None),
-                                ))
-                            }
+
+                            router_data.response = Err(error);
+
+                            router_data
                         }
+                    };
+                    Ok(response)
+                }
+                Err(error) => {
+                    connector_event.set_error(json!({"error": error.to_string()}));
+                    log_connector_event(state, req, &connector_event).await;
+                    if error.current_context().is_upstream_timeout() {
+                        let error_response = ErrorResponse {
+                            code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
+                            message: consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string(),
+                            reason: Some(consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string()),
+                            status_code: 504,
+                            attempt_status: None,
+                            connector_transaction_id: None,
+                        };
+                        router_data.response = Err(error_response);
+                        router_data.connector_http_status_code = Some(504);
+                        router_data.external_latency = Some(
+                            router_data
+                                .external_latency
+                                .map_or(external_latency, |val| val + external_latency),
+                        );
+                        Ok(router_data)
+                    } else {
+                        Err(error
+                            .change_context(errors::ConnectorError::ProcessingStepFailed(None)))
                     }
                 }
-                None => Ok(router_data),
             }
         }
+        None => Ok(router_data),
+    }
+}
+
+/// Flows which only fetch data from the connector, and can be repeated without side effects
+fn is_idempotent_flow<T: 'static>() -> bool {
+    use hyperswitch_domain_models::router_flow_types as flows;
+
+    let flow = std::any::TypeId::of::<T>();
+    [
+        std::any::TypeId::of::<flows::PSync>(),
+        std::any::TypeId::of::<flows::RSync>(),
+        std::any::TypeId::of::<flows::PoSync>(),
+        std::any::TypeId::of::<flows::AccessTokenAuth>(),
+        std::any::TypeId::of::<flows::Balance>(),
+        std::any::TypeId::of::<flows::Retrieve>(),
+        std::any::TypeId::of::<flows::VerifyWebhookSource>(),
+    ]
+    .contains(&flow)
+}
+
+/// A connector call has failed transiently if the connector could not be reached, or if the
+/// connector responded with a 5xx status code (this includes request timeouts)
+fn is_transient_connector_failure<T, Req, Resp>(
+    response: &CustomResult<types::RouterData<T, Req, Resp>, errors::ConnectorError>,
+) -> bool {
+    match response {
+        Ok(router_data) => router_data
+            .response
+            .as_ref()
+            .err()
+            .is_some_and(|error_response| (500..=599).contains(&error_response.status_code)),
+        Err(error) => matches!(
+            error.current_context(),
+            errors::ConnectorError::ProcessingStepFailed(None)
+        ),
     }
 }
 
@@ -961,6 +1064,7 @@ where
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
         }
+        Ok(ApplicationResponse::FileStream(file_stream)) => http_response_file_stream(file_stream),
         Ok(ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => http_redirect_response(res, response),
@@ -1185,6 +1289,12 @@ pub fn http_response_file_data<T: body::MessageBody + 'static>(
     HttpResponse::Ok().content_type(content_type).body(res)
 }
 
+pub fn http_response_file_stream(file_stream: FileStream) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(file_stream.content_type)
+        .streaming(file_stream.stream.map_ok(web::Bytes::from))
+}
+
 pub fn http_response_html_data<T: body::MessageBody + 'static>(
     res: T,
     optional_headers: Option<HashSet<(&'static str, String)>>,
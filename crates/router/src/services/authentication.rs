@@ -138,6 +138,8 @@ pub enum AuthenticationType {
     SinglePurposeJwt {
         user_id: String,
         purpose: TokenPurpose,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        impersonator_user_id: Option<String>,
     },
     SinglePurposeOrLoginJwt {
         user_id: String,
@@ -207,6 +209,7 @@ pub struct UserFromSinglePurposeToken {
     pub origin: domain::Origin,
     pub path: Vec<TokenPurpose>,
     pub tenant_id: Option<id_type::TenantId>,
+    pub impersonator_user_id: Option<String>,
 }
 
 #[cfg(feature = "olap")]
@@ -218,6 +221,21 @@ pub struct SinglePurposeToken {
     pub path: Vec<TokenPurpose>,
     pub exp: u64,
     pub tenant_id: Option<id_type::TenantId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonator_user_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonated_user_role: Option<ImpersonatedUserRole>,
+}
+
+/// The role of the impersonated user, and the entity it is held in, which requests made using an
+/// impersonation token are authorized with
+#[cfg(feature = "olap")]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct ImpersonatedUserRole {
+    pub role_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
 }
 
 #[cfg(feature = "olap")]
@@ -230,8 +248,11 @@ impl SinglePurposeToken {
         path: Vec<TokenPurpose>,
         tenant_id: Option<id_type::TenantId>,
     ) -> UserResult<String> {
-        let exp_duration =
-            std::time::Duration::from_secs(consts::SINGLE_PURPOSE_TOKEN_TIME_IN_SECS);
+        let exp_duration = std::time::Duration::from_secs(
+            settings
+                .user
+                .get_single_purpose_token_expiry_in_secs(&purpose),
+        );
         let exp = jwt::generate_exp(exp_duration)?.as_secs();
         let token_payload = Self {
             user_id,
@@ -240,9 +261,106 @@ impl SinglePurposeToken {
             exp,
             path,
             tenant_id,
+            impersonator_user_id: None,
+            impersonated_user_role: None,
         };
         jwt::generate_jwt(&token_payload, settings).await
     }
+
+    /// Issues a token which lets `impersonator_user_id` act as `user_id`. The token is bound to
+    /// the impersonation origin, which has no further flows, so it cannot be renewed.
+    pub async fn new_impersonation_token(
+        user_id: String,
+        impersonator_user_id: String,
+        impersonated_user_role: ImpersonatedUserRole,
+        settings: &Settings,
+        tenant_id: Option<id_type::TenantId>,
+    ) -> UserResult<String> {
+        let purpose = TokenPurpose::Impersonation;
+        let exp_duration = std::time::Duration::from_secs(
+            settings
+                .user
+                .get_single_purpose_token_expiry_in_secs(&purpose),
+        );
+        let exp = jwt::generate_exp(exp_duration)?.as_secs();
+        let token_payload = Self {
+            user_id,
+            purpose,
+            origin: domain::Origin::Impersonation,
+            exp,
+            path: vec![],
+            tenant_id,
+            impersonator_user_id: Some(impersonator_user_id),
+            impersonated_user_role: Some(impersonated_user_role),
+        };
+        jwt::generate_jwt(&token_payload, settings).await
+    }
+
+    /// Tokens issued before the expiry of their purpose was shortened must not outlive the
+    /// currently configured expiry
+    fn validate_expiry_for_purpose(
+        &self,
+        user_settings: &crate::configs::settings::UserSettings,
+    ) -> RouterResult<()> {
+        let max_exp = std::time::SystemTime::now()
+            .checked_add(std::time::Duration::from_secs(
+                user_settings.get_single_purpose_token_expiry_in_secs(&self.purpose),
+            ))
+            .and_then(|max_exp| max_exp.duration_since(std::time::UNIX_EPOCH).ok())
+            .ok_or(errors::ApiErrorResponse::InternalServerError)?
+            .as_secs();
+
+        if self.exp > max_exp {
+            return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+        }
+
+        Ok(())
+    }
+
+    /// Tags requests made using an impersonation token, so that they can be told apart from the
+    /// requests made by the impersonated user
+    fn log_impersonation(&self) {
+        if let Some(impersonator_user_id) = &self.impersonator_user_id {
+            logger::info!(
+                %impersonator_user_id,
+                impersonated_user_id = %self.user_id,
+                "Request made using impersonation token"
+            );
+        }
+    }
+
+    /// Returns the login token which an impersonation token stands in for, if the token is an
+    /// impersonation token which has not been revoked
+    async fn get_impersonation_auth_token<A>(&self, state: &A) -> RouterResult<Option<AuthToken>>
+    where
+        A: SessionStateInfo + Sync,
+    {
+        if self.purpose != TokenPurpose::Impersonation {
+            return Ok(None);
+        }
+        let (Some(impersonator_user_id), Some(impersonated_user_role)) =
+            (&self.impersonator_user_id, &self.impersonated_user_role)
+        else {
+            return Ok(None);
+        };
+
+        // Impersonation tokens are revoked along with the sessions of the impersonator
+        if blacklist::check_user_in_blacklist(state, impersonator_user_id, self.exp).await? {
+            return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+        }
+        self.validate_expiry_for_purpose(&state.conf().user)?;
+        self.log_impersonation();
+
+        Ok(Some(AuthToken {
+            user_id: self.user_id.clone(),
+            merchant_id: impersonated_user_role.merchant_id.clone(),
+            role_id: impersonated_user_role.role_id.clone(),
+            exp: self.exp,
+            org_id: impersonated_user_role.org_id.clone(),
+            profile_id: impersonated_user_role.profile_id.clone(),
+            tenant_id: self.tenant_id.clone(),
+        }))
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -266,9 +384,25 @@ impl AuthToken {
         org_id: id_type::OrganizationId,
         profile_id: id_type::ProfileId,
         tenant_id: Option<id_type::TenantId>,
+        role_expires_at: Option<time::PrimitiveDateTime>,
     ) -> UserResult<String> {
         let exp_duration = std::time::Duration::from_secs(consts::JWT_TOKEN_TIME_IN_SECS);
         let exp = jwt::generate_exp(exp_duration)?.as_secs();
+
+        // Tokens of time bound role assignments do not outlive the assignment
+        let exp = match role_expires_at {
+            Some(role_expires_at) => {
+                let role_expires_at = role_expires_at.assume_utc().unix_timestamp();
+                if role_expires_at <= date_time::now_unix_timestamp() {
+                    return Err(errors::UserErrors::UserRoleExpired.into());
+                }
+                u64::try_from(role_expires_at)
+                    .change_context(errors::UserErrors::InternalServerError)?
+                    .min(exp)
+            }
+            None => exp,
+        };
+
         let token_payload = Self {
             user_id,
             merchant_id,
@@ -888,6 +1022,8 @@ where
         if self.0 != payload.purpose {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
+        payload.validate_expiry_for_purpose(&state.conf().user)?;
+        payload.log_impersonation();
 
         Ok((
             UserFromSinglePurposeToken {
@@ -895,10 +1031,12 @@ where
                 origin: payload.origin.clone(),
                 path: payload.path,
                 tenant_id: payload.tenant_id,
+                impersonator_user_id: payload.impersonator_user_id.clone(),
             },
             AuthenticationType::SinglePurposeJwt {
                 user_id: payload.user_id,
                 purpose: payload.purpose,
+                impersonator_user_id: payload.impersonator_user_id,
             },
         ))
     }
@@ -927,6 +1065,8 @@ where
         if self.0 != payload.purpose {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
+        payload.validate_expiry_for_purpose(&state.conf().user)?;
+        payload.log_impersonation();
 
         Ok((
             Some(UserFromSinglePurposeToken {
@@ -934,10 +1074,12 @@ where
                 origin: payload.origin.clone(),
                 path: payload.path,
                 tenant_id: payload.tenant_id,
+                impersonator_user_id: payload.impersonator_user_id.clone(),
             }),
             AuthenticationType::SinglePurposeJwt {
                 user_id: payload.user_id,
                 purpose: payload.purpose,
+                impersonator_user_id: payload.impersonator_user_id,
             },
         ))
     }
@@ -2095,22 +2237,25 @@ where
     }
 }
 
+/// JWT authentication which requires the role of the user to hold a permission, or a set of
+/// permissions with [`authorization::PermissionSet`]
 #[derive(Debug)]
-pub(crate) struct JWTAuth {
-    pub permission: Permission,
+pub(crate) struct JWTAuth<P = Permission> {
+    pub permission: P,
 }
 
 #[async_trait]
-impl<A> AuthenticateAndFetch<(), A> for JWTAuth
+impl<A, P> AuthenticateAndFetch<(), A> for JWTAuth<P>
 where
     A: SessionStateInfo + Sync,
+    P: authorization::PermissionRequirement,
 {
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<((), AuthenticationType)> {
-        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_auth_token(request_headers, state).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2121,7 +2266,11 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission_and_log_denial(
+            self.permission,
+            &role_info,
+            &payload.user_id,
+        )?;
 
         Ok((
             (),
@@ -2135,16 +2284,17 @@ where
 
 #[cfg(feature = "olap")]
 #[async_trait]
-impl<A> AuthenticateAndFetch<UserFromToken, A> for JWTAuth
+impl<A, P> AuthenticateAndFetch<UserFromToken, A> for JWTAuth<P>
 where
     A: SessionStateInfo + Sync,
+    P: authorization::PermissionRequirement,
 {
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(UserFromToken, AuthenticationType)> {
-        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_auth_token(request_headers, state).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2154,7 +2304,11 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission_and_log_denial(
+            self.permission,
+            &role_info,
+            &payload.user_id,
+        )?;
 
         Ok((
             UserFromToken {
@@ -2175,16 +2329,17 @@ where
 
 #[cfg(feature = "olap")]
 #[async_trait]
-impl<A> AuthenticateAndFetch<AuthenticationDataWithMultipleProfiles, A> for JWTAuth
+impl<A, P> AuthenticateAndFetch<AuthenticationDataWithMultipleProfiles, A> for JWTAuth<P>
 where
     A: SessionStateInfo + Sync,
+    P: authorization::PermissionRequirement,
 {
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationDataWithMultipleProfiles, AuthenticationType)> {
-        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_auth_token(request_headers, state).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2194,7 +2349,11 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission_and_log_denial(
+            self.permission,
+            &role_info,
+            &payload.user_id,
+        )?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3025,18 +3184,39 @@ where
     decode_jwt(&final_token, state).await
 }
 
+/// Parses the login token of the request. Impersonation tokens are accepted in its place, and are
+/// authorized with the role of the impersonated user.
+pub async fn parse_auth_token<A>(headers: &HeaderMap, state: &A) -> RouterResult<AuthToken>
+where
+    A: SessionStateInfo + Sync,
+{
+    let auth_token_result = parse_jwt_payload::<A, AuthToken>(headers, state).await;
+
+    #[cfg(feature = "olap")]
+    if auth_token_result.is_err() {
+        if let Ok(payload) = parse_jwt_payload::<A, SinglePurposeToken>(headers, state).await {
+            if let Some(auth_token) = payload.get_impersonation_auth_token(state).await? {
+                return Ok(auth_token);
+            }
+        }
+    }
+
+    auth_token_result
+}
+
 #[cfg(feature = "v1")]
 #[async_trait]
-impl<A> AuthenticateAndFetch<AuthenticationData, A> for JWTAuth
+impl<A, P> AuthenticateAndFetch<AuthenticationData, A> for JWTAuth<P>
 where
     A: SessionStateInfo + Sync,
+    P: authorization::PermissionRequirement,
 {
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_auth_token(request_headers, state).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -3046,7 +3226,11 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission_and_log_denial(
+            self.permission,
+            &role_info,
+            &payload.user_id,
+        )?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3089,16 +3273,17 @@ where
 
 #[cfg(feature = "v2")]
 #[async_trait]
-impl<A> AuthenticateAndFetch<AuthenticationData, A> for JWTAuth
+impl<A, P> AuthenticateAndFetch<AuthenticationData, A> for JWTAuth<P>
 where
     A: SessionStateInfo + Sync,
+    P: authorization::PermissionRequirement,
 {
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_auth_token(request_headers, state).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -3111,7 +3296,11 @@ where
             .get_id_type_from_header::<id_type::ProfileId>(headers::X_PROFILE_ID)?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission_and_log_denial(
+            self.permission,
+            &role_info,
+            &payload.user_id,
+        )?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3166,16 +3355,17 @@ pub type AuthenticationDataWithUserId = (AuthenticationData, String);
 
 #[cfg(feature = "v1")]
 #[async_trait]
-impl<A> AuthenticateAndFetch<AuthenticationDataWithUserId, A> for JWTAuth
+impl<A, P> AuthenticateAndFetch<AuthenticationDataWithUserId, A> for JWTAuth<P>
 where
     A: SessionStateInfo + Sync,
+    P: authorization::PermissionRequirement,
 {
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationDataWithUserId, AuthenticationType)> {
-        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_auth_token(request_headers, state).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -3185,7 +3375,11 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission_and_log_denial(
+            self.permission,
+            &role_info,
+            &payload.user_id,
+        )?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3616,16 +3810,17 @@ where
 
 #[cfg(feature = "recon")]
 #[async_trait]
-impl<A> AuthenticateAndFetch<AuthenticationDataWithUser, A> for JWTAuth
+impl<A, P> AuthenticateAndFetch<AuthenticationDataWithUser, A> for JWTAuth<P>
 where
     A: SessionStateInfo + Sync,
+    P: authorization::PermissionRequirement,
 {
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationDataWithUser, AuthenticationType)> {
-        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_auth_token(request_headers, state).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -3634,7 +3829,11 @@ where
             &state.session_state().tenant.tenant_id,
         )?;
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission_and_log_denial(
+            self.permission,
+            &role_info,
+            &payload.user_id,
+        )?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3777,16 +3976,17 @@ fn throw_error_if_platform_merchant_authentication_required(
 
 #[cfg(feature = "recon")]
 #[async_trait]
-impl<A> AuthenticateAndFetch<UserFromTokenWithRoleInfo, A> for JWTAuth
+impl<A, P> AuthenticateAndFetch<UserFromTokenWithRoleInfo, A> for JWTAuth<P>
 where
     A: SessionStateInfo + Sync,
+    P: authorization::PermissionRequirement,
 {
     async fn authenticate_and_fetch(
         &self,
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(UserFromTokenWithRoleInfo, AuthenticationType)> {
-        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_auth_token(request_headers, state).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -3795,7 +3995,11 @@ where
             &state.session_state().tenant.tenant_id,
         )?;
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission_and_log_denial(
+            self.permission,
+            &role_info,
+            &payload.user_id,
+        )?;
 
         let user = UserFromToken {
             user_id: payload.user_id.clone(),
@@ -4,6 +4,7 @@ use common_utils::id_type;
 use error_stack::ResultExt;
 use redis_interface::RedisConnectionPool;
 use router_env::logger;
+use strum::IntoEnumIterator;
 
 use super::authentication::AuthToken;
 use crate::{
@@ -127,6 +128,92 @@ pub fn check_permission(
         )
 }
 
+/// Checks the permission like [`check_permission`], additionally logging the permissions held by
+/// the user when the request is denied, to help investigate access issues
+pub fn check_permission_and_log_denial<P: PermissionRequirement>(
+    required_permission: P,
+    role_info: &roles::RoleInfo,
+    user_id: &str,
+) -> RouterResult<()> {
+    required_permission
+        .is_satisfied_by(role_info)
+        .then_some(())
+        .ok_or(
+            ApiErrorResponse::AccessForbidden {
+                resource: required_permission.to_string(),
+            }
+            .into(),
+        )
+        .inspect_err(|_| {
+            let held_permissions = permissions::Permission::iter()
+                .filter(|permission| role_info.check_permission_exists(*permission))
+                .map(|permission| permission.to_string())
+                .collect::<Vec<_>>();
+
+            logger::warn!(
+                user_id,
+                role_id = role_info.get_role_id(),
+                required_permission = %required_permission,
+                ?held_permissions,
+                "Permission denied"
+            );
+        })
+}
+
+/// Permissions which are required to be held by the role of a user to access an API
+pub trait PermissionRequirement: Copy + std::fmt::Display + Send + Sync {
+    fn is_satisfied_by(&self, role_info: &roles::RoleInfo) -> bool;
+}
+
+impl PermissionRequirement for permissions::Permission {
+    fn is_satisfied_by(&self, role_info: &roles::RoleInfo) -> bool {
+        role_info.check_permission_exists(*self)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PermissionCombinator {
+    All,
+    Any,
+}
+
+/// A set of permissions which are required either all together or any one of them, depending on
+/// the combinator. An empty set is never satisfied.
+#[derive(Debug, Clone, Copy)]
+pub struct PermissionSet {
+    pub permissions: &'static [permissions::Permission],
+    pub combinator: PermissionCombinator,
+}
+
+impl PermissionRequirement for PermissionSet {
+    fn is_satisfied_by(&self, role_info: &roles::RoleInfo) -> bool {
+        if self.permissions.is_empty() {
+            return false;
+        }
+
+        let mut permission_checks = self
+            .permissions
+            .iter()
+            .map(|permission| role_info.check_permission_exists(*permission));
+
+        match self.combinator {
+            PermissionCombinator::All => permission_checks.all(|exists| exists),
+            PermissionCombinator::Any => permission_checks.any(|exists| exists),
+        }
+    }
+}
+
+impl std::fmt::Display for PermissionSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let permissions = self
+            .permissions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        write!(f, "{}", permissions.join(", "))
+    }
+}
+
 pub fn check_tenant(
     token_tenant_id: Option<id_type::TenantId>,
     header_tenant_id: &id_type::TenantId,
@@ -10,7 +10,7 @@ pub fn get_group_authorization_info() -> Vec<GroupInfo> {
 }
 
 // TODO: To be deprecated
-fn get_group_info_from_permission_group(group: PermissionGroup) -> GroupInfo {
+pub fn get_group_info_from_permission_group(group: PermissionGroup) -> GroupInfo {
     let description = get_group_description(group);
     GroupInfo { group, description }
 }
@@ -59,3 +59,119 @@ pub fn get_parent_group_description(group: ParentGroup) -> &'static str {
         ParentGroup::ReconReports => "View, manage reconciliation reports and analytics",
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DescriptionLanguage {
+    #[default]
+    English,
+    German,
+    French,
+}
+
+impl DescriptionLanguage {
+    /// Resolves the language from a locale or `Accept-Language` value, unsupported languages
+    /// fall back to English
+    pub fn from_locale(locale: &str) -> Self {
+        let primary_language = locale
+            .split([',', ';', '-', '_'])
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+
+        match primary_language.as_str() {
+            "de" => Self::German,
+            "fr" => Self::French,
+            _ => Self::English,
+        }
+    }
+}
+
+pub fn get_localized_group_description(
+    group: PermissionGroup,
+    language: DescriptionLanguage,
+) -> &'static str {
+    match language {
+        DescriptionLanguage::English => get_group_description(group),
+        DescriptionLanguage::German => match group {
+            PermissionGroup::OperationsView => {
+                "Zahlungen, Erstattungen, Auszahlungen, Mandate, Streitfälle und Kunden anzeigen"
+            }
+            PermissionGroup::OperationsManage => "Zahlungen, Erstattungen, Auszahlungen, Mandate, Streitfälle und Kunden erstellen, ändern und löschen",
+            PermissionGroup::ConnectorsView => "Verbundene Zahlungsabwickler, Auszahlungsabwickler und Betrugs- & Risikomanager anzeigen",
+            PermissionGroup::ConnectorsManage => "Konnektoren wie Zahlungsabwickler, Auszahlungsabwickler und Betrugs- & Risikomanager erstellen, ändern und löschen",
+            PermissionGroup::WorkflowsView => {
+                "Routing, 3DS-Entscheidungsmanager und Aufschlags-Entscheidungsmanager anzeigen"
+            }
+            PermissionGroup::WorkflowsManage => "Routing, 3DS-Entscheidungsmanager und Aufschlags-Entscheidungsmanager erstellen, ändern und löschen",
+            PermissionGroup::AnalyticsView => "Analysen anzeigen",
+            PermissionGroup::UsersView => "Benutzer anzeigen",
+            PermissionGroup::UsersManage => "Benutzer verwalten und in das Team einladen",
+            PermissionGroup::MerchantDetailsView | PermissionGroup::AccountView => {
+                "Händlerdetails anzeigen"
+            }
+            PermissionGroup::MerchantDetailsManage | PermissionGroup::AccountManage => "Händlerdetails wie API-Schlüssel, Webhooks usw. erstellen, ändern und löschen",
+            PermissionGroup::OrganizationManage => "Aufgaben auf Organisationsebene verwalten, z. B. neue Händlerkonten und Rollen auf Organisationsebene erstellen",
+            PermissionGroup::ReconReportsView => "Abstimmungsberichte und Analysen anzeigen",
+            PermissionGroup::ReconReportsManage => "Abstimmungsberichte verwalten",
+            PermissionGroup::ReconOpsView => "Alle Abstimmungsvorgänge einschließlich Berichten und Analysen anzeigen",
+            PermissionGroup::ReconOpsManage => {
+                "Alle Abstimmungsvorgänge einschließlich Berichten und Analysen verwalten"
+            }
+        },
+        DescriptionLanguage::French => match group {
+            PermissionGroup::OperationsView => "Consulter les paiements, remboursements, versements, mandats, litiges et clients",
+            PermissionGroup::OperationsManage => "Créer, modifier et supprimer les paiements, remboursements, versements, mandats, litiges et clients",
+            PermissionGroup::ConnectorsView => "Consulter les processeurs de paiement, processeurs de versement et gestionnaires de fraude et de risque connectés",
+            PermissionGroup::ConnectorsManage => "Créer, modifier et supprimer les connecteurs tels que les processeurs de paiement, processeurs de versement et gestionnaires de fraude et de risque",
+            PermissionGroup::WorkflowsView => "Consulter le routage, le gestionnaire de décision 3DS et le gestionnaire de décision de surcharge",
+            PermissionGroup::WorkflowsManage => "Créer, modifier et supprimer le routage, le gestionnaire de décision 3DS et le gestionnaire de décision de surcharge",
+            PermissionGroup::AnalyticsView => "Consulter les analyses",
+            PermissionGroup::UsersView => "Consulter les utilisateurs",
+            PermissionGroup::UsersManage => "Gérer et inviter des utilisateurs dans l'équipe",
+            PermissionGroup::MerchantDetailsView | PermissionGroup::AccountView => {
+                "Consulter les informations du marchand"
+            }
+            PermissionGroup::MerchantDetailsManage | PermissionGroup::AccountManage => "Créer, modifier et supprimer les informations du marchand comme les clés API, les webhooks, etc",
+            PermissionGroup::OrganizationManage => "Gérer les tâches au niveau de l'organisation comme la création de comptes marchands, de rôles d'organisation, etc",
+            PermissionGroup::ReconReportsView => {
+                "Consulter les rapports et analyses de rapprochement"
+            }
+            PermissionGroup::ReconReportsManage => "Gérer les rapports de rapprochement",
+            PermissionGroup::ReconOpsView => "Consulter toutes les opérations de rapprochement, y compris les rapports et analyses",
+            PermissionGroup::ReconOpsManage => "Gérer toutes les opérations de rapprochement, y compris les rapports et analyses",
+        },
+    }
+}
+
+pub fn get_localized_parent_group_name(
+    group: ParentGroup,
+    language: DescriptionLanguage,
+) -> &'static str {
+    match (language, group) {
+        (DescriptionLanguage::English, ParentGroup::Operations) => "Operations",
+        (DescriptionLanguage::English, ParentGroup::Connectors) => "Connectors",
+        (DescriptionLanguage::English, ParentGroup::Workflows) => "Workflows",
+        (DescriptionLanguage::English, ParentGroup::Analytics) => "Analytics",
+        (DescriptionLanguage::English, ParentGroup::Users) => "Users",
+        (DescriptionLanguage::English, ParentGroup::Account) => "Account",
+        (DescriptionLanguage::English, ParentGroup::ReconOps) => "Reconciliation Operations",
+        (DescriptionLanguage::English, ParentGroup::ReconReports) => "Reconciliation Reports",
+        (DescriptionLanguage::German, ParentGroup::Operations) => "Vorgänge",
+        (DescriptionLanguage::German, ParentGroup::Connectors) => "Konnektoren",
+        (DescriptionLanguage::German, ParentGroup::Workflows) => "Workflows",
+        (DescriptionLanguage::German, ParentGroup::Analytics) => "Analysen",
+        (DescriptionLanguage::German, ParentGroup::Users) => "Benutzer",
+        (DescriptionLanguage::German, ParentGroup::Account) => "Konto",
+        (DescriptionLanguage::German, ParentGroup::ReconOps) => "Abstimmungsvorgänge",
+        (DescriptionLanguage::German, ParentGroup::ReconReports) => "Abstimmungsberichte",
+        (DescriptionLanguage::French, ParentGroup::Operations) => "Opérations",
+        (DescriptionLanguage::French, ParentGroup::Connectors) => "Connecteurs",
+        (DescriptionLanguage::French, ParentGroup::Workflows) => "Flux de travail",
+        (DescriptionLanguage::French, ParentGroup::Analytics) => "Analyses",
+        (DescriptionLanguage::French, ParentGroup::Users) => "Utilisateurs",
+        (DescriptionLanguage::French, ParentGroup::Account) => "Compte",
+        (DescriptionLanguage::French, ParentGroup::ReconOps) => "Opérations de rapprochement",
+        (DescriptionLanguage::French, ParentGroup::ReconReports) => "Rapports de rapprochement",
+    }
+}
@@ -154,11 +154,12 @@ impl ParentGroupExt for ParentGroup {
     }
 }
 
-pub static OPERATIONS: [Resource; 8] = [
+pub static OPERATIONS: [Resource; 9] = [
     Resource::Payment,
     Resource::Refund,
     Resource::Mandate,
     Resource::Dispute,
+    Resource::DisputeEvidence,
     Resource::Customer,
     Resource::Payout,
     Resource::Report,
@@ -1,5 +1,6 @@
 use common_enums::{EntityType, PermissionScope, Resource};
 use router_derive::generate_permissions;
+use strum::IntoEnumIterator;
 
 generate_permissions! {
     permissions: [
@@ -15,6 +16,10 @@ generate_permissions! {
             scopes: [Read, Write],
             entities: [Profile, Merchant]
         },
+        DisputeEvidence: {
+            scopes: [Write],
+            entities: [Profile, Merchant]
+        },
         Mandate: {
             scopes: [Read, Write],
             entities: [Merchant]
@@ -95,6 +100,10 @@ generate_permissions! {
             scopes: [Read, Write],
             entities: [Merchant]
         },
+        SupportAdmin: {
+            scopes: [Write],
+            entities: [Merchant]
+        },
     ]
 }
 
@@ -103,6 +112,7 @@ pub fn get_resource_name(resource: Resource, entity_type: EntityType) -> &'stati
         (Resource::Payment, _) => "Payments",
         (Resource::Refund, _) => "Refunds",
         (Resource::Dispute, _) => "Disputes",
+        (Resource::DisputeEvidence, _) => "Dispute Evidence",
         (Resource::Mandate, _) => "Mandates",
         (Resource::Customer, _) => "Customers",
         (Resource::Payout, _) => "Payouts",
@@ -122,6 +132,7 @@ pub fn get_resource_name(resource: Resource, entity_type: EntityType) -> &'stati
         (Resource::ReconFiles, _) => "Reconciliation Process Manager",
         (Resource::ReconReports, _) => "Reconciliation Reports",
         (Resource::ReconAndSettlementAnalytics, _) => "Reconciliation Analytics",
+        (Resource::SupportAdmin, _) => "Support Administration",
         (Resource::Account, EntityType::Profile) => "Business Profile Account",
         (Resource::Account, EntityType::Merchant) => "Merchant Account",
         (Resource::Account, EntityType::Organization) => "Organization Account",
@@ -129,6 +140,10 @@ pub fn get_resource_name(resource: Resource, entity_type: EntityType) -> &'stati
     }
 }
 
+pub fn get_permission_from_name(name: &str) -> Option<Permission> {
+    Permission::iter().find(|permission| permission.to_string() == name)
+}
+
 pub fn get_scope_name(scope: PermissionScope) -> &'static str {
     match scope {
         PermissionScope::Read => "View",
@@ -6,10 +6,14 @@ use std::collections::HashSet;
 use api_models::enums::ReconPermissionScope;
 use common_enums::{EntityType, PermissionGroup, Resource, RoleScope};
 use common_utils::{errors::CustomResult, id_type};
+use time::PrimitiveDateTime;
 
 #[cfg(feature = "recon")]
 use super::permission_groups::{RECON_OPS, RECON_REPORTS};
-use super::{permission_groups::PermissionGroupExt, permissions::Permission};
+use super::{
+    permission_groups::PermissionGroupExt,
+    permissions::{get_permission_from_name, Permission},
+};
 use crate::{core::errors, routes::SessionState};
 
 pub mod predefined_roles;
@@ -25,6 +29,16 @@ pub struct RoleInfo {
     is_deletable: bool,
     is_updatable: bool,
     is_internal: bool,
+    #[serde(default)]
+    is_deleted: bool,
+    #[serde(default)]
+    version: Option<i32>,
+    /// Leaf permissions granted through merchant defined permission groups
+    #[serde(default)]
+    custom_permissions: Vec<Permission>,
+    /// Tracked in the roles table for custom roles only
+    #[serde(default)]
+    last_used_at: Option<PrimitiveDateTime>,
 }
 
 impl RoleInfo {
@@ -69,6 +83,22 @@ impl RoleInfo {
         self.is_updatable
     }
 
+    pub fn is_deleted(&self) -> bool {
+        self.is_deleted
+    }
+
+    pub fn get_version(&self) -> Option<i32> {
+        self.version
+    }
+
+    pub fn get_custom_permissions(&self) -> &[Permission] {
+        &self.custom_permissions
+    }
+
+    pub fn get_last_used_at(&self) -> Option<PrimitiveDateTime> {
+        self.last_used_at
+    }
+
     pub fn get_resources_set(&self) -> HashSet<Resource> {
         self.get_permission_groups()
             .iter()
@@ -78,10 +108,11 @@ impl RoleInfo {
 
     pub fn check_permission_exists(&self, required_permission: Permission) -> bool {
         required_permission.entity_type() <= self.entity_type
-            && self.get_permission_groups().iter().any(|group| {
-                required_permission.scope() <= group.scope()
-                    && group.resources().contains(&required_permission.resource())
-            })
+            && (self.custom_permissions.contains(&required_permission)
+                || self.get_permission_groups().iter().any(|group| {
+                    required_permission.scope() <= group.scope()
+                        && group.resources().contains(&required_permission.resource())
+                }))
     }
 
     #[cfg(feature = "recon")]
@@ -168,10 +199,19 @@ impl From<diesel_models::role::Role> for RoleInfo {
             groups: role.groups,
             scope: role.scope,
             entity_type: role.entity_type,
-            is_invitable: true,
+            is_invitable: role.deleted_at.is_none(),
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: role.deleted_at.is_some(),
+            version: Some(role.version),
+            custom_permissions: role
+                .custom_permissions
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|permission| get_permission_from_name(permission))
+                .collect(),
+            last_used_at: role.last_used_at,
         }
     }
 }
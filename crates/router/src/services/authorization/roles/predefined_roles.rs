@@ -4,7 +4,7 @@ use common_enums::{EntityType, PermissionGroup, RoleScope};
 use once_cell::sync::Lazy;
 
 use super::RoleInfo;
-use crate::consts;
+use crate::{consts, services::authorization::permissions::Permission};
 
 pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|| {
     let mut roles = HashMap::new();
@@ -41,6 +41,11 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: false,
             is_updatable: false,
             is_internal: true,
+            is_deleted: false,
+            version: None,
+            // Support administration is not part of any permission group
+            custom_permissions: vec![Permission::MerchantSupportAdminWrite],
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -65,6 +70,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: false,
             is_updatable: false,
             is_internal: true,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
 
@@ -100,6 +109,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: false,
             is_updatable: false,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
 
@@ -135,6 +148,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
 
@@ -169,6 +186,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -193,6 +214,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -214,6 +239,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -239,6 +268,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -265,6 +298,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -287,6 +324,38 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
+        },
+    );
+    roles.insert(
+        consts::user_role::ROLE_ID_MERCHANT_AUDITOR,
+        RoleInfo {
+            groups: vec![
+                PermissionGroup::OperationsView,
+                PermissionGroup::ConnectorsView,
+                PermissionGroup::WorkflowsView,
+                PermissionGroup::AnalyticsView,
+                PermissionGroup::UsersView,
+                PermissionGroup::MerchantDetailsView,
+                PermissionGroup::AccountView,
+                PermissionGroup::ReconOpsView,
+                PermissionGroup::ReconReportsView,
+            ],
+            role_id: consts::user_role::ROLE_ID_MERCHANT_AUDITOR.to_string(),
+            role_name: "auditor".to_string(),
+            scope: RoleScope::Organization,
+            entity_type: EntityType::Merchant,
+            is_invitable: true,
+            is_deletable: true,
+            is_updatable: true,
+            is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
 
@@ -317,6 +386,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -339,6 +412,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -360,6 +437,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -383,6 +464,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -406,6 +491,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles.insert(
@@ -426,6 +515,10 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            is_deleted: false,
+            version: None,
+            custom_permissions: Vec::new(),
+            last_used_at: None,
         },
     );
     roles
@@ -480,6 +480,13 @@ impl ConnectorSpecifications for ConnectorEnum {
             Self::New(connector) => connector.get_connector_about(),
         }
     }
+
+    fn get_statement_descriptor_max_length(&self) -> Option<usize> {
+        match self {
+            Self::Old(connector) => connector.get_statement_descriptor_max_length(),
+            Self::New(connector) => connector.get_statement_descriptor_max_length(),
+        }
+    }
 }
 
 impl api::ConnectorCommon for ConnectorEnum {
@@ -540,6 +547,7 @@ pub trait ConnectorIntegrationInterface<F, ResourceCommonData, Req, Resp>: Send
     fn get_multiple_capture_sync_method(
         &self,
     ) -> CustomResult<CaptureSyncMethod, errors::ConnectorError>;
+    fn is_flow_implemented(&self) -> bool;
     fn build_request(
         &self,
         req: &RouterData<F, Req, Resp>,
@@ -588,6 +596,12 @@ where
             }
         }
     }
+    fn is_flow_implemented(&self) -> bool {
+        match self {
+            ConnectorIntegrationEnum::Old(old_integration) => old_integration.is_flow_implemented(),
+            ConnectorIntegrationEnum::New(new_integration) => new_integration.is_flow_implemented(),
+        }
+    }
     fn build_request(
         &self,
         req: &RouterData<T, Req, Resp>,
@@ -191,6 +191,7 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
                 .map(ForeignInto::foreign_into),
             is_clear_pan_retries_enabled: item.is_clear_pan_retries_enabled,
             force_3ds_challenge: item.force_3ds_challenge,
+            allowed_payment_method_types: item.allowed_payment_method_types,
         })
     }
 }
@@ -442,5 +443,6 @@ pub async fn create_profile_from_merchant_account(
             .attach_printable("error while generating card testing secret key")?,
         is_clear_pan_retries_enabled: request.is_clear_pan_retries_enabled.unwrap_or_default(),
         force_3ds_challenge: request.force_3ds_challenge.unwrap_or_default(),
+        allowed_payment_method_types: request.allowed_payment_method_types,
     }))
 }
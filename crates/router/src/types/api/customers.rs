@@ -3,6 +3,8 @@ pub use api_models::customers::{
     CustomerDeleteResponse, CustomerListRequest, CustomerRequest, CustomerUpdateRequest,
     CustomerUpdateRequestInternal,
 };
+#[cfg(feature = "v1")]
+pub use api_models::customers::{CustomerMergeRequest, CustomerMergeResponse};
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use hyperswitch_domain_models::customer;
 use serde::Serialize;
@@ -39,6 +41,7 @@ impl ForeignFrom<(domain::Customer, Option<payments::AddressDetails>)> for Custo
             metadata: cust.metadata,
             address,
             default_payment_method_id: cust.default_payment_method_id,
+            merged_into_customer_id: cust.merged_into_customer_id,
         }
         .into()
     }
@@ -35,7 +35,25 @@ pub struct AttachEvidenceRequest {
     pub evidence_type: EvidenceType,
 }
 
-#[derive(Debug, serde::Deserialize, strum::Display, strum::EnumString, Clone, serde::Serialize)]
+#[derive(Debug, Clone, Serialize)]
+pub struct SubmitEvidenceBundleRequest {
+    /// The text fields of the evidence, along with the ID of the dispute
+    pub submit_evidence_request: api_models::disputes::SubmitEvidenceRequest,
+    /// The documents of the evidence, which are uploaded before the evidence is submitted
+    pub evidence_files: Vec<AttachEvidenceRequest>,
+}
+
+impl common_utils::events::ApiEventMetric for SubmitEvidenceBundleRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Dispute {
+            dispute_id: self.submit_evidence_request.dispute_id.clone(),
+        })
+    }
+}
+
+#[derive(
+    Debug, serde::Deserialize, strum::Display, strum::EnumString, Clone, PartialEq, serde::Serialize,
+)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum EvidenceType {
@@ -1,6 +1,8 @@
 pub use api_models::refunds::{
-    RefundRequest, RefundResponse, RefundStatus, RefundType, RefundUpdateRequest,
-    RefundsRetrieveRequest,
+    RefundBulkItem, RefundBulkItemResponse, RefundBulkItemStatus, RefundBulkRequest,
+    RefundBulkResponse, RefundEligibilityConstraints, RefundEligibilityRequest,
+    RefundEligibilityResponse, RefundRequest, RefundResponse, RefundStatus, RefundType,
+    RefundUpdateRequest, RefundsRetrieveRequest,
 };
 pub use hyperswitch_domain_models::router_flow_types::refunds::{Execute, RSync};
 pub use hyperswitch_interfaces::api::refunds::{Refund, RefundExecute, RefundSync};
@@ -62,6 +62,7 @@ impl VerifyConnectorData {
             shipping_cost: None,
             merchant_account_id: None,
             merchant_config_currency: None,
+            three_ds_challenge_flow: None,
         }
     }
 
@@ -1436,6 +1436,7 @@ where
             entity_type: Some(entity.entity_type),
             version: UserRoleVersion::V2,
             tenant_id: entity.tenant_id,
+            expires_at: None,
         }
     }
 
@@ -91,9 +91,14 @@ impl SPTFlow {
         state: &SessionState,
         next_flow: &NextFlow,
     ) -> UserResult<Secret<String>> {
+        let purpose = match self {
+            Self::MerchantSelect => state.conf.user.get_accept_invitation_token_purpose(),
+            flow => flow.into(),
+        };
+
         auth::SinglePurposeToken::new_token(
             next_flow.user.get_user_id().to_string(),
-            self.into(),
+            purpose,
             next_flow.origin.clone(),
             &state.conf,
             next_flow.path.to_vec(),
@@ -138,6 +143,7 @@ impl JWTFlow {
             org_id,
             profile_id,
             Some(user_role.tenant_id.clone()),
+            user_role.expires_at,
         )
         .await
         .map(|token| token.into())
@@ -155,6 +161,7 @@ pub enum Origin {
     VerifyEmail,
     AcceptInvitationFromEmail,
     ResetPassword,
+    Impersonation,
 }
 
 impl Origin {
@@ -167,6 +174,8 @@ impl Origin {
             Self::MagicLink => &MAGIC_LINK_FLOW,
             Self::AcceptInvitationFromEmail => &ACCEPT_INVITATION_FROM_EMAIL_FLOW,
             Self::ResetPassword => &RESET_PASSWORD_FLOW,
+            // Impersonation tokens cannot be exchanged for any further token
+            Self::Impersonation => &[],
         }
     }
 }
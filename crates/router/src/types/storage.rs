@@ -10,8 +10,10 @@ pub mod callback_mapper;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod custom_permission_group;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod deleted_user_role;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod enums;
@@ -31,13 +33,18 @@ pub mod merchant_key_store;
 #[cfg(feature = "v2")]
 pub mod passive_churn_recovery;
 pub mod payment_attempt;
+pub mod payment_fx_details;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
+pub mod pending_invitation;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
+pub mod role_assignment_rule;
+pub mod role_audit;
+pub mod role_template;
 pub mod routing_algorithm;
 pub mod unified_translations;
 pub mod user;
@@ -67,11 +74,13 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
-    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
+    capture::*, cards_info::*, configs::*, custom_permission_group::*, customers::*,
+    dashboard_metadata::*, deleted_user_role::*, dispute::*, dynamic_routing_stats::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, generic_link::*, gsm::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_fx_details::*, payment_link::*, payment_method::*,
+    pending_invitation::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    role_assignment_rule::*, role_audit::*, role_template::*, routing_algorithm::*,
     unified_translations::*, user::*, user_authentication_method::*, user_role::*,
 };
 use crate::types::api::routing;
@@ -86,6 +95,53 @@ pub struct RoutingData {
     pub algorithm: Option<api_models::routing::StraightThroughAlgorithm>,
 }
 
+/// Tracking data of the process tracker task which expires a payment that is not confirmed in time
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentIntentExpiryTrackingData {
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+/// Tracking data of the recurring process tracker task which syncs the status of payments that
+/// have been pending with the connector for too long
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct StalePaymentSyncTrackingData {
+    /// Connectors which were down in the previous runs, keyed by the connector name
+    pub connector_backoffs: HashMap<String, ConnectorSyncBackoff>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorSyncBackoff {
+    /// Number of consecutive runs in which the connector was down
+    pub failed_runs: u32,
+    /// Time before which the payments of the connector are not synced
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub retry_after: time::PrimitiveDateTime,
+}
+
+/// Tracking data of the process tracker task which replays the webhook events of a merchant
+/// created within a time range, in batches
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct WebhookReplayTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: time::PrimitiveDateTime,
+    /// Whether events already acknowledged by the merchant endpoint are replayed as well
+    pub force: bool,
+    /// Number of events within the time range which have been processed in the previous runs
+    pub offset: i64,
+    pub replayed_events: usize,
+    pub skipped_events: usize,
+    pub failed_events: usize,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(from = "PaymentRoutingInfoSerde", into = "PaymentRoutingInfoSerde")]
 pub struct PaymentRoutingInfo {
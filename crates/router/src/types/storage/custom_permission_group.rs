@@ -0,0 +1 @@
+pub use diesel_models::custom_permission_group::*;
@@ -0,0 +1 @@
+pub use diesel_models::deleted_user_role::*;
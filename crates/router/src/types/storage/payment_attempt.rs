@@ -8,6 +8,25 @@ pub use hyperswitch_domain_models::payments::payment_attempt::{
 use crate::{
     core::errors, errors::RouterResult, types::transformers::ForeignFrom, utils::OptionExt,
 };
+/// Tracking data of the process tracker task which captures a payment at its scheduled time
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ScheduledCaptureTrackingData {
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+/// Tracking data of the process tracker task which retries voiding the authorization made to
+/// verify a card
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct CardVerificationVoidTrackingData {
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
 pub trait PaymentAttemptExt {
     fn make_new_capture(
         &self,
@@ -125,13 +144,15 @@ impl AttemptStatusExt for enums::AttemptStatus {
 ))]
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used, clippy::print_stderr)]
-    use hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptNew;
+    use hyperswitch_domain_models::payments::payment_attempt::{
+        PaymentAttemptInterface, PaymentAttemptNew,
+    };
     use tokio::sync::oneshot;
     use uuid::Uuid;
 
     use crate::{
         configs::settings::Settings,
-        db::StorageImpl,
+        db::{MockDb, StorageImpl},
         routes, services,
         types::{self, storage::enums},
     };
@@ -431,4 +452,173 @@ mod tests {
         // checking it after fetch
         assert_eq!(response.mandate_id, Some("man_121212".to_string()));
     }
+
+    fn get_payment_attempt_new(
+        status: enums::AttemptStatus,
+        connector: types::Connector,
+        modified_at: time::PrimitiveDateTime,
+    ) -> PaymentAttemptNew {
+        PaymentAttemptNew {
+            payment_id: Default::default(),
+            merchant_id: Default::default(),
+            attempt_id: Uuid::new_v4().to_string(),
+            status,
+            connector: Some(connector.to_string()),
+            created_at: Some(modified_at),
+            modified_at: Some(modified_at),
+            profile_id: common_utils::generate_profile_id_of_default_length(),
+            net_amount: Default::default(),
+            currency: Default::default(),
+            save_to_locker: Default::default(),
+            error_message: Default::default(),
+            offer_amount: Default::default(),
+            payment_method_id: Default::default(),
+            payment_method: Default::default(),
+            capture_method: Default::default(),
+            capture_on: Default::default(),
+            confirm: Default::default(),
+            authentication_type: Default::default(),
+            last_synced: Default::default(),
+            cancellation_reason: Default::default(),
+            amount_to_capture: Default::default(),
+            mandate_id: Default::default(),
+            browser_info: Default::default(),
+            payment_token: Default::default(),
+            error_code: Default::default(),
+            connector_metadata: Default::default(),
+            payment_experience: Default::default(),
+            payment_method_type: Default::default(),
+            payment_method_data: Default::default(),
+            business_sub_label: Default::default(),
+            straight_through_algorithm: Default::default(),
+            preprocessing_step_id: Default::default(),
+            mandate_details: Default::default(),
+            error_reason: Default::default(),
+            connector_response_reference_id: Default::default(),
+            multiple_capture_count: Default::default(),
+            amount_capturable: Default::default(),
+            updated_by: Default::default(),
+            authentication_data: Default::default(),
+            encoded_data: Default::default(),
+            merchant_connector_id: Default::default(),
+            unified_code: Default::default(),
+            unified_message: Default::default(),
+            external_three_ds_authentication_attempted: Default::default(),
+            authentication_connector: Default::default(),
+            authentication_id: Default::default(),
+            mandate_data: Default::default(),
+            payment_method_billing_address_id: Default::default(),
+            fingerprint_id: Default::default(),
+            client_source: Default::default(),
+            client_version: Default::default(),
+            customer_acceptance: Default::default(),
+            organization_id: Default::default(),
+            connector_mandate_detail: Default::default(),
+            request_extended_authorization: Default::default(),
+            extended_authorization_applied: Default::default(),
+            capture_before: Default::default(),
+            card_discovery: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_db_find_payment_attempts_by_statuses_modified_within() {
+        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let mock_db = &mock_db;
+        let now = common_utils::date_time::now();
+        let insert_payment_attempt = move |status, connector, minutes_ago| {
+            mock_db.insert_payment_attempt(
+                get_payment_attempt_new(
+                    status,
+                    connector,
+                    now - time::Duration::minutes(minutes_ago),
+                ),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+        };
+
+        let stale_attempt = insert_payment_attempt(
+            enums::AttemptStatus::Pending,
+            types::Connector::DummyConnector1,
+            20,
+        )
+        .await
+        .unwrap();
+        let older_stale_attempt = insert_payment_attempt(
+            enums::AttemptStatus::Authorizing,
+            types::Connector::DummyConnector1,
+            30,
+        )
+        .await
+        .unwrap();
+        // Modified too recently to be stale
+        insert_payment_attempt(
+            enums::AttemptStatus::Pending,
+            types::Connector::DummyConnector1,
+            1,
+        )
+        .await
+        .unwrap();
+        // Modified before the lookback
+        insert_payment_attempt(
+            enums::AttemptStatus::Pending,
+            types::Connector::DummyConnector1,
+            120,
+        )
+        .await
+        .unwrap();
+        // Not pending
+        insert_payment_attempt(
+            enums::AttemptStatus::Charged,
+            types::Connector::DummyConnector1,
+            20,
+        )
+        .await
+        .unwrap();
+        // Of an excluded connector
+        insert_payment_attempt(
+            enums::AttemptStatus::Pending,
+            types::Connector::DummyConnector2,
+            20,
+        )
+        .await
+        .unwrap();
+
+        let time_range = common_utils::types::TimeRange {
+            start_time: now - time::Duration::minutes(60),
+            end_time: Some(now - time::Duration::minutes(5)),
+        };
+        let find_stale_attempt_ids = |limit| async move {
+            mock_db
+                .find_payment_attempts_by_statuses_modified_within(
+                    vec![
+                        enums::AttemptStatus::Pending,
+                        enums::AttemptStatus::Authorizing,
+                    ],
+                    &time_range,
+                    vec![types::Connector::DummyConnector2.to_string()],
+                    limit,
+                )
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|payment_attempt| payment_attempt.attempt_id)
+                .collect::<Vec<_>>()
+        };
+
+        // The attempts which have been stale the longest are returned first
+        assert_eq!(
+            find_stale_attempt_ids(10).await,
+            [
+                older_stale_attempt.attempt_id.clone(),
+                stale_attempt.attempt_id
+            ]
+        );
+        assert_eq!(
+            find_stale_attempt_ids(1).await,
+            [older_stale_attempt.attempt_id]
+        );
+    }
 }
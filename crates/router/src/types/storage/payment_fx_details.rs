@@ -0,0 +1 @@
+pub use diesel_models::payment_fx_details::{PaymentFxDetails, PaymentFxDetailsNew};
@@ -1,4 +1,4 @@
-use api_models::payments::AmountFilter;
+use api_models::payments::{AmountFilter, Order, SortBy, SortOn};
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
@@ -57,56 +57,41 @@ impl RefundDbExt for Refund {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
             .into_boxed();
-        let mut search_by_pay_or_ref_id = false;
-
-        if let (Some(pid), Some(ref_id)) = (
-            &refund_list_details.payment_id,
-            &refund_list_details.refund_id,
-        ) {
-            search_by_pay_or_ref_id = true;
-            filter = filter
-                .filter(
-                    dsl::payment_id
-                        .eq(pid.to_owned())
-                        .or(dsl::refund_id.eq(ref_id.to_owned())),
-                )
-                .limit(limit)
-                .offset(offset);
+
+        // The refund id breaks ties between refunds with the same sort value, so that pages do not
+        // overlap or skip refunds
+        filter = match refund_list_details.order {
+            Some(Order {
+                on: SortOn::Amount,
+                by: SortBy::Asc,
+            }) => filter.order((dsl::refund_amount.asc(), dsl::refund_id.asc())),
+            Some(Order {
+                on: SortOn::Amount,
+                by: SortBy::Desc,
+            }) => filter.order((dsl::refund_amount.desc(), dsl::refund_id.desc())),
+            Some(Order {
+                on: SortOn::Created,
+                by: SortBy::Asc,
+            }) => filter.order((dsl::created_at.asc(), dsl::refund_id.asc())),
+            Some(Order {
+                on: SortOn::Created,
+                by: SortBy::Desc,
+            }) => filter.order((dsl::created_at.desc(), dsl::refund_id.desc())),
+            None => filter.order((dsl::modified_at.desc(), dsl::refund_id.desc())),
         };
 
-        if !search_by_pay_or_ref_id {
-            match &refund_list_details.payment_id {
-                Some(pid) => {
-                    filter = filter.filter(dsl::payment_id.eq(pid.to_owned()));
-                }
-                None => {
-                    filter = filter.limit(limit).offset(offset);
-                }
-            };
+        if let Some(pid) = &refund_list_details.payment_id {
+            filter = filter.filter(dsl::payment_id.eq(pid.to_owned()));
         }
-        if !search_by_pay_or_ref_id {
-            match &refund_list_details.refund_id {
-                Some(ref_id) => {
-                    filter = filter.filter(dsl::refund_id.eq(ref_id.to_owned()));
-                }
-                None => {
-                    filter = filter.limit(limit).offset(offset);
-                }
-            };
+
+        if let Some(ref_id) = &refund_list_details.refund_id {
+            filter = filter.filter(dsl::refund_id.eq(ref_id.to_owned()));
+        }
+
+        if let Some(profile_id) = &refund_list_details.profile_id {
+            filter = filter.filter(dsl::profile_id.eq_any(profile_id.to_owned()));
         }
-        match &refund_list_details.profile_id {
-            Some(profile_id) => {
-                filter = filter
-                    .filter(dsl::profile_id.eq_any(profile_id.to_owned()))
-                    .limit(limit)
-                    .offset(offset);
-            }
-            None => {
-                filter = filter.limit(limit).offset(offset);
-            }
-        };
 
         if let Some(time_range) = refund_list_details.time_range {
             filter = filter.filter(dsl::created_at.ge(time_range.start_time));
@@ -148,6 +133,34 @@ impl RefundDbExt for Refund {
             filter = filter.filter(dsl::refund_status.eq_any(filter_refund_status.clone()));
         }
 
+        filter = match (&refund_list_details.page_after, &refund_list_details.order) {
+            (
+                Some((created_at, refund_id)),
+                Some(Order {
+                    on: SortOn::Created,
+                    by: SortBy::Asc,
+                }),
+            ) => filter.filter(
+                dsl::created_at.gt(*created_at).or(dsl::created_at
+                    .eq(*created_at)
+                    .and(dsl::refund_id.gt(refund_id.clone()))),
+            ),
+            (
+                Some((created_at, refund_id)),
+                Some(Order {
+                    on: SortOn::Created,
+                    by: SortBy::Desc,
+                }),
+            ) => filter.filter(
+                dsl::created_at.lt(*created_at).or(dsl::created_at
+                    .eq(*created_at)
+                    .and(dsl::refund_id.lt(refund_id.clone()))),
+            ),
+            _ => filter,
+        };
+
+        filter = filter.limit(limit).offset(offset);
+
         logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
         db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
@@ -224,31 +237,14 @@ impl RefundDbExt for Refund {
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
             .into_boxed();
 
-        let mut search_by_pay_or_ref_id = false;
-
-        if let (Some(pid), Some(ref_id)) = (
-            &refund_list_details.payment_id,
-            &refund_list_details.refund_id,
-        ) {
-            search_by_pay_or_ref_id = true;
-            filter = filter.filter(
-                dsl::payment_id
-                    .eq(pid.to_owned())
-                    .or(dsl::refund_id.eq(ref_id.to_owned())),
-            );
-        };
-
-        if !search_by_pay_or_ref_id {
-            if let Some(pay_id) = &refund_list_details.payment_id {
-                filter = filter.filter(dsl::payment_id.eq(pay_id.to_owned()));
-            }
+        if let Some(pay_id) = &refund_list_details.payment_id {
+            filter = filter.filter(dsl::payment_id.eq(pay_id.to_owned()));
         }
 
-        if !search_by_pay_or_ref_id {
-            if let Some(ref_id) = &refund_list_details.refund_id {
-                filter = filter.filter(dsl::refund_id.eq(ref_id.to_owned()));
-            }
+        if let Some(ref_id) = &refund_list_details.refund_id {
+            filter = filter.filter(dsl::refund_id.eq(ref_id.to_owned()));
         }
+
         if let Some(profile_id) = &refund_list_details.profile_id {
             filter = filter.filter(dsl::profile_id.eq_any(profile_id.to_owned()));
         }
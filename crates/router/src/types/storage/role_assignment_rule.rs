@@ -0,0 +1 @@
+pub use diesel_models::role_assignment_rule::*;
@@ -449,6 +449,7 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
                 Some(storage_enums::EventType::ActionRequired)
             }
             api_enums::IntentStatus::Cancelled => Some(storage_enums::EventType::PaymentCancelled),
+            api_enums::IntentStatus::Expired => Some(storage_enums::EventType::PaymentExpired),
             api_enums::IntentStatus::PartiallyCaptured
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable => {
                 Some(storage_enums::EventType::PaymentCaptured)
@@ -965,6 +966,21 @@ impl ForeignFrom<storage::Authorization> for payments::IncrementalAuthorizationR
     }
 }
 
+#[cfg(feature = "v1")]
+impl ForeignFrom<storage::PaymentFxDetails> for payments::PaymentFxDetailsResponse {
+    fn foreign_from(payment_fx_details: storage::PaymentFxDetails) -> Self {
+        Self {
+            amount: payment_fx_details.amount,
+            currency: payment_fx_details.currency,
+            settlement_amount: payment_fx_details.settlement_amount,
+            settlement_currency: payment_fx_details.settlement_currency,
+            exchange_rate: payment_fx_details.exchange_rate,
+            provider: payment_fx_details.provider,
+            rate_fetched_at: payment_fx_details.rate_fetched_at,
+        }
+    }
+}
+
 impl ForeignFrom<&storage::Authentication> for payments::ExternalAuthenticationDetailsResponse {
     fn foreign_from(authn_data: &storage::Authentication) -> Self {
         let version = authn_data
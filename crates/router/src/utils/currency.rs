@@ -110,6 +110,13 @@ impl FxExchangeRatesCacheEntry {
     fn is_expired(&self, call_delay: i64) -> bool {
         self.timestamp + call_delay < date_time::now_unix_timestamp()
     }
+
+    /// The time at which the exchange rates were fetched from the forex API
+    pub fn get_fetched_at(&self) -> Option<time::PrimitiveDateTime> {
+        time::OffsetDateTime::from_unix_timestamp(self.timestamp)
+            .ok()
+            .map(|fetched_at| time::PrimitiveDateTime::new(fetched_at.date(), fetched_at.time()))
+    }
 }
 
 async fn retrieve_forex_from_local_cache() -> Option<FxExchangeRatesCacheEntry> {
@@ -86,6 +86,22 @@ impl UserFromToken {
         .await
         .change_context(UserErrors::InternalServerError)
     }
+
+    /// Expiry of the role assignment the token was issued for, if it is time bound
+    pub async fn get_role_expiry_from_db(
+        &self,
+        state: &SessionState,
+    ) -> UserResult<Option<time::PrimitiveDateTime>> {
+        super::user_role::get_user_role_expiry_in_lineage(
+            state,
+            &self.user_id,
+            self.tenant_id.as_ref().unwrap_or(&state.tenant.tenant_id),
+            &self.org_id,
+            &self.merchant_id,
+            &self.profile_id,
+        )
+        .await
+    }
 }
 
 pub async fn generate_jwt_auth_token_with_attributes(
@@ -96,6 +112,7 @@ pub async fn generate_jwt_auth_token_with_attributes(
     role_id: String,
     profile_id: id_type::ProfileId,
     tenant_id: Option<id_type::TenantId>,
+    role_expires_at: Option<time::PrimitiveDateTime>,
 ) -> UserResult<Secret<String>> {
     let token = AuthToken::new_token(
         user_id,
@@ -105,6 +122,7 @@ pub async fn generate_jwt_auth_token_with_attributes(
         org_id,
         profile_id,
         tenant_id,
+        role_expires_at,
     )
     .await?;
     Ok(Secret::new(token))
@@ -1,15 +1,21 @@
-use std::{cmp, collections::HashSet};
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
 
+use api_models::user_role as user_role_api;
 use common_enums::{EntityType, PermissionGroup};
 use common_utils::id_type;
 use diesel_models::{
     enums::{UserRoleVersion, UserStatus},
+    pending_invitation::PendingInvitation,
     role::ListRolesByEntityPayload,
     user_role::{UserRole, UserRoleUpdate},
 };
 use error_stack::{report, Report, ResultExt};
 use router_env::logger;
 use storage_impl::errors::StorageError;
+use time::PrimitiveDateTime;
 
 use crate::{
     consts,
@@ -25,8 +31,10 @@ use crate::{
 
 pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
     if groups.is_empty() {
-        return Err(report!(UserErrors::InvalidRoleOperation))
-            .attach_printable("Role groups cannot be empty");
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Role groups cannot be empty".to_string(),
+        )
+        .into());
     }
 
     let unique_groups: HashSet<_> = groups.iter().copied().collect();
@@ -96,6 +104,160 @@ pub async fn validate_role_name(
     Ok(())
 }
 
+pub fn get_custom_roles_limit_config_key(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}_{}",
+        consts::user_role::CUSTOM_ROLES_LIMIT_CONFIG_KEY_PREFIX,
+        merchant_id.get_string_repr()
+    )
+}
+
+pub async fn validate_custom_roles_limit(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    tenant_id: &id_type::TenantId,
+) -> UserResult<()> {
+    let max_custom_roles = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_custom_roles_limit_config_key(merchant_id),
+            Some(consts::user_role::DEFAULT_MAX_CUSTOM_ROLES_PER_MERCHANT.to_string()),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to fetch custom roles limit config")?
+        .config
+        .parse::<i64>()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse custom roles limit config")?;
+
+    let custom_roles_count = state
+        .global_store
+        .get_roles_count_by_merchant_id(merchant_id, tenant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get count of custom roles")?;
+
+    if custom_roles_count >= max_custom_roles {
+        return Err(report!(UserErrors::MaxCustomRolesReached)).attach_printable(format!(
+            "Merchant already has {} custom roles, limit is {}",
+            custom_roles_count, max_custom_roles
+        ));
+    }
+
+    Ok(())
+}
+
+/// Creates invitations for a newly registered user from the pending invitations sent to their
+/// email, the invitations still have to be accepted by the user
+pub async fn attach_pending_invitations_to_user(
+    state: &SessionState,
+    user: &domain::UserFromStorage,
+) -> UserResult<()> {
+    let email = user.get_email();
+    let pending_invitations = state
+        .global_store
+        .list_pending_invitations_by_email_tenant_id(&email, &state.tenant.tenant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if pending_invitations.is_empty() {
+        return Ok(());
+    }
+
+    let now = common_utils::date_time::now();
+    let mut has_failed_invitations = false;
+    for pending_invitation in pending_invitations {
+        let pending_invitation_id = pending_invitation.id;
+        // An invitation which cannot be attached should not keep the user from the others
+        if let Err(error) =
+            attach_pending_invitation_to_user(state, user, pending_invitation, now).await
+        {
+            logger::error!(
+                ?error,
+                pending_invitation_id,
+                "Failed to attach pending invitation to user"
+            );
+            has_failed_invitations = true;
+        }
+    }
+
+    // The pending invitations are retained to be attached again, if any of them failed
+    if has_failed_invitations {
+        return Ok(());
+    }
+
+    state
+        .global_store
+        .delete_pending_invitations_by_email_tenant_id(&email, &state.tenant.tenant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(())
+}
+
+async fn attach_pending_invitation_to_user(
+    state: &SessionState,
+    user: &domain::UserFromStorage,
+    pending_invitation: PendingInvitation,
+    now: PrimitiveDateTime,
+) -> UserResult<()> {
+    let user_role = domain::NewUserRole {
+        user_id: user.get_user_id().to_owned(),
+        role_id: pending_invitation.role_id,
+        status: UserStatus::InvitationSent,
+        created_by: pending_invitation.created_by.clone(),
+        last_modified_by: pending_invitation.created_by,
+        created_at: now,
+        last_modified: now,
+        entity: domain::NoLevel,
+    };
+
+    match (
+        pending_invitation.entity_type,
+        pending_invitation.profile_id,
+    ) {
+        (EntityType::Organization, _) => {
+            user_role
+                .add_entity(domain::OrganizationLevel {
+                    tenant_id: pending_invitation.tenant_id,
+                    org_id: pending_invitation.org_id,
+                })
+                .insert_in_v2(state)
+                .await?;
+        }
+        (EntityType::Merchant, _) => {
+            user_role
+                .add_entity(domain::MerchantLevel {
+                    tenant_id: pending_invitation.tenant_id,
+                    org_id: pending_invitation.org_id,
+                    merchant_id: pending_invitation.merchant_id,
+                })
+                .insert_in_v2(state)
+                .await?;
+        }
+        (EntityType::Profile, Some(profile_id)) => {
+            user_role
+                .add_entity(domain::ProfileLevel {
+                    tenant_id: pending_invitation.tenant_id,
+                    org_id: pending_invitation.org_id,
+                    merchant_id: pending_invitation.merchant_id,
+                    profile_id,
+                })
+                .insert_in_v2(state)
+                .await?;
+        }
+        (EntityType::Tenant, _) | (EntityType::Profile, None) => {
+            logger::error!(
+                pending_invitation_id = pending_invitation.id,
+                "Invalid entity in pending invitation"
+            );
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn set_role_info_in_cache_by_user_role(
     state: &SessionState,
     user_role: &UserRole,
@@ -203,6 +365,197 @@ pub async fn update_v1_and_v2_user_roles_in_db(
     (updated_v1_role, updated_v2_role)
 }
 
+pub fn validate_user_role_expiry(expires_at: Option<time::PrimitiveDateTime>) -> UserResult<()> {
+    if expires_at.is_some_and(|expires_at| expires_at <= common_utils::date_time::now()) {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Role expiry must be in the future".to_string(),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Fetches the expiry of the role assignment the user holds in the lineage, if it is time bound
+pub async fn get_user_role_expiry_in_lineage(
+    state: &SessionState,
+    user_id: &str,
+    tenant_id: &id_type::TenantId,
+    org_id: &id_type::OrganizationId,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> UserResult<Option<PrimitiveDateTime>> {
+    for version in [UserRoleVersion::V2, UserRoleVersion::V1] {
+        match state
+            .global_store
+            .find_user_role_by_user_id_and_lineage(
+                user_id,
+                tenant_id,
+                org_id,
+                merchant_id,
+                profile_id,
+                version,
+            )
+            .await
+        {
+            Ok(user_role) => return Ok(user_role.expires_at),
+            Err(err) if err.current_context().is_db_not_found() => continue,
+            Err(err) => {
+                return Err(err
+                    .change_context(UserErrors::InternalServerError)
+                    .attach_printable("Failed to fetch user role"))
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+pub async fn is_invitation_already_accepted(
+    state: &SessionState,
+    user_id: &str,
+    tenant_id: &id_type::TenantId,
+    entities: &[user_role_api::Entity],
+) -> UserResult<bool> {
+    let active_entities = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: Some(UserStatus::Active),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .filter_map(|user_role| user_role.get_entity_id_and_type())
+        .collect::<HashSet<_>>();
+
+    Ok(entities
+        .iter()
+        .any(|entity| active_entities.contains(&(entity.entity_id.clone(), entity.entity_type))))
+}
+
+/// Single purpose tokens only grant access to the entities the user of the token has been
+/// invited to, any other requested entity is rejected
+pub async fn validate_entities_allowed_for_user(
+    state: &SessionState,
+    user_id: &str,
+    tenant_id: &id_type::TenantId,
+    entities: &[user_role_api::Entity],
+) -> UserResult<()> {
+    let allowed_entities = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .filter_map(|user_role| user_role.get_entity_id_and_type())
+        .collect::<HashSet<_>>();
+
+    if let Some(entity) = entities
+        .iter()
+        .find(|entity| !allowed_entities.contains(&(entity.entity_id.clone(), entity.entity_type)))
+    {
+        return Err(report!(UserErrors::EntityNotAllowedForToken)).attach_printable(format!(
+            "User is not associated with {} {}",
+            entity.entity_type, entity.entity_id
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+pub async fn set_invitation_resend_lock_in_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<()> {
+    let redis_conn = super::user::get_redis_connection(state)?;
+    let key = format!(
+        "{}{}",
+        consts::user::REDIS_RESEND_INVITATION_PREFIX,
+        user_id
+    );
+
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &key.as_str().into(),
+            common_utils::date_time::now_unix_timestamp(),
+            Some(consts::user::REDIS_RESEND_INVITATION_TTL_IN_SECS),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?
+    {
+        redis_interface::SetnxReply::KeySet => Ok(()),
+        redis_interface::SetnxReply::KeyNotSet => {
+            Err(UserErrors::InvitationResendTooFrequent.into())
+        }
+    }
+}
+
+/// Fixed window rate limit on role creates and updates, shared by all users of a merchant
+pub async fn check_role_write_rate_limit(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> UserResult<()> {
+    let rate_limit = &state.conf.user.role_write_rate_limit;
+    let window_in_secs = rate_limit.window_in_secs.max(1);
+    let now = common_utils::date_time::now_unix_timestamp();
+    let window_start = now - now.rem_euclid(window_in_secs);
+    let window_end = window_start + window_in_secs;
+
+    let redis_conn = super::user::get_redis_connection(state)?;
+    let key = format!(
+        "{}{}_{}",
+        consts::user::REDIS_ROLE_WRITE_RATE_LIMIT_PREFIX,
+        merchant_id.get_string_repr(),
+        window_start
+    );
+
+    let request_count = redis_conn
+        .increment_fields_in_hash(&key.as_str().into(), &[("count", 1)])
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .first()
+        .copied()
+        .unwrap_or_default();
+
+    if request_count == 1 {
+        redis_conn
+            .set_expire_at(&key.as_str().into(), window_end)
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+    }
+
+    if request_count > rate_limit.max_requests {
+        return Err(report!(UserErrors::RoleWriteRateLimitExceeded {
+            retry_after_in_secs: u64::try_from(window_end - now).unwrap_or_default(),
+        }))
+        .attach_printable(format!(
+            "Merchant made {} role write requests in the current window",
+            request_count
+        ));
+    }
+
+    Ok(())
+}
+
 pub async fn get_single_org_id(
     state: &SessionState,
     user_role: &UserRole,
@@ -507,3 +860,129 @@ pub fn get_min_entity(
 
     Ok(cmp::min(user_entity, filter_entity))
 }
+
+/// Records that the role was used to authenticate a request. Writes are throttled to once per
+/// window per role. Predefined roles have no row in the roles table, so their usage is tracked
+/// per organization in redis instead.
+pub async fn record_role_usage(
+    state: &SessionState,
+    role_id: &str,
+    org_id: &id_type::OrganizationId,
+) -> UserResult<()> {
+    let redis_conn = super::user::get_redis_connection(state)?;
+    let throttle_key = format!(
+        "{}{}_{}",
+        consts::user::REDIS_ROLE_USAGE_THROTTLE_PREFIX,
+        org_id.get_string_repr(),
+        role_id
+    );
+    let now = common_utils::date_time::now();
+
+    let throttle_reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &throttle_key.as_str().into(),
+            now.assume_utc().unix_timestamp(),
+            Some(consts::user::REDIS_ROLE_USAGE_THROTTLE_TTL_IN_SECS),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if let redis_interface::SetnxReply::KeyNotSet = throttle_reply {
+        return Ok(());
+    }
+
+    if roles::predefined_roles::PREDEFINED_ROLES.contains_key(role_id) {
+        let key = format!(
+            "{}{}",
+            consts::user::REDIS_PREDEFINED_ROLE_USAGE_PREFIX,
+            org_id.get_string_repr()
+        );
+        redis_conn
+            .set_hash_fields(
+                &key.as_str().into(),
+                vec![(role_id.to_string(), now.assume_utc().unix_timestamp())],
+                Some(consts::user::REDIS_PREDEFINED_ROLE_USAGE_TTL_IN_SECS),
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)
+    } else {
+        state
+            .global_store
+            .update_role_last_used_at(role_id, now)
+            .await
+            .change_context(UserErrors::InternalServerError)
+    }
+}
+
+/// Last usage of the predefined roles in the organization, keyed by role id
+pub async fn get_predefined_roles_last_used_at(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+) -> UserResult<HashMap<String, PrimitiveDateTime>> {
+    let redis_conn = super::user::get_redis_connection(state)?;
+    let key = format!(
+        "{}{}",
+        consts::user::REDIS_PREDEFINED_ROLE_USAGE_PREFIX,
+        org_id.get_string_repr()
+    );
+
+    let last_used_at = redis_conn
+        .get_hash_fields::<HashMap<String, i64>>(&key.as_str().into())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(last_used_at
+        .into_iter()
+        .filter_map(|(role_id, timestamp)| {
+            time::OffsetDateTime::from_unix_timestamp(timestamp)
+                .ok()
+                .map(|last_used_at| {
+                    (
+                        role_id,
+                        common_utils::date_time::convert_to_pdt(last_used_at),
+                    )
+                })
+        })
+        .collect())
+}
+
+pub fn get_custom_permission_group_response(
+    permission_group: diesel_models::custom_permission_group::CustomPermissionGroup,
+) -> user_role_api::role::CustomPermissionGroupResponse {
+    user_role_api::role::CustomPermissionGroupResponse {
+        group_name: permission_group.group_name,
+        description: permission_group.description,
+        permissions: permission_group.permissions,
+        created_by: permission_group.created_by,
+        created_at: permission_group.created_at,
+    }
+}
+
+pub fn get_role_template_response(
+    role_template: diesel_models::role_template::RoleTemplate,
+    synced_roles: Option<Vec<user_role_api::role::DerivedRoleSyncResponse>>,
+) -> user_role_api::role::RoleTemplateResponse {
+    user_role_api::role::RoleTemplateResponse {
+        template_id: role_template.template_id,
+        role_name: role_template.role_name,
+        groups: role_template.groups,
+        created_by: role_template.created_by,
+        created_at: role_template.created_at,
+        last_modified_at: role_template.last_modified_at,
+        synced_roles,
+    }
+}
+
+pub fn get_role_assignment_rule_response(
+    role_assignment_rule: diesel_models::role_assignment_rule::RoleAssignmentRule,
+) -> user_role_api::RoleAssignmentRuleResponse {
+    user_role_api::RoleAssignmentRuleResponse {
+        rule_id: role_assignment_rule.id,
+        priority: role_assignment_rule.priority,
+        attribute: role_assignment_rule.attribute,
+        value: role_assignment_rule.value,
+        role_id: role_assignment_rule.role_id,
+        created_by: role_assignment_rule.created_by,
+        created_at: role_assignment_rule.created_at,
+    }
+}
@@ -2,12 +2,23 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod card_verification_void;
+pub mod deleted_user_role_purge;
 pub mod outgoing_webhook_retry;
+pub mod payment_intent_expiry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_sync;
 
 pub mod refund_router;
 
+pub mod scheduled_capture;
+
+pub mod stale_payment_sync;
+
 pub mod tokenized_data;
 
+pub mod webhook_replay;
+
 pub mod passive_churn_recovery_workflow;
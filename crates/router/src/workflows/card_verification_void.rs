@@ -0,0 +1,137 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, utils as pt_utils,
+};
+
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+use crate::core::payments::card_verification;
+use crate::{
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+pub struct CardVerificationVoidWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CardVerificationVoidWorkflow {
+    #[cfg(any(feature = "v2", feature = "payment_methods_v2"))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::payment_attempt::CardVerificationVoidTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardVerificationVoidTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The authorization has been voided or captured in the meantime
+        if payment_intent.status != enums::IntentStatus::RequiresCapture {
+            logger::info!(
+                "Skipping the void of the card verification as the payment is in {} status",
+                payment_intent.status
+            );
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        // TODO: Add support for ReqState in PT flows
+        let void_result = card_verification::void_authorization(
+            state,
+            state.get_req_state(),
+            &merchant_account,
+            tracking_data.profile_id,
+            &key_store,
+            tracking_data.payment_id,
+            None, //Platform merchant account
+        )
+        .await;
+
+        if card_verification::is_authorization_voided(&void_result) {
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        if let Err(error) = void_result {
+            logger::error!(
+                ?error,
+                "Failed to void the authorization made to verify the card"
+            );
+        }
+
+        let schedule_time = pt_utils::get_time_from_delta(pt_utils::get_schedule_time(
+            process_data::ConnectorPTMapping::default(),
+            &tracking_data.merchant_id,
+            process.retry_count + 1,
+        ));
+        match schedule_time {
+            Some(schedule_time) => {
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+            None => {
+                logger::error!("Retries exceeded for voiding the card verification authorization");
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                    .await?
+            }
+        };
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
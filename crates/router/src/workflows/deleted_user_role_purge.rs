@@ -0,0 +1,89 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{
+    deleted_user_role::DeletedUserRolePurgeTrackingData, process_tracker::business_status,
+};
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    db::user_role::ListUserRolesByUserIdPayload, errors, logger, routes::SessionState,
+    types::storage,
+};
+
+pub struct DeletedUserRolePurgeWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DeletedUserRolePurgeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: DeletedUserRolePurgeTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DeletedUserRolePurgeTrackingData")?;
+
+        // Deleted user roles which have been restored in the meantime no longer exist
+        for deleted_user_role_id in tracking_data.deleted_user_role_ids {
+            if let Err(error) = state
+                .global_store
+                .delete_deleted_user_role_by_id(deleted_user_role_id)
+                .await
+            {
+                if !error.current_context().is_db_not_found() {
+                    return Err(error.into());
+                }
+            }
+        }
+
+        // Deleting the user was deferred till the deleted user roles could no longer be restored
+        let remaining_user_roles = state
+            .global_store
+            .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+                user_id: &tracking_data.user_id,
+                tenant_id: &tracking_data.tenant_id,
+                org_id: None,
+                merchant_id: None,
+                profile_id: None,
+                entity_id: None,
+                version: None,
+                status: None,
+                limit: Some(1),
+            })
+            .await?;
+
+        let pending_deleted_user_roles = state
+            .global_store
+            .list_deleted_user_roles_by_user_id(&tracking_data.user_id, &tracking_data.tenant_id)
+            .await?;
+
+        if remaining_user_roles.is_empty() && pending_deleted_user_roles.is_empty() {
+            match state
+                .global_store
+                .delete_user_by_user_id(&tracking_data.user_id)
+                .await
+            {
+                Ok(_) => logger::info!("Deleted user without any user roles"),
+                Err(error) if error.current_context().is_db_not_found() => (),
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
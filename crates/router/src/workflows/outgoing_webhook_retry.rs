@@ -165,6 +165,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                     merchant_account.clone(),
                     key_store.clone(),
                     &tracking_data,
+                    event.metadata.as_ref(),
                 ))
                 .await?;
 
@@ -353,6 +354,7 @@ async fn get_outgoing_webhook_content_and_event_type(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     tracking_data: &OutgoingWebhookTrackingData,
+    event_metadata: Option<&storage::EventMetadata>,
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
     use api_models::{
         mandates::MandateId,
@@ -426,7 +428,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::Form(_)
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
-                | ApplicationResponse::FileData(_) => {
+                | ApplicationResponse::FileData(_)
+                | ApplicationResponse::FileStream(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
@@ -483,7 +486,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -515,7 +519,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -565,5 +570,29 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        // User role changes are point in time events, their content is stored along with the
+        // event as it cannot be reconstructed from the current state
+        diesel_models::enums::EventClass::UserRoles => {
+            let user_role_response = match event_metadata {
+                Some(storage::EventMetadata::UserRole { content, .. }) => content
+                    .clone()
+                    .parse_value::<api_models::user_role::UserRoleWebhookContent>(
+                        "UserRoleWebhookContent",
+                    )
+                    .map_err(|_| errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    }),
+                _ => Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                    resource_name: tracking_data.primary_object_id.clone(),
+                }),
+            }
+            .map(Box::new)?;
+
+            Ok((
+                OutgoingWebhookContent::UserRoleDetails(user_role_response),
+                Some(tracking_data.event_type),
+            ))
+        }
     }
 }
@@ -0,0 +1,250 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{api_locking, payments as payment_flows, webhooks as webhooks_core},
+    db::StorageInterface,
+    errors,
+    routes::{lock_utils, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+pub struct PaymentIntentExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentIntentExpiryWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentIntentExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentIntentExpiryTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        // The payment is locked the same way as the payments API does, so that a payment which is
+        // being confirmed is not expired from under the confirmation
+        let lock_action = api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: tracking_data.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::Payments,
+                override_lock_retries: None,
+            },
+        };
+        lock_action
+            .clone()
+            .perform_locking_action(state, tracking_data.merchant_id.clone())
+            .await?;
+
+        let expiry_result =
+            expire_payment_intent(state, &tracking_data, &merchant_account, &key_store).await;
+
+        lock_action
+            .free_lock_action(state, tracking_data.merchant_id.clone())
+            .await?;
+
+        let Some(payment_intent) = expiry_result? else {
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        };
+
+        let profile_id = payment_intent
+            .profile_id
+            .clone()
+            .get_required_value("profile_id")?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+            .await?;
+
+        let retrieve_request = api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id),
+            merchant_id: Some(tracking_data.merchant_id),
+            force_sync: false,
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let payments_response = Box::pin(payment_flows::payments_core::<
+            api::PSync,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::PSync>,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            merchant_account.clone(),
+            None,
+            key_store.clone(),
+            payment_flows::PaymentStatus,
+            retrieve_request,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Avoid,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+            None, //Platform merchant account
+        ))
+        .await?
+        .get_json_body()
+        .map_err(
+            |_| sch_errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: payment_intent.payment_id.get_string_repr().to_owned(),
+            },
+        )?;
+
+        if let Some(event_type) = Option::<enums::EventType>::foreign_from(payments_response.status)
+        {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account,
+                business_profile,
+                &key_store,
+                event_type,
+                enums::EventClass::Payments,
+                payment_intent.payment_id.get_string_repr().to_owned(),
+                enums::EventObjectType::PaymentDetails,
+                api::OutgoingWebhookContent::PaymentDetails(Box::new(payments_response)),
+                Some(payment_intent.created_at),
+            ))
+            .await
+            .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+            .ok();
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Returns whether a payment in the status is awaiting the customer, in which case it expires if it
+/// is not confirmed in time
+pub fn is_payment_intent_expirable(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation
+    )
+}
+
+/// Marks the payment as expired, and returns the expired payment. Nothing is done if the payment
+/// has been confirmed or cancelled in the meantime.
+#[cfg(feature = "v1")]
+async fn expire_payment_intent(
+    state: &SessionState,
+    tracking_data: &storage::PaymentIntentExpiryTrackingData,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> Result<Option<storage::PaymentIntent>, sch_errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key_manager_state = &state.into();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    if !is_payment_intent_expirable(payment_intent.status) {
+        logger::info!(
+            "Skipping payment expiry as the payment intent is in {} status",
+            payment_intent.status
+        );
+        return Ok(None);
+    }
+
+    let payment_intent = db
+        .update_payment_intent(
+            key_manager_state,
+            payment_intent,
+            storage::PaymentIntentUpdate::ManualUpdate {
+                status: Some(enums::IntentStatus::Expired),
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    Ok(Some(payment_intent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_payments_awaiting_the_customer_expire() {
+        assert!(is_payment_intent_expirable(
+            enums::IntentStatus::RequiresPaymentMethod
+        ));
+        assert!(is_payment_intent_expirable(
+            enums::IntentStatus::RequiresConfirmation
+        ));
+        for status in [
+            enums::IntentStatus::Processing,
+            enums::IntentStatus::RequiresCustomerAction,
+            enums::IntentStatus::RequiresCapture,
+            enums::IntentStatus::Succeeded,
+            enums::IntentStatus::Failed,
+            enums::IntentStatus::Cancelled,
+            enums::IntentStatus::Expired,
+        ] {
+            assert!(!is_payment_intent_expirable(status));
+        }
+    }
+}
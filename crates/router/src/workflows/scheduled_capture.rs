@@ -0,0 +1,185 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payments as payment_flows,
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+const AUTHORIZATION_EXPIRED_ERROR_CODE: &str = "AUTHORIZATION_EXPIRED";
+const AUTHORIZATION_EXPIRED_ERROR_MESSAGE: &str =
+    "Authorization expired before the scheduled capture";
+
+pub struct ScheduledCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ScheduledCaptureWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::payment_attempt::ScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledCaptureTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &tracking_data.attempt_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment has been captured, voided or has failed in the meantime
+        if payment_attempt.status != enums::AttemptStatus::Authorized {
+            logger::info!(
+                "Skipping scheduled capture as the payment attempt is in {} status",
+                payment_attempt.status
+            );
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        let authorization_expires_at =
+            payment_attempt
+                .created_at
+                .saturating_add(time::Duration::hours(i64::from(
+                    state.conf.delayed_capture.authorization_validity_in_hours,
+                )));
+
+        if common_utils::date_time::now() > authorization_expires_at {
+            logger::info!("Authorization expired before the scheduled capture");
+
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    key_manager_state,
+                    &tracking_data.payment_id,
+                    &tracking_data.merchant_id,
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+
+            db.update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::ManualUpdate {
+                    status: Some(enums::AttemptStatus::CaptureFailed),
+                    error_code: Some(AUTHORIZATION_EXPIRED_ERROR_CODE.to_string()),
+                    error_message: Some(AUTHORIZATION_EXPIRED_ERROR_MESSAGE.to_string()),
+                    error_reason: Some(AUTHORIZATION_EXPIRED_ERROR_MESSAGE.to_string()),
+                    updated_by: merchant_account.storage_scheme.to_string(),
+                    unified_code: None,
+                    unified_message: None,
+                    connector_transaction_id: None,
+                },
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+            db.update_payment_intent(
+                key_manager_state,
+                payment_intent,
+                storage::PaymentIntentUpdate::ManualUpdate {
+                    status: Some(enums::IntentStatus::Failed),
+                    updated_by: merchant_account.storage_scheme.to_string(),
+                },
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::FAILURE)
+                .await?);
+        }
+
+        let capture_request = api::PaymentsCaptureRequest {
+            payment_id: tracking_data.payment_id,
+            merchant_id: Some(tracking_data.merchant_id),
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        Box::pin(payment_flows::payments_core::<
+            api::Capture,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::Capture>,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            merchant_account,
+            None,
+            key_store,
+            payment_flows::PaymentCapture,
+            capture_request,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Trigger,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+            None, //Platform merchant account
+        ))
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    configs::settings::StalePaymentSyncConfig,
+    core::{
+        errors::{RouterResult, StorageErrorExt},
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+/// Statuses of the attempts whose outcome is awaited from the connector
+#[cfg(feature = "v1")]
+const PENDING_ATTEMPT_STATUSES: [enums::AttemptStatus; 5] = [
+    enums::AttemptStatus::Pending,
+    enums::AttemptStatus::Authorizing,
+    enums::AttemptStatus::CodInitiated,
+    enums::AttemptStatus::CaptureInitiated,
+    enums::AttemptStatus::VoidInitiated,
+];
+
+pub struct StalePaymentSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for StalePaymentSyncWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let config = &state.conf.stale_payment_sync;
+
+        // The task is rescheduled when the scheduler is started after the job is enabled again
+        if !config.enabled {
+            logger::info!("Finishing the stale payment sync task as the job is disabled");
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        let mut tracking_data: storage::StalePaymentSyncTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("StalePaymentSyncTrackingData")?;
+        let now = common_utils::date_time::now();
+
+        // The payments of connectors which are backed off are left out, so that they do not take
+        // up the batch of the other connectors
+        let backed_off_connectors = tracking_data
+            .connector_backoffs
+            .iter()
+            .filter(|(_, backoff)| backoff.retry_after > now)
+            .map(|(connector, _)| connector.clone())
+            .collect();
+        let time_range = common_utils::types::TimeRange {
+            start_time: now - time::Duration::seconds(config.lookback_in_seconds),
+            end_time: Some(now - time::Duration::seconds(config.stale_after_in_seconds)),
+        };
+        let stale_payment_attempts = db
+            .find_payment_attempts_by_statuses_modified_within(
+                PENDING_ATTEMPT_STATUSES.to_vec(),
+                &time_range,
+                backed_off_connectors,
+                config.batch_size,
+            )
+            .await?;
+
+        let mut payment_attempts_by_connector: HashMap<String, Vec<storage::PaymentAttempt>> =
+            HashMap::new();
+        for payment_attempt in stale_payment_attempts {
+            if let Some(connector) = payment_attempt.connector.clone() {
+                payment_attempts_by_connector
+                    .entry(connector)
+                    .or_default()
+                    .push(payment_attempt);
+            }
+        }
+
+        // Connectors are synced with concurrently, so that a slow connector does not hold up the
+        // others, while the payments of a connector are synced one after another
+        let connector_outcomes =
+            futures::future::join_all(payment_attempts_by_connector.into_iter().map(
+                |(connector, payment_attempts)| async move {
+                    let is_connector_down =
+                        sync_connector_payments(state, &connector, payment_attempts).await;
+                    (connector, is_connector_down)
+                },
+            ))
+            .await;
+
+        for (connector, is_connector_down) in connector_outcomes {
+            if is_connector_down {
+                let failed_runs = tracking_data
+                    .connector_backoffs
+                    .get(&connector)
+                    .map_or(0, |backoff| backoff.failed_runs)
+                    .saturating_add(1);
+                let backoff_duration = get_backoff_duration(config, failed_runs);
+                logger::warn!(
+                    "Backing off stale payment sync for connector: {connector} for {backoff_duration}"
+                );
+                tracking_data.connector_backoffs.insert(
+                    connector,
+                    storage::ConnectorSyncBackoff {
+                        failed_runs,
+                        retry_after: now + backoff_duration,
+                    },
+                );
+            } else {
+                tracking_data.connector_backoffs.remove(&connector);
+            }
+        }
+
+        // Back offs of connectors without any stale payments since are dropped eventually
+        let max_backoff = time::Duration::seconds(config.max_backoff_in_seconds);
+        tracking_data
+            .connector_backoffs
+            .retain(|_, backoff| backoff.retry_after + max_backoff > now);
+
+        let tracking_data = tracking_data.encode_to_value()?;
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(now + time::Duration::seconds(config.interval_in_seconds)),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Outcome of syncing the status of a stale payment with the connector
+#[cfg(feature = "v1")]
+enum SyncOutcome {
+    Synced,
+    /// The attempt has been superseded by a newer attempt of the payment
+    Skipped,
+    ConnectorDown,
+}
+
+/// Syncs the status of the stale payments of a connector, and returns whether the connector is
+/// down. The remaining payments of a connector which is down are left to a later run.
+#[cfg(feature = "v1")]
+async fn sync_connector_payments(
+    state: &SessionState,
+    connector: &str,
+    payment_attempts: Vec<storage::PaymentAttempt>,
+) -> bool {
+    let failure_threshold = state
+        .conf
+        .stale_payment_sync
+        .connector_failure_threshold
+        .max(1);
+    let mut consecutive_failures = 0;
+
+    for payment_attempt in payment_attempts {
+        match sync_payment(state, &payment_attempt).await {
+            Ok(SyncOutcome::Synced) => consecutive_failures = 0,
+            Ok(SyncOutcome::Skipped) => (),
+            Ok(SyncOutcome::ConnectorDown) => consecutive_failures += 1,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to sync the status of stale payment: {}",
+                    payment_attempt.payment_id.get_string_repr()
+                );
+                consecutive_failures += 1;
+            }
+        }
+
+        if consecutive_failures >= failure_threshold {
+            logger::warn!(
+                "Connector: {connector} is down, failed to sync {consecutive_failures} stale payments in a row"
+            );
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(feature = "v1")]
+async fn sync_payment(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<SyncOutcome> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &payment_attempt.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &payment_attempt.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &payment_attempt.payment_id,
+            &payment_attempt.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    if payment_intent.active_attempt.get_id() != payment_attempt.attempt_id {
+        return Ok(SyncOutcome::Skipped);
+    }
+
+    let retrieve_request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_attempt.payment_id.clone()),
+        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        force_sync: true,
+        ..Default::default()
+    };
+
+    // Webhooks are sent to the merchant by the payments core, if the payment reaches a status
+    // which the merchant is notified of
+    // TODO: Add support for ReqState in PT flows
+    let (_, _, _, connector_http_status_code, _) =
+        Box::pin(payment_flows::payments_operation_core::<
+            api::PSync,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::PSync>,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account,
+            None,
+            key_store,
+            operations::PaymentStatus,
+            retrieve_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+            None, //Platform merchant account
+        ))
+        .await?;
+
+    Ok(
+        if connector_http_status_code.is_some_and(|status_code| status_code >= 500) {
+            SyncOutcome::ConnectorDown
+        } else {
+            SyncOutcome::Synced
+        },
+    )
+}
+
+/// Returns the time for which a connector is backed off, which is doubled for every consecutive
+/// run in which the connector is down
+fn get_backoff_duration(config: &StalePaymentSyncConfig, failed_runs: u32) -> time::Duration {
+    let backoff_in_seconds = 2_i64
+        .checked_pow(failed_runs.saturating_sub(1))
+        .and_then(|multiplier| config.backoff_in_seconds.checked_mul(multiplier))
+        .unwrap_or(config.max_backoff_in_seconds)
+        .min(config.max_backoff_in_seconds);
+    time::Duration::seconds(backoff_in_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_duration_doubles_up_to_the_limit() {
+        let config = StalePaymentSyncConfig {
+            backoff_in_seconds: 600,
+            max_backoff_in_seconds: 3_600,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            [1, 2, 3, 4, 64].map(|failed_runs| get_backoff_duration(&config, failed_runs)),
+            [600, 1_200, 2_400, 3_600, 3_600].map(time::Duration::seconds)
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::webhooks::webhook_events,
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+pub struct WebhookReplayWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookReplayWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let config = &state.conf.webhook_replay;
+        let mut tracking_data: storage::WebhookReplayTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("WebhookReplayTrackingData")?;
+
+        let batch_size = config.events_per_batch.max(1);
+        let listed_events =
+            webhook_events::replay_webhooks_batch(state, &mut tracking_data, batch_size).await?;
+
+        // A batch smaller than the batch size is the last batch of the time range
+        if i64::try_from(listed_events).unwrap_or(i64::MAX) < batch_size {
+            logger::info!(
+                "Finished webhook replay for merchant: {}, replayed: {}, skipped: {}, failed: {}",
+                tracking_data.merchant_id.get_string_repr(),
+                tracking_data.replayed_events,
+                tracking_data.skipped_events,
+                tracking_data.failed_events
+            );
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        // The next batch is replayed after an interval, so that the merchant endpoint is not
+        // overwhelmed by a replay of a large time range
+        let now = common_utils::date_time::now();
+        let tracking_data = tracking_data.encode_to_value()?;
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(
+                        now + time::Duration::seconds(config.batch_interval_in_seconds),
+                    ),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
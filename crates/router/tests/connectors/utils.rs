@@ -984,6 +984,7 @@ impl Default for PaymentAuthorizeType {
             shipping_cost: None,
             merchant_account_id: None,
             merchant_config_currency: None,
+            three_ds_challenge_flow: None,
         };
         Self(data)
     }
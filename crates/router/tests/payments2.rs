@@ -215,6 +215,7 @@ async fn payments_create_core() {
         connector_mandate_id: None,
         shipping_cost: None,
         card_discovery: None,
+        fx_details: None,
     };
 
     let expected_response =
@@ -491,6 +492,7 @@ async fn payments_create_core_adyen_no_redirect() {
             connector_mandate_id: None,
             shipping_cost: None,
             card_discovery: None,
+            fx_details: None,
         },
         vec![],
     ));
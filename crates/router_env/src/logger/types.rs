@@ -104,6 +104,8 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers merge flow.
+    CustomersMerge,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -132,6 +134,8 @@ pub enum Flow {
     PaymentMethodCollectLink,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
+    /// List the merchant connector accounts and currencies with which a payment method can be reused
+    PaymentMethodContextsList,
     /// Payment methods update flow.
     PaymentMethodsUpdate,
     /// Payment methods delete flow.
@@ -164,6 +168,8 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payments search by metadata flow
+    PaymentsMetadataSearch,
     /// Payments filters flow
     PaymentsFilters,
     /// Payments aggregates flow
@@ -223,6 +229,10 @@ pub enum Flow {
     RefundsFilters,
     /// Refunds aggregates flow
     RefundsAggregate,
+    /// Refunds eligibility flow
+    RefundsEligibility,
+    /// Refunds bulk create flow
+    RefundsBulkCreate,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -233,6 +243,8 @@ pub enum Flow {
     ReconServiceRequest,
     /// Recon token verification flow
     ReconVerifyToken,
+    /// Reconciliation report generation flow
+    ReconReportGenerate,
     /// Routing create flow,
     RoutingCreateConfig,
     /// Routing link config
@@ -297,6 +309,8 @@ pub enum Flow {
     RetrieveFile,
     /// Dispute Evidence submission flow
     DisputesEvidenceSubmit,
+    /// Dispute Evidence bundle upload and submission flow
+    DisputesEvidenceBundleSubmit,
     /// Create Config Key flow
     CreateConfigKey,
     /// Attach Dispute Evidence flow
@@ -309,6 +323,10 @@ pub enum Flow {
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Retrieve the circuit breaker state of a connector
+    ConnectorCircuitBreakerRetrieve,
+    /// Retrieve the response time statistics of a connector
+    ConnectorLatencyRetrieve,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
@@ -319,6 +337,10 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Create a signed single use payment link for a payment
+    SignedPaymentLinkCreate,
+    /// Open a signed single use payment link
+    SignedPaymentLinkInitiate,
     /// Create a profile
     ProfileCreate,
     /// Update a profile
@@ -363,6 +385,8 @@ pub enum Flow {
     FrmFulfillment,
     /// Get connectors feature matrix
     FeatureMatrix,
+    /// Get the feature matrix of a single connector
+    ConnectorFeatureMatrix,
     /// Change password flow
     ChangePassword,
     /// Signout flow
@@ -375,6 +399,8 @@ pub enum Flow {
     VerifyPaymentConnector,
     /// Internal user signup
     InternalUserSignup,
+    /// Impersonate a user for support
+    ImpersonateUser,
     /// Create tenant level user
     TenantUserCreate,
     /// Switch org
@@ -403,8 +429,14 @@ pub enum Flow {
     GetRoleFromToken,
     /// Get resources and groups for role from token
     GetRoleFromTokenV2,
+    /// Get effective permissions for role from token
+    GetMyPermissions,
+    /// Get identity, lineage and permissions of the current user
+    WhoAmI,
     /// Update user role
     UpdateUserRole,
+    /// Update roles of multiple users
+    BulkUpdateUserRoles,
     /// Create merchant account for user in a org
     UserMerchantAccountCreate,
     /// Create Org in a given tenancy
@@ -431,10 +463,16 @@ pub enum Flow {
     InviteMultipleUser,
     /// Reinvite user
     ReInviteUser,
+    /// Resend a pending invitation using user id
+    ResendInvitation,
+    /// Invite an email which does not have an account yet
+    InviteUserByEmail,
     /// Accept invite from email
     AcceptInviteFromEmail,
     /// Delete user role
     DeleteUserRole,
+    /// Restore a user role deleted within the grace period
+    UndoDeleteUserRole,
     /// Incremental Authorization flow
     PaymentsIncrementalAuthorization,
     /// Get action URL for connector onboarding
@@ -451,16 +489,46 @@ pub enum Flow {
     UpdateUserAccountDetails,
     /// Accept user invitation using entities
     AcceptInvitationsV2,
+    /// Accept all pending invitations of the user
+    AcceptAllInvitations,
     /// Accept user invitation using entities before user login
     AcceptInvitationsPreAuth,
+    /// List merchants which can be selected before user login
+    ListSwitchableMerchants,
     /// Initiate external authentication for a payment
     PaymentsExternalAuthentication,
     /// Authorize the payment after external 3ds authentication
     PaymentsAuthorize,
     /// Create Role
     CreateRole,
+    /// Clone an existing role into a new custom role
+    CloneRole,
+    /// Compare permission groups of two roles
+    CompareRoles,
+    /// List role audit records
+    ListRoleAudit,
     /// Update Role
     UpdateRole,
+    /// Preview the users affected by a role update
+    PreviewRoleUpdate,
+    /// Soft delete a custom role
+    DeleteRole,
+    /// Restore a soft deleted custom role
+    RestoreRole,
+    /// Create a custom permission group
+    CreatePermissionGroup,
+    /// List custom permission groups
+    ListPermissionGroups,
+    /// Create an organization level role template
+    CreateRoleTemplate,
+    /// Update a role template
+    UpdateRoleTemplate,
+    /// Create roles from a role template for multiple merchants
+    ApplyRoleTemplate,
+    /// Create a rule assigning a role to users accepting merchant invitations
+    CreateRoleAssignmentRule,
+    /// List role assignment rules of a merchant
+    ListRoleAssignmentRules,
     /// User email flow start
     UserFromEmail,
     /// Begin TOTP
@@ -499,6 +567,14 @@ pub enum Flow {
     ListProfileForUserInOrgAndMerchant,
     /// List Users in Org
     ListUsersInLineage,
+    /// List roles held by a user across merchants
+    ListRolesForUser,
+    /// Check user role data for references to missing roles or merchants
+    CheckRoleIntegrity,
+    /// Export the custom roles and user role assignments of a merchant
+    ExportRbac,
+    /// Import custom roles and user role assignments from an export into a merchant
+    ImportRbac,
     /// List invitations for user
     ListInvitationsForUser,
     /// Get theme using lineage
@@ -519,6 +595,12 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Replay the webhook events created within a time range
+    WebhookEventReplay,
+    /// Retrieve the webhook event types a merchant is subscribed to
+    WebhookEventSubscriptionRetrieve,
+    /// Update the webhook event types a merchant is subscribed to
+    WebhookEventSubscriptionUpdate,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -531,6 +613,12 @@ pub enum Flow {
     RefundsManualUpdate,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
+    /// Retrieve the masked raw connector requests and responses stored for a payment
+    PaymentsRawConnectorPayloadsRetrieve,
+    /// Retrieve the chronological timeline of events of a payment
+    PaymentsTimelineRetrieve,
+    /// Verify a card without charging the customer
+    PaymentsVerifyCard,
     /// Dynamic Tax Calcultion
     SessionUpdateTaxCalculation,
     ProxyConfirmIntent,
@@ -156,6 +156,38 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_by_statuses_modified_within(
+        &self,
+        statuses: Vec<storage_enums::AttemptStatus>,
+        time_range: &common_utils::types::TimeRange,
+        excluded_connectors: Vec<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+        let modified_before = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        let mut stale_payment_attempts: Vec<PaymentAttempt> = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                statuses.contains(&payment_attempt.status)
+                    && payment_attempt.modified_at >= time_range.start_time
+                    && payment_attempt.modified_at <= modified_before
+                    && payment_attempt
+                        .connector
+                        .as_ref()
+                        .is_some_and(|connector| !excluded_connectors.contains(connector))
+            })
+            .cloned()
+            .collect();
+        stale_payment_attempts.sort_by_key(|payment_attempt| payment_attempt.modified_at);
+        stale_payment_attempts.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(stale_payment_attempts)
+    }
+
     #[cfg(feature = "v1")]
     #[allow(clippy::panic)]
     async fn insert_payment_attempt(
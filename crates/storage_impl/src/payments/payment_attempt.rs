@@ -381,6 +381,38 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             })
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_attempts_by_statuses_modified_within(
+        &self,
+        statuses: Vec<diesel_models::enums::AttemptStatus>,
+        time_range: &common_utils::types::TimeRange,
+        excluded_connectors: Vec<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::find_by_statuses_modified_between(
+            &conn,
+            statuses,
+            time_range.start_time,
+            time_range
+                .end_time
+                .unwrap_or_else(common_utils::date_time::now),
+            excluded_connectors,
+            limit,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(*er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|a| {
+            a.into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
+    }
+
     #[cfg(feature = "v1")]
     #[instrument(skip_all)]
     async fn find_payment_attempt_by_attempt_id_merchant_id(
@@ -1423,6 +1455,27 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_attempts_by_statuses_modified_within(
+        &self,
+        statuses: Vec<diesel_models::enums::AttemptStatus>,
+        time_range: &common_utils::types::TimeRange,
+        excluded_connectors: Vec<String>,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError> {
+        // Attempts of all merchants can only be looked up in the database, so the attempts of
+        // merchants using the KV store are found once they have been drained
+        self.router_store
+            .find_payment_attempts_by_statuses_modified_within(
+                statuses,
+                time_range,
+                excluded_connectors,
+                limit,
+            )
+            .await
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     #[instrument(skip_all)]
     async fn get_filters_for_payments(
@@ -731,6 +731,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
         use common_utils::errors::ReportSwitchExt;
+        use diesel::PgAnyJsonExpressionMethods;
         use futures::{future::try_join_all, FutureExt};
 
         let conn = connection::pg_connection_read(self).await.switch()?;
@@ -816,6 +817,16 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(status) = &params.status {
                     query = query.filter(pi_dsl::status.eq_any(status.clone()));
                 }
+
+                // Served by the expression index on the metadata key, the value is compared as
+                // text so that it matches irrespective of the JSON type it is stored as
+                if let Some((key, value)) = &params.metadata {
+                    query = query.filter(
+                        pi_dsl::metadata
+                            .retrieve_as_text(key.clone())
+                            .eq(value.clone()),
+                    );
+                }
             }
         }
 
@@ -922,6 +933,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         merchant_key_store: &MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<(PaymentIntent, PaymentAttempt)>, StorageError> {
+        use diesel::BoolExpressionMethods;
         use futures::{future::try_join_all, FutureExt};
 
         use crate::DataModelExt;
@@ -941,6 +953,8 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 query.filter(pi_dsl::payment_id.eq(payment_intent_id.to_owned()))
             }
             PaymentIntentFetchConstraints::List(params) => {
+                // The payment id breaks ties between payments created at the same time, so that
+                // pages listed after a payment do not overlap or skip payments
                 query = match params.order {
                     Order {
                         on: SortOn::Amount,
@@ -953,17 +967,43 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     Order {
                         on: SortOn::Created,
                         by: SortBy::Asc,
-                    } => query.order(pi_dsl::created_at.asc()),
+                    } => query.order((pi_dsl::created_at.asc(), pi_dsl::payment_id.asc())),
                     Order {
                         on: SortOn::Created,
                         by: SortBy::Desc,
-                    } => query.order(pi_dsl::created_at.desc()),
+                    } => query.order((pi_dsl::created_at.desc(), pi_dsl::payment_id.desc())),
                 };
 
                 if let Some(limit) = params.limit {
                     query = query.limit(limit.into());
                 }
 
+                query = match (&params.page_after, &params.order) {
+                    (
+                        Some((created_at, payment_id)),
+                        Order {
+                            on: SortOn::Created,
+                            by: SortBy::Asc,
+                        },
+                    ) => query.filter(
+                        pi_dsl::created_at.gt(*created_at).or(pi_dsl::created_at
+                            .eq(*created_at)
+                            .and(pi_dsl::payment_id.gt(payment_id.clone()))),
+                    ),
+                    (
+                        Some((created_at, payment_id)),
+                        Order {
+                            on: SortOn::Created,
+                            by: SortBy::Desc,
+                        },
+                    ) => query.filter(
+                        pi_dsl::created_at.lt(*created_at).or(pi_dsl::created_at
+                            .eq(*created_at)
+                            .and(pi_dsl::payment_id.lt(payment_id.clone()))),
+                    ),
+                    _ => query,
+                };
+
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(pi_dsl::customer_id.eq(customer_id.clone()));
                 }
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS role_audit_tenant_org_merchant_index;
+
+DROP TABLE IF EXISTS role_audit;
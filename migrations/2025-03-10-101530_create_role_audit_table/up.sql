@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS role_audit (
+    id SERIAL PRIMARY KEY,
+    action VARCHAR(64) NOT NULL,
+    actor_user_id VARCHAR(64) NOT NULL,
+    target_id VARCHAR(64) NOT NULL,
+    groups_before TEXT[] NOT NULL,
+    groups_after TEXT[] NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(64) NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS role_audit_tenant_org_merchant_index ON role_audit (tenant_id, org_id, merchant_id, created_at);
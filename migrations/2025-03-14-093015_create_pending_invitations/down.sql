@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS pending_invitations_email_tenant_merchant_index;
+
+DROP TABLE IF EXISTS pending_invitations;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS pending_invitations (
+    id SERIAL PRIMARY KEY,
+    email VARCHAR(255) NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    entity_id VARCHAR(64) NOT NULL,
+    entity_type VARCHAR(64) NOT NULL,
+    role_id VARCHAR(64) NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS pending_invitations_email_tenant_merchant_index ON pending_invitations (email, tenant_id, merchant_id);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE role_audit DROP COLUMN IF EXISTS reason;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE role_audit ADD COLUMN IF NOT EXISTS reason VARCHAR(255) DEFAULT NULL;
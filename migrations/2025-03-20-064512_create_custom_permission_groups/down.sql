@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE roles DROP COLUMN IF EXISTS custom_permissions;
+
+DROP INDEX IF EXISTS custom_permission_groups_unique_name_index;
+
+DROP TABLE IF EXISTS custom_permission_groups;
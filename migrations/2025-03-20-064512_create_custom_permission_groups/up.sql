@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS custom_permission_groups (
+    id SERIAL PRIMARY KEY,
+    group_name VARCHAR(64) NOT NULL,
+    description VARCHAR(255) DEFAULT NULL,
+    permissions TEXT[] NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(64) NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS custom_permission_groups_unique_name_index ON custom_permission_groups (tenant_id, org_id, merchant_id, group_name);
+
+ALTER TABLE roles ADD COLUMN IF NOT EXISTS custom_permissions TEXT[] DEFAULT NULL;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS roles_template_id_index;
+
+ALTER TABLE roles DROP COLUMN IF EXISTS template_id;
+
+DROP INDEX IF EXISTS role_templates_unique_role_name_index;
+
+DROP TABLE IF EXISTS role_templates;
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS role_templates (
+    template_id VARCHAR(64) PRIMARY KEY,
+    role_name VARCHAR(64) NOT NULL,
+    groups TEXT[] NOT NULL,
+    org_id VARCHAR(64) NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_by VARCHAR(64) NOT NULL,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS role_templates_unique_role_name_index ON role_templates (tenant_id, org_id, role_name);
+
+ALTER TABLE roles ADD COLUMN IF NOT EXISTS template_id VARCHAR(64) DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS roles_template_id_index ON roles (template_id);
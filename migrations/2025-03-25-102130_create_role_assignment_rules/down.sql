@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS role_assignment_rules_unique_priority_index;
+
+DROP TABLE IF EXISTS role_assignment_rules;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS role_assignment_rules (
+    id SERIAL PRIMARY KEY,
+    priority INTEGER NOT NULL,
+    attribute VARCHAR(64) NOT NULL,
+    value VARCHAR(255) NOT NULL,
+    role_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(64) NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS role_assignment_rules_unique_priority_index ON role_assignment_rules (tenant_id, org_id, merchant_id, priority);
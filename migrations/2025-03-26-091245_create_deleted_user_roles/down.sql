@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS deleted_user_roles_user_id_index;
+
+DROP TABLE IF EXISTS deleted_user_roles;
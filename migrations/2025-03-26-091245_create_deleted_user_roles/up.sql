@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS deleted_user_roles (
+    id SERIAL PRIMARY KEY,
+    user_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64),
+    role_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(64),
+    status "UserStatus" NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    last_modified_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    last_modified TIMESTAMP NOT NULL,
+    profile_id VARCHAR(64),
+    entity_id VARCHAR(64),
+    entity_type VARCHAR(64),
+    version "UserRoleVersion" NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL,
+    expires_at TIMESTAMP,
+    deleted_by VARCHAR(64) NOT NULL,
+    deleted_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS deleted_user_roles_user_id_index ON deleted_user_roles (user_id, tenant_id);
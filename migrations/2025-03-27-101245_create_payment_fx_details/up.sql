@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_fx_details (
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    provider VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    settlement_amount BIGINT NOT NULL,
+    settlement_currency "Currency" NOT NULL,
+    exchange_rate VARCHAR(64) NOT NULL,
+    rate_fetched_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, attempt_id)
+);
+
+CREATE INDEX IF NOT EXISTS payment_fx_details_payment_id_index ON payment_fx_details (merchant_id, payment_id);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "ScaExemptionType" ADD VALUE IF NOT EXISTS 'recurring';
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX CONCURRENTLY IF EXISTS payment_intent_metadata_udf1_index;
+
+DROP INDEX CONCURRENTLY IF EXISTS payment_intent_metadata_udf2_index;
+
+DROP INDEX CONCURRENTLY IF EXISTS payment_intent_metadata_udf3_index;
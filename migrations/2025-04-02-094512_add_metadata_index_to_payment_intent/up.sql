@@ -0,0 +1,8 @@
+-- Your SQL goes here
+-- Expression indexes on the metadata keys by which payments can be searched by default, the index
+-- of any other key listed in `payment_metadata_search.indexed_keys` has to be created likewise
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_intent_metadata_udf1_index ON payment_intent (merchant_id, (metadata ->> 'udf1'));
+
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_intent_metadata_udf2_index ON payment_intent (merchant_id, (metadata ->> 'udf2'));
+
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_intent_metadata_udf3_index ON payment_intent (merchant_id, (metadata ->> 'udf3'));
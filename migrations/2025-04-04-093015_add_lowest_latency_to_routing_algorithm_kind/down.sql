@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM pg_enum
+WHERE enumlabel = 'lowest_latency'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'RoutingAlgorithmKind'
+);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_attempt_status_modified_at_index ON payment_attempt (status, modified_at);